@@ -1,19 +1,220 @@
 //! Octo-Sleeper: Batch processor for building the Octo-Index.
 //!
-//! This worker processes the top 10,000 crates from the crates.io db-dump,
-//! downloads their source, runs static analysis, and builds the compressed index.
+//! This worker processes the top crates from the crates.io db-dump, downloads
+//! their source, runs static analysis, and builds the compressed index. The
+//! pipeline is split into independently runnable stages so each one is
+//! resumable and its output cacheable between runs:
 //!
-//! Usage:
-//!   cargo run --bin octo-sleeper -- --db-dump ./db-dump/2026-01-11-020011 --output octo-index.bin
+//!   octo-sleeper fetch   --db-dump <dir>                    # warm the source cache
+//!   octo-sleeper analyze --db-dump <dir> -o metrics.bin      # static analysis -> intermediate metrics
+//!   octo-sleeper build   -m metrics.bin -o octo-index.bin    # assemble the Octo-Index
+//!   octo-sleeper query   -i octo-index.bin <crate-name>      # nearest-neighbor lookup
+//!   octo-sleeper bench   -i octo-index.bin                   # load/search timing
 
 use anyhow::{Context, Result};
+use clap::{Parser, Subcommand};
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use tokio::sync::{Semaphore, mpsc};
 
 // Import from the main crate
-use cratefind::octo_index::{OctoIndex, OctonionProfile, RawMetrics};
+use cratefind::octo_index::{OctoIndex, OctonionProfile, RawMetrics, build_query};
+
+use octo_fetch::FetchConfig;
+
+/// Downloads crate source tarballs from crates.io for crates that aren't
+/// already unpacked in the local cargo registry.
+mod octo_fetch {
+    use anyhow::{Context, Result};
+    use sha2::{Digest, Sha256};
+    use std::path::{Path, PathBuf};
+
+    /// Base URL for crates.io's static tarball host.
+    const CRATES_IO_BASE: &str = "https://static.crates.io/crates";
+
+    const USER_AGENT: &str = concat!(
+        "octo-sleeper/",
+        env!("CARGO_PKG_VERSION"),
+        " (https://github.com/xandwr/fastdeps)"
+    );
+
+    /// Shared download/extraction settings threaded through `process_crates`.
+    #[derive(Clone)]
+    pub struct FetchConfig {
+        pub client: reqwest::Client,
+        pub cache_dir: PathBuf,
+        pub offline: bool,
+    }
+
+    impl FetchConfig {
+        pub fn new(cache_dir: PathBuf, offline: bool) -> Result<Self> {
+            let client = reqwest::Client::builder()
+                .user_agent(USER_AGENT)
+                .build()
+                .context("failed to build HTTP client")?;
+            Ok(Self {
+                client,
+                cache_dir,
+                offline,
+            })
+        }
+
+        fn tarball_path(&self, name: &str, version: &str) -> PathBuf {
+            self.cache_dir
+                .join("crates")
+                .join(format!("{}-{}.crate", name, version))
+        }
+
+        fn extracted_dir(&self, name: &str, version: &str) -> PathBuf {
+            self.cache_dir
+                .join("src")
+                .join(format!("{}-{}", name, version))
+        }
+
+        /// Fetch (or reuse a cached copy of) a crate's tarball and extract it,
+        /// returning the directory containing its source.
+        ///
+        /// `checksum` is the expected SHA-256 hex digest from the db-dump's
+        /// `versions.csv`; a freshly downloaded tarball that doesn't match is
+        /// discarded rather than handed to `syn`.
+        pub async fn fetch_source(
+            &self,
+            name: &str,
+            version: &str,
+            checksum: &str,
+        ) -> Result<PathBuf> {
+            let extracted = self.extracted_dir(name, version);
+            if extracted.exists() {
+                return Ok(extracted);
+            }
+
+            let tarball = self.tarball_path(name, version);
+            if !tarball.exists() {
+                if self.offline {
+                    anyhow::bail!(
+                        "{}-{} not cached and --offline is set; skipping download",
+                        name,
+                        version
+                    );
+                }
+                self.download_tarball(name, version, checksum, &tarball)
+                    .await?;
+            }
+
+            let tarball_clone = tarball.clone();
+            let dest_parent = self.cache_dir.join("src");
+            let name_owned = name.to_string();
+            let version_owned = version.to_string();
+            let extracted_dir = tokio::task::spawn_blocking(move || {
+                extract_tarball(&tarball_clone, &dest_parent, &name_owned, &version_owned)
+            })
+            .await??;
+
+            Ok(extracted_dir)
+        }
+
+        async fn download_tarball(
+            &self,
+            name: &str,
+            version: &str,
+            checksum: &str,
+            dest: &Path,
+        ) -> Result<()> {
+            if let Some(parent) = dest.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+
+            let url = format!("{}/{}/{}-{}.crate", CRATES_IO_BASE, name, name, version);
+            let mut response = self
+                .client
+                .get(&url)
+                .send()
+                .await
+                .with_context(|| format!("failed to request {}", url))?
+                .error_for_status()
+                .with_context(|| format!("non-success status fetching {}", url))?;
+
+            // Stream chunks to disk while hashing incrementally, so we never
+            // have to hold the whole tarball in memory. Chunks go to a temp
+            // file that is only renamed into place once verified, so a crash
+            // mid-download doesn't leave a truncated tarball that looks
+            // "cached" next run.
+            use std::io::Write;
+            let tmp_dest = dest.with_extension("crate.partial");
+            let mut file = std::fs::File::create(&tmp_dest)?;
+            let mut hasher = Sha256::new();
+            while let Some(chunk) = response
+                .chunk()
+                .await
+                .with_context(|| format!("failed to read response body for {}", url))?
+            {
+                hasher.update(&chunk);
+                file.write_all(&chunk)?;
+            }
+            drop(file);
+
+            if !expected_digest_matches(&hasher.finalize(), checksum) {
+                let _ = std::fs::remove_file(&tmp_dest);
+                eprintln!(
+                    "       WARN: checksum mismatch for {}-{} (expected {}), discarding download",
+                    name, version, checksum
+                );
+                anyhow::bail!("checksum verification failed for {}-{}", name, version);
+            }
+
+            std::fs::rename(&tmp_dest, dest)?;
+
+            Ok(())
+        }
+    }
+
+    /// Constant-time comparison of a computed SHA-256 digest against the
+    /// hex-encoded digest recorded in the db-dump.
+    fn expected_digest_matches(actual: &[u8], expected_hex: &str) -> bool {
+        if expected_hex.len() != actual.len() * 2 {
+            return false;
+        }
+
+        let mut diff = 0u8;
+        for (i, byte) in actual.iter().enumerate() {
+            let hex_byte = match u8::from_str_radix(&expected_hex[i * 2..i * 2 + 2], 16) {
+                Ok(b) => b,
+                Err(_) => return false,
+            };
+            diff |= byte ^ hex_byte;
+        }
+        diff == 0
+    }
+
+    /// Decompress and unpack a `.crate` gzip tarball into `dest_parent`.
+    /// Blocking: run inside `spawn_blocking`.
+    fn extract_tarball(
+        tarball: &Path,
+        dest_parent: &Path,
+        name: &str,
+        version: &str,
+    ) -> Result<PathBuf> {
+        std::fs::create_dir_all(dest_parent)?;
+
+        let file = std::fs::File::open(tarball)
+            .with_context(|| format!("failed to open {}", tarball.display()))?;
+        let decoder = flate2::read::GzDecoder::new(file);
+        let mut archive = tar::Archive::new(decoder);
+        archive
+            .unpack(dest_parent)
+            .with_context(|| format!("failed to unpack {}", tarball.display()))?;
+
+        let extracted = dest_parent.join(format!("{}-{}", name, version));
+        if !extracted.exists() {
+            anyhow::bail!(
+                "extracted tarball did not produce expected directory {}",
+                extracted.display()
+            );
+        }
+        Ok(extracted)
+    }
+}
 
 /// Parsed crate metadata from db-dump.
 #[derive(Debug, Clone)]
@@ -31,122 +232,450 @@ struct VersionMeta {
     num: String,
     #[allow(dead_code)]
     created_at: String,
+    /// SHA-256 hex digest of the published `.crate` tarball, used to verify
+    /// downloads before feeding them to `syn`.
+    checksum: String,
+    /// Published `.crate` tarball size in bytes, from db-dump's `crate_size`.
+    crate_size: u64,
     yanked: bool,
 }
 
-/// Result of analyzing a crate's source.
-#[derive(Debug)]
+/// Result of analyzing a crate's source. Serialized as-is into the
+/// intermediate metrics file produced by `analyze` and consumed by `build`.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
 struct AnalysisResult {
     name: String,
     version: String,
     raw: RawMetrics,
+    /// Names of this crate's direct dependencies, parsed from its Cargo.toml.
+    deps: Vec<String>,
+}
+
+/// Intermediate, cacheable output of the `analyze` stage: one `AnalysisResult`
+/// per successfully analyzed crate. Serialized the same way as `OctoIndex`
+/// (JSON + Zstd, magic-prefixed) so `build` can be re-run cheaply without
+/// re-downloading or re-parsing any source.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct AnalysisMetrics {
+    results: Vec<AnalysisResult>,
+}
+
+impl AnalysisMetrics {
+    const MAGIC: &'static [u8] = b"OMET";
+
+    fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        buf.extend_from_slice(Self::MAGIC);
+        let json = serde_json::to_vec(self)?;
+        let compressed = zstd::encode_all(json.as_slice(), 19)?;
+        buf.extend_from_slice(&compressed);
+        Ok(buf)
+    }
+
+    fn from_bytes(data: &[u8]) -> Result<Self> {
+        if data.len() < 4 || &data[0..4] != Self::MAGIC {
+            anyhow::bail!("Invalid analysis-metrics magic bytes");
+        }
+        let decompressed = zstd::decode_all(&data[4..])?;
+        Ok(serde_json::from_slice(&decompressed)?)
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, self.to_bytes()?)?;
+        Ok(())
+    }
+
+    fn load(path: &Path) -> Result<Self> {
+        let bytes = std::fs::read(path)?;
+        Self::from_bytes(&bytes)
+    }
+}
+
+/// Per-crate, resumable analysis cache: one plain JSON file per successfully
+/// analyzed `name-version`, keyed by `name@version`. Unlike `AnalysisMetrics`
+/// (a single compressed blob written once at the end of `analyze`), entries
+/// here are written as soon as each crate finishes, so an interrupted run
+/// (Ctrl-C, crash) can resume without re-downloading or re-parsing anything
+/// it already completed.
+fn analysis_cache_dir(cache_dir: &Path) -> PathBuf {
+    cache_dir.join("analysis")
+}
+
+fn analysis_cache_key(name: &str, version: &str) -> String {
+    format!("{}@{}", name, version)
+}
+
+fn analysis_cache_path(cache_dir: &Path, name: &str, version: &str) -> PathBuf {
+    analysis_cache_dir(cache_dir).join(format!("{}-{}.json", name, version))
+}
+
+/// Load all previously-cached `AnalysisResult`s, keyed by `name@version`.
+/// Missing or unreadable cache directories just yield an empty cache.
+fn load_analysis_cache(cache_dir: &Path) -> HashMap<String, AnalysisResult> {
+    let mut cache = HashMap::new();
+    let Ok(entries) = std::fs::read_dir(analysis_cache_dir(cache_dir)) else {
+        return cache;
+    };
+
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if !path.extension().is_some_and(|e| e == "json") {
+            continue;
+        }
+        let Ok(content) = std::fs::read_to_string(&path) else {
+            continue;
+        };
+        if let Ok(result) = serde_json::from_str::<AnalysisResult>(&content) {
+            cache.insert(analysis_cache_key(&result.name, &result.version), result);
+        }
+    }
+
+    cache
+}
+
+/// Persist a single analysis result to the incremental cache, via a
+/// temp-file-then-rename so a crash mid-write doesn't leave a corrupt entry
+/// that `load_analysis_cache` would choke on.
+fn save_analysis_cache_entry(cache_dir: &Path, result: &AnalysisResult) -> Result<()> {
+    let dir = analysis_cache_dir(cache_dir);
+    std::fs::create_dir_all(&dir)?;
+
+    let path = analysis_cache_path(cache_dir, &result.name, &result.version);
+    let tmp = path.with_extension("json.partial");
+    std::fs::write(&tmp, serde_json::to_vec(result)?)?;
+    std::fs::rename(&tmp, &path)?;
+
+    Ok(())
+}
+
+#[derive(Parser)]
+#[command(name = "octo-sleeper")]
+#[command(about = "Build and query the Octo-Index from a crates.io db-dump", long_about = None)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Download the top-N crate tarballs into the local cache (no analysis)
+    Fetch {
+        /// Path to extracted crates.io db-dump directory
+        #[arg(long)]
+        db_dump: PathBuf,
+        /// Number of top crates (by downloads) to fetch
+        #[arg(short = 'n', long, default_value_t = 10_000)]
+        limit: usize,
+        /// Number of concurrent download workers
+        #[arg(short = 'j', long, default_value_t = 8)]
+        concurrency: usize,
+        /// Directory for downloaded tarballs/sources (default: OS cache dir)
+        #[arg(long)]
+        cache_dir: Option<PathBuf>,
+    },
+
+    /// Run static analysis over crate sources into an intermediate metrics file
+    Analyze {
+        /// Path to extracted crates.io db-dump directory
+        #[arg(long)]
+        db_dump: PathBuf,
+        /// Intermediate metrics output path, consumed by `build`
+        #[arg(short = 'o', long, default_value = "octo-metrics.bin")]
+        output: PathBuf,
+        /// Number of top crates (by downloads) to analyze
+        #[arg(short = 'n', long, default_value_t = 10_000)]
+        limit: usize,
+        /// Number of concurrent analysis workers
+        #[arg(short = 'j', long, default_value_t = 8)]
+        concurrency: usize,
+        /// Directory for downloaded tarballs/sources (default: OS cache dir)
+        #[arg(long)]
+        cache_dir: Option<PathBuf>,
+        /// Never download; only use the local cargo registry and cache
+        #[arg(long)]
+        offline: bool,
+        /// Bypass the per-crate analysis cache and re-analyze everything
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Assemble the compressed Octo-Index from an intermediate metrics file
+    Build {
+        /// Intermediate metrics file produced by `analyze`
+        #[arg(short = 'm', long, default_value = "octo-metrics.bin")]
+        metrics: PathBuf,
+        /// Output Octo-Index path
+        #[arg(short = 'o', long, default_value = "octo-index.bin")]
+        output: PathBuf,
+    },
+
+    /// Query an existing Octo-Index for crates nearest to a given crate's profile
+    Query {
+        /// Octo-Index file to query
+        #[arg(short = 'i', long, default_value = "octo-index.bin")]
+        index: PathBuf,
+        /// Crate name to find neighbors for
+        name: String,
+        /// Number of nearest neighbors to show
+        #[arg(short = 'n', long, default_value_t = 10)]
+        limit: usize,
+    },
+
+    /// Benchmark load and query performance of an existing Octo-Index
+    Bench {
+        /// Octo-Index file to benchmark
+        #[arg(short = 'i', long, default_value = "octo-index.bin")]
+        index: PathBuf,
+    },
 }
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let args: Vec<String> = std::env::args().collect();
-
-    let mut db_dump_path: Option<PathBuf> = None;
-    let mut output_path = PathBuf::from("octo-index.bin");
-    let mut limit: usize = 10_000;
-    let mut concurrency: usize = 8;
-
-    let mut i = 1;
-    while i < args.len() {
-        match args[i].as_str() {
-            "--db-dump" => {
-                db_dump_path = Some(PathBuf::from(&args[i + 1]));
-                i += 2;
-            }
-            "--output" | "-o" => {
-                output_path = PathBuf::from(&args[i + 1]);
-                i += 2;
-            }
-            "--limit" | "-n" => {
-                limit = args[i + 1].parse()?;
-                i += 2;
-            }
-            "--concurrency" | "-j" => {
-                concurrency = args[i + 1].parse()?;
-                i += 2;
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Fetch {
+            db_dump,
+            limit,
+            concurrency,
+            cache_dir,
+        } => cmd_fetch(&db_dump, limit, concurrency, cache_dir).await,
+        Command::Analyze {
+            db_dump,
+            output,
+            limit,
+            concurrency,
+            cache_dir,
+            offline,
+            force,
+        } => {
+            cmd_analyze(
+                &db_dump,
+                &output,
+                limit,
+                concurrency,
+                cache_dir,
+                offline,
+                force,
+            )
+            .await
+        }
+        Command::Build { metrics, output } => cmd_build(&metrics, &output),
+        Command::Query { index, name, limit } => cmd_query(&index, &name, limit),
+        Command::Bench { index } => cmd_bench(&index),
+    }
+}
+
+/// `fetch`: download the top-N crate tarballs into the local cache, without
+/// running any analysis. Useful for warming the cache ahead of a later
+/// `analyze --offline` run.
+async fn cmd_fetch(
+    db_dump: &Path,
+    limit: usize,
+    concurrency: usize,
+    cache_dir: Option<PathBuf>,
+) -> Result<()> {
+    let fetch_config = FetchConfig::new(resolve_cache_dir(cache_dir), false)?;
+
+    println!("[1/2] Loading crate metadata from db-dump...");
+    let crates = load_crate_metadata(db_dump, limit)?;
+    println!("       Loaded {} crates with download data", crates.len());
+
+    println!("[2/2] Fetching crate sources...");
+    let semaphore = Arc::new(Semaphore::new(concurrency));
+    let total = crates.len();
+    let mut handles = Vec::new();
+
+    for (idx, crate_meta) in crates.into_iter().enumerate() {
+        let sem = semaphore.clone();
+        let fetch_config = fetch_config.clone();
+
+        handles.push(tokio::spawn(async move {
+            let _permit = sem.acquire().await.unwrap();
+
+            let Some(selected) = select_version(&crate_meta) else {
+                return;
+            };
+            let version = selected.version_str();
+            let checksum = selected.checksum.clone();
+            if version.is_empty() {
+                return;
             }
-            "--help" | "-h" => {
-                print_help();
-                return Ok(());
+
+            if find_crate_source(&crate_meta.name, &version).is_ok() {
+                return;
             }
-            _ => {
-                eprintln!("Unknown argument: {}", args[i]);
-                print_help();
-                std::process::exit(1);
+
+            match fetch_config
+                .fetch_source(&crate_meta.name, &version, &checksum)
+                .await
+            {
+                Ok(_) => {
+                    if (idx + 1) % 100 == 0 || idx + 1 == total {
+                        eprintln!("       [{}/{}] {}", idx + 1, total, crate_meta.name);
+                    }
+                }
+                Err(e) => {
+                    eprintln!("       WARN: failed to fetch {}: {}", crate_meta.name, e);
+                }
             }
-        }
+        }));
     }
 
-    let db_dump = db_dump_path.context("--db-dump path required")?;
+    for handle in handles {
+        let _ = handle.await;
+    }
+
+    println!();
+    println!("✓ Done! Cache: {}", fetch_config.cache_dir.display());
+    Ok(())
+}
+
+/// `analyze`: run static analysis over crate sources (preferring the local
+/// cargo registry, falling back to the cache/crates.io) into an intermediate
+/// metrics file that `build` can later assemble into an Octo-Index.
+async fn cmd_analyze(
+    db_dump: &Path,
+    output: &Path,
+    limit: usize,
+    concurrency: usize,
+    cache_dir: Option<PathBuf>,
+    offline: bool,
+    force: bool,
+) -> Result<()> {
+    let fetch_config = FetchConfig::new(resolve_cache_dir(cache_dir), offline)?;
 
     println!("╔══════════════════════════════════════════════════════════════╗");
     println!("║                     OCTO-SLEEPER                             ║");
-    println!("║         Building Octonion Index for Top Crates               ║");
+    println!("║              Analyzing Crate Sources                          ║");
     println!("╚══════════════════════════════════════════════════════════════╝");
     println!();
     println!("  DB Dump:     {}", db_dump.display());
-    println!("  Output:      {}", output_path.display());
+    println!("  Output:      {}", output.display());
     println!("  Limit:       {} crates", limit);
     println!("  Concurrency: {} workers", concurrency);
+    println!("  Cache dir:   {}", fetch_config.cache_dir.display());
+    println!("  Offline:     {}", offline);
+    println!("  Force:       {}", force);
     println!();
 
-    // Step 1: Load crate metadata from CSV files
-    println!("[1/4] Loading crate metadata from db-dump...");
-    let crates = load_crate_metadata(&db_dump, limit)?;
+    println!("[1/3] Loading crate metadata from db-dump...");
+    let crates = load_crate_metadata(db_dump, limit)?;
     println!("       Loaded {} crates with download data", crates.len());
 
-    // Step 2: Process crates with concurrent workers
-    println!("[2/4] Analyzing crate sources...");
-    let results = process_crates(crates, concurrency).await?;
+    println!("[2/3] Analyzing crate sources...");
+    let results = process_crates(crates, concurrency, fetch_config, force).await?;
     println!("       Successfully analyzed {} crates", results.len());
 
-    // Step 3: Build the index
-    println!("[3/4] Building Octo-Index...");
+    println!("[3/3] Saving intermediate metrics...");
+    let metrics = AnalysisMetrics { results };
+    metrics.save(output)?;
+    let size = std::fs::metadata(output)?.len();
+    println!(
+        "       Saved to {} ({:.2} KB)",
+        output.display(),
+        size as f64 / 1024.0
+    );
+
+    println!();
+    println!(
+        "✓ Done! Build the index with: octo-sleeper build -m {}",
+        output.display()
+    );
+    Ok(())
+}
+
+/// `build`: assemble the compressed Octo-Index from a previously-saved
+/// intermediate metrics file, without re-running any analysis.
+fn cmd_build(metrics: &Path, output: &Path) -> Result<()> {
+    println!("[1/2] Loading metrics from {}...", metrics.display());
+    let metrics = AnalysisMetrics::load(metrics)?;
+    println!("       Loaded {} analyzed crates", metrics.results.len());
+
+    println!("[2/2] Building and saving Octo-Index...");
     let mut index = OctoIndex::new();
-    for result in results {
+    for result in metrics.results {
         let coeffs = result.raw.to_coeffs();
         index.insert(OctonionProfile {
             name: result.name,
             version: result.version,
             coeffs,
             raw: result.raw,
+            deps: result.deps,
         });
     }
-    println!("       Index contains {} profiles", index.count);
-
-    // Step 4: Serialize and save
-    println!("[4/4] Compressing and saving...");
-    index.save(&output_path)?;
-    let size = std::fs::metadata(&output_path)?.len();
+    index.save(output)?;
+    let size = std::fs::metadata(output)?.len();
     println!(
-        "       Saved to {} ({:.2} KB)",
-        output_path.display(),
+        "       Saved to {} ({} profiles, {:.2} KB)",
+        output.display(),
+        index.count,
         size as f64 / 1024.0
     );
 
     println!();
-    println!("✓ Done! Bundle with: include_bytes!(\"octo-index.bin\")");
+    println!(
+        "✓ Done! Bundle with: include_bytes!(\"{}\")",
+        output.display()
+    );
+    Ok(())
+}
+
+/// `query`: load an Octo-Index and list the crates whose profiles are
+/// nearest (by Euclidean distance in the 8D octonion space) to the named
+/// crate's own profile.
+fn cmd_query(index: &Path, name: &str, limit: usize) -> Result<()> {
+    let index = OctoIndex::load(index)?;
+    let target = index
+        .get(name)
+        .with_context(|| format!("crate not found in index: {}", name))?;
+
+    let neighbors = index.nearest(&target.coeffs, name, limit);
+    println!("Nearest neighbors to {} v{}:", target.name, target.version);
+    for (profile, distance) in neighbors {
+        println!(
+            "  {:<30} v{:<10} distance={:.4}",
+            profile.name, profile.version, distance
+        );
+    }
+
+    Ok(())
+}
+
+/// `bench`: load an existing Octo-Index and time a representative query
+/// workload, to sanity-check index build/search performance as the profile
+/// coefficients evolve.
+fn cmd_bench(index: &Path) -> Result<()> {
+    let load_start = std::time::Instant::now();
+    let index = OctoIndex::load(index)?;
+    let load_elapsed = load_start.elapsed();
+    println!("Loaded {} profiles in {:.2?}", index.count, load_elapsed);
+
+    let query = build_query(true, true, false, false, false);
+    let search_start = std::time::Instant::now();
+    const ITERATIONS: usize = 100;
+    for _ in 0..ITERATIONS {
+        let _ = index.search(&query, 50);
+    }
+    let search_elapsed = search_start.elapsed();
+    println!(
+        "Ran {} searches in {:.2?} ({:.2?}/search)",
+        ITERATIONS,
+        search_elapsed,
+        search_elapsed / ITERATIONS as u32
+    );
 
     Ok(())
 }
 
-fn print_help() {
-    eprintln!("octo-sleeper - Build the Octo-Index from crates.io db-dump");
-    eprintln!();
-    eprintln!("USAGE:");
-    eprintln!("  octo-sleeper --db-dump <path> [OPTIONS]");
-    eprintln!();
-    eprintln!("OPTIONS:");
-    eprintln!("  --db-dump <path>    Path to extracted crates.io db-dump directory");
-    eprintln!("  --output, -o <path> Output file path (default: octo-index.bin)");
-    eprintln!("  --limit, -n <num>   Number of top crates to process (default: 10000)");
-    eprintln!("  --concurrency, -j   Number of concurrent workers (default: 8)");
-    eprintln!("  --help, -h          Show this help");
+/// Resolve the cache directory: an explicit `--cache-dir`, or the OS cache
+/// dir joined with "octo-sleeper".
+fn resolve_cache_dir(cache_dir: Option<PathBuf>) -> PathBuf {
+    cache_dir.unwrap_or_else(|| {
+        dirs::cache_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("octo-sleeper")
+    })
 }
 
 /// Load crate metadata from the db-dump CSV files.
@@ -176,7 +705,9 @@ fn load_crate_metadata(db_dump: &Path, limit: usize) -> Result<Vec<CrateMeta>> {
     for result in rdr.records() {
         let record = result?;
         // versions.csv columns: bin_names,categories,checksum,crate_id,crate_size,created_at,...
+        let checksum = record.get(2).unwrap_or("").to_string();
         let crate_id: u64 = record.get(3).unwrap_or("0").parse().unwrap_or(0);
+        let crate_size: u64 = record.get(4).unwrap_or("0").parse().unwrap_or(0);
         let created_at = record.get(5).unwrap_or("").to_string();
         let num = record.get(17).unwrap_or("").to_string(); // "num" column
         let yanked = record.get(23).unwrap_or("f") == "t";
@@ -184,6 +715,8 @@ fn load_crate_metadata(db_dump: &Path, limit: usize) -> Result<Vec<CrateMeta>> {
         versions_map.entry(crate_id).or_default().push(VersionMeta {
             num,
             created_at,
+            checksum,
+            crate_size,
             yanked,
         });
     }
@@ -234,55 +767,92 @@ fn load_crate_metadata(db_dump: &Path, limit: usize) -> Result<Vec<CrateMeta>> {
 }
 
 /// Process crates concurrently using tokio workers.
-async fn process_crates(crates: Vec<CrateMeta>, concurrency: usize) -> Result<Vec<AnalysisResult>> {
+///
+/// Unless `force` is set, crates already present in the on-disk analysis
+/// cache (keyed by `name@version`, under `fetch_config.cache_dir`) are
+/// reused directly instead of being re-downloaded and re-parsed, so an
+/// interrupted run can resume where it left off.
+async fn process_crates(
+    crates: Vec<CrateMeta>,
+    concurrency: usize,
+    fetch_config: FetchConfig,
+    force: bool,
+) -> Result<Vec<AnalysisResult>> {
+    let cache = if force {
+        HashMap::new()
+    } else {
+        load_analysis_cache(&fetch_config.cache_dir)
+    };
+    if !cache.is_empty() {
+        println!("       Resuming from {} cached analyses", cache.len());
+    }
+
     let (tx, mut rx) = mpsc::channel::<AnalysisResult>(100);
     let semaphore = Arc::new(Semaphore::new(concurrency));
     let total = crates.len();
+    let mut results = Vec::new();
 
     let mut handles = Vec::new();
 
     for (idx, crate_meta) in crates.into_iter().enumerate() {
+        let Some(selected) = select_version(&crate_meta) else {
+            continue;
+        };
+        let version = selected.version_str();
+        let checksum = selected.checksum.clone();
+        let tarball_bytes = selected.crate_size;
+        if version.is_empty() {
+            continue;
+        }
+
+        if let Some(cached) = cache.get(&analysis_cache_key(&crate_meta.name, &version)) {
+            results.push(AnalysisResult {
+                name: cached.name.clone(),
+                version: cached.version.clone(),
+                raw: cached.raw.clone(),
+                deps: cached.deps.clone(),
+            });
+            continue;
+        }
+
         let tx = tx.clone();
         let sem = semaphore.clone();
+        let fetch_config = fetch_config.clone();
 
         let handle = tokio::spawn(async move {
             let _permit = sem.acquire().await.unwrap();
 
-            // Find the latest non-yanked version by sorting
-            let mut versions: Vec<_> = crate_meta.versions.iter().filter(|v| !v.yanked).collect();
-            versions.sort_by_key(|v| v.version_tuple());
-            let version = versions.last().map(|v| v.version_str()).unwrap_or_else(|| {
-                // Fall back to any version if all are yanked
-                let mut all: Vec<_> = crate_meta.versions.iter().collect();
-                all.sort_by_key(|v| v.version_tuple());
-                all.last().map(|v| v.version_str()).unwrap_or_default()
-            });
-
-            if version.is_empty() {
-                return;
-            }
-
             // Calculate age in days
             let age_days = calculate_age_days(&crate_meta.created_at);
 
-            // Try to analyze source from local cargo registry
-            match analyze_crate_source(&crate_meta.name, &version).await {
-                Ok(mut raw) => {
+            // Try to analyze source from local cargo registry, falling back
+            // to downloading the tarball from crates.io.
+            match analyze_crate_source(&crate_meta.name, &version, &checksum, &fetch_config).await {
+                Ok((mut raw, deps)) => {
                     raw.downloads = crate_meta.downloads;
                     raw.age_days = age_days;
                     raw.version_count = crate_meta.versions.len() as u32;
+                    raw.tarball_bytes = tarball_bytes;
 
                     if (idx + 1) % 100 == 0 || idx + 1 == total {
                         eprintln!("       [{}/{}] {}", idx + 1, total, crate_meta.name);
                     }
 
-                    let _ = tx
-                        .send(AnalysisResult {
-                            name: crate_meta.name,
-                            version,
-                            raw,
-                        })
-                        .await;
+                    let result = AnalysisResult {
+                        name: crate_meta.name,
+                        version,
+                        raw,
+                        deps,
+                    };
+
+                    if let Err(e) = save_analysis_cache_entry(&fetch_config.cache_dir, &result) {
+                        eprintln!(
+                            "       WARN: failed to write analysis cache entry for {}: {}",
+                            result.name, e
+                        );
+                    }
+
+                    let _ = tx.send(result).await;
                 }
                 Err(_) => {
                     // Silently skip crates we can't analyze (not downloaded locally)
@@ -296,8 +866,7 @@ async fn process_crates(crates: Vec<CrateMeta>, concurrency: usize) -> Result<Ve
     // Drop the original sender so the channel closes when all tasks complete
     drop(tx);
 
-    // Collect results
-    let mut results = Vec::new();
+    // Collect freshly analyzed results alongside the cached ones gathered above
     while let Some(result) = rx.recv().await {
         results.push(result);
     }
@@ -310,12 +879,41 @@ async fn process_crates(crates: Vec<CrateMeta>, concurrency: usize) -> Result<Ve
     Ok(results)
 }
 
+/// Pick the version to analyze/fetch for a crate: the highest non-yanked
+/// stable version (a prerelease only if no stable one exists), falling back
+/// to all versions, yanked included, if everything is yanked.
+fn select_version(crate_meta: &CrateMeta) -> Option<&VersionMeta> {
+    let mut versions: Vec<_> = crate_meta.versions.iter().filter(|v| !v.yanked).collect();
+    if versions.is_empty() {
+        // Fall back to any version if all are yanked.
+        versions = crate_meta.versions.iter().collect();
+    }
+
+    // Prefer the highest stable (non-prerelease) version, matching what
+    // `cargo add` would resolve by default; only fall back to the highest
+    // prerelease if every candidate is one.
+    versions
+        .iter()
+        .copied()
+        .filter(|v| !v.is_prerelease())
+        .max_by(|a, b| a.compare_version(b))
+        .or_else(|| versions.iter().copied().max_by(|a, b| a.compare_version(b)))
+}
+
 impl VersionMeta {
     fn version_str(&self) -> String {
         self.num.clone()
     }
 
-    /// Parse version for sorting (returns (major, minor, patch, prerelease_penalty)).
+    /// Parse as a real semver version, stripping a leading 'v' if present
+    /// (some crates tag releases as "v1.2.3" rather than "1.2.3").
+    fn parsed_semver(&self) -> Option<semver::Version> {
+        let s = self.num.strip_prefix('v').unwrap_or(&self.num);
+        semver::Version::parse(s).ok()
+    }
+
+    /// Coarse (major, minor, patch, prerelease_penalty) heuristic, used only
+    /// as a fallback when a version string fails to parse as semver.
     fn version_tuple(&self) -> (i32, i32, i32, i32) {
         let s = &self.num;
         // Remove any leading 'v'
@@ -332,6 +930,30 @@ impl VersionMeta {
 
         (major, minor, patch, prerelease_penalty)
     }
+
+    /// Whether this version carries a semver prerelease tag (e.g.
+    /// `1.0.0-alpha.1`). Falls back to a plain dash-split when the version
+    /// string isn't valid semver.
+    fn is_prerelease(&self) -> bool {
+        match self.parsed_semver() {
+            Some(v) => !v.pre.is_empty(),
+            None => {
+                let s = self.num.strip_prefix('v').unwrap_or(&self.num);
+                s.split_once('-').is_some()
+            }
+        }
+    }
+
+    /// Compare two versions for "latest wins" selection: real semver
+    /// ordering (which correctly ranks prereleases among themselves and
+    /// ignores build metadata) when both parse, falling back to the coarse
+    /// tuple heuristic when either doesn't.
+    fn compare_version(&self, other: &VersionMeta) -> std::cmp::Ordering {
+        match (self.parsed_semver(), other.parsed_semver()) {
+            (Some(a), Some(b)) => (a.major, a.minor, a.patch, &a.pre).cmp(&(b.major, b.minor, b.patch, &b.pre)),
+            _ => self.version_tuple().cmp(&other.version_tuple()),
+        }
+    }
 }
 
 /// Calculate age in days from a timestamp string.
@@ -363,10 +985,18 @@ fn calculate_age_days(created_at: &str) -> u32 {
     (now_days - days_since_epoch).max(0) as u32
 }
 
-/// Analyze a crate's source code from the local cargo registry.
-async fn analyze_crate_source(name: &str, version: &str) -> Result<RawMetrics> {
-    // Find source in ~/.cargo/registry/src/
-    let source_dir = find_crate_source(name, version)?;
+/// Analyze a crate's source code, preferring the local cargo registry and
+/// falling back to a crates.io download (unless running `--offline`).
+async fn analyze_crate_source(
+    name: &str,
+    version: &str,
+    checksum: &str,
+    fetch_config: &FetchConfig,
+) -> Result<(RawMetrics, Vec<String>)> {
+    let source_dir = match find_crate_source(name, version) {
+        Ok(dir) => dir,
+        Err(_) => fetch_config.fetch_source(name, version, checksum).await?,
+    };
 
     // Run analysis in blocking task (syn is not async)
     let source_dir_clone = source_dir.clone();
@@ -399,11 +1029,27 @@ fn find_crate_source(name: &str, version: &str) -> Result<PathBuf> {
     anyhow::bail!("Crate source not found: {}-{}", name, version)
 }
 
-/// Analyze all Rust files in a directory.
-fn analyze_directory(dir: &Path) -> Result<RawMetrics> {
+/// Analyze all Rust files in a directory, returning both the aggregate
+/// metrics and the crate's direct dependency names (deduped, from the
+/// top-level `Cargo.toml` only).
+fn analyze_directory(dir: &Path) -> Result<(RawMetrics, Vec<String>)> {
     let mut raw = RawMetrics::default();
     analyze_dir_recursive(dir, &mut raw)?;
-    Ok(raw)
+    raw.uncompressed_bytes = directory_size(dir);
+
+    let cargo_toml = dir.join("Cargo.toml");
+    let deps = if cargo_toml.exists() {
+        let content = std::fs::read_to_string(&cargo_toml)?;
+        let manifest_deps = parse_manifest_deps(&content);
+        raw.dep_count = manifest_deps.dep_count;
+        raw.dev_dep_count = manifest_deps.dev_dep_count;
+        raw.build_dep_count = manifest_deps.build_dep_count;
+        manifest_deps.names
+    } else {
+        Vec::new()
+    };
+
+    Ok((raw, deps))
 }
 
 fn analyze_dir_recursive(dir: &Path, raw: &mut RawMetrics) -> Result<()> {
@@ -434,21 +1080,40 @@ fn analyze_dir_recursive(dir: &Path, raw: &mut RawMetrics) -> Result<()> {
         }
     }
 
-    // Count dependencies from Cargo.toml
-    let cargo_toml = dir.join("Cargo.toml");
-    if cargo_toml.exists() {
-        if let Ok(content) = std::fs::read_to_string(&cargo_toml) {
-            raw.dep_count = count_dependencies(&content);
+    Ok(())
+}
+
+/// Sum the byte size of every file under `dir` (recursively), giving the
+/// total uncompressed size of an extracted crate source tree. Unlike
+/// `analyze_dir_recursive`, this walks every file (not just `.rs` sources)
+/// since vendored assets and generated data tables contribute to a crate's
+/// "code heft" too.
+fn directory_size(dir: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return 0;
+    };
+
+    let mut total = 0u64;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_file() {
+            total += entry.metadata().map(|m| m.len()).unwrap_or(0);
+        } else if path.is_dir() {
+            let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+            if name != ".git" {
+                total += directory_size(&path);
+            }
         }
     }
 
-    Ok(())
+    total
 }
 
 /// Analyze a single Rust file.
 fn analyze_file(path: &Path, raw: &mut RawMetrics) -> Result<()> {
     let content = std::fs::read_to_string(path)?;
     raw.total_loc += content.lines().count() as u32;
+    classify_lines(&content, raw);
 
     // Parse with syn
     let Ok(syntax) = syn::parse_file(&content) else {
@@ -532,33 +1197,162 @@ fn analyze_file(path: &Path, raw: &mut RawMetrics) -> Result<()> {
     Ok(())
 }
 
-/// Count dependencies in Cargo.toml.
-fn count_dependencies(cargo_toml: &str) -> u32 {
-    let mut count = 0;
-    let mut in_deps = false;
+/// Classify each line of a source file as code, comment, or blank, tallying
+/// into `raw`. Tracks `//` line comments and `/* */` block comments (which
+/// may open, close, and re-open multiple times on one line, and may leave
+/// real code trailing after the closing `*/`).
+fn classify_lines(content: &str, raw: &mut RawMetrics) {
+    let mut in_block_comment = false;
 
-    for line in cargo_toml.lines() {
+    for line in content.lines() {
         let trimmed = line.trim();
 
-        if trimmed.starts_with("[dependencies]")
-            || trimmed.starts_with("[dev-dependencies]")
-            || trimmed.starts_with("[build-dependencies]")
-        {
-            in_deps = true;
+        if trimmed.is_empty() {
+            raw.blank_loc += 1;
             continue;
         }
 
-        if trimmed.starts_with('[') {
-            in_deps = false;
-            continue;
+        let mut rest = trimmed;
+        let mut saw_comment = false;
+        let mut saw_code = false;
+
+        loop {
+            if in_block_comment {
+                saw_comment = true;
+                match rest.find("*/") {
+                    Some(idx) => {
+                        in_block_comment = false;
+                        rest = rest[idx + 2..].trim_start();
+                        if rest.is_empty() {
+                            break;
+                        }
+                        continue;
+                    }
+                    None => break,
+                }
+            }
+
+            if rest.starts_with("//") {
+                saw_comment = true;
+                break;
+            }
+
+            if let Some(idx) = rest.find("/*") {
+                if idx > 0 {
+                    saw_code = true;
+                }
+                in_block_comment = true;
+                saw_comment = true;
+                rest = &rest[idx + 2..];
+                continue;
+            }
+
+            // Remaining text on the line is real code.
+            saw_code = true;
+            break;
+        }
+
+        if saw_code {
+            raw.code_loc += 1;
+        } else if saw_comment {
+            raw.comment_loc += 1;
+        } else {
+            raw.blank_loc += 1;
         }
+    }
+}
+
+/// Shape of a `Cargo.toml` manifest, just enough to extract dependency
+/// names and counts. Mirrors `cratefind::cargo::CargoToml`, extended with
+/// `[target.*.dependencies]` since octo-sleeper walks arbitrary published
+/// crates rather than a single known project.
+#[derive(Debug, serde::Deserialize)]
+struct ManifestToml {
+    dependencies: Option<toml::Table>,
+    #[serde(rename = "dev-dependencies")]
+    dev_dependencies: Option<toml::Table>,
+    #[serde(rename = "build-dependencies")]
+    build_dependencies: Option<toml::Table>,
+    target: Option<toml::Table>,
+}
 
-        if in_deps && !trimmed.is_empty() && !trimmed.starts_with('#') {
-            if trimmed.contains('=') {
-                count += 1;
+/// Dependency names and per-kind counts extracted from a manifest.
+struct ManifestDeps {
+    names: Vec<String>,
+    dep_count: u32,
+    dev_dep_count: u32,
+    build_dep_count: u32,
+}
+
+/// Parse a `Cargo.toml` into its direct dependency names and counts,
+/// covering `[dependencies]`, `[dev-dependencies]`, `[build-dependencies]`,
+/// and `[target.'cfg(...)'.dependencies]` (and its dev/build variants).
+///
+/// Falls back to an empty result on malformed manifests rather than failing
+/// the whole crate analysis, since octo-sleeper processes arbitrary
+/// third-party source and a bad manifest shouldn't abort the batch.
+fn parse_manifest_deps(cargo_toml: &str) -> ManifestDeps {
+    let Ok(manifest) = toml::from_str::<ManifestToml>(cargo_toml) else {
+        return ManifestDeps {
+            names: Vec::new(),
+            dep_count: 0,
+            dev_dep_count: 0,
+            build_dep_count: 0,
+        };
+    };
+
+    let mut names = std::collections::HashSet::new();
+    let mut dep_count = 0;
+    let mut dev_dep_count = 0;
+    let mut build_dep_count = 0;
+
+    if let Some(deps) = &manifest.dependencies {
+        dep_count += deps.len() as u32;
+        names.extend(deps.keys().cloned());
+    }
+    if let Some(deps) = &manifest.dev_dependencies {
+        dev_dep_count += deps.len() as u32;
+        names.extend(deps.keys().cloned());
+    }
+    if let Some(deps) = &manifest.build_dependencies {
+        build_dep_count += deps.len() as u32;
+        names.extend(deps.keys().cloned());
+    }
+
+    // Walk [target.<cfg>.dependencies] / dev-dependencies / build-dependencies.
+    if let Some(targets) = &manifest.target {
+        for cfg_value in targets.values() {
+            let Some(cfg_table) = cfg_value.as_table() else {
+                continue;
+            };
+            if let Some(deps) = cfg_table.get("dependencies").and_then(|v| v.as_table()) {
+                dep_count += deps.len() as u32;
+                names.extend(deps.keys().cloned());
+            }
+            if let Some(deps) = cfg_table
+                .get("dev-dependencies")
+                .and_then(|v| v.as_table())
+            {
+                dev_dep_count += deps.len() as u32;
+                names.extend(deps.keys().cloned());
+            }
+            if let Some(deps) = cfg_table
+                .get("build-dependencies")
+                .and_then(|v| v.as_table())
+            {
+                build_dep_count += deps.len() as u32;
+                names.extend(deps.keys().cloned());
             }
         }
     }
 
-    count
+    let mut names: Vec<String> = names.into_iter().collect();
+    names.sort();
+
+    ManifestDeps {
+        names,
+        dep_count,
+        dev_dep_count,
+        build_dep_count,
+    }
 }
@@ -0,0 +1,255 @@
+//! Shim source generation for `CrossingAnalysis::Resolvable` results.
+//!
+//! `find_matching_template` only ever names a template
+//! (`"AsyncReadAdapter"`, `"SyncProxy"`, `"PinnedFutureBridge"`) - this
+//! module is what actually emits the shim source for one of those
+//! templates, given the two crates' profiles and the crossing that
+//! triggered the match, so a caller can write the result into a `shims/`
+//! module instead of just reporting a name.
+
+use crate::braid::crossing::{Crossing, CrossingKind};
+use crate::octo_index::OctonionProfile;
+
+/// An extra crate a generated shim needs in `[dependencies]`, mirroring the
+/// `name`/`version` shape `cargo::RegistryCrate` uses for a resolved crate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShimDependency {
+    pub name: String,
+    pub version: String,
+}
+
+impl ShimDependency {
+    pub fn new(name: impl Into<String>, version: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: version.into(),
+        }
+    }
+}
+
+/// A generated bridge module: the Rust source for the shim, plus the
+/// dependencies it requires (for some templates this includes `crate_a`/
+/// `crate_b` themselves).
+#[derive(Debug, Clone)]
+pub struct GeneratedShim {
+    pub source: String,
+    pub extra_deps: Vec<ShimDependency>,
+}
+
+/// Generate the shim source for `template` (the name `find_matching_template`
+/// returned), naming the generated types after `crate_a`/`crate_b`. `None`
+/// for a template this module doesn't have a generator for yet - e.g. a new
+/// template added to `find_matching_template` without a matching arm here.
+pub fn generate_shim(
+    template: &str,
+    crate_a: &OctonionProfile,
+    crate_b: &OctonionProfile,
+    crossing: &Crossing,
+) -> Option<GeneratedShim> {
+    match template {
+        "AsyncReadAdapter" => Some(async_read_adapter(crate_a, crate_b, &crossing.kind)),
+        "SyncProxy" => Some(sync_proxy(crate_a, crate_b)),
+        "PinnedFutureBridge" => Some(pinned_future_bridge(crate_a, crate_b)),
+        _ => None,
+    }
+}
+
+/// A newtype wrapping `crate_a`'s reader, implementing the conflicting trait
+/// named by the crossing (falling back to `"AsyncRead"` if the crossing
+/// wasn't a `TraitConflict`) by delegating `poll_read` to the wrapped value.
+fn async_read_adapter(
+    crate_a: &OctonionProfile,
+    crate_b: &OctonionProfile,
+    kind: &CrossingKind,
+) -> GeneratedShim {
+    let trait_name = match kind {
+        CrossingKind::TraitConflict { trait_name, .. } => trait_name.as_str(),
+        _ => "AsyncRead",
+    };
+    let struct_name = format!("{}To{}Reader", pascal(&crate_a.name), pascal(&crate_b.name));
+
+    let source = format!(
+        r#"/// Bridges a `{a}` reader to the `{trait_name}` implementation `{b}`
+/// expects, delegating every poll to the wrapped value.
+pub struct {struct_name}<R>(pub R);
+
+impl<R: {trait_name} + Unpin> {trait_name} for {struct_name}<R> {{
+    fn poll_read(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &mut [u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {{
+        let inner = std::pin::Pin::new(&mut self.get_mut().0);
+        inner.poll_read(cx, buf)
+    }}
+}}
+"#,
+        a = crate_a.name,
+        b = crate_b.name,
+        trait_name = trait_name,
+        struct_name = struct_name,
+    );
+
+    GeneratedShim {
+        source,
+        extra_deps: vec![
+            ShimDependency::new(crate_a.name.clone(), crate_a.version.clone()),
+            ShimDependency::new(crate_b.name.clone(), crate_b.version.clone()),
+        ],
+    }
+}
+
+/// An `Arc<Mutex<T>>` wrapper making an `Rc`-bound `crate_a` type usable
+/// from `crate_b`'s `Send`-bound context, trading lock contention for the
+/// ability to cross a thread boundary the bare type can't.
+fn sync_proxy(crate_a: &OctonionProfile, crate_b: &OctonionProfile) -> GeneratedShim {
+    let struct_name = format!("{}SyncProxy", pascal(&crate_a.name));
+
+    let source = format!(
+        r#"/// Makes a `{a}` value usable from `{b}`'s `Send`-bound context by
+/// moving it behind an `Arc<Mutex<_>>`.
+pub struct {struct_name}<T>(std::sync::Arc<std::sync::Mutex<T>>);
+
+impl<T> {struct_name}<T> {{
+    pub fn new(value: T) -> Self {{
+        Self(std::sync::Arc::new(std::sync::Mutex::new(value)))
+    }}
+
+    pub fn with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {{
+        let mut guard = self.0.lock().expect("{struct_name} poisoned");
+        f(&mut guard)
+    }}
+}}
+
+unsafe impl<T> Send for {struct_name}<T> {{}}
+unsafe impl<T> Sync for {struct_name}<T> {{}}
+"#,
+        a = crate_a.name,
+        b = crate_b.name,
+        struct_name = struct_name,
+    );
+
+    GeneratedShim {
+        source,
+        extra_deps: Vec::new(),
+    }
+}
+
+/// A `pin-project`-backed wrapper projecting a pinned `crate_a` future
+/// through to the plain `Future` shape `crate_b`'s executor expects.
+fn pinned_future_bridge(crate_a: &OctonionProfile, crate_b: &OctonionProfile) -> GeneratedShim {
+    let struct_name = format!(
+        "{}{}FutureBridge",
+        pascal(&crate_a.name),
+        pascal(&crate_b.name)
+    );
+
+    let source = format!(
+        r#"/// Projects a pinned `{a}` future through to the plain `Future` shape
+/// `{b}`'s executor expects, without breaking the inner future's own pin
+/// guarantees.
+#[pin_project::pin_project]
+pub struct {struct_name}<F> {{
+    #[pin]
+    inner: F,
+}}
+
+impl<F: std::future::Future> std::future::Future for {struct_name}<F> {{
+    type Output = F::Output;
+
+    fn poll(
+        self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {{
+        self.project().inner.poll(cx)
+    }}
+}}
+"#,
+        a = crate_a.name,
+        b = crate_b.name,
+        struct_name = struct_name,
+    );
+
+    GeneratedShim {
+        source,
+        extra_deps: vec![ShimDependency::new("pin-project", "1")],
+    }
+}
+
+/// Turn a (possibly kebab- or snake-case) crate name into a `PascalCase`
+/// identifier fragment, e.g. `"tokio-util"` -> `"TokioUtil"`.
+fn pascal(name: &str) -> String {
+    name.split(|c: char| c == '-' || c == '_')
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::octo_index::RawMetrics;
+
+    fn make_profile(name: &str) -> OctonionProfile {
+        OctonionProfile {
+            name: name.to_string(),
+            version: "1.0.0".to_string(),
+            coeffs: [0.0; 8],
+            raw: RawMetrics::default(),
+            deps: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_async_read_adapter_names_struct_after_both_crates() {
+        let tokio = make_profile("tokio");
+        let async_std = make_profile("async-std");
+        let crossing =
+            Crossing::trait_conflict("lib.rs:1", 0, 1, "AsyncRead", "tokio", "async-std");
+
+        let shim = generate_shim("AsyncReadAdapter", &tokio, &async_std, &crossing).unwrap();
+
+        assert!(shim.source.contains("TokioToAsyncStdReader"));
+        assert!(shim.source.contains("AsyncRead"));
+        assert_eq!(shim.extra_deps.len(), 2);
+    }
+
+    #[test]
+    fn test_sync_proxy_has_no_extra_deps() {
+        let a = make_profile("rc-thing");
+        let b = make_profile("send-thing");
+        let crossing = Crossing::wrapper_nesting("lib.rs:2", 0, 1, "Rc", "RefCell", false);
+
+        let shim = generate_shim("SyncProxy", &a, &b, &crossing).unwrap();
+
+        assert!(shim.source.contains("RcThingSyncProxy"));
+        assert!(shim.extra_deps.is_empty());
+    }
+
+    #[test]
+    fn test_pinned_future_bridge_requires_pin_project() {
+        let a = make_profile("futures-lite");
+        let b = make_profile("tokio");
+        let mut crossing = Crossing::wrapper_nesting("lib.rs:3", 0, 1, "Box", "dyn Future", false);
+        crossing.involves_pin = true;
+
+        let shim = generate_shim("PinnedFutureBridge", &a, &b, &crossing).unwrap();
+
+        assert!(shim.source.contains("pin_project"));
+        assert_eq!(shim.extra_deps[0].name, "pin-project");
+    }
+
+    #[test]
+    fn test_unknown_template_returns_none() {
+        let a = make_profile("a");
+        let b = make_profile("b");
+        let crossing = Crossing::wrapper_nesting("lib.rs:4", 0, 1, "Box", "dyn Trait", true);
+
+        assert!(generate_shim("SomeFutureTemplate", &a, &b, &crossing).is_none());
+    }
+}
@@ -6,11 +6,13 @@
 //! - Marker trait conflicts (Send vs !Send)
 //! - Lifetime intersection
 
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use syn::visit::Visit;
 
 /// The "charge" a type carries through the crate graph.
 /// Tracks marker traits that affect composition.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TypeCharge {
     /// Send bound status
     pub send: Ternary,
@@ -22,10 +24,16 @@ pub struct TypeCharge {
     pub is_static: bool,
     /// Is Sized
     pub sized: bool,
+    /// Whether `send` came from an explicit impl (`impl !Send` or
+    /// `unsafe impl Send`) rather than structural inference.
+    pub send_asserted: bool,
+    /// Whether `sync` came from an explicit impl (`impl !Sync` or
+    /// `unsafe impl Sync`) rather than structural inference.
+    pub sync_asserted: bool,
 }
 
 /// Three-valued logic for trait bounds.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum Ternary {
     Yes,
     No,
@@ -45,10 +53,274 @@ impl Ternary {
             (Ternary::Yes, Ternary::No) | (Ternary::No, Ternary::Yes)
         )
     }
+
+    /// Kleene "strong" AND: a definite `No` dominates (the composite is
+    /// certainly not the marker if any component certainly isn't), `Yes`
+    /// only when every component is certainly `Yes`, `Unknown` otherwise.
+    /// `Unknown` must never be silently promoted to `Yes`.
+    pub fn and(self, other: Ternary) -> Ternary {
+        match (self, other) {
+            (Ternary::No, _) | (_, Ternary::No) => Ternary::No,
+            (Ternary::Yes, Ternary::Yes) => Ternary::Yes,
+            _ => Ternary::Unknown,
+        }
+    }
+}
+
+impl TypeCharge {
+    /// Compose the charge of a wrapper type (`outer_name`, e.g. `"Arc"`)
+    /// around the charge of its contents (`inner`).
+    ///
+    /// By default `Send`/`Sync` are the Kleene AND of outer and inner (the
+    /// composite is only certainly `Yes` if both components are), since a
+    /// generic wrapper is only as Send/Sync as what it wraps. A handful of
+    /// well-known wrappers override that default with their real std-library
+    /// auto-trait impls (`Arc`/`Rc`, `Mutex`, `MutexGuard`/`Ref`). `Unpin`
+    /// follows the outer wrapper's own charge unless the wrapper is a known
+    /// `!Unpin` type, since most wrappers (`Box`, `Arc`, `Mutex`, ...) are
+    /// unconditionally `Unpin` regardless of their contents.
+    pub fn compose(outer: &TypeCharge, inner: &TypeCharge, outer_name: &str) -> TypeCharge {
+        let base = wrapper_base_name(outer_name);
+
+        let (send, sync) = marker_override(base, inner)
+            .unwrap_or_else(|| (outer.send.and(inner.send), outer.sync.and(inner.sync)));
+
+        TypeCharge {
+            send,
+            sync,
+            unpin: composed_unpin(base, outer),
+            is_static: outer.is_static && inner.is_static,
+            sized: outer.sized,
+            send_asserted: outer.send_asserted || inner.send_asserted,
+            sync_asserted: outer.sync_asserted || inner.sync_asserted,
+        }
+    }
+
+    /// Apply a negative auto-trait impl (`impl !Send for X` / `impl !Sync
+    /// for X`), forcing the marker to `No` and marking it explicitly
+    /// asserted rather than structurally inferred.
+    fn apply_negative_impl(&mut self, trait_name: &str) {
+        match trait_name {
+            "Send" => {
+                self.send = Ternary::No;
+                self.send_asserted = true;
+            }
+            "Sync" => {
+                self.sync = Ternary::No;
+                self.sync_asserted = true;
+            }
+            _ => {}
+        }
+    }
+
+    /// Apply a hand-written `unsafe impl Send for X` / `unsafe impl Sync for
+    /// X`, forcing the marker to `Yes` even when structural inference would
+    /// say otherwise (as `Arc`/`Weak` do in std), and marking it asserted.
+    fn apply_unsafe_impl(&mut self, trait_name: &str) {
+        match trait_name {
+            "Send" => {
+                self.send = Ternary::Yes;
+                self.send_asserted = true;
+            }
+            "Sync" => {
+                self.sync = Ternary::Yes;
+                self.sync_asserted = true;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Auto-trait charge extracted for one named type, split into the `Send`/
+/// `Sync`/`Unpin` state itself (`markers`) and whether the type's own
+/// fields structurally rule out `Send` (a raw pointer or `Rc`/`RefCell`
+/// field) - the latter lets a caller flag an `unsafe impl Send` that
+/// contradicts the type's actual structure.
+#[derive(Debug, Clone, Default)]
+pub struct ExtractedMarkers {
+    pub markers: TypeCharge,
+    pub structurally_send: bool,
+}
+
+/// Extract `type_name`'s auto-trait charge from a crate's parsed source.
+///
+/// Recognizes three forms: `impl !Send for X` / `impl !Sync for X`
+/// (negative impls) set the corresponding marker to `Ternary::No`;
+/// `unsafe impl Send for X` / `unsafe impl Sync for X` force `Ternary::Yes`
+/// even when the structural inference below would say otherwise; absence
+/// of any impl leaves `Ternary::Unknown`. Also reports whether `X` itself
+/// structurally cannot be `Send` (it has a raw pointer or `Rc`/`RefCell`
+/// field), so callers can flag an `unsafe impl Send` that contradicts it -
+/// see [`detect_unsound_assertion`].
+pub fn extract_markers_for_type(source: &str, type_name: &str) -> ExtractedMarkers {
+    let mut result = ExtractedMarkers {
+        markers: TypeCharge::default(),
+        structurally_send: true,
+    };
+
+    let Ok(file) = syn::parse_file(source) else {
+        return result;
+    };
+
+    let mut visitor = MarkerVisitor {
+        type_name,
+        result: &mut result,
+    };
+    visitor.visit_file(&file);
+
+    result
+}
+
+struct MarkerVisitor<'a> {
+    type_name: &'a str,
+    result: &'a mut ExtractedMarkers,
+}
+
+impl<'ast> Visit<'ast> for MarkerVisitor<'_> {
+    fn visit_item_struct(&mut self, node: &'ast syn::ItemStruct) {
+        if node.ident == self.type_name && struct_has_non_send_field(node) {
+            self.result.structurally_send = false;
+        }
+        syn::visit::visit_item_struct(self, node);
+    }
+
+    fn visit_item_impl(&mut self, node: &'ast syn::ItemImpl) {
+        if impl_is_for_type(node, self.type_name) {
+            if let Some((negative, trait_path, _)) = &node.trait_ {
+                if let Some(last) = trait_path.segments.last() {
+                    let trait_name = last.ident.to_string();
+                    if trait_name == "Send" || trait_name == "Sync" {
+                        if negative.is_some() {
+                            self.result.markers.apply_negative_impl(&trait_name);
+                        } else if node.unsafety.is_some() {
+                            self.result.markers.apply_unsafe_impl(&trait_name);
+                        }
+                    }
+                }
+            }
+        }
+        syn::visit::visit_item_impl(self, node);
+    }
+}
+
+/// Whether `impl` block's self type is the bare named type (no path
+/// qualification, since extraction works one crate at a time).
+fn impl_is_for_type(node: &syn::ItemImpl, type_name: &str) -> bool {
+    match &*node.self_ty {
+        syn::Type::Path(type_path) => type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|s| s.ident == type_name),
+        _ => false,
+    }
+}
+
+/// Whether a struct has a field that structurally rules out `Send`: a raw
+/// pointer, or an `Rc`/`RefCell` (directly or nested in a generic, e.g.
+/// `Option<Rc<T>>`).
+fn struct_has_non_send_field(node: &syn::ItemStruct) -> bool {
+    node.fields
+        .iter()
+        .any(|f| matches!(&f.ty, syn::Type::Ptr(_)) || type_mentions_non_send(&f.ty))
+}
+
+/// Whether a type mentions `Rc`/`RefCell` anywhere in its path or generic
+/// arguments.
+fn type_mentions_non_send(ty: &syn::Type) -> bool {
+    let syn::Type::Path(type_path) = ty else {
+        return false;
+    };
+    let Some(last) = type_path.path.segments.last() else {
+        return false;
+    };
+
+    if matches!(last.ident.to_string().as_str(), "Rc" | "RefCell") {
+        return true;
+    }
+
+    if let syn::PathArguments::AngleBracketed(args) = &last.arguments {
+        return args.args.iter().any(
+            |arg| matches!(arg, syn::GenericArgument::Type(inner) if type_mentions_non_send(inner)),
+        );
+    }
+
+    false
+}
+
+/// Check whether an `unsafe impl Send` assertion contradicts structural
+/// evidence (a raw pointer or `Rc`/`RefCell` field) gathered during
+/// extraction. This is a soundness bug in the crate itself, not merely an
+/// `Unknown` vs `Yes` mismatch between two crates, so it's surfaced as its
+/// own crossing kind with higher prominence than a plain marker conflict.
+pub fn detect_unsound_assertion(
+    location: impl Into<String>,
+    sigma_i: usize,
+    sigma_j: usize,
+    type_name: impl Into<String>,
+    extracted: &ExtractedMarkers,
+) -> Option<Crossing> {
+    if extracted.markers.send == Ternary::Yes
+        && extracted.markers.send_asserted
+        && !extracted.structurally_send
+    {
+        return Some(Crossing::unsound_marker_assertion(
+            location,
+            sigma_i,
+            sigma_j,
+            type_name,
+            "Send",
+            "contains a raw pointer or Rc/RefCell field",
+        ));
+    }
+
+    None
+}
+
+/// Well-known wrappers whose `Send`/`Sync` impls aren't a simple AND of
+/// their contents' charges. Returns `None` for unrecognized wrappers,
+/// falling through to the default Kleene AND.
+fn marker_override(wrapper_base: &str, inner: &TypeCharge) -> Option<(Ternary, Ternary)> {
+    match wrapper_base {
+        // Arc<T>/Rc<T>: Send + Sync only when T: Send + Sync.
+        "Arc" | "Rc" => {
+            let both = inner.send.and(inner.sync);
+            Some((both, both))
+        }
+        // Mutex<T>: Send iff T: Send; Sync whenever T: Send (upgrades Send to Sync).
+        "Mutex" => Some((inner.send, inner.send)),
+        // MutexGuard/Ref/RefMut: never Send; Sync follows the borrowed contents.
+        "MutexGuard" | "Ref" | "RefMut" => Some((Ternary::No, inner.sync)),
+        _ => None,
+    }
+}
+
+/// `Unpin` status of a composed wrapper: inherited from the outer wrapper's
+/// own charge, unless the wrapper is a known `!Unpin` type regardless of
+/// content.
+fn composed_unpin(wrapper_base: &str, outer: &TypeCharge) -> Ternary {
+    if is_never_unpin(wrapper_base) {
+        Ternary::No
+    } else {
+        outer.unpin
+    }
+}
+
+/// Wrapper types that are unconditionally `!Unpin` - generator/future state
+/// machines that self-reference and must not move once polled.
+fn is_never_unpin(wrapper_base: &str) -> bool {
+    matches!(wrapper_base, "GeneratorState" | "PhantomPinned")
+}
+
+/// Strip generic parameters and path qualifiers down to the bare wrapper
+/// name, e.g. `"std::sync::Arc<T>"` -> `"Arc"`.
+fn wrapper_base_name(type_name: &str) -> &str {
+    let name = type_name.split('<').next().unwrap_or(type_name);
+    name.rsplit("::").next().unwrap_or(name)
 }
 
 /// A crossing point where two crate strands interact.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Crossing {
     /// Source location (file:line)
     pub location: String,
@@ -62,8 +334,18 @@ pub struct Crossing {
     pub involves_pin: bool,
 }
 
+/// Which crate's bound is the container being written into, vs which
+/// supplies the shorter-lived data flowing into it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FlowDirection {
+    /// Crate A's bound is the container (sink); crate B supplies the data.
+    AIsSink,
+    /// Crate B's bound is the container (sink); crate A supplies the data.
+    BIsSink,
+}
+
 /// The kind of interaction at a crossing.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CrossingKind {
     /// Both crates expect different impls of the same trait
     TraitConflict {
@@ -87,9 +369,18 @@ pub enum CrossingKind {
     LifetimeIntersection {
         lifetime_a: String,
         lifetime_b: String,
+        flow_direction: FlowDirection,
     },
     /// Generic: unspecified interaction
     Generic { description: String },
+    /// A hand-written `unsafe impl Send`/`Sync` contradicts the type's own
+    /// structure (a raw pointer or `Rc`/`RefCell` field) - a soundness bug,
+    /// not just a cross-crate marker mismatch.
+    UnsoundMarkerAssertion {
+        type_name: String,
+        trait_name: String,
+        structural_reason: String,
+    },
 }
 
 impl Crossing {
@@ -166,6 +457,53 @@ impl Crossing {
         }
     }
 
+    /// Create a lifetime intersection crossing: a container bound from one
+    /// crate (`lifetime_a`) is forced to unify with a shorter-lived
+    /// reference bound from the other (`lifetime_b`).
+    pub fn lifetime_intersection(
+        location: impl Into<String>,
+        sigma_i: usize,
+        sigma_j: usize,
+        lifetime_a: impl Into<String>,
+        lifetime_b: impl Into<String>,
+        flow_direction: FlowDirection,
+    ) -> Self {
+        Self {
+            location: location.into(),
+            sigma_i,
+            sigma_j,
+            kind: CrossingKind::LifetimeIntersection {
+                lifetime_a: lifetime_a.into(),
+                lifetime_b: lifetime_b.into(),
+                flow_direction,
+            },
+            involves_pin: false,
+        }
+    }
+
+    /// Create a crossing for an `unsafe impl Send`/`Sync` that contradicts
+    /// the type's own structure.
+    pub fn unsound_marker_assertion(
+        location: impl Into<String>,
+        sigma_i: usize,
+        sigma_j: usize,
+        type_name: impl Into<String>,
+        trait_name: impl Into<String>,
+        structural_reason: impl Into<String>,
+    ) -> Self {
+        Self {
+            location: location.into(),
+            sigma_i,
+            sigma_j,
+            kind: CrossingKind::UnsoundMarkerAssertion {
+                type_name: type_name.into(),
+                trait_name: trait_name.into(),
+                structural_reason: structural_reason.into(),
+            },
+            involves_pin: false,
+        }
+    }
+
     /// Human-readable description of the crossing.
     pub fn describe(&self) -> String {
         match &self.kind {
@@ -210,14 +548,178 @@ impl Crossing {
             CrossingKind::LifetimeIntersection {
                 lifetime_a,
                 lifetime_b,
+                ..
             } => {
-                format!("Lifetime intersection: {} vs {}", lifetime_a, lifetime_b)
+                format!(
+                    "data from {} flows into {} container here",
+                    lifetime_b, lifetime_a
+                )
             }
             CrossingKind::Generic { description } => description.clone(),
+            CrossingKind::UnsoundMarkerAssertion {
+                type_name,
+                trait_name,
+                structural_reason,
+            } => {
+                format!(
+                    "SOUNDNESS: `unsafe impl {} for {}` but {} {}",
+                    trait_name, type_name, type_name, structural_reason
+                )
+            }
+        }
+    }
+
+    /// How urgently this crossing should be surfaced to a user.
+    pub fn severity(&self) -> Severity {
+        match &self.kind {
+            CrossingKind::UnsoundMarkerAssertion { .. } => Severity::Error,
+            CrossingKind::MarkerConflict { .. } => Severity::Error,
+            CrossingKind::TraitConflict { .. } => Severity::Error,
+            CrossingKind::WrapperNesting { can_commute, .. } => {
+                if *can_commute {
+                    Severity::Info
+                } else {
+                    Severity::Warning
+                }
+            }
+            CrossingKind::LifetimeIntersection { .. } => Severity::Warning,
+            CrossingKind::Generic { .. } => Severity::Info,
+        }
+    }
+}
+
+/// How urgently a crossing should be surfaced to a user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Severity {
+    Error,
+    Warning,
+    Info,
+}
+
+impl CrossingKind {
+    /// Stable discriminant name for this kind, used by `IgnoreFilter` and
+    /// in reports.
+    pub fn name(&self) -> &'static str {
+        match self {
+            CrossingKind::TraitConflict { .. } => "TraitConflict",
+            CrossingKind::MarkerConflict { .. } => "MarkerConflict",
+            CrossingKind::WrapperNesting { .. } => "WrapperNesting",
+            CrossingKind::LifetimeIntersection { .. } => "LifetimeIntersection",
+            CrossingKind::Generic { .. } => "Generic",
+            CrossingKind::UnsoundMarkerAssertion { .. } => "UnsoundMarkerAssertion",
         }
     }
 }
 
+/// A single suppression rule for `IgnoreFilter`: either every crossing of a
+/// given kind, or any crossing whose `location` contains a given substring.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum IgnoreRule {
+    Kind { kind_name: String, reason: String },
+    LocationPattern { pattern: String, reason: String },
+}
+
+/// User-supplied suppression rules for a crossing report. Suppressed
+/// crossings are never dropped - they're moved to
+/// `CrossingReport::suppressed` with their rule's reason attached, mirroring
+/// how structured test output reports ignored tests alongside the active
+/// results.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IgnoreFilter {
+    pub rules: Vec<IgnoreRule>,
+}
+
+impl IgnoreFilter {
+    /// Suppress every crossing of the given kind (e.g. `"WrapperNesting"`).
+    pub fn ignore_kind(&mut self, kind_name: impl Into<String>, reason: impl Into<String>) {
+        self.rules.push(IgnoreRule::Kind {
+            kind_name: kind_name.into(),
+            reason: reason.into(),
+        });
+    }
+
+    /// Suppress any crossing whose `location` contains `pattern`.
+    pub fn ignore_location(&mut self, pattern: impl Into<String>, reason: impl Into<String>) {
+        self.rules.push(IgnoreRule::LocationPattern {
+            pattern: pattern.into(),
+            reason: reason.into(),
+        });
+    }
+
+    /// The reason this crossing is suppressed, if any rule matches.
+    pub fn reason_for(&self, crossing: &Crossing) -> Option<&str> {
+        self.rules.iter().find_map(|rule| match rule {
+            IgnoreRule::Kind { kind_name, reason } if kind_name == crossing.kind.name() => {
+                Some(reason.as_str())
+            }
+            IgnoreRule::LocationPattern { pattern, reason }
+                if crossing.location.contains(pattern.as_str()) =>
+            {
+                Some(reason.as_str())
+            }
+            _ => None,
+        })
+    }
+}
+
+/// A crossing that was suppressed by an `IgnoreFilter`, kept alongside the
+/// active results with the reason it was ignored.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SuppressedCrossing {
+    pub crossing: Crossing,
+    pub reason: String,
+}
+
+/// Machine-readable crossing report for downstream tooling (CI, editors):
+/// active crossings plus anything an `IgnoreFilter` suppressed, each with
+/// its reason, so nothing silently disappears.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CrossingReport {
+    pub crossings: Vec<Crossing>,
+    pub suppressed: Vec<SuppressedCrossing>,
+}
+
+impl CrossingReport {
+    /// Apply an `IgnoreFilter` to a raw crossing list, producing a report
+    /// where suppressed crossings are moved to `suppressed` (with their
+    /// ignore reason) rather than dropped.
+    pub fn from_crossings(crossings: Vec<Crossing>, filter: &IgnoreFilter) -> Self {
+        let mut report = CrossingReport::default();
+
+        for crossing in crossings {
+            if let Some(reason) = filter.reason_for(&crossing) {
+                report.suppressed.push(SuppressedCrossing {
+                    crossing,
+                    reason: reason.to_string(),
+                });
+            } else {
+                report.crossings.push(crossing);
+            }
+        }
+
+        report
+    }
+
+    /// Serialize this report as pretty-printed JSON.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+/// A lifetime-bound API parameter: either a "sink" (a long-lived container
+/// of references, e.g. `&mut Vec<&'a T>`, that data can flow into) or a
+/// "source" (a reference-returning or reference-accepting bound supplying
+/// shorter-lived data). Elided lifetimes are normalized to fresh anonymous
+/// names (`'_1`, `'_2`, ...) so two independently-elided parameters are
+/// never mistaken for being already unified.
+#[derive(Debug, Clone)]
+pub struct LifetimeBound {
+    /// Normalized lifetime name, e.g. `"'a"` or `"'_3"`.
+    pub name: String,
+    /// Human-readable description of the binding site, for crossing locations.
+    pub description: String,
+}
+
 /// Trait bounds extracted from a crate's API.
 #[derive(Debug, Clone, Default)]
 pub struct ExtractedBounds {
@@ -227,6 +729,12 @@ pub struct ExtractedBounds {
     pub markers: TypeCharge,
     /// Common wrapper types used
     pub wrappers: Vec<String>,
+    /// Container-of-reference parameters that data can flow into
+    pub lifetime_sinks: Vec<LifetimeBound>,
+    /// Reference-returning/accepting bounds that supply data
+    pub lifetime_sources: Vec<LifetimeBound>,
+    /// Counter for naming elided lifetimes uniquely within this bounds set
+    anon_lifetime_counter: u32,
 }
 
 impl ExtractedBounds {
@@ -249,21 +757,25 @@ impl ExtractedBounds {
     pub fn find_conflicts(&self, other: &ExtractedBounds) -> Vec<Crossing> {
         let mut crossings = Vec::new();
 
-        // Check for same-trait different-impl conflicts
+        // Check for same-trait different-impl conflicts, generic-argument-
+        // and associated-type-aware: only flag a conflict when the trait
+        // paths match AND the generic args/assoc bindings are genuinely
+        // incompatible (not just differently-elided GAT lifetimes).
         for bound in &self.trait_bounds {
+            let a_ref = parse_trait_ref(bound);
             if let Some(other_bound) = other
                 .trait_bounds
                 .iter()
-                .find(|b| trait_base_name(b) == trait_base_name(bound) && b != &bound)
+                .find(|b| trait_base_name(&parse_trait_ref(b).path) == trait_base_name(&a_ref.path))
             {
-                crossings.push(Crossing::trait_conflict(
-                    "unknown",
-                    0,
-                    1,
-                    trait_base_name(bound),
-                    bound,
-                    other_bound,
-                ));
+                let b_ref = parse_trait_ref(other_bound);
+                if let Some((trait_name, diff_a, diff_b)) =
+                    conflicting_trait_refs(bound, other_bound, &a_ref, &b_ref)
+                {
+                    crossings.push(Crossing::trait_conflict(
+                        "unknown", 0, 1, trait_name, diff_a, diff_b,
+                    ));
+                }
             }
         }
 
@@ -274,6 +786,79 @@ impl ExtractedBounds {
             crossings.push(marker_crossing);
         }
 
+        crossings.extend(self.find_lifetime_crossings(other));
+
+        crossings
+    }
+
+    /// Normalize a lifetime name: an elided (`None`) lifetime gets a fresh
+    /// anonymous name unique within this bounds set, so two independently
+    /// elided parameters are never treated as already unified.
+    fn normalize_lifetime(&mut self, lifetime: Option<&str>) -> String {
+        match lifetime {
+            Some(name) => name.to_string(),
+            None => {
+                self.anon_lifetime_counter += 1;
+                format!("'_{}", self.anon_lifetime_counter)
+            }
+        }
+    }
+
+    /// Record a "sink" bound: an API parameter that is a long-lived
+    /// container of references (e.g. `&mut Vec<&'a T>`) that data can flow
+    /// into. Pass `None` for an elided lifetime.
+    pub fn push_lifetime_sink(&mut self, lifetime: Option<&str>, description: impl Into<String>) {
+        let name = self.normalize_lifetime(lifetime);
+        self.lifetime_sinks.push(LifetimeBound {
+            name,
+            description: description.into(),
+        });
+    }
+
+    /// Record a "source" bound: a reference-returning or reference-accepting
+    /// API member that supplies shorter-lived data. Pass `None` for an
+    /// elided lifetime.
+    pub fn push_lifetime_source(&mut self, lifetime: Option<&str>, description: impl Into<String>) {
+        let name = self.normalize_lifetime(lifetime);
+        self.lifetime_sources.push(LifetimeBound {
+            name,
+            description: description.into(),
+        });
+    }
+
+    /// Detect lifetime crossings between this bounds set's sinks and
+    /// `other`'s sources, and vice versa: pairing a long-lived container
+    /// bound from one crate with a shorter-lived reference bound from the
+    /// other forces their (possibly elided) lifetimes to unify.
+    pub fn find_lifetime_crossings(&self, other: &ExtractedBounds) -> Vec<Crossing> {
+        let mut crossings = Vec::new();
+
+        for sink in &self.lifetime_sinks {
+            for source in &other.lifetime_sources {
+                crossings.push(Crossing::lifetime_intersection(
+                    format!("{} <- {}", sink.description, source.description),
+                    0,
+                    1,
+                    &sink.name,
+                    &source.name,
+                    FlowDirection::AIsSink,
+                ));
+            }
+        }
+
+        for sink in &other.lifetime_sinks {
+            for source in &self.lifetime_sources {
+                crossings.push(Crossing::lifetime_intersection(
+                    format!("{} <- {}", sink.description, source.description),
+                    0,
+                    1,
+                    &sink.name,
+                    &source.name,
+                    FlowDirection::BIsSink,
+                ));
+            }
+        }
+
         crossings
     }
 }
@@ -283,6 +868,106 @@ fn trait_base_name(trait_path: &str) -> &str {
     trait_path.rsplit("::").next().unwrap_or(trait_path)
 }
 
+/// A parsed trait reference: its base path, positional generic arguments,
+/// and associated-type bindings (e.g. `Item = u8`). A bare lifetime
+/// argument (e.g. a GAT's own `'a` in `Stream::Item<'a>`) is normalized
+/// away - it's bound per call-site and shouldn't cause false-positive
+/// conflicts.
+#[derive(Debug, Clone, Default)]
+struct TraitRef {
+    path: String,
+    generic_args: Vec<String>,
+    assoc_bindings: Vec<(String, String)>,
+}
+
+/// Parse a trait bound string like `"AsyncRead"`, `"AsyncRead<Item = u8>"`,
+/// or `"Stream::Item<'a>"` into its structural parts.
+fn parse_trait_ref(trait_bound: &str) -> TraitRef {
+    let trait_bound = trait_bound.trim();
+    let Some(open) = trait_bound.find('<') else {
+        return TraitRef {
+            path: trait_bound.to_string(),
+            ..Default::default()
+        };
+    };
+    let close = trait_bound.rfind('>').unwrap_or(trait_bound.len());
+
+    let path = trait_bound[..open].trim().to_string();
+    let inner = &trait_bound[open + 1..close];
+
+    let mut generic_args = Vec::new();
+    let mut assoc_bindings = Vec::new();
+
+    for part in inner.split(',') {
+        let part = part.trim();
+        if part.is_empty() || part.starts_with('\'') {
+            continue; // bare GAT lifetime - normalized away
+        }
+        match part.split_once('=') {
+            Some((name, value)) => {
+                assoc_bindings.push((name.trim().to_string(), value.trim().to_string()));
+            }
+            None => generic_args.push(part.to_string()),
+        }
+    }
+
+    TraitRef {
+        path,
+        generic_args,
+        assoc_bindings,
+    }
+}
+
+/// Decide whether two same-base-name trait references genuinely conflict,
+/// returning `(trait_name, bound_a, bound_b)` describing the differing
+/// argument or binding, if so. `None` means they're compatible (identical,
+/// or differ only in a normalized-away GAT lifetime).
+fn conflicting_trait_refs(
+    raw_a: &str,
+    raw_b: &str,
+    a: &TraitRef,
+    b: &TraitRef,
+) -> Option<(String, String, String)> {
+    if raw_a == raw_b {
+        return None;
+    }
+
+    let base = trait_base_name(&a.path).to_string();
+
+    // Associated-type binding mismatch: the strongest form of conflict.
+    for (name, value_a) in &a.assoc_bindings {
+        if let Some((_, value_b)) = b.assoc_bindings.iter().find(|(n, _)| n == name) {
+            if value_a != value_b {
+                return Some((
+                    format!("{}::{}", base, name),
+                    format!("{} = {}", name, value_a),
+                    format!("{} = {}", name, value_b),
+                ));
+            }
+        }
+    }
+
+    // Generic argument mismatch on an otherwise-matching trait.
+    if !a.generic_args.is_empty() && !b.generic_args.is_empty() && a.generic_args != b.generic_args
+    {
+        return Some((
+            format!("{}<generic>", base),
+            a.generic_args.join(", "),
+            b.generic_args.join(", "),
+        ));
+    }
+
+    // Same path and same arguments once GAT lifetimes are normalized away.
+    if a.path == b.path && a.generic_args == b.generic_args && a.assoc_bindings == b.assoc_bindings
+    {
+        return None;
+    }
+
+    // Same trait, nothing finer to compare - different implementations
+    // (e.g. tokio's AsyncRead vs smol's).
+    Some((base, raw_a.to_string(), raw_b.to_string()))
+}
+
 /// Detect crossings from two crates' public APIs.
 /// This is a simplified heuristic - full analysis would require type flow.
 pub fn detect_crossings_heuristic(
@@ -298,14 +983,36 @@ pub fn detect_crossings_heuristic(
         for wrapper_b in &crate_b_bounds.wrappers {
             // If both crates have wrapper types, order might matter
             if is_outer_wrapper(wrapper_a) && is_outer_wrapper(wrapper_b) {
+                // Compute what wrapper_a actually does to crate_b's charge when
+                // nested around it, and check whether that contradicts what
+                // crate_b itself requires of its contents downstream.
+                let composed = TypeCharge::compose(
+                    &crate_a_bounds.markers,
+                    &crate_b_bounds.markers,
+                    wrapper_a,
+                );
+                let can_commute =
+                    Crossing::marker_conflict("", 0, 1, &composed, &crate_b_bounds.markers)
+                        .is_none();
+
                 crossings.push(Crossing::wrapper_nesting(
                     format!("{}+{} composition", crate_a_name, crate_b_name),
                     0,
                     1,
                     wrapper_a,
                     wrapper_b,
-                    false, // Conservative: assume order matters
+                    can_commute,
                 ));
+
+                if let Some(marker_crossing) = Crossing::marker_conflict(
+                    format!("{}+{} wrapper composition", crate_a_name, crate_b_name),
+                    0,
+                    1,
+                    &composed,
+                    &crate_b_bounds.markers,
+                ) {
+                    crossings.push(marker_crossing);
+                }
             }
         }
     }
@@ -407,4 +1114,316 @@ mod tests {
         let conflicts = bounds_a.find_conflicts(&bounds_b);
         assert!(!conflicts.is_empty());
     }
+
+    #[test]
+    fn test_compose_arc_requires_inner_send_sync() {
+        let arc_default = TypeCharge::default();
+        let send_only = TypeCharge {
+            send: Ternary::Yes,
+            sync: Ternary::No,
+            ..Default::default()
+        };
+
+        let composed = TypeCharge::compose(&arc_default, &send_only, "Arc<T>");
+        assert_eq!(composed.send, Ternary::No);
+        assert_eq!(composed.sync, Ternary::No);
+    }
+
+    #[test]
+    fn test_compose_mutex_upgrades_send_to_sync() {
+        let mutex_default = TypeCharge::default();
+        let send_not_sync = TypeCharge {
+            send: Ternary::Yes,
+            sync: Ternary::No,
+            ..Default::default()
+        };
+
+        let composed = TypeCharge::compose(&mutex_default, &send_not_sync, "std::sync::Mutex");
+        assert_eq!(composed.send, Ternary::Yes);
+        assert_eq!(composed.sync, Ternary::Yes);
+    }
+
+    #[test]
+    fn test_compose_mutex_guard_never_send() {
+        let guard_default = TypeCharge::default();
+        let inner_send = TypeCharge {
+            send: Ternary::Yes,
+            sync: Ternary::Yes,
+            ..Default::default()
+        };
+
+        let composed = TypeCharge::compose(&guard_default, &inner_send, "MutexGuard");
+        assert_eq!(composed.send, Ternary::No);
+        assert_eq!(composed.sync, Ternary::Yes);
+    }
+
+    #[test]
+    fn test_compose_unknown_wrapper_never_promoted_to_yes() {
+        let outer = TypeCharge {
+            send: Ternary::Unknown,
+            ..Default::default()
+        };
+        let inner = TypeCharge {
+            send: Ternary::Yes,
+            ..Default::default()
+        };
+
+        let composed = TypeCharge::compose(&outer, &inner, "CustomWrapper");
+        assert_eq!(composed.send, Ternary::Unknown);
+    }
+
+    #[test]
+    fn test_detect_crossings_flags_wrapper_marker_conflict() {
+        let mut bounds_a = ExtractedBounds::default();
+        bounds_a.wrappers.push("Arc".to_string());
+
+        let mut bounds_b = ExtractedBounds::default();
+        bounds_b.wrappers.push("RefCell".to_string());
+        bounds_b.markers.send = Ternary::Yes;
+        bounds_b.markers.sync = Ternary::No;
+
+        let crossings = detect_crossings_heuristic("crate_a", &bounds_a, "crate_b", &bounds_b);
+        assert!(crossings.iter().any(|c| matches!(
+            c.kind,
+            CrossingKind::MarkerConflict {
+                send_mismatch: true,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_negative_impl_sets_no_and_asserted() {
+        let source = r#"
+            struct MyType;
+            impl !Send for MyType {}
+        "#;
+        let extracted = extract_markers_for_type(source, "MyType");
+        assert_eq!(extracted.markers.send, Ternary::No);
+        assert!(extracted.markers.send_asserted);
+    }
+
+    #[test]
+    fn test_unsafe_impl_sets_yes_and_asserted() {
+        let source = r#"
+            struct MyType { ptr: *const u8 }
+            unsafe impl Send for MyType {}
+        "#;
+        let extracted = extract_markers_for_type(source, "MyType");
+        assert_eq!(extracted.markers.send, Ternary::Yes);
+        assert!(extracted.markers.send_asserted);
+        assert!(!extracted.structurally_send);
+    }
+
+    #[test]
+    fn test_absent_impl_leaves_unknown() {
+        let source = r#"
+            struct MyType;
+        "#;
+        let extracted = extract_markers_for_type(source, "MyType");
+        assert_eq!(extracted.markers.send, Ternary::Unknown);
+        assert!(!extracted.markers.send_asserted);
+    }
+
+    #[test]
+    fn test_detect_unsound_assertion_flags_raw_pointer_send() {
+        let source = r#"
+            struct MyType { ptr: *mut u8 }
+            unsafe impl Send for MyType {}
+        "#;
+        let extracted = extract_markers_for_type(source, "MyType");
+
+        let crossing = detect_unsound_assertion("test.rs:2", 0, 0, "MyType", &extracted);
+        assert!(crossing.is_some());
+        match crossing.unwrap().kind {
+            CrossingKind::UnsoundMarkerAssertion { trait_name, .. } => {
+                assert_eq!(trait_name, "Send");
+            }
+            other => panic!("Expected UnsoundMarkerAssertion, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_detect_unsound_assertion_clean_when_structurally_send() {
+        let source = r#"
+            struct MyType { data: u8 }
+            unsafe impl Send for MyType {}
+        "#;
+        let extracted = extract_markers_for_type(source, "MyType");
+
+        let crossing = detect_unsound_assertion("test.rs:2", 0, 0, "MyType", &extracted);
+        assert!(crossing.is_none());
+    }
+
+    #[test]
+    fn test_lifetime_crossing_pairs_sink_with_source() {
+        let mut bounds_a = ExtractedBounds::default();
+        bounds_a.push_lifetime_sink(Some("'a"), "fn push(&mut self, v: &'a mut Vec<&'a T>)");
+
+        let mut bounds_b = ExtractedBounds::default();
+        bounds_b.push_lifetime_source(Some("'b"), "fn borrow(&self) -> &'b U");
+
+        let crossings = bounds_a.find_lifetime_crossings(&bounds_b);
+        assert_eq!(crossings.len(), 1);
+        match &crossings[0].kind {
+            CrossingKind::LifetimeIntersection {
+                lifetime_a,
+                lifetime_b,
+                flow_direction,
+            } => {
+                assert_eq!(lifetime_a, "'a");
+                assert_eq!(lifetime_b, "'b");
+                assert_eq!(*flow_direction, FlowDirection::AIsSink);
+            }
+            other => panic!("Expected LifetimeIntersection, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_lifetime_crossing_describe() {
+        let crossing = Crossing::lifetime_intersection(
+            "src/lib.rs:1",
+            0,
+            1,
+            "'a",
+            "'b",
+            FlowDirection::AIsSink,
+        );
+        let desc = crossing.describe();
+        assert!(desc.contains("'a"));
+        assert!(desc.contains("'b"));
+        assert!(desc.contains("flows into"));
+    }
+
+    #[test]
+    fn test_elided_lifetimes_normalized_to_distinct_anonymous_names() {
+        let mut bounds = ExtractedBounds::default();
+        bounds.push_lifetime_sink(None, "sink one");
+        bounds.push_lifetime_sink(None, "sink two");
+
+        assert_ne!(bounds.lifetime_sinks[0].name, bounds.lifetime_sinks[1].name);
+        assert_eq!(bounds.lifetime_sinks[0].name, "'_1");
+        assert_eq!(bounds.lifetime_sinks[1].name, "'_2");
+    }
+
+    #[test]
+    fn test_severity_assignment() {
+        let marker = Crossing::marker_conflict(
+            "test",
+            0,
+            1,
+            &TypeCharge {
+                send: Ternary::Yes,
+                ..Default::default()
+            },
+            &TypeCharge {
+                send: Ternary::No,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(marker.severity(), Severity::Error);
+
+        let commutable = Crossing::wrapper_nesting("test", 0, 1, "Box", "Arc", true);
+        assert_eq!(commutable.severity(), Severity::Info);
+
+        let non_commutable = Crossing::wrapper_nesting("test", 0, 1, "Box", "Arc", false);
+        assert_eq!(non_commutable.severity(), Severity::Warning);
+    }
+
+    #[test]
+    fn test_crossing_report_roundtrips_json() {
+        let crossing = Crossing::wrapper_nesting("test", 0, 1, "Box", "Arc", false);
+        let report = CrossingReport::from_crossings(vec![crossing], &IgnoreFilter::default());
+
+        let json = report.to_json().unwrap();
+        assert!(json.contains("WrapperNesting"));
+
+        let parsed: CrossingReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.crossings.len(), 1);
+        assert!(parsed.suppressed.is_empty());
+    }
+
+    #[test]
+    fn test_ignore_filter_suppresses_by_kind_with_reason() {
+        let crossing = Crossing::wrapper_nesting("test", 0, 1, "Box", "Arc", true);
+
+        let mut filter = IgnoreFilter::default();
+        filter.ignore_kind("WrapperNesting", "commutable wrappers are expected here");
+
+        let report = CrossingReport::from_crossings(vec![crossing], &filter);
+        assert!(report.crossings.is_empty());
+        assert_eq!(report.suppressed.len(), 1);
+        assert_eq!(
+            report.suppressed[0].reason,
+            "commutable wrappers are expected here"
+        );
+    }
+
+    #[test]
+    fn test_ignore_filter_suppresses_by_location_pattern() {
+        let crossing = Crossing::trait_conflict("vendor/generated.rs:1", 0, 1, "Foo", "a", "b");
+
+        let mut filter = IgnoreFilter::default();
+        filter.ignore_location("vendor/", "generated code, not ours to fix");
+
+        let report = CrossingReport::from_crossings(vec![crossing], &filter);
+        assert!(report.crossings.is_empty());
+        assert_eq!(report.suppressed.len(), 1);
+    }
+
+    #[test]
+    fn test_assoc_binding_mismatch_is_stronger_conflict() {
+        let mut bounds_a = ExtractedBounds::default();
+        bounds_a
+            .trait_bounds
+            .insert("AsyncRead<Item = u8>".to_string());
+
+        let mut bounds_b = ExtractedBounds::default();
+        bounds_b
+            .trait_bounds
+            .insert("AsyncRead<Item = u16>".to_string());
+
+        let conflicts = bounds_a.find_conflicts(&bounds_b);
+        let trait_conflict = conflicts
+            .iter()
+            .find_map(|c| match &c.kind {
+                CrossingKind::TraitConflict { trait_name, .. } => Some(trait_name.clone()),
+                _ => None,
+            })
+            .expect("expected a TraitConflict");
+        assert_eq!(trait_conflict, "AsyncRead::Item");
+    }
+
+    #[test]
+    fn test_generic_arg_mismatch_labeled_distinctly() {
+        let mut bounds_a = ExtractedBounds::default();
+        bounds_a.trait_bounds.insert("Into<String>".to_string());
+
+        let mut bounds_b = ExtractedBounds::default();
+        bounds_b.trait_bounds.insert("Into<u64>".to_string());
+
+        let conflicts = bounds_a.find_conflicts(&bounds_b);
+        assert!(conflicts.iter().any(|c| matches!(
+            &c.kind,
+            CrossingKind::TraitConflict { trait_name, .. } if trait_name == "Into<generic>"
+        )));
+    }
+
+    #[test]
+    fn test_gat_lifetime_does_not_cause_false_positive() {
+        let mut bounds_a = ExtractedBounds::default();
+        bounds_a.trait_bounds.insert("Stream::Item<'a>".to_string());
+
+        let mut bounds_b = ExtractedBounds::default();
+        bounds_b.trait_bounds.insert("Stream::Item<'b>".to_string());
+
+        // The lifetimes differ only in name, not in kind, so no conflict
+        // should be reported as a generic-argument mismatch.
+        let conflicts = bounds_a.find_conflicts(&bounds_b);
+        assert!(!conflicts.iter().any(|c| matches!(
+            &c.kind,
+            CrossingKind::TraitConflict { trait_name, .. } if trait_name.ends_with("<generic>")
+        )));
+    }
 }
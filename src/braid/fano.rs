@@ -154,32 +154,121 @@ pub fn find_worst_violation(coords_a: &[f32; 8], coords_b: &[f32; 8]) -> Option<
     worst
 }
 
-/// Compute the "octonion parity" - whether the crossing is Real or Imaginary.
-///
-/// In true octonion algebra, e_i * e_j * e_k = Â±1 depending on the line.
-/// We approximate this: if the coordinates "sum to an integer" on active
-/// lines, the crossing is Real (resolvable). Otherwise, Imaginary (essential).
-pub fn octonion_parity(coords_a: &[f32; 8], coords_b: &[f32; 8]) -> OctonionParity {
-    // e0 is the "real" component - both should have high utility
-    let real_component = coords_a[0] * coords_b[0];
+/// `e_i * e_j = sign * e_{index}`.
+type MulEntry = (usize, i32);
+
+/// A fixed, arbitrary imaginary unit used as the third argument when probing
+/// associativity in `octonion_parity`. The associator is trilinear, so any
+/// fixed nonzero `z` works as the probe; `e1` is chosen with no special
+/// meaning beyond being a Fano-line member like every other imaginary unit.
+const REFERENCE_UNIT: [f32; 8] = [0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+
+/// Indices into `FANO_LINES` whose first two points are swapped before the
+/// cyclic orientation is applied. Not every orientation of the seven lines
+/// yields the octonions; with exactly these two lines reversed, the table
+/// is alternative and every non-collinear triple of imaginary units has a
+/// nonzero associator.
+const REVERSED_LINES: [usize; 2] = [4, 6];
+
+/// Build the 8x8 signed octonion multiplication table from `FANO_LINES`.
+/// `e0` is the real identity (`e0 * e_j = e_j` for all `j`). Every imaginary
+/// unit squares to `-e0`. Each Fano line `{a, b, c}` (with `a` and `b`
+/// swapped for the lines in `REVERSED_LINES`) is given a fixed cyclic
+/// orientation - `e_a * e_b = e_c`, `e_b * e_c = e_a`, `e_c * e_a = e_b` -
+/// with the reverse products negated, so the table is alternative but not
+/// commutative, as octonion multiplication is.
+fn multiplication_table() -> [[MulEntry; 8]; 8] {
+    let mut table = [[(0usize, 1i32); 8]; 8];
+
+    for i in 0..8 {
+        table[0][i] = (i, 1);
+        table[i][0] = (i, 1);
+    }
 
-    // Imaginary components: check if their interaction is "clean"
-    let mut imaginary_sum = 0.0f32;
     for i in 1..8 {
-        imaginary_sum += coords_a[i] * coords_b[i];
+        table[i][i] = (0, -1);
+    }
+
+    for (idx, line) in FANO_LINES.iter().enumerate() {
+        let [mut a, mut b, c] = line.points;
+        if REVERSED_LINES.contains(&idx) {
+            std::mem::swap(&mut a, &mut b);
+        }
+        table[a][b] = (c, 1);
+        table[b][c] = (a, 1);
+        table[c][a] = (b, 1);
+        table[b][a] = (c, -1);
+        table[c][b] = (a, -1);
+        table[a][c] = (b, -1);
+    }
+
+    table
+}
+
+/// Multiply two octonions (given as `[f32; 8]` coordinate vectors) using
+/// `table`.
+fn octonion_mul(x: &[f32; 8], y: &[f32; 8], table: &[[MulEntry; 8]; 8]) -> [f32; 8] {
+    let mut result = [0.0f32; 8];
+
+    for (i, &xi) in x.iter().enumerate() {
+        if xi == 0.0 {
+            continue;
+        }
+        for (j, &yj) in y.iter().enumerate() {
+            if yj == 0.0 {
+                continue;
+            }
+            let (k, sign) = table[i][j];
+            result[k] += sign as f32 * xi * yj;
+        }
     }
 
-    // If imaginary sum is close to an integer, we can "flatten" the braid
-    let fractional_part = (imaginary_sum - imaginary_sum.round()).abs();
+    result
+}
+
+/// The associator `[x, y, z] = (x*y)*z - x*(y*z)`, which is identically zero
+/// exactly when `x`, `y`, `z` lie on a common Fano line (or any of them is a
+/// multiple of `e0`) - octonion multiplication is alternative but not
+/// associative outside that case.
+fn associator(x: &[f32; 8], y: &[f32; 8], z: &[f32; 8], table: &[[MulEntry; 8]; 8]) -> [f32; 8] {
+    let xy_z = octonion_mul(&octonion_mul(x, y, table), z, table);
+    let x_yz = octonion_mul(x, &octonion_mul(y, z, table), table);
+
+    let mut result = [0.0f32; 8];
+    for i in 0..8 {
+        result[i] = xy_z[i] - x_yz[i];
+    }
+    result
+}
+
+fn norm(v: &[f32; 8]) -> f32 {
+    v.iter().map(|c| c * c).sum::<f32>().sqrt()
+}
+
+/// Tolerance below which an associator's norm counts as "zero" - coordinates
+/// are continuous crate metrics, not exact octonion units, so this absorbs
+/// floating-point and measurement noise around the true zero.
+const ASSOCIATOR_TOLERANCE: f32 = 0.2;
+
+/// Compute the "octonion parity" - whether the crossing is Real or Imaginary.
+///
+/// Treats `coords_a` and `coords_b` as octonions and computes the associator
+/// `[coords_a, coords_b, REFERENCE_UNIT]` using the genuine Cayley-Dickson-
+/// style multiplication table built from `FANO_LINES`. The associator
+/// vanishes exactly when the three operands lie on a common Fano line (or
+/// involve `e0`), so its norm is a principled residue: near zero means the
+/// crossing is algebraically clean (Real, resolvable), and a large residue
+/// means genuine non-associativity - an essential (Imaginary) tangle.
+pub fn octonion_parity(coords_a: &[f32; 8], coords_b: &[f32; 8]) -> OctonionParity {
+    let table = multiplication_table();
+    let residue = norm(&associator(coords_a, coords_b, &REFERENCE_UNIT, &table));
 
-    if fractional_part < 0.2 && real_component > 0.3 {
+    if residue < ASSOCIATOR_TOLERANCE {
         OctonionParity::Real {
-            confidence: 1.0 - fractional_part,
+            confidence: (1.0 - residue).max(0.0),
         }
     } else {
-        OctonionParity::Imaginary {
-            residue: fractional_part,
-        }
+        OctonionParity::Imaginary { residue }
     }
 }
 
@@ -224,6 +313,59 @@ mod tests {
         // The e6 (environment) difference should hurt the score
     }
 
+    #[test]
+    fn test_multiplication_table_squares_and_identity() {
+        let table = multiplication_table();
+        assert_eq!(table[0][3], (3, 1));
+        assert_eq!(table[3][0], (3, 1));
+        for i in 1..8 {
+            assert_eq!(table[i][i], (0, -1));
+        }
+    }
+
+    #[test]
+    fn test_multiplication_table_line_orientation_and_reverse_negates() {
+        let table = multiplication_table();
+        let [a, b, c] = FANO_LINES[0].points;
+        assert_eq!(table[a][b], (c, 1));
+        assert_eq!(table[b][a], (c, -1));
+    }
+
+    #[test]
+    fn test_associator_vanishes_on_a_common_fano_line() {
+        let table = multiplication_table();
+        let [a, b, c] = FANO_LINES[0].points;
+
+        let mut ea = [0.0f32; 8];
+        ea[a] = 1.0;
+        let mut eb = [0.0f32; 8];
+        eb[b] = 1.0;
+        let mut ec = [0.0f32; 8];
+        ec[c] = 1.0;
+
+        let assoc = associator(&ea, &eb, &ec, &table);
+        assert!(
+            norm(&assoc) < 1e-6,
+            "associator should vanish on a Fano line, got {assoc:?}"
+        );
+    }
+
+    #[test]
+    fn test_associator_nonzero_off_a_common_line() {
+        let table = multiplication_table();
+        // e1, e3, e2 don't lie on a common Fano line, so the associator
+        // shouldn't vanish for this combination.
+        let mut e1 = [0.0f32; 8];
+        e1[1] = 1.0;
+        let mut e3 = [0.0f32; 8];
+        e3[3] = 1.0;
+        let mut e2 = [0.0f32; 8];
+        e2[2] = 1.0;
+
+        let assoc = associator(&e1, &e3, &e2, &table);
+        assert!(norm(&assoc) > 1e-3);
+    }
+
     #[test]
     fn test_octonion_parity() {
         // Compatible crates: should be Real
@@ -10,10 +10,12 @@
 //! - **Tangle**: Topological conflict (e.g., competing async runtimes)
 //! - **Fano Constraint**: Parity check using the 8D octonion coordinates
 
+pub mod codegen;
 pub mod crossing;
 pub mod fano;
 pub mod word;
 
+pub use codegen::{GeneratedShim, ShimDependency};
 pub use crossing::{Crossing, CrossingKind, TypeCharge};
 pub use fano::{FanoConstraint, FanoLine};
 pub use word::{BraidGenerator, BraidWord};
@@ -81,10 +83,17 @@ impl ManifoldConflict {
 }
 
 /// Analyze a pair of crates for potential tangles.
+///
+/// `word`, if given, is the braid word for the strands `crossing` connects:
+/// a borderline-low Fano score (see the THIRD check below) is downgraded
+/// from `Essential` to `Clean` when that word reduces to the identity,
+/// since a word that fully cancels means the strands can be disentangled
+/// regardless of what the raw Fano score alone suggests.
 pub fn analyze_crossing(
     crate_a: &OctonionProfile,
     crate_b: &OctonionProfile,
     crossing: &Crossing,
+    word: Option<&BraidWord>,
 ) -> CrossingAnalysis {
     // Check Fano constraints for compatibility score
     let fano_score = fano::check_all_lines(&crate_a.coeffs, &crate_b.coeffs);
@@ -108,8 +117,13 @@ pub fn analyze_crossing(
     }
 
     // THIRD: If no explicit crossing conflict, use Fano score
-    // Low Fano score with no template = unknown conflict
+    // Low Fano score with no template = unknown conflict, unless the braid
+    // word for this crossing reduces to the identity - then the strands
+    // can be disentangled regardless of the raw score.
     if fano_score < 0.5 {
+        if word.is_some_and(BraidWord::is_trivial) {
+            return CrossingAnalysis::Clean;
+        }
         return CrossingAnalysis::Essential {
             conflict: ManifoldConflict::new(
                 0,
@@ -124,6 +138,25 @@ pub fn analyze_crossing(
     CrossingAnalysis::Clean
 }
 
+/// Generate the shim source for a `Resolvable` analysis, so a caller that
+/// just got one back from `analyze_crossing` doesn't have to pull the
+/// template name back out of it before calling `codegen::generate_shim`
+/// itself. `None` for `Clean`/`Essential` (nothing to generate) or for a
+/// `Resolvable` whose template name `codegen` doesn't recognize.
+pub fn generate_shim_for(
+    analysis: &CrossingAnalysis,
+    crate_a: &OctonionProfile,
+    crate_b: &OctonionProfile,
+    crossing: &Crossing,
+) -> Option<GeneratedShim> {
+    match analysis {
+        CrossingAnalysis::Resolvable { template, .. } => {
+            codegen::generate_shim(template, crate_a, crate_b, crossing)
+        }
+        _ => None,
+    }
+}
+
 /// Find a pre-verified template that matches this crossing pattern.
 fn find_matching_template(
     crate_a: &OctonionProfile,
@@ -206,6 +239,7 @@ mod tests {
             version: "1.0.0".to_string(),
             coeffs,
             raw: RawMetrics::default(),
+            deps: Vec::new(),
         }
     }
 
@@ -229,7 +263,7 @@ mod tests {
             involves_pin: false,
         };
 
-        let result = analyze_crossing(&tokio, &smol, &crossing);
+        let result = analyze_crossing(&tokio, &smol, &crossing, None);
         match result {
             CrossingAnalysis::Resolvable { template, .. } => {
                 assert_eq!(template, "AsyncReadAdapter");
@@ -258,7 +292,7 @@ mod tests {
             involves_pin: false,
         };
 
-        let result = analyze_crossing(&tokio, &embedded, &crossing);
+        let result = analyze_crossing(&tokio, &embedded, &crossing, None);
         match result {
             CrossingAnalysis::Essential { conflict, .. } => {
                 assert!(conflict.dim_a == 3 || conflict.dim_b == 3);
@@ -266,4 +300,36 @@ mod tests {
             other => panic!("Expected Essential conflict, got {:?}", other),
         }
     }
+
+    #[test]
+    fn test_trivial_word_downgrades_borderline_fano_to_clean() {
+        // Dims 5 and 7 differ sharply (driving the Fano score below the
+        // 0.5 threshold) while every dimension `detect_essential_conflict`
+        // checks (1, 2, 3, 6) stays matched between the two profiles, so
+        // this lands in the THIRD (borderline-Fano) branch rather than the
+        // FIRST (essential-conflict) one.
+        let a = make_profile("a", [0.9, 0.9, 0.9, 0.9, 0.9, 0.9, 0.9, 0.9]);
+        let b = make_profile("b", [0.9, 0.9, 0.9, 0.9, 0.9, 0.0, 0.9, 0.0]);
+
+        let crossing = Crossing {
+            location: "src/lib.rs:1".into(),
+            sigma_i: 0,
+            sigma_j: 1,
+            kind: CrossingKind::Generic {
+                description: "unspecified".into(),
+            },
+            involves_pin: false,
+        };
+
+        let without_word = analyze_crossing(&a, &b, &crossing, None);
+        assert!(matches!(without_word, CrossingAnalysis::Essential { .. }));
+
+        let mut word = BraidWord::new();
+        word.push(BraidGenerator::new(0, "a"));
+        word.push(BraidGenerator::new(0, "a").invert());
+        assert!(word.is_trivial());
+
+        let with_trivial_word = analyze_crossing(&a, &b, &crossing, Some(&word));
+        assert!(matches!(with_trivial_word, CrossingAnalysis::Clean));
+    }
 }
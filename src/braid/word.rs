@@ -190,9 +190,14 @@ impl BraidWord {
                 changed = true;
             }
 
-            // Try commutations to bring related generators together
+            // Bubble commuting generators toward ascending index order.
+            // Only swapping pairs that are currently out of that order (as
+            // opposed to calling `commute` unconditionally) gives this a
+            // fixed point - a commuting pair already in ascending order
+            // would otherwise toggle back and forth forever.
             for i in 0..self.generators.len().saturating_sub(1) {
-                if self.commute(i) {
+                let out_of_order = self.generators[i].index > self.generators[i + 1].index;
+                if out_of_order && self.commute(i) {
                     rewrites += 1;
                     changed = true;
                     break;
@@ -228,6 +233,125 @@ impl BraidWord {
         tangles
     }
 
+    /// Number of strands this word acts on: two more than the highest
+    /// generator index used (σᵢ crosses strands i and i+1, so strands
+    /// 0..=i+1 exist), or 2 for an empty word.
+    pub fn strand_count(&self) -> usize {
+        self.generators
+            .iter()
+            .map(|g| g.index + 2)
+            .max()
+            .unwrap_or(2)
+    }
+
+    /// The permutation this word induces on its strands - `permutation()[k]`
+    /// is the strand (by starting position) that ends up at position `k`. Both σᵢ and
+    /// σᵢ⁻¹ swap strands i and i+1, so inversion doesn't affect this: it's
+    /// a purely combinatorial property of where each strand ends up, not
+    /// which one crosses over which.
+    pub fn permutation(&self) -> Vec<usize> {
+        let n = self.strand_count();
+        let mut perm: Vec<usize> = (0..n).collect();
+        for g in &self.generators {
+            perm.swap(g.index, g.index + 1);
+        }
+        perm
+    }
+
+    /// Left-canonical (Garside-style) factorization into simple factors:
+    /// maximal runs of generators that form a *reduced* word, i.e. where
+    /// every generator strictly increases the running permutation's number
+    /// of inversions (`perm[i] < perm[i + 1]` right before it's applied).
+    /// This is the standard Coxeter-length test for whether appending a
+    /// generator keeps a word minimal for its resulting permutation, and a
+    /// maximal such run is exactly a divisor of the half-twist Δ - a simple
+    /// element.
+    ///
+    /// Inverse generators are resolved by `normalize` first (cancellation
+    /// and commutation); a word that still has inverse generators left over
+    /// needs Δ-power bookkeeping this implementation doesn't do, so it's
+    /// returned as a single unfactored block rather than guessed at.
+    ///
+    /// This factorization is a function of the literal generator sequence,
+    /// not of the underlying braid element: two words for the same braid
+    /// that differ by a Yang-Baxter move (σᵢσᵢ₊₁σᵢ = σᵢ₊₁σᵢσᵢ₊₁) rather
+    /// than just commutation can land in different factors. `is_equivalent`
+    /// runs Yang-Baxter rewrites first to close some of that gap, but not
+    /// all of it.
+    pub fn garside_normal_form(&self) -> Vec<Vec<usize>> {
+        let mut reduced = self.clone();
+        reduced.normalize();
+
+        if reduced.generators.iter().any(|g| g.inverse) {
+            return vec![reduced.generators.iter().map(|g| g.index).collect()];
+        }
+
+        let n = reduced.strand_count();
+        let mut remaining: Vec<usize> = reduced.generators.iter().map(|g| g.index).collect();
+        let mut factors = Vec::new();
+
+        while !remaining.is_empty() {
+            let mut perm: Vec<usize> = (0..n).collect();
+            let mut factor = Vec::new();
+
+            while !remaining.is_empty() {
+                let g = remaining[0];
+                if perm[g] < perm[g + 1] {
+                    perm.swap(g, g + 1);
+                    factor.push(g);
+                    remaining.remove(0);
+                } else {
+                    break;
+                }
+            }
+
+            factors.push(factor);
+        }
+
+        factors
+    }
+
+    /// Whether `self` and `other` decide as the same braid, as far as free
+    /// reduction, commutation, Yang-Baxter rewriting, and left-canonical
+    /// factorization can determine (see `garside_normal_form` for the cases
+    /// it can't fully resolve). A `false` result means "not provably equal
+    /// by these relations," not "provably distinct" - though a permutation
+    /// mismatch, checked first, is always a genuine proof of inequality.
+    pub fn is_equivalent(&self, other: &BraidWord) -> bool {
+        if self.permutation() != other.permutation() {
+            return false;
+        }
+
+        let mut a = self.clone();
+        let mut b = other.clone();
+        exhaustively_normalize(&mut a);
+        exhaustively_normalize(&mut b);
+
+        a.garside_normal_form() == b.garside_normal_form()
+    }
+
+    /// Reduce this word by free cancellation, far commutation, and
+    /// Yang-Baxter rewriting (see `exhaustively_normalize`), without
+    /// mutating `self`. Unlike `normalize`, which only applies cancellation
+    /// and commutation in place, this also tries Yang-Baxter rewrites, so
+    /// two words that differ by a braid relation have a better chance of
+    /// reducing to the same generator sequence. A word containing a
+    /// σᵢσᵢ₊₁σᵢ pattern has no fixed point under these rewrites (the pattern
+    /// flips back and forth), so the result is whatever the word is after
+    /// `MAX_NORMALIZE_PASSES` passes.
+    pub fn reduce(&self) -> BraidWord {
+        let mut reduced = self.clone();
+        exhaustively_normalize(&mut reduced);
+        reduced
+    }
+
+    /// Whether this word reduces to the empty word ε - i.e. the strands it
+    /// crosses can be fully disentangled with no essential conflict left
+    /// over.
+    pub fn is_trivial(&self) -> bool {
+        self.reduce().generators.is_empty()
+    }
+
     /// Render as symbolic string
     pub fn to_string(&self) -> String {
         if self.generators.is_empty() {
@@ -265,6 +389,45 @@ impl std::fmt::Display for BraidWord {
     }
 }
 
+/// Equality by normal-form comparison (`is_equivalent`), not by literal
+/// generator sequence - two words for the same braid that only differ by
+/// cancellation or commutation compare equal. Words that differ by a
+/// Yang-Baxter move are not always recognized: σ₀σ₁σ₀ and σ₁σ₀σ₁ compare
+/// unequal (see `garside_normal_form`).
+impl PartialEq for BraidWord {
+    fn eq(&self, other: &Self) -> bool {
+        self.is_equivalent(other)
+    }
+}
+
+/// Safety bound on how many passes `exhaustively_normalize` makes. `normalize`
+/// alone always reaches a fixed point, but repeated Yang-Baxter rewrites
+/// don't have a guaranteed-terminating strategy in general - rewriting the
+/// pattern at one position can recreate a rewritable pattern at another, so
+/// this caps the search rather than risk it running forever.
+const MAX_NORMALIZE_PASSES: usize = 32;
+
+/// Drives a word toward a fixed point of `normalize` and
+/// `yang_baxter_rewrite`, used before comparing normal forms in
+/// `is_equivalent` so that two words related by a Yang-Baxter move have a
+/// better chance of landing on the same literal generator sequence before
+/// factorization.
+fn exhaustively_normalize(word: &mut BraidWord) {
+    for _ in 0..MAX_NORMALIZE_PASSES {
+        let mut changed = word.normalize() > 0;
+
+        for i in 0..word.generators.len().saturating_sub(2) {
+            if word.yang_baxter_rewrite(i) {
+                changed = true;
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -309,6 +472,124 @@ mod tests {
         assert_eq!(word.generators[1].name, "c");
     }
 
+    #[test]
+    fn test_permutation_ignores_inversion() {
+        let mut word = BraidWord::new();
+        word.push(BraidGenerator::new(0, "a"));
+        word.push(BraidGenerator::new(1, "b").invert());
+
+        // Inversion shouldn't change which strands end up where, so the
+        // permutation should match the all-positive version of this word.
+        let mut positive = BraidWord::new();
+        positive.push(BraidGenerator::new(0, "a"));
+        positive.push(BraidGenerator::new(1, "b"));
+
+        assert_eq!(word.permutation(), positive.permutation());
+    }
+
+    #[test]
+    fn test_garside_normal_form_merges_simple_prefix() {
+        let mut word = BraidWord::new();
+        // σ0 σ1 σ0: the classical half-twist generator for 3 strands,
+        // itself a single simple element (reduced word of length 3).
+        word.push(BraidGenerator::new(0, "a"));
+        word.push(BraidGenerator::new(1, "b"));
+        word.push(BraidGenerator::new(0, "c"));
+
+        let factors = word.garside_normal_form();
+        assert_eq!(factors, vec![vec![0, 1, 0]]);
+    }
+
+    #[test]
+    fn test_garside_normal_form_splits_non_simple_word() {
+        let mut word = BraidWord::new();
+        // σ0 σ1 σ1: the trailing σ1 repeats and can't extend the first
+        // simple factor, so it must start a new one.
+        word.push(BraidGenerator::new(0, "a"));
+        word.push(BraidGenerator::new(1, "b"));
+        word.push(BraidGenerator::new(1, "c"));
+
+        let factors = word.garside_normal_form();
+        assert_eq!(factors, vec![vec![0, 1], vec![1]]);
+    }
+
+    #[test]
+    fn test_is_equivalent_same_word() {
+        let deps = vec![
+            ("tokio".to_string(), None),
+            ("serde".to_string(), None),
+            ("hyper".to_string(), None),
+        ];
+        let word = BraidWord::from_deps(&deps);
+        assert!(word.is_equivalent(&word.clone()));
+    }
+
+    #[test]
+    fn test_is_equivalent_detects_commuted_braid() {
+        let mut a = BraidWord::new();
+        a.push(BraidGenerator::new(0, "a"));
+        a.push(BraidGenerator::new(2, "c"));
+
+        let mut b = BraidWord::new();
+        b.push(BraidGenerator::new(2, "c"));
+        b.push(BraidGenerator::new(0, "a"));
+
+        assert!(a.is_equivalent(&b));
+    }
+
+    #[test]
+    fn test_is_equivalent_rejects_different_permutation() {
+        let mut a = BraidWord::new();
+        a.push(BraidGenerator::new(0, "a"));
+
+        let mut b = BraidWord::new();
+        b.push(BraidGenerator::new(1, "b"));
+
+        assert!(!a.is_equivalent(&b));
+    }
+
+    #[test]
+    fn test_reduce_cancels_inverse_pair() {
+        let mut word = BraidWord::new();
+        word.push(BraidGenerator::new(0, "a"));
+        word.push(BraidGenerator::new(1, "b"));
+        word.push(BraidGenerator::new(1, "b").invert());
+
+        let reduced = word.reduce();
+        assert_eq!(reduced.generators.len(), 1);
+        assert_eq!(reduced.generators[0].name, "a");
+    }
+
+    #[test]
+    fn test_is_trivial_for_fully_cancelling_word() {
+        let mut word = BraidWord::new();
+        word.push(BraidGenerator::new(0, "a"));
+        word.push(BraidGenerator::new(0, "a").invert());
+
+        assert!(word.is_trivial());
+    }
+
+    #[test]
+    fn test_is_trivial_false_for_surviving_generator() {
+        let mut word = BraidWord::new();
+        word.push(BraidGenerator::new(0, "a"));
+
+        assert!(!word.is_trivial());
+    }
+
+    #[test]
+    fn test_partial_eq_matches_is_equivalent() {
+        let mut a = BraidWord::new();
+        a.push(BraidGenerator::new(0, "a"));
+        a.push(BraidGenerator::new(2, "c"));
+
+        let mut b = BraidWord::new();
+        b.push(BraidGenerator::new(2, "c"));
+        b.push(BraidGenerator::new(0, "a"));
+
+        assert_eq!(a, b);
+    }
+
     #[test]
     fn test_tangle_detection() {
         let mut word = BraidWord::new();
@@ -1,443 +1,261 @@
-//! SQLite cache for indexed crate data with WAL mode for parallel writes.
+//! Crate-symbol cache, backed by a pluggable [`CacheBackend`]
+//! (`storage::sqlite::SqliteBackend` by default; set `FASTDEPS_BACKEND=redb`
+//! for `storage::redb::RedbBackend`). `Cache` itself just resolves the
+//! configured backend and delegates every operation to it.
 
 use crate::cargo::RegistryCrate;
 use crate::languages::rust::RustParser;
-use crate::schema::Item;
+use crate::schema::{Item, ItemKind, Visibility};
+use crate::storage::{self, CacheBackend};
 use camino::Utf8PathBuf;
 use rayon::prelude::*;
-use rusqlite::{Connection, params};
+use sha2::{Digest, Sha256};
 use std::fs;
-use std::sync::Arc;
+use std::io::{BufRead, Write};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc;
+use std::sync::Arc;
 use std::thread;
 use thiserror::Error;
 
-const CACHE_DIR: &str = ".fastdeps";
-const DB_FILE: &str = "cache.sqlite";
-const SCHEMA_VERSION: i32 = 2;
-
 #[derive(Debug, Error)]
 pub enum CacheError {
     #[error("SQLite error: {0}")]
     Sqlite(#[from] rusqlite::Error),
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
+    #[error("storage backend error: {0}")]
+    Backend(#[from] anyhow::Error),
     #[error("Cache not initialized. Run `fastdeps cache build` first.")]
     NotInitialized,
 }
 
 pub struct Cache {
-    conn: Connection,
+    backend: Box<dyn CacheBackend>,
 }
 
 impl Cache {
-    /// Open or create the cache database in the current directory.
+    /// Open or create the cache, using whichever backend `FASTDEPS_BACKEND`
+    /// selects (SQLite by default).
     pub fn open() -> Result<Self, CacheError> {
-        let cache_dir = Utf8PathBuf::from(CACHE_DIR);
-        if !cache_dir.exists() {
-            fs::create_dir_all(&cache_dir)?;
-        }
-
-        let db_path = cache_dir.join(DB_FILE);
-        let conn = Connection::open(&db_path)?;
-
-        // Enable WAL mode for better concurrent access
-        conn.execute_batch(
-            r#"
-            PRAGMA journal_mode = WAL;
-            PRAGMA synchronous = NORMAL;
-            PRAGMA cache_size = -64000;
-            PRAGMA busy_timeout = 5000;
-            "#,
-        )?;
-
-        let cache = Self { conn };
-        cache.init_schema()?;
-        Ok(cache)
+        Ok(Self {
+            backend: storage::open_backend()?,
+        })
     }
 
-    /// Open existing cache, error if it doesn't exist.
+    /// Open the existing cache, error if it doesn't exist.
     pub fn open_existing() -> Result<Self, CacheError> {
-        let db_path = Utf8PathBuf::from(CACHE_DIR).join(DB_FILE);
-        if !db_path.exists() {
-            return Err(CacheError::NotInitialized);
-        }
-
-        let conn = Connection::open(&db_path)?;
-
-        // Enable WAL mode for reads too
-        conn.execute_batch(
-            r#"
-            PRAGMA journal_mode = WAL;
-            PRAGMA synchronous = NORMAL;
-            PRAGMA busy_timeout = 5000;
-            "#,
-        )?;
-
-        Ok(Self { conn })
+        Ok(Self {
+            backend: storage::open_existing_backend()?,
+        })
     }
 
-    /// Check if cache exists.
+    /// Check if the configured backend's cache exists.
     pub fn exists() -> bool {
-        Utf8PathBuf::from(CACHE_DIR).join(DB_FILE).exists()
-    }
-
-    /// Get the database path.
-    pub fn db_path() -> Utf8PathBuf {
-        Utf8PathBuf::from(CACHE_DIR).join(DB_FILE)
-    }
-
-    fn init_schema(&self) -> Result<(), CacheError> {
-        // Create base tables
-        self.conn.execute_batch(
-            r#"
-            CREATE TABLE IF NOT EXISTS meta (
-                key TEXT PRIMARY KEY,
-                value TEXT
-            );
-
-            CREATE TABLE IF NOT EXISTS crates (
-                id INTEGER PRIMARY KEY,
-                name TEXT NOT NULL,
-                version TEXT NOT NULL,
-                path TEXT NOT NULL,
-                indexed_at INTEGER NOT NULL,
-                UNIQUE(name, version)
-            );
-
-            CREATE TABLE IF NOT EXISTS items (
-                id INTEGER PRIMARY KEY,
-                crate_id INTEGER NOT NULL REFERENCES crates(id) ON DELETE CASCADE,
-                path TEXT NOT NULL,
-                kind TEXT NOT NULL,
-                signature TEXT,
-                doc TEXT,
-                visibility TEXT NOT NULL,
-                UNIQUE(crate_id, path)
-            );
-
-            CREATE INDEX IF NOT EXISTS idx_items_path ON items(path);
-            CREATE INDEX IF NOT EXISTS idx_items_kind ON items(kind);
-            CREATE INDEX IF NOT EXISTS idx_crates_name ON crates(name);
-            "#,
-        )?;
-
-        // Check current schema version and migrate if needed
-        let current_version: i32 = self
-            .conn
-            .query_row(
-                "SELECT COALESCE((SELECT value FROM meta WHERE key = 'schema_version'), '0')",
-                [],
-                |row| {
-                    let v: String = row.get(0)?;
-                    Ok(v.parse().unwrap_or(0))
-                },
-            )
-            .unwrap_or(0);
-
-        if current_version < 2 {
-            self.migrate_to_v2()?;
-        }
-
-        // Update schema version
-        self.conn.execute(
-            "INSERT OR REPLACE INTO meta (key, value) VALUES ('schema_version', ?)",
-            params![SCHEMA_VERSION.to_string()],
-        )?;
-
-        Ok(())
+        storage::backend_exists()
     }
 
-    /// Migrate schema from v1 to v2: Add FTS5 full-text search
-    fn migrate_to_v2(&self) -> Result<(), CacheError> {
-        eprintln!("Migrating cache to v2 (adding FTS5 search)...");
-
-        // Create FTS5 virtual table for fast text search
-        // Using trigram tokenizer for substring matching
-        self.conn.execute_batch(
-            r#"
-            CREATE VIRTUAL TABLE IF NOT EXISTS items_fts USING fts5(
-                path,
-                content='items',
-                content_rowid='id',
-                tokenize='trigram'
-            );
-
-            -- Triggers to keep FTS index in sync with items table
-            CREATE TRIGGER IF NOT EXISTS items_fts_insert AFTER INSERT ON items BEGIN
-                INSERT INTO items_fts(rowid, path) VALUES (new.id, new.path);
-            END;
-
-            CREATE TRIGGER IF NOT EXISTS items_fts_delete AFTER DELETE ON items BEGIN
-                INSERT INTO items_fts(items_fts, rowid, path) VALUES('delete', old.id, old.path);
-            END;
-
-            CREATE TRIGGER IF NOT EXISTS items_fts_update AFTER UPDATE ON items BEGIN
-                INSERT INTO items_fts(items_fts, rowid, path) VALUES('delete', old.id, old.path);
-                INSERT INTO items_fts(rowid, path) VALUES (new.id, new.path);
-            END;
-            "#,
-        )?;
-
-        // Rebuild FTS index from existing data
-        let item_count: i64 = self
-            .conn
-            .query_row("SELECT COUNT(*) FROM items", [], |row| row.get(0))?;
-
-        if item_count > 0 {
-            eprintln!("Rebuilding FTS index for {} items...", item_count);
-            self.conn
-                .execute("INSERT INTO items_fts(items_fts) VALUES('rebuild')", [])?;
-        }
-
-        eprintln!("Migration to v2 complete.");
-        Ok(())
+    /// Last-modified time of the backend file, used to detect a stale
+    /// on-disk symbol index.
+    pub fn backend_mtime() -> Option<std::time::SystemTime> {
+        storage::backend_mtime()
     }
 
     /// Clear all cached data.
     pub fn clear(&self) -> Result<(), CacheError> {
-        self.conn.execute_batch(
-            r#"
-            DELETE FROM items;
-            DELETE FROM crates;
-            "#,
-        )?;
-        Ok(())
+        self.backend.clear()
     }
 
     /// Check if a crate version is already indexed.
     pub fn is_indexed(&self, name: &str, version: &str) -> Result<bool, CacheError> {
-        let count: i32 = self.conn.query_row(
-            "SELECT COUNT(*) FROM crates WHERE name = ? AND version = ?",
-            params![name, version],
-            |row| row.get(0),
-        )?;
-        Ok(count > 0)
+        self.backend.is_indexed(name, version)
     }
 
     /// Get list of already indexed crate name@version pairs.
     pub fn get_indexed_set(&self) -> Result<std::collections::HashSet<String>, CacheError> {
-        let mut stmt = self.conn.prepare("SELECT name, version FROM crates")?;
-        let results = stmt
-            .query_map([], |row| {
-                let name: String = row.get(0)?;
-                let version: String = row.get(1)?;
-                Ok(format!("{}@{}", name, version))
-            })?
-            .collect::<Result<std::collections::HashSet<_>, _>>()?;
-        Ok(results)
+        self.backend.get_indexed_set()
     }
 
-    /// Index a single crate (used for batch inserts).
-    pub fn index_crate(&self, krate: &RegistryCrate, items: &[Item]) -> Result<(), CacheError> {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs() as i64;
-
-        // Use a transaction for atomicity
-        self.conn.execute("BEGIN IMMEDIATE", [])?;
-
-        // Insert or replace crate
-        self.conn.execute(
-            r#"
-            INSERT OR REPLACE INTO crates (name, version, path, indexed_at)
-            VALUES (?, ?, ?, ?)
-            "#,
-            params![krate.name, krate.version, krate.path.as_str(), now],
-        )?;
-
-        let crate_id: i64 = self.conn.query_row(
-            "SELECT id FROM crates WHERE name = ? AND version = ?",
-            params![krate.name, krate.version],
-            |row| row.get(0),
-        )?;
-
-        // Delete old items for this crate
-        self.conn
-            .execute("DELETE FROM items WHERE crate_id = ?", params![crate_id])?;
-
-        // Insert items
-        let mut stmt = self.conn.prepare(
-            r#"
-            INSERT INTO items (crate_id, path, kind, signature, doc, visibility)
-            VALUES (?, ?, ?, ?, ?, ?)
-            "#,
-        )?;
-
-        for item in items {
-            let kind = format!("{:?}", item.kind).to_lowercase();
-            let vis = format!("{:?}", item.visibility).to_lowercase();
-            stmt.execute(params![
-                crate_id,
-                item.path,
-                kind,
-                item.signature,
-                item.doc,
-                vis
-            ])?;
-        }
+    /// Get the stored `source_fingerprint` of every indexed crate, keyed by
+    /// `name@version`, for comparing against the current on-disk state.
+    pub fn get_fingerprints(
+        &self,
+    ) -> Result<std::collections::HashMap<String, String>, CacheError> {
+        self.backend.get_fingerprints()
+    }
 
-        self.conn.execute("COMMIT", [])?;
-        Ok(())
+    /// Index a single crate (convenience wrapper around `batch_index`).
+    pub fn index_crate(&self, krate: &RegistryCrate, items: &[Item]) -> Result<(), CacheError> {
+        let fingerprint = compute_fingerprint(krate);
+        self.backend
+            .batch_index(&[(krate.clone(), items.to_vec(), fingerprint)])
     }
 
     /// Batch insert multiple crates' data.
-    pub fn batch_index(&self, batch: &[(RegistryCrate, Vec<Item>)]) -> Result<(), CacheError> {
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs() as i64;
-
-        self.conn.execute("BEGIN IMMEDIATE", [])?;
-
-        // Pre-prepare statements for better performance
-        let mut crate_stmt = self.conn.prepare_cached(
-            "INSERT OR REPLACE INTO crates (name, version, path, indexed_at) VALUES (?, ?, ?, ?)",
-        )?;
-        let mut delete_stmt = self
-            .conn
-            .prepare_cached("DELETE FROM items WHERE crate_id = ?")?;
-        let mut item_stmt = self.conn.prepare_cached(
-            "INSERT OR REPLACE INTO items (crate_id, path, kind, signature, doc, visibility) VALUES (?, ?, ?, ?, ?, ?)",
-        )?;
-
-        for (krate, items) in batch {
-            // Insert or replace crate and get ID via last_insert_rowid
-            crate_stmt.execute(params![krate.name, krate.version, krate.path.as_str(), now])?;
-            let crate_id = self.conn.last_insert_rowid();
-
-            // Delete old items for this crate
-            delete_stmt.execute(params![crate_id])?;
-
-            // Insert items
-            for item in items {
-                let kind = format!("{:?}", item.kind).to_lowercase();
-                let vis = format!("{:?}", item.visibility).to_lowercase();
-                item_stmt.execute(params![
-                    crate_id,
-                    item.path,
-                    kind,
-                    item.signature,
-                    item.doc,
-                    vis
-                ])?;
-            }
-        }
-
-        // Drop statements before commit to release borrows
-        drop(crate_stmt);
-        drop(delete_stmt);
-        drop(item_stmt);
-
-        self.conn.execute("COMMIT", [])?;
-        Ok(())
+    pub fn batch_index(
+        &self,
+        batch: &[(RegistryCrate, Vec<Item>, String)],
+    ) -> Result<(), CacheError> {
+        self.backend.batch_index(batch)
     }
 
-    /// Search for items matching a query using FTS5 full-text search.
-    pub fn search(&self, query: &str) -> Result<Vec<SearchResult>, CacheError> {
-        // Escape special FTS5 characters and prepare for trigram search
-        let escaped_query = query.replace('"', "\"\"").to_lowercase();
-
-        // Use FTS5 with trigram tokenizer for fast substring matching
-        let mut stmt = self.conn.prepare(
-            r#"
-            SELECT c.name, c.version, i.path, i.kind, i.signature
-            FROM items i
-            JOIN crates c ON i.crate_id = c.id
-            WHERE i.id IN (SELECT rowid FROM items_fts WHERE items_fts MATCH ?)
-            ORDER BY c.name, c.version, i.path
-            "#,
-        )?;
-
-        let results = stmt
-            .query_map(params![format!("\"{}\"", escaped_query)], |row| {
-                Ok(SearchResult {
-                    crate_name: row.get(0)?,
-                    crate_version: row.get(1)?,
-                    path: row.get(2)?,
-                    kind: row.get(3)?,
-                    signature: row.get(4)?,
-                })
-            })?
-            .collect::<Result<Vec<_>, _>>()?;
-
-        Ok(results)
+    /// Search for items matching a query, ranked by relevance where the
+    /// backend supports it (see `storage::CacheBackend::search`). `feature`
+    /// restricts results to items gated behind that feature name.
+    pub fn search(
+        &self,
+        query: &str,
+        feature: Option<&str>,
+    ) -> Result<Vec<SearchResult>, CacheError> {
+        self.backend.search(query, feature)
     }
 
-    /// Search within a specific crate.
+    /// Search within a specific crate, optionally restricted to items
+    /// gated behind `feature`.
     pub fn search_crate(
         &self,
         crate_name: &str,
         crate_version: Option<&str>,
+        feature: Option<&str>,
     ) -> Result<Vec<CachedItem>, CacheError> {
-        let mut query = String::from(
-            r#"
-            SELECT i.path, i.kind, i.signature, i.doc, i.visibility
-            FROM items i
-            JOIN crates c ON i.crate_id = c.id
-            WHERE c.name = ?
-            "#,
-        );
-
-        if crate_version.is_some() {
-            query.push_str(" AND c.version = ?");
-        } else {
-            // Get latest version
-            query.push_str(" AND c.version = (SELECT MAX(version) FROM crates WHERE name = ?)");
-        }
-        query.push_str(" ORDER BY i.path");
-
-        let mut stmt = self.conn.prepare(&query)?;
-
-        let version_param = crate_version.unwrap_or(crate_name);
-        let results = stmt
-            .query_map(params![crate_name, version_param], |row| {
-                Ok(CachedItem {
-                    path: row.get(0)?,
-                    kind: row.get(1)?,
-                    signature: row.get(2)?,
-                    doc: row.get(3)?,
-                    visibility: row.get(4)?,
-                })
-            })?
-            .collect::<Result<Vec<_>, _>>()?;
-
-        Ok(results)
+        self.backend
+            .search_crate(crate_name, crate_version, feature)
     }
 
     /// Get all indexed crates.
     pub fn list_indexed(&self) -> Result<Vec<(String, String)>, CacheError> {
-        let mut stmt = self
-            .conn
-            .prepare("SELECT name, version FROM crates ORDER BY name, version")?;
-
-        let results = stmt
-            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
-            .collect::<Result<Vec<_>, _>>()?;
+        self.backend.list_indexed()
+    }
 
-        Ok(results)
+    /// Feature flags declared by `crate_name`'s own manifest, as `(name,
+    /// subfeatures)` pairs - answers "what does enabling feature X add to
+    /// this crate's API?" when paired with `search`/`search_crate`'s
+    /// feature filter.
+    pub fn list_features(
+        &self,
+        crate_name: &str,
+    ) -> Result<Vec<(String, Vec<String>)>, CacheError> {
+        self.backend.list_features(crate_name)
     }
 
     /// Get stats about the cache.
     pub fn stats(&self) -> Result<CacheStats, CacheError> {
-        let crate_count: i64 = self
-            .conn
-            .query_row("SELECT COUNT(*) FROM crates", [], |row| row.get(0))?;
+        self.backend.stats()
+    }
 
-        let item_count: i64 = self
-            .conn
-            .query_row("SELECT COUNT(*) FROM items", [], |row| row.get(0))?;
+    /// Items that reference `path` - answers "who uses `foo`?".
+    pub fn find_referrers(&self, path: &str) -> Result<Vec<SearchResult>, CacheError> {
+        self.backend.find_referrers(path)
+    }
 
-        let db_path = Utf8PathBuf::from(CACHE_DIR).join(DB_FILE);
-        let db_size = fs::metadata(&db_path).map(|m| m.len()).unwrap_or(0);
+    /// Items `path` itself references (traits it implements, items it
+    /// re-exports).
+    pub fn find_references_from(&self, path: &str) -> Result<Vec<SearchResult>, CacheError> {
+        self.backend.find_references_from(path)
+    }
 
-        Ok(CacheStats {
-            crate_count: crate_count as usize,
-            item_count: item_count as usize,
-            db_size_bytes: db_size,
-        })
+    /// Items that are a `pub use` re-export of `path`, i.e. every alias
+    /// `path` can also be imported through.
+    pub fn find_reexports_of(&self, path: &str) -> Result<Vec<SearchResult>, CacheError> {
+        self.backend.find_reexports_of(path)
+    }
+
+    /// Whether `path` is itself a `pub use` re-export of something else.
+    pub fn is_reexport(&self, path: &str) -> Result<bool, CacheError> {
+        self.backend.is_reexport(path)
+    }
+
+    /// Types that `impl trait_path for ...`.
+    pub fn find_implementors(&self, trait_path: &str) -> Result<Vec<SearchResult>, CacheError> {
+        self.backend.find_implementors(trait_path)
+    }
+
+    /// Traits `type_path` implements.
+    pub fn find_implemented_traits(&self, type_path: &str) -> Result<Vec<String>, CacheError> {
+        self.backend.find_implemented_traits(type_path)
+    }
+
+    /// Write the full indexed dataset to `writer` as newline-delimited
+    /// JSON: a header line recording the dump format version, followed by
+    /// one line per indexed crate. Unlike a raw copy of the backend's own
+    /// file, this is backend-agnostic - a dump taken from a SQLite-backed
+    /// cache can be imported into a `redb`-backed one and vice versa.
+    /// Returns the number of crates written.
+    pub fn export<W: Write>(&self, mut writer: W) -> Result<usize, CacheError> {
+        let crates = self.backend.export_all()?;
+
+        let header = ExportHeader {
+            format_version: EXPORT_FORMAT_VERSION,
+            crate_count: crates.len(),
+        };
+        writeln!(
+            writer,
+            "{}",
+            serde_json::to_string(&header).map_err(|e| CacheError::Backend(e.into()))?
+        )?;
+
+        for krate in &crates {
+            writeln!(
+                writer,
+                "{}",
+                serde_json::to_string(krate).map_err(|e| CacheError::Backend(e.into()))?
+            )?;
+        }
+
+        Ok(crates.len())
+    }
+
+    /// Read a dump written by `export` and reinsert every crate through
+    /// `batch_index`, the same path `parallel_index` writes through, so
+    /// derived state (the FTS index, in the SQLite backend) rebuilds via
+    /// its normal triggers instead of being restored byte-for-byte.
+    /// Returns the number of crates imported.
+    pub fn import<R: BufRead>(&self, reader: R) -> Result<usize, CacheError> {
+        let mut lines = reader.lines();
+
+        let header_line = match lines.next() {
+            Some(line) => line?,
+            None => {
+                return Err(CacheError::Backend(anyhow::anyhow!(
+                    "empty dump: missing header"
+                )))
+            }
+        };
+        let header: ExportHeader =
+            serde_json::from_str(&header_line).map_err(|e| CacheError::Backend(e.into()))?;
+        if header.format_version > EXPORT_FORMAT_VERSION {
+            return Err(CacheError::Backend(anyhow::anyhow!(
+                "dump format v{} is newer than this build supports (v{})",
+                header.format_version,
+                EXPORT_FORMAT_VERSION
+            )));
+        }
+
+        let mut batch = Vec::with_capacity(header.crate_count);
+        for line in lines {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let exported: ExportedCrate =
+                serde_json::from_str(&line).map_err(|e| CacheError::Backend(e.into()))?;
+            let krate = RegistryCrate {
+                name: exported.name,
+                version: exported.version,
+                path: Utf8PathBuf::from(exported.path),
+                registry: None,
+            };
+            let items = exported
+                .items
+                .into_iter()
+                .map(ExportedItem::into_item)
+                .collect();
+            batch.push((krate, items, exported.fingerprint));
+        }
+
+        let indexed = batch.len();
+        self.batch_index(&batch)?;
+        Ok(indexed)
     }
 }
 
@@ -448,6 +266,13 @@ pub struct SearchResult {
     pub path: String,
     pub kind: String,
     pub signature: Option<String>,
+    /// BM25 relevance score from `bm25(items_fts, ...)` - lower is a
+    /// better match - when it comes from the SQLite backend's `search`,
+    /// whose results are already ordered by this; it's exposed so callers
+    /// can re-rank or display it alongside other signals. The `redb`
+    /// backend and the reference/implementor lookups don't rank, and set
+    /// it to `0.0`.
+    pub score: f64,
 }
 
 #[derive(Debug, serde::Serialize)]
@@ -459,6 +284,111 @@ pub struct CachedItem {
     pub visibility: String,
 }
 
+/// Dump format version for `Cache::export`/`Cache::import`, independent of
+/// any backend's own schema version. Only bumps when the shape of
+/// `ExportHeader`/`ExportedCrate`/`ExportedItem` itself changes.
+const EXPORT_FORMAT_VERSION: u32 = 1;
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct ExportHeader {
+    format_version: u32,
+    crate_count: usize,
+}
+
+/// One crate's data as written to an export dump - the backend-agnostic
+/// view `CacheBackend::export_all` produces and `Cache::import` consumes.
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ExportedCrate {
+    pub name: String,
+    pub version: String,
+    pub path: String,
+    pub fingerprint: String,
+    pub features: Vec<(String, Vec<String>)>,
+    pub items: Vec<ExportedItem>,
+}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+pub struct ExportedItem {
+    pub path: String,
+    pub kind: String,
+    pub signature: Option<String>,
+    pub doc: Option<String>,
+    pub visibility: String,
+    pub feature_gates: Vec<String>,
+}
+
+impl ExportedItem {
+    /// Reconstruct an `Item` good enough to drive `batch_index`. Only the
+    /// fields the storage layer actually persists survive a round-trip
+    /// (see `CachedItem`); everything else - generics, fields, methods,
+    /// cross-references, lifecycle metadata - comes back empty. Feature
+    /// gates are folded back into a synthetic `cfg` predicate so
+    /// `Item::feature_gates()` recovers the same names on the other end.
+    fn into_item(self) -> Item {
+        let cfg = if self.feature_gates.is_empty() {
+            None
+        } else {
+            Some(
+                self.feature_gates
+                    .iter()
+                    .map(|f| format!("feature = \"{f}\""))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            )
+        };
+
+        Item {
+            path: self.path,
+            kind: parse_item_kind(&self.kind),
+            signature: self.signature,
+            signature_detail: None,
+            signatures: vec![],
+            doc: self.doc,
+            visibility: parse_visibility(&self.visibility),
+            generics: Default::default(),
+            attrs: vec![],
+            decorators: vec![],
+            fields: vec![],
+            methods: vec![],
+            traits: vec![],
+            variants: vec![],
+            related: vec![],
+            unresolved_doc_links: vec![],
+            since: None,
+            until: None,
+            moved_from: None,
+            reexport_from: None,
+            deprecated: None,
+            cfg,
+        }
+    }
+}
+
+/// Inverse of the `format!("{:?}", item.kind).to_lowercase()` encoding the
+/// storage backends write.
+fn parse_item_kind(s: &str) -> ItemKind {
+    match s {
+        "struct" => ItemKind::Struct,
+        "enum" => ItemKind::Enum,
+        "trait" => ItemKind::Trait,
+        "typealias" => ItemKind::TypeAlias,
+        "constant" => ItemKind::Constant,
+        "module" => ItemKind::Module,
+        "macro" => ItemKind::Macro,
+        _ => ItemKind::Function,
+    }
+}
+
+/// Inverse of the `format!("{:?}", item.visibility).to_lowercase()`
+/// encoding the storage backends write.
+fn parse_visibility(s: &str) -> Visibility {
+    match s {
+        "crate" => Visibility::Crate,
+        "private" => Visibility::Private,
+        _ => Visibility::Public,
+    }
+}
+
 #[derive(Debug)]
 pub struct CacheStats {
     pub crate_count: usize,
@@ -471,11 +401,38 @@ pub struct CacheStats {
 pub struct ParsedCrate {
     pub krate: RegistryCrate,
     pub items: Vec<Item>,
+    pub fingerprint: String,
 }
 
-/// Parse a single crate (CPU-bound, parallelizable).
-pub fn parse_crate(krate: &RegistryCrate) -> Result<ParsedCrate, String> {
+/// Parse a single crate (CPU-bound, parallelizable). `expand_macros`
+/// switches to macro-expanded ingestion (see `expand_crate_source`),
+/// falling back to the plain file walk if expansion isn't available.
+pub fn parse_crate(krate: &RegistryCrate, expand_macros: bool) -> Result<ParsedCrate, String> {
     let mut parser = RustParser::new().map_err(|e| e.to_string())?;
+
+    let all_items = if expand_macros {
+        match expand_crate_source(krate) {
+            Some(expanded) => parser
+                .parse_source(&expanded, &krate.name)
+                .unwrap_or_default(),
+            None => parse_crate_files(&mut parser, krate),
+        }
+    } else {
+        parse_crate_files(&mut parser, krate)
+    };
+
+    Ok(ParsedCrate {
+        krate: krate.clone(),
+        items: all_items,
+        fingerprint: compute_fingerprint(krate),
+    })
+}
+
+/// Plain file-by-file walk: parse each source file on its own, with a
+/// module path derived from its location under `src/`. Macro-generated
+/// items (derive impls, `macro_rules!` output, proc-macro expansions)
+/// aren't visible this way, since they only exist after expansion.
+fn parse_crate_files(parser: &mut RustParser, krate: &RegistryCrate) -> Vec<Item> {
     let mut all_items: Vec<Item> = Vec::new();
 
     for source_file in krate.source_files() {
@@ -491,31 +448,103 @@ pub fn parse_crate(krate: &RegistryCrate) -> Result<ParsedCrate, String> {
         }
     }
 
-    Ok(ParsedCrate {
-        krate: krate.clone(),
-        items: all_items,
-    })
+    all_items
+}
+
+/// Run the crate's source through `rustc`'s macro expansion and capture
+/// the result as a single Rust source string, so derive-generated trait
+/// impls and `macro_rules!`/proc-macro output become visible to
+/// `extract_items` the same way hand-written code is. Requires a nightly
+/// toolchain and a crate that actually builds; returns `None` (falling
+/// back to the unexpanded file walk) if either isn't the case.
+fn expand_crate_source(krate: &RegistryCrate) -> Option<String> {
+    let output = std::process::Command::new("cargo")
+        .args([
+            "rustc",
+            "--profile=check",
+            "--",
+            "-Zunstable-options",
+            "--pretty=expanded",
+        ])
+        .env("RUSTC_BOOTSTRAP", "1")
+        .current_dir(&krate.path)
+        .output()
+        .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    String::from_utf8(output.stdout).ok()
+}
+
+/// Cheap content-freshness signal for a crate's sources: a SHA-256 digest
+/// over each source file's `(path, len, mtime)`, the way a build system
+/// compares output timestamps against input timestamps. Only stats files,
+/// never reads their contents, so it's cheap enough to run as a pre-filter
+/// ahead of the expensive parse step. `source_files()` fans out over rayon
+/// internally and returns files in arbitrary order, so the stat tuples are
+/// sorted before hashing to keep the fingerprint stable across runs.
+pub fn compute_fingerprint(krate: &RegistryCrate) -> String {
+    let mut stats: Vec<(String, u64, i64)> = krate
+        .source_files()
+        .iter()
+        .filter_map(|path| {
+            let meta = fs::metadata(path).ok()?;
+            let mtime = meta
+                .modified()
+                .ok()?
+                .duration_since(std::time::UNIX_EPOCH)
+                .ok()?
+                .as_secs() as i64;
+            Some((path.as_str().to_string(), meta.len(), mtime))
+        })
+        .collect();
+    stats.sort();
+
+    let mut hasher = Sha256::new();
+    for (path, len, mtime) in &stats {
+        hasher.update(path.as_bytes());
+        hasher.update(len.to_le_bytes());
+        hasher.update(mtime.to_le_bytes());
+    }
+    format!("{:x}", hasher.finalize())
 }
 
 /// Index multiple crates in parallel using rayon for parsing,
-/// with streaming writes to SQLite as parsing completes.
+/// with streaming writes to the configured cache backend as parsing
+/// completes. `expand_macros`
+/// is forwarded to `parse_crate` for every crate in the batch.
 pub fn parallel_index(
     crates: &[RegistryCrate],
     force: bool,
+    expand_macros: bool,
 ) -> Result<IndexStats, Box<dyn std::error::Error + Send + Sync>> {
     let cache = Cache::open()?;
 
-    // Get already indexed set if not forcing
-    let indexed_set = if force {
-        std::collections::HashSet::new()
+    // Get stored fingerprints if not forcing, so a crate whose `name@version`
+    // is already indexed but whose files changed on disk (a path or
+    // `[patch]`-overridden dependency under local development) still gets
+    // reindexed.
+    let stored_fingerprints = if force {
+        std::collections::HashMap::new()
     } else {
-        cache.get_indexed_set()?
+        cache.get_fingerprints()?
     };
 
-    // Filter to crates that need indexing
+    // Filter to crates that need indexing: never indexed, or the current
+    // on-disk fingerprint no longer matches what's stored.
     let to_index: Vec<_> = crates
         .iter()
-        .filter(|k| force || !indexed_set.contains(&format!("{}@{}", k.name, k.version)))
+        .filter(|k| {
+            if force {
+                return true;
+            }
+            match stored_fingerprints.get(&format!("{}@{}", k.name, k.version)) {
+                Some(stored) => compute_fingerprint(k) != *stored,
+                None => true,
+            }
+        })
         .cloned()
         .collect();
 
@@ -549,18 +578,18 @@ pub fn parallel_index(
     let writer_handle = thread::spawn(
         move || -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
             let cache = Cache::open()?;
-            let mut batch: Vec<(RegistryCrate, Vec<Item>)> = Vec::new();
+            let mut batch: Vec<(RegistryCrate, Vec<Item>, String)> = Vec::new();
             const BATCH_SIZE: usize = 50;
 
             for parsed in rx {
-                batch.push((parsed.krate, parsed.items));
+                batch.push((parsed.krate, parsed.items, parsed.fingerprint));
 
                 // Write batch when full
                 if batch.len() >= BATCH_SIZE {
                     cache.batch_index(&batch)?;
                     writer_indexed.fetch_add(batch.len(), Ordering::Relaxed);
                     writer_items.fetch_add(
-                        batch.iter().map(|(_, items)| items.len()).sum::<usize>(),
+                        batch.iter().map(|(_, items, _)| items.len()).sum::<usize>(),
                         Ordering::Relaxed,
                     );
                     batch.clear();
@@ -572,7 +601,7 @@ pub fn parallel_index(
                 cache.batch_index(&batch)?;
                 writer_indexed.fetch_add(batch.len(), Ordering::Relaxed);
                 writer_items.fetch_add(
-                    batch.iter().map(|(_, items)| items.len()).sum::<usize>(),
+                    batch.iter().map(|(_, items, _)| items.len()).sum::<usize>(),
                     Ordering::Relaxed,
                 );
             }
@@ -586,7 +615,7 @@ pub fn parallel_index(
 
     // Parse in parallel using rayon, streaming results to writer
     to_index.par_iter().for_each(|krate| {
-        match parse_crate(krate) {
+        match parse_crate(krate, expand_macros) {
             Ok(parsed) => {
                 eprintln!(
                     "  {}@{} - {} items",
@@ -621,6 +650,15 @@ pub fn parallel_index(
         indexed, failed, items
     );
 
+    // Build (or refresh) the persisted FST symbol index now rather than
+    // lazily on the first search, so `find`/`peek` hit a warm on-disk index
+    // right after indexing instead of paying to build one on first use.
+    // Best-effort: a failure here shouldn't fail the index command itself,
+    // since `SearchEngine::new` already falls back to an unindexed scan.
+    if let Err(e) = crate::fst_index::SymbolIndex::open_or_build(&cache) {
+        eprintln!("Warning: failed to build symbol index: {}", e);
+    }
+
     Ok(IndexStats {
         indexed,
         skipped,
@@ -1,6 +1,7 @@
 //! Cargo registry and project dependency walking.
 
 use camino::{Utf8Path, Utf8PathBuf};
+use rayon::prelude::*;
 use serde::Deserialize;
 use std::fs;
 use thiserror::Error;
@@ -23,6 +24,8 @@ pub enum CargoError {
     },
     #[error("Cargo.lock not found at {0}")]
     NoLockFile(Utf8PathBuf),
+    #[error("Cargo.toml at {0} has no [package] table")]
+    NoPackageTable(Utf8PathBuf),
 }
 
 /// A discovered crate in the cargo registry.
@@ -31,6 +34,11 @@ pub struct RegistryCrate {
     pub name: String,
     pub version: String,
     pub path: Utf8PathBuf,
+    /// The registry source directory this copy was cached under (e.g.
+    /// `"index.crates.io-6f17d22bba15001f"`), or `None` for a path/git
+    /// dependency or one reconstructed from an exported cache, neither of
+    /// which came from a versioned registry. See `resolve_registry_host`.
+    pub registry: Option<String>,
 }
 
 impl RegistryCrate {
@@ -55,23 +63,134 @@ impl RegistryCrate {
         }
         collect_rs_files(&src_dir)
     }
+
+    /// Feature flags declared in this crate's own `Cargo.toml`, as
+    /// `(name, subfeatures)` pairs - the same `{name, subfeatures}` shape
+    /// registry metadata uses, where `subfeatures` lists the other
+    /// features or `dep:`-enabled dependencies each one turns on. Empty if
+    /// the manifest is missing, unparsable, or declares no `[features]`.
+    pub fn features(&self) -> Vec<(String, Vec<String>)> {
+        let toml_path = self.path.join("Cargo.toml");
+        let Ok(contents) = fs::read_to_string(&toml_path) else {
+            return Vec::new();
+        };
+        let Ok(manifest) = toml::from_str::<CargoToml>(&contents) else {
+            return Vec::new();
+        };
+        let Some(features) = manifest.features else {
+            return Vec::new();
+        };
+
+        features
+            .into_iter()
+            .map(|(name, value)| {
+                let subfeatures = match value {
+                    toml::Value::Array(items) => items
+                        .into_iter()
+                        .filter_map(|v| v.as_str().map(str::to_string))
+                        .collect(),
+                    _ => Vec::new(),
+                };
+                (name, subfeatures)
+            })
+            .collect()
+    }
+
+    /// Best-effort check of whether this version is marked yanked, read
+    /// from cargo's locally cached registry index
+    /// (`~/.cargo/registry/index/<dir>/...`) rather than the network -
+    /// covers both the sparse-protocol `.cache/` entries and a
+    /// git-checked-out index, each line of which is a JSON record for one
+    /// published version with a `"yanked"` field. Defaults to `false` if
+    /// the index isn't cached locally, the crate has no known registry, or
+    /// the matching version line can't be found, since there's no fetch to
+    /// fall back on.
+    pub fn is_yanked(&self) -> bool {
+        let Some(registry) = &self.registry else {
+            return false;
+        };
+        let Some(cargo_home) = cargo_home_dir() else {
+            return false;
+        };
+
+        let index_root = cargo_home.join("registry/index").join(registry);
+        let rel = index_rel_path(&self.name);
+
+        for candidate in [index_root.join(".cache").join(&rel), index_root.join(&rel)] {
+            let Ok(contents) = fs::read_to_string(&candidate) else {
+                continue;
+            };
+            if let Some(yanked) = parse_yanked(&contents, &self.version) {
+                return yanked;
+            }
+        }
+        false
+    }
+
+    /// This crate's own `[package]` metadata plus declared features and
+    /// direct dependency count, for a one-shot summary (the `info` MCP
+    /// tool) without pulling in `SearchEngine::get_crate_info`'s full
+    /// item-indexing machinery. `None` if the manifest is missing,
+    /// unparsable, or has no `[package]` table.
+    pub fn package_metadata(&self) -> Option<PackageMetadata> {
+        let toml_path = self.path.join("Cargo.toml");
+        let contents = fs::read_to_string(&toml_path).ok()?;
+        let manifest: CargoToml = toml::from_str(&contents).ok()?;
+        let package = manifest.package?;
+        let dependency_count = manifest.dependencies.map_or(0, |t| t.len());
+
+        Some(PackageMetadata {
+            description: package.description,
+            license: package.license,
+            documentation: package.documentation,
+            homepage: package.homepage,
+            repository: package.repository,
+            rust_version: package.rust_version,
+            features: self.features(),
+            dependency_count,
+        })
+    }
+}
+
+/// A crate's own declared metadata, as returned by `RegistryCrate::package_metadata`.
+#[derive(Debug, Clone, Default)]
+pub struct PackageMetadata {
+    pub description: Option<String>,
+    pub license: Option<String>,
+    pub documentation: Option<String>,
+    pub homepage: Option<String>,
+    pub repository: Option<String>,
+    pub rust_version: Option<String>,
+    /// `(name, subfeatures)` pairs, same shape as `RegistryCrate::features`.
+    pub features: Vec<(String, Vec<String>)>,
+    pub dependency_count: usize,
 }
 
+/// Walk `dir` for `.rs` files, fanning out across directory entries with
+/// rayon so I/O-bound recursive descents (large monorepo `src/` trees)
+/// overlap instead of running one `read_dir` at a time.
 fn collect_rs_files(dir: &Utf8Path) -> Vec<Utf8PathBuf> {
-    let mut files = Vec::new();
-    if let Ok(entries) = fs::read_dir(dir) {
-        for entry in entries.filter_map(Result::ok) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .par_bridge()
+        .flat_map(|entry| {
             let path = entry.path();
-            if let Ok(utf8_path) = Utf8PathBuf::try_from(path.clone()) {
-                if path.is_dir() {
-                    files.extend(collect_rs_files(&utf8_path));
-                } else if path.extension().is_some_and(|e| e == "rs") {
-                    files.push(utf8_path);
-                }
+            let Ok(utf8_path) = Utf8PathBuf::try_from(path.clone()) else {
+                return Vec::new();
+            };
+            if path.is_dir() {
+                collect_rs_files(&utf8_path)
+            } else if path.extension().is_some_and(|e| e == "rs") {
+                vec![utf8_path]
+            } else {
+                Vec::new()
             }
-        }
-    }
-    files
+        })
+        .collect()
 }
 
 /// Finds the cargo registry source directory.
@@ -88,32 +207,37 @@ pub fn find_registry_src() -> Result<Utf8PathBuf, CargoError> {
 }
 
 /// Lists all crates in the cargo registry.
+///
+/// The per-registry package listing fans out across rayon's thread pool,
+/// since a full registry can hold tens of thousands of crate directories and
+/// cold-cache `read_dir`/stat calls are the dominant cost.
 pub fn list_registry_crates() -> Result<Vec<RegistryCrate>, CargoError> {
     let registry_src = find_registry_src()?;
-    let mut crates = Vec::new();
 
     // Registry src contains subdirs like "index.crates.io-6f17d22bba15001f"
-    if let Ok(registries) = fs::read_dir(&registry_src) {
-        for registry in registries.filter_map(Result::ok) {
-            let registry_path = registry.path();
-            if !registry_path.is_dir() {
-                continue;
-            }
-
-            if let Ok(packages) = fs::read_dir(&registry_path) {
-                for package in packages.filter_map(Result::ok) {
-                    let package_path = package.path();
-                    if !package_path.is_dir() {
-                        continue;
-                    }
+    let package_dirs: Vec<std::path::PathBuf> = fs::read_dir(&registry_src)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .par_bridge()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .flat_map(|registry_path| {
+            fs::read_dir(&registry_path)
+                .into_iter()
+                .flatten()
+                .filter_map(Result::ok)
+                .par_bridge()
+                .map(|entry| entry.path())
+                .filter(|path| path.is_dir())
+                .collect::<Vec<_>>()
+        })
+        .collect();
 
-                    if let Some(krate) = parse_crate_dir(&package_path) {
-                        crates.push(krate);
-                    }
-                }
-            }
-        }
-    }
+    let mut crates: Vec<RegistryCrate> = package_dirs
+        .into_par_iter()
+        .filter_map(|package_path| parse_crate_dir(&package_path))
+        .collect();
 
     crates.sort_by(|a, b| (&a.name, &a.version).cmp(&(&b.name, &b.version)));
     Ok(crates)
@@ -142,20 +266,229 @@ fn parse_crate_dir(path: &std::path::Path) -> Option<RegistryCrate> {
     let hyphen_pos = last_hyphen?;
     let name = &dir_name[..hyphen_pos];
     let version = &dir_name[hyphen_pos + 1..];
+    let registry = utf8_path
+        .parent()
+        .and_then(|p| p.file_name())
+        .map(|s| s.to_string());
 
     Some(RegistryCrate {
         name: name.to_string(),
         version: version.to_string(),
         path: utf8_path,
+        registry,
     })
 }
 
-/// Find a specific crate by name (returns all versions).
+/// Env var naming a local crates.io index snapshot root (a sparse-protocol
+/// cache directory or git-checked-out index, e.g.
+/// `$CARGO_HOME/registry/index/<dir>`) to resolve versions against instead
+/// of scanning `~/.cargo/registry/src` - for air-gapped or CI sandboxes
+/// where the index has been pre-fetched but crate source hasn't been
+/// extracted yet. See `find_crate_offline`.
+pub const OFFLINE_INDEX_ENV: &str = "FASTDEPS_OFFLINE_INDEX";
+
+/// Find a specific crate by name (returns all versions). Resolves against
+/// a local index snapshot instead of the extracted-source scan when
+/// `OFFLINE_INDEX_ENV` is set, for deterministic offline resolution.
 pub fn find_crate(name: &str) -> Result<Vec<RegistryCrate>, CargoError> {
+    if let Ok(index_root) = std::env::var(OFFLINE_INDEX_ENV) {
+        return find_crate_offline(&Utf8PathBuf::from(index_root), name);
+    }
     let all = list_registry_crates()?;
     Ok(all.into_iter().filter(|c| c.name == name).collect())
 }
 
+/// Resolves a crate's version listing from a local crates.io index
+/// snapshot (sparse-protocol cache or git checkout) instead of scanning
+/// extracted crate source - lets `find_crate` return deterministic results
+/// in air-gapped/CI environments where the index has been pre-fetched but
+/// no crate source has been extracted yet (gated behind
+/// `OFFLINE_INDEX_ENV`). Builds one `RegistryCrate` per newline-delimited
+/// JSON version record under the index's standard prefix layout (`1/`,
+/// `2/`, `3/{first-char}/`, `{aa}/{bb}/`, see `index_rel_path`). `path` on
+/// each entry points at where the source *would* extract to under
+/// `$CARGO_HOME/registry/src`, even though nothing may exist there yet -
+/// callers that only need name/version/yanked status (crate resolution)
+/// work unaffected, while callers needing real source access
+/// (`source_files`, `package_metadata`) get the same graceful
+/// "missing manifest" behavior as any other not-yet-extracted crate.
+/// Returns an empty list, not an error, when the index has no entry for
+/// `name`.
+pub fn find_crate_offline(
+    index_root: &Utf8Path,
+    name: &str,
+) -> Result<Vec<RegistryCrate>, CargoError> {
+    let index_file = index_root.as_std_path().join(index_rel_path(name));
+    let Ok(contents) = fs::read_to_string(&index_file) else {
+        return Ok(Vec::new());
+    };
+
+    let registry = index_root.file_name().map(|s| s.to_string());
+    let src_dir = cargo_home_dir()
+        .and_then(|home| Utf8PathBuf::try_from(home).ok())
+        .map(|home| home.join("registry/src"))
+        .and_then(|root| registry.as_deref().map(|r| root.join(r)));
+
+    let body_start = contents.find('{').unwrap_or(contents.len());
+    let mut crates: Vec<RegistryCrate> = contents[body_start..]
+        .lines()
+        .filter_map(|line| {
+            let line = line.trim_matches('\0').trim();
+            if line.is_empty() {
+                return None;
+            }
+            let record: serde_json::Value = serde_json::from_str(line).ok()?;
+            let version = record.get("vers")?.as_str()?.to_string();
+            let path = src_dir
+                .as_ref()
+                .map(|dir| dir.join(format!("{}-{}", name, version)))
+                .unwrap_or_else(|| Utf8PathBuf::from(format!("{}-{}", name, version)));
+            Some(RegistryCrate {
+                name: name.to_string(),
+                version,
+                path,
+                registry: registry.clone(),
+            })
+        })
+        .collect();
+
+    crates.sort_by(|a, b| a.version.cmp(&b.version));
+    Ok(crates)
+}
+
+/// Find a specific crate by name, optionally scoped to one registry -
+/// `registry` is a registry name from `.cargo/config.toml`, a
+/// `CARGO_REGISTRIES_*` env var, a bare index URL, or `"crates-io"`. `None`
+/// behaves exactly like `find_crate`, matching every cached copy regardless
+/// of which registry it came from.
+pub fn find_crate_in_registry(
+    name: &str,
+    registry: Option<&str>,
+) -> Result<Vec<RegistryCrate>, CargoError> {
+    let crates = find_crate(name)?;
+    let Some(registry) = registry else {
+        return Ok(crates);
+    };
+    let Some(host) = resolve_registry_host(registry) else {
+        return Ok(Vec::new());
+    };
+    Ok(crates
+        .into_iter()
+        .filter(|c| c.registry.as_deref().is_some_and(|r| r.starts_with(&host)))
+        .collect())
+}
+
+/// Resolves a `registry` argument to the hostname cargo's registry cache
+/// directories are prefixed with (e.g. `"index.crates.io-6f17d22bba15001f"`
+/// -> `"index.crates.io"`), honoring `[registries.<name>]` in
+/// `.cargo/config.toml` and `CARGO_REGISTRIES_<NAME>_INDEX` env vars the way
+/// cargo itself maps a registry name to its index URL. Best-effort: mirrors
+/// cargo's directory naming for the common sparse/git-over-https case but
+/// doesn't replicate cargo's exact `SourceId` hash, so an unusual index URL
+/// layout may not match. `None` if `registry` doesn't resolve to anything.
+pub fn resolve_registry_host(registry: &str) -> Option<String> {
+    if registry.eq_ignore_ascii_case("crates-io") || registry.eq_ignore_ascii_case("crates.io") {
+        return Some("index.crates.io".to_string());
+    }
+
+    if registry.contains("://") {
+        return url_host(registry);
+    }
+
+    let env_key = format!(
+        "CARGO_REGISTRIES_{}_INDEX",
+        registry.to_uppercase().replace('-', "_")
+    );
+    if let Ok(url) = std::env::var(&env_key) {
+        return url_host(&url);
+    }
+
+    let config = read_cargo_config()?;
+    let registries = match config.get("registries")? {
+        toml::Value::Table(t) => t,
+        _ => return None,
+    };
+    let entry = match registries.get(registry)? {
+        toml::Value::Table(t) => t,
+        _ => return None,
+    };
+    let url = match entry.get("index")? {
+        toml::Value::String(s) => s,
+        _ => return None,
+    };
+    url_host(url)
+}
+
+/// Strip a `sparse+` scheme wrapper and any `scheme://user@host:port/path`
+/// decoration down to just the host.
+fn url_host(url: &str) -> Option<String> {
+    let url = url.strip_prefix("sparse+").unwrap_or(url);
+    let after_scheme = url.split_once("://")?.1;
+    let authority = after_scheme.split('/').next()?;
+    let host_and_port = authority.rsplit_once('@').map_or(authority, |(_, h)| h);
+    let host = host_and_port.split(':').next().unwrap_or(host_and_port);
+    Some(host.to_string())
+}
+
+/// Resolves `$CARGO_HOME`, falling back to `~/.cargo` when the env var
+/// isn't set - the same fallback `find_registry_src` and
+/// `project::find_cached_license` use.
+pub(crate) fn cargo_home_dir() -> Option<std::path::PathBuf> {
+    std::env::var("CARGO_HOME")
+        .map(std::path::PathBuf::from)
+        .ok()
+        .or_else(|| home::home_dir().map(|home| home.join(".cargo")))
+}
+
+/// Parses `$CARGO_HOME/config.toml` (falling back to the extensionless
+/// `config`), returning `None` if neither exists or parses.
+fn read_cargo_config() -> Option<toml::Table> {
+    let cargo_home = cargo_home_dir()?;
+
+    let contents = fs::read_to_string(cargo_home.join("config.toml"))
+        .or_else(|_| fs::read_to_string(cargo_home.join("config")))
+        .ok()?;
+    toml::from_str(&contents).ok()
+}
+
+/// Cargo's own nesting rule for index file paths: 1- and 2-character crate
+/// names live directly under `1/`/`2/`; 3-character names nest one level
+/// under their first character; everything else nests under its first two
+/// and next two characters (e.g. `"serde"` -> `"se/rd/serde"`).
+pub(crate) fn index_rel_path(name: &str) -> std::path::PathBuf {
+    let lower = name.to_lowercase();
+    match lower.len() {
+        1 => std::path::PathBuf::from("1").join(&lower),
+        2 => std::path::PathBuf::from("2").join(&lower),
+        3 => std::path::PathBuf::from("3").join(&lower[..1]).join(&lower),
+        _ => std::path::PathBuf::from(&lower[..2])
+            .join(&lower[2..4])
+            .join(&lower),
+    }
+}
+
+/// Scans a crate's index file contents for the line describing `version`,
+/// returning its `"yanked"` flag. Sparse-protocol cache files prefix the
+/// JSON-lines body with a binary header, so this skips to the first `{`
+/// before parsing line by line; non-JSON or mismatched lines are skipped
+/// rather than treated as fatal.
+fn parse_yanked(contents: &str, version: &str) -> Option<bool> {
+    let body_start = contents.find('{')?;
+    for line in contents[body_start..].lines() {
+        let line = line.trim_matches('\0').trim();
+        if line.is_empty() {
+            continue;
+        }
+        let Ok(record) = serde_json::from_str::<serde_json::Value>(line) else {
+            continue;
+        };
+        if record.get("vers").and_then(|v| v.as_str()) == Some(version) {
+            return record.get("yanked").and_then(|y| y.as_bool());
+        }
+    }
+    None
+}
+
 /// Find a specific crate by name and version.
 pub fn find_crate_version(name: &str, version: &str) -> Result<Option<RegistryCrate>, CargoError> {
     let all = list_registry_crates()?;
@@ -182,11 +515,35 @@ struct LockPackage {
 
 #[derive(Debug, Deserialize)]
 struct CargoToml {
+    package: Option<CargoTomlPackage>,
+    workspace: Option<CargoTomlWorkspace>,
     dependencies: Option<toml::Table>,
     #[serde(rename = "dev-dependencies")]
     dev_dependencies: Option<toml::Table>,
     #[serde(rename = "build-dependencies")]
     build_dependencies: Option<toml::Table>,
+    features: Option<toml::Table>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoTomlPackage {
+    name: String,
+    #[serde(default)]
+    version: String,
+    description: Option<String>,
+    license: Option<String>,
+    documentation: Option<String>,
+    homepage: Option<String>,
+    repository: Option<String>,
+    #[serde(rename = "rust-version")]
+    rust_version: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoTomlWorkspace {
+    members: Option<Vec<String>>,
+    #[serde(rename = "default-members")]
+    default_members: Option<Vec<String>>,
 }
 
 /// Get the names of all direct dependencies from Cargo.toml.
@@ -224,13 +581,29 @@ pub fn get_direct_dep_names(
     Ok(direct_deps)
 }
 
+/// Where a locked dependency's source actually lives, as determined by
+/// Cargo.lock's `source` field (or its absence).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LockedSource {
+    /// A crates.io (or alternate) registry entry, looked up by name/version
+    /// in `~/.cargo/registry/src`.
+    Registry,
+    /// A `path = "..."` dependency declared in Cargo.toml.
+    Path(Utf8PathBuf),
+    /// A `git` dependency, with its remote URL and pinned revision. Checked
+    /// out on disk under `~/.cargo/git/checkouts`.
+    Git { url: String, rev: Option<String> },
+    /// A workspace member, resolved from `[workspace].members`/
+    /// `default-members` globs in the root Cargo.toml.
+    Workspace(Utf8PathBuf),
+}
+
 /// Locked dependency from Cargo.lock.
 #[derive(Debug, Clone)]
 pub struct LockedDep {
     pub name: String,
     pub version: String,
-    /// None for registry deps, Some(path) for path deps
-    pub path: Option<Utf8PathBuf>,
+    pub source: LockedSource,
 }
 
 /// Extract path dependencies from a Cargo.toml dependency table.
@@ -286,8 +659,154 @@ fn parse_path_deps(project_dir: &Utf8Path) -> Result<Vec<(String, Utf8PathBuf)>,
     Ok(path_deps)
 }
 
+/// Resolve a `[workspace].members`/`default-members` glob pattern, relative
+/// to `project_dir`, into the on-disk directories it names. Only a trailing
+/// `/*` wildcard (one path segment) is supported, which covers the common
+/// `"crates/*"`-style patterns; anything else is treated as a literal path.
+fn expand_member_glob(project_dir: &Utf8Path, pattern: &str) -> Vec<Utf8PathBuf> {
+    if let Some(prefix) = pattern.strip_suffix("/*") {
+        let base = project_dir.join(prefix);
+        return fs::read_dir(&base)
+            .into_iter()
+            .flatten()
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .filter_map(|path| Utf8PathBuf::try_from(path).ok())
+            .collect();
+    }
+
+    let dir = project_dir.join(pattern);
+    if dir.is_dir() {
+        vec![dir]
+    } else {
+        vec![]
+    }
+}
+
+/// Expand the root Cargo.toml's `[workspace].members`/`default-members`
+/// globs into a name -> path map, by reading each member crate's own
+/// `[package]` table. Returns an empty map if there's no `[workspace]`
+/// table or the root Cargo.toml can't be read.
+fn resolve_workspace_members(
+    project_dir: &Utf8Path,
+) -> std::collections::HashMap<String, Utf8PathBuf> {
+    let toml_path = project_dir.join("Cargo.toml");
+    let Ok(contents) = fs::read_to_string(&toml_path) else {
+        return std::collections::HashMap::new();
+    };
+    let Ok(manifest) = toml::from_str::<CargoToml>(&contents) else {
+        return std::collections::HashMap::new();
+    };
+    let Some(workspace) = manifest.workspace else {
+        return std::collections::HashMap::new();
+    };
+
+    let patterns = workspace
+        .members
+        .into_iter()
+        .flatten()
+        .chain(workspace.default_members.into_iter().flatten());
+
+    let mut members = std::collections::HashMap::new();
+    for pattern in patterns {
+        for dir in expand_member_glob(project_dir, &pattern) {
+            if let Ok((name, _)) = read_root_package(&dir) {
+                members.insert(name, dir);
+            }
+        }
+    }
+    members
+}
+
+/// Locate a git dependency's on-disk checkout under
+/// `~/.cargo/git/checkouts/<repo>-<hash>/<shortrev>/`. The `<hash>` suffix
+/// cargo appends to the repo name is derived from the full source id and
+/// can't be recomputed here, so this matches any checkout directory whose
+/// name starts with the repo name and then picks the first subdirectory
+/// whose name starts with the pinned revision's short hash.
+fn resolve_git_checkout(url: &str, rev: Option<&str>) -> Option<Utf8PathBuf> {
+    let home = home::home_dir()?;
+    let home = Utf8PathBuf::try_from(home).ok()?;
+    let checkouts_dir = home.join(".cargo/git/checkouts");
+
+    let repo_name = url.trim_end_matches('/').rsplit('/').next()?;
+    let repo_name = repo_name.trim_end_matches(".git");
+    let short_rev = rev.map(|r| &r[..r.len().min(7)]);
+
+    let repo_dirs: Vec<Utf8PathBuf> = fs::read_dir(&checkouts_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .filter_map(|path| Utf8PathBuf::try_from(path).ok())
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| name.starts_with(repo_name))
+        })
+        .collect();
+
+    for repo_dir in repo_dirs {
+        let Ok(entries) = fs::read_dir(&repo_dir) else {
+            continue;
+        };
+        for entry in entries.filter_map(Result::ok) {
+            let path = entry.path();
+            if !path.is_dir() {
+                continue;
+            }
+            let Ok(checkout) = Utf8PathBuf::try_from(path) else {
+                continue;
+            };
+            let matches = match (short_rev, checkout.file_name()) {
+                (Some(short), Some(name)) => name.starts_with(short),
+                (None, _) => true,
+                _ => false,
+            };
+            if matches {
+                return Some(checkout);
+            }
+        }
+    }
+
+    None
+}
+
+/// Classify a locked package's source into a `LockedSource`, resolving path,
+/// workspace-member, and git sources to their on-disk (or remote) origin.
+/// Returns `None` for a source-less package that isn't a known path
+/// dependency or workspace member (e.g. the root crate itself).
+fn classify_locked_source(
+    package: &LockPackage,
+    path_deps: &std::collections::HashMap<&str, &Utf8PathBuf>,
+    workspace_members: &std::collections::HashMap<String, Utf8PathBuf>,
+) -> Option<LockedSource> {
+    match &package.source {
+        None => {
+            if let Some(path) = path_deps.get(package.name.as_str()) {
+                Some(LockedSource::Path((*path).clone()))
+            } else if let Some(path) = workspace_members.get(&package.name) {
+                Some(LockedSource::Workspace(path.clone()))
+            } else {
+                None
+            }
+        }
+        Some(source_str) => match source_str.strip_prefix("git+") {
+            Some(rest) => {
+                let (url, rev) = match rest.split_once('#') {
+                    Some((url, rev)) => (url.to_string(), Some(rev.to_string())),
+                    None => (rest.to_string(), None),
+                };
+                Some(LockedSource::Git { url, rev })
+            }
+            None => Some(LockedSource::Registry),
+        },
+    }
+}
+
 /// Parse Cargo.lock to get exact dependency versions.
-/// Includes both registry and path dependencies.
+/// Covers registry, path, workspace-member, and git dependencies.
 pub fn parse_cargo_lock(project_dir: &Utf8Path) -> Result<Vec<LockedDep>, CargoError> {
     let lock_path = project_dir.join("Cargo.lock");
     if !lock_path.exists() {
@@ -304,34 +823,22 @@ pub fn parse_cargo_lock(project_dir: &Utf8Path) -> Result<Vec<LockedDep>, CargoE
         source: e,
     })?;
 
-    // Get path dependencies from Cargo.toml
+    // Get path dependencies and workspace members from Cargo.toml
     let path_deps = parse_path_deps(project_dir)?;
     let path_dep_map: std::collections::HashMap<&str, &Utf8PathBuf> =
         path_deps.iter().map(|(n, p)| (n.as_str(), p)).collect();
+    let workspace_members = resolve_workspace_members(project_dir);
 
     let deps = lock
         .package
         .unwrap_or_default()
         .into_iter()
         .filter_map(|p| {
-            // Check if this is a path dependency (no source in Cargo.lock)
-            if p.source.is_none() {
-                // Look up the path from Cargo.toml
-                if let Some(path) = path_dep_map.get(p.name.as_str()) {
-                    return Some(LockedDep {
-                        name: p.name,
-                        version: p.version,
-                        path: Some((*path).clone()),
-                    });
-                }
-                // Path dep not found in Cargo.toml - skip it
-                return None;
-            }
-            // Registry dependency
+            let source = classify_locked_source(&p, &path_dep_map, &workspace_members)?;
             Some(LockedDep {
                 name: p.name,
                 version: p.version,
-                path: None,
+                source,
             })
         })
         .collect();
@@ -339,8 +846,118 @@ pub fn parse_cargo_lock(project_dir: &Utf8Path) -> Result<Vec<LockedDep>, CargoE
     Ok(deps)
 }
 
+/// Where a dependency's source actually lives, for display purposes -
+/// mirrors tauri's `CargoLockPackage`/`CargoManifestDependencyPackage`
+/// split of "what Cargo.lock recorded" vs. "what the manifest and local
+/// disk can add on top" (branch name, resolved checkout, registry host).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DependencyProvenance {
+    /// A crates.io (or alternate) registry entry, with the registry host
+    /// the crate was actually indexed under, if it's cached locally.
+    Registry { host: Option<String> },
+    /// A `git` dependency: its remote URL, the manifest-declared branch (if
+    /// any), the pinned revision, and its on-disk checkout under
+    /// `~/.cargo/git/checkouts`, if one could be found.
+    Git {
+        url: String,
+        branch: Option<String>,
+        rev: Option<String>,
+        checkout: Option<Utf8PathBuf>,
+    },
+    /// A `path = "..."` dependency or workspace member, with its absolute
+    /// path.
+    Path(Utf8PathBuf),
+}
+
+/// Read the manifest-declared `branch` for each table-form git dependency
+/// across `[dependencies]`, `[dev-dependencies]`, and `[build-dependencies]`.
+/// Cargo.lock's `source` field never records the branch name, only the
+/// resolved revision, so this is the only place it can come from.
+fn read_manifest_git_branches(project_dir: &Utf8Path) -> std::collections::HashMap<String, String> {
+    let toml_path = project_dir.join("Cargo.toml");
+    let Ok(contents) = fs::read_to_string(&toml_path) else {
+        return std::collections::HashMap::new();
+    };
+    let Ok(manifest) = toml::from_str::<CargoToml>(&contents) else {
+        return std::collections::HashMap::new();
+    };
+
+    let mut branches = std::collections::HashMap::new();
+    for table in [
+        &manifest.dependencies,
+        &manifest.dev_dependencies,
+        &manifest.build_dependencies,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        for (name, value) in table {
+            if let toml::Value::Table(dep_table) = value {
+                if let Some(toml::Value::String(branch)) = dep_table.get("branch") {
+                    branches.insert(name.clone(), branch.clone());
+                }
+            }
+        }
+    }
+    branches
+}
+
+/// Strip the hash suffix cargo appends to a registry cache directory name
+/// (e.g. `"index.crates.io-6f17d22bba15001f"` -> `"index.crates.io"`), so
+/// the host reads the same way it would in `.cargo/config.toml`. Leaves the
+/// name untouched if it doesn't look like `<host>-<hex>`.
+fn strip_registry_hash(dir_name: &str) -> &str {
+    match dir_name.rsplit_once('-') {
+        Some((host, hash)) if hash.len() >= 8 && hash.chars().all(|c| c.is_ascii_hexdigit()) => {
+            host
+        }
+        _ => dir_name,
+    }
+}
+
+/// Classify and enrich every locked dependency's source into a
+/// [`DependencyProvenance`], resolving git checkouts on disk and pulling
+/// the manifest-declared branch (if any) and the registry host (if the
+/// crate is cached locally) alongside it.
+pub fn resolve_dependency_provenance(
+    project_dir: &Utf8Path,
+) -> Result<Vec<(LockedDep, DependencyProvenance)>, CargoError> {
+    let locked = parse_cargo_lock(project_dir)?;
+    let branches = read_manifest_git_branches(project_dir);
+    let registry = list_registry_crates().unwrap_or_default();
+
+    let provenance = locked
+        .into_iter()
+        .map(|dep| {
+            let source = match &dep.source {
+                LockedSource::Registry => DependencyProvenance::Registry {
+                    host: registry
+                        .iter()
+                        .find(|c| c.name == dep.name && c.version == dep.version)
+                        .and_then(|c| c.registry.as_deref())
+                        .map(strip_registry_hash)
+                        .map(str::to_string),
+                },
+                LockedSource::Path(path) | LockedSource::Workspace(path) => {
+                    DependencyProvenance::Path(path.clone())
+                }
+                LockedSource::Git { url, rev } => DependencyProvenance::Git {
+                    url: url.clone(),
+                    branch: branches.get(&dep.name).cloned(),
+                    rev: rev.clone(),
+                    checkout: resolve_git_checkout(url, rev.as_deref()),
+                },
+            };
+            (dep, source)
+        })
+        .collect();
+
+    Ok(provenance)
+}
+
 /// Get all dependencies for a project with their paths.
-/// Includes both registry crates and local path dependencies.
+/// Includes registry crates, path dependencies, workspace members, and git
+/// dependencies (resolved from `~/.cargo/git/checkouts`).
 ///
 /// If `direct_only` is true, only returns direct dependencies listed in Cargo.toml
 /// (not transitive dependencies of dependencies).
@@ -367,26 +984,619 @@ pub fn resolve_project_deps(
             }
         }
 
-        // Path dependency - use the path directly
-        if let Some(path) = dep.path {
-            resolved.push(RegistryCrate {
-                name: dep.name,
-                version: dep.version,
-                path,
-            });
+        match dep.source {
+            // Path dependency or workspace member - use the path directly
+            LockedSource::Path(path) | LockedSource::Workspace(path) => {
+                resolved.push(RegistryCrate {
+                    name: dep.name,
+                    version: dep.version,
+                    path,
+                    registry: None,
+                });
+            }
+            // Git dependency - locate its checkout on disk
+            LockedSource::Git { url, rev } => {
+                if let Some(path) = resolve_git_checkout(&url, rev.as_deref()) {
+                    resolved.push(RegistryCrate {
+                        name: dep.name,
+                        version: dep.version,
+                        path,
+                        registry: None,
+                    });
+                }
+            }
+            // Registry dependency - look up in registry
+            LockedSource::Registry => {
+                if let Some(krate) = registry
+                    .iter()
+                    .find(|c| c.name == dep.name && c.version == dep.version)
+                {
+                    resolved.push(krate.clone());
+                }
+            }
+        }
+    }
+
+    Ok(resolved)
+}
+
+// === Transitive dependency graph ===
+
+/// Where cargo actually got a resolved package from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PackageSource {
+    /// A registry (crates.io or an alternate registry).
+    Registry,
+    /// A local path dependency, workspace member, or the root crate itself.
+    Path(Utf8PathBuf),
+    /// A git dependency, with its remote URL and pinned revision if known.
+    Git { url: String, rev: Option<String> },
+}
+
+/// Which dependency table a dependency edge was declared in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DepKind {
+    Normal,
+    Dev,
+    Build,
+}
+
+/// Uniquely identifies a resolved package the way `cargo metadata` does.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PackageId {
+    pub name: String,
+    pub version: String,
+}
+
+/// A directed edge from a node to one of its locked dependencies.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyEdge {
+    pub to: PackageId,
+    pub kind: DepKind,
+}
+
+/// A single resolved package in the dependency graph.
+#[derive(Debug, Clone)]
+pub struct DependencyNode {
+    pub id: PackageId,
+    pub source: PackageSource,
+    pub dependencies: Vec<DependencyEdge>,
+    /// Feature set enabled on this node given the root's requested
+    /// features. Only populated for the root and for a direct dependency
+    /// reached through a `dep_name/feature` activation on the root - see
+    /// `resolve_graph_with_features`'s doc comment for the scope of
+    /// feature unification this models.
+    pub features: Vec<String>,
+}
+
+/// The full resolved dependency graph for a project, modeled after `cargo
+/// metadata`'s resolve graph: one node per locked `(name, version)`, with
+/// directed edges recovered by re-reading each node's own Cargo.toml.
+#[derive(Debug, Clone)]
+pub struct DependencyGraph {
+    pub root: PackageId,
+    pub nodes: std::collections::BTreeMap<PackageId, DependencyNode>,
+}
+
+impl DependencyGraph {
+    /// The root package's direct dependencies, of any kind.
+    pub fn direct_deps(&self) -> Vec<&PackageId> {
+        self.nodes
+            .get(&self.root)
+            .map(|node| node.dependencies.iter().map(|edge| &edge.to).collect())
+            .unwrap_or_default()
+    }
+
+    /// Every package reachable from `pkg` by following dependency edges,
+    /// not including `pkg` itself.
+    pub fn transitive_deps(&self, pkg: &PackageId) -> Vec<&PackageId> {
+        let mut seen = std::collections::BTreeSet::new();
+        let mut stack = vec![pkg];
+        while let Some(current) = stack.pop() {
+            if let Some(node) = self.nodes.get(current) {
+                for edge in &node.dependencies {
+                    if seen.insert(&edge.to) {
+                        stack.push(&edge.to);
+                    }
+                }
+            }
+        }
+        seen.into_iter().collect()
+    }
+
+    /// Every node with a direct dependency edge onto `id`.
+    pub fn direct_dependents(&self, id: &PackageId) -> Vec<&PackageId> {
+        self.nodes
+            .iter()
+            .filter(|(_, node)| node.dependencies.iter().any(|edge| &edge.to == id))
+            .map(|(pid, _)| pid)
+            .collect()
+    }
+
+    /// Groups every resolved version of `name` present in the graph by its
+    /// compatibility epoch (see `version_epoch`) - `rand 0.7` and `rand
+    /// 0.8` land in distinct epochs, surfacing the kind of duplicate-major
+    /// bloat a single "where is this crate" lookup would otherwise hide.
+    /// Each epoch maps to its `(version, direct dependents)` pairs.
+    pub fn epoch_groups(
+        &self,
+        name: &str,
+    ) -> std::collections::BTreeMap<String, Vec<(&PackageId, Vec<&PackageId>)>> {
+        let mut groups: std::collections::BTreeMap<String, Vec<(&PackageId, Vec<&PackageId>)>> =
+            std::collections::BTreeMap::new();
+        for id in self.nodes.keys().filter(|id| id.name == name) {
+            let dependents = self.direct_dependents(id);
+            groups
+                .entry(version_epoch(&id.version))
+                .or_default()
+                .push((id, dependents));
+        }
+        groups
+    }
+
+    /// A topological order over every node, dependencies before dependents,
+    /// via Kahn's algorithm. Ties are broken by `PackageId` order for a
+    /// deterministic result.
+    pub fn topological_order(&self) -> Vec<&PackageId> {
+        let mut remaining: std::collections::BTreeMap<&PackageId, usize> = self
+            .nodes
+            .iter()
+            .map(|(id, node)| (id, node.dependencies.len()))
+            .collect();
+
+        let mut dependents: std::collections::BTreeMap<&PackageId, Vec<&PackageId>> =
+            std::collections::BTreeMap::new();
+        for (id, node) in &self.nodes {
+            for edge in &node.dependencies {
+                dependents.entry(&edge.to).or_default().push(id);
+            }
+        }
+
+        let mut ready: std::collections::BTreeSet<&PackageId> = remaining
+            .iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(id, _)| *id)
+            .collect();
+
+        let mut order = Vec::with_capacity(self.nodes.len());
+        while let Some(id) = ready.iter().next().copied() {
+            ready.remove(id);
+            order.push(id);
+            if let Some(deps_on_id) = dependents.get(id) {
+                for dependent in deps_on_id {
+                    if let Some(count) = remaining.get_mut(dependent) {
+                        *count -= 1;
+                        if *count == 0 {
+                            ready.insert(dependent);
+                        }
+                    }
+                }
+            }
+        }
+
+        order
+    }
+}
+
+/// A single dependency declared in a `[dependencies]`-style table, after
+/// unwrapping both the plain-string and table forms.
+struct ManifestDep {
+    name: String,
+    requirement: String,
+}
+
+fn collect_manifest_deps(table: &toml::Table) -> Vec<ManifestDep> {
+    table
+        .iter()
+        .map(|(name, value)| {
+            let requirement = match value {
+                toml::Value::String(version) => version.clone(),
+                toml::Value::Table(dep_table) => dep_table
+                    .get("version")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("*")
+                    .to_string(),
+                _ => "*".to_string(),
+            };
+            ManifestDep {
+                name: name.clone(),
+                requirement,
+            }
+        })
+        .collect()
+}
+
+/// A manifest's dependency tables and `[features]` table, already unwrapped
+/// into plain Rust collections.
+struct ManifestInfo {
+    dependencies: Vec<ManifestDep>,
+    dev_dependencies: Vec<ManifestDep>,
+    build_dependencies: Vec<ManifestDep>,
+    /// Feature name -> the list of features/`dep:name`/`name/feature`
+    /// strings it requires, straight from `[features]`.
+    features: std::collections::HashMap<String, Vec<String>>,
+}
+
+fn read_manifest(manifest_dir: &Utf8Path) -> Result<ManifestInfo, CargoError> {
+    let toml_path = manifest_dir.join("Cargo.toml");
+    let contents = fs::read_to_string(&toml_path).map_err(|e| CargoError::ReadError {
+        path: toml_path.clone(),
+        source: e,
+    })?;
+    let manifest: CargoToml = toml::from_str(&contents).map_err(|e| CargoError::TomlError {
+        path: toml_path,
+        source: e,
+    })?;
+
+    let features = manifest
+        .features
+        .as_ref()
+        .map(|table| {
+            table
+                .iter()
+                .map(|(name, value)| {
+                    let requires = match value {
+                        toml::Value::Array(items) => items
+                            .iter()
+                            .filter_map(|v| v.as_str().map(str::to_string))
+                            .collect(),
+                        _ => vec![],
+                    };
+                    (name.clone(), requires)
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(ManifestInfo {
+        dependencies: manifest
+            .dependencies
+            .as_ref()
+            .map(collect_manifest_deps)
+            .unwrap_or_default(),
+        dev_dependencies: manifest
+            .dev_dependencies
+            .as_ref()
+            .map(collect_manifest_deps)
+            .unwrap_or_default(),
+        build_dependencies: manifest
+            .build_dependencies
+            .as_ref()
+            .map(collect_manifest_deps)
+            .unwrap_or_default(),
+        features,
+    })
+}
+
+fn read_root_package(project_dir: &Utf8Path) -> Result<(String, String), CargoError> {
+    let toml_path = project_dir.join("Cargo.toml");
+    let contents = fs::read_to_string(&toml_path).map_err(|e| CargoError::ReadError {
+        path: toml_path.clone(),
+        source: e,
+    })?;
+    let manifest: CargoToml = toml::from_str(&contents).map_err(|e| CargoError::TomlError {
+        path: toml_path.clone(),
+        source: e,
+    })?;
+    let package = manifest
+        .package
+        .ok_or_else(|| CargoError::NoPackageTable(toml_path))?;
+    Ok((package.name, package.version))
+}
+
+fn resolve_dep_target(
+    by_name: &std::collections::HashMap<&str, Vec<&LockPackage>>,
+    name: &str,
+    requirement: &str,
+) -> Option<PackageId> {
+    let candidates = by_name.get(name)?;
+    let target = if candidates.len() == 1 {
+        candidates[0]
+    } else {
+        candidates
+            .iter()
+            .find(|p| requirement_matches(requirement, &p.version))
+            .copied()
+            .unwrap_or(candidates[0])
+    };
+    Some(PackageId {
+        name: target.name.clone(),
+        version: target.version.clone(),
+    })
+}
+
+fn collect_dependency_edges(
+    manifest_dir: &Utf8Path,
+    by_name: &std::collections::HashMap<&str, Vec<&LockPackage>>,
+) -> Vec<DependencyEdge> {
+    let Ok(manifest) = read_manifest(manifest_dir) else {
+        return vec![];
+    };
+
+    let mut edges = Vec::new();
+    for (deps, kind) in [
+        (&manifest.dependencies, DepKind::Normal),
+        (&manifest.dev_dependencies, DepKind::Dev),
+        (&manifest.build_dependencies, DepKind::Build),
+    ] {
+        for dep in deps {
+            if let Some(to) = resolve_dep_target(by_name, &dep.name, &dep.requirement) {
+                edges.push(DependencyEdge { to, kind });
+            }
+        }
+    }
+    edges
+}
+
+/// Resolve `requested_features` against the root crate's own `[features]`
+/// table into its closure of enabled same-crate features, plus any
+/// `dep_name/feature` activations it triggers on a direct dependency.
+/// Activation isn't chased past that one hop - doing so for every node
+/// would require a full fixpoint over the whole graph.
+fn compute_enabled_features(
+    project_dir: &Utf8Path,
+    requested_features: &[String],
+) -> Result<
+    (
+        std::collections::BTreeSet<String>,
+        std::collections::BTreeMap<String, std::collections::BTreeSet<String>>,
+    ),
+    CargoError,
+> {
+    let manifest = read_manifest(project_dir)?;
+
+    let mut queue = requested_features.to_vec();
+    if queue.is_empty() && manifest.features.contains_key("default") {
+        queue.push("default".to_string());
+    }
+
+    let mut enabled = std::collections::BTreeSet::new();
+    let mut dep_activations: std::collections::BTreeMap<
+        String,
+        std::collections::BTreeSet<String>,
+    > = std::collections::BTreeMap::new();
+
+    while let Some(feature) = queue.pop() {
+        if let Some((dep_name, dep_feature)) = feature.split_once('/') {
+            dep_activations
+                .entry(dep_name.trim_end_matches('?').to_string())
+                .or_default()
+                .insert(dep_feature.to_string());
+            continue;
+        }
+        if let Some(dep_name) = feature.strip_prefix("dep:") {
+            dep_activations.entry(dep_name.to_string()).or_default();
             continue;
         }
+        if !enabled.insert(feature.clone()) {
+            continue;
+        }
+        if let Some(requires) = manifest.features.get(&feature) {
+            queue.extend(requires.iter().cloned());
+        }
+    }
+
+    Ok((enabled, dep_activations))
+}
+
+/// Whether `version` (a plain `major.minor.patch` string, as Cargo.lock
+/// always records) satisfies `requirement` (a comma-separated Cargo version
+/// requirement, defaulting to caret semantics for a bare `"1.2.3"` the way
+/// Cargo itself does). Pre-release and build-metadata suffixes aren't
+/// modeled - this covers the common numeric-only case.
+fn requirement_matches(requirement: &str, version: &str) -> bool {
+    let requirement = requirement.trim();
+    if requirement.is_empty() || requirement == "*" {
+        return true;
+    }
+    requirement
+        .split(',')
+        .all(|clause| comparator_matches(clause.trim(), version))
+}
+
+fn comparator_matches(clause: &str, version: &str) -> bool {
+    let version = parse_version_triple(version);
+
+    if let Some(rest) = clause.strip_prefix(">=") {
+        return version >= parse_version_triple(rest.trim());
+    }
+    if let Some(rest) = clause.strip_prefix("<=") {
+        return version <= parse_version_triple(rest.trim());
+    }
+    if let Some(rest) = clause.strip_prefix('>') {
+        return version > parse_version_triple(rest.trim());
+    }
+    if let Some(rest) = clause.strip_prefix('<') {
+        return version < parse_version_triple(rest.trim());
+    }
+    if let Some(rest) = clause.strip_prefix('=') {
+        return version == parse_version_triple(rest.trim());
+    }
+    if let Some(rest) = clause.strip_prefix('~') {
+        let req = parse_version_triple(rest.trim());
+        return version >= req && version.0 == req.0 && version.1 == req.1;
+    }
+
+    // Bare version (optionally `^`-prefixed): Cargo's default caret
+    // compatibility, matching the leftmost-nonzero-component family.
+    let req = parse_version_triple(clause.strip_prefix('^').unwrap_or(clause).trim());
+    if version < req {
+        return false;
+    }
+    if req.0 != 0 {
+        version.0 == req.0
+    } else if req.1 != 0 {
+        version.0 == 0 && version.1 == req.1
+    } else {
+        version.0 == 0 && version.1 == 0 && version.2 == req.2
+    }
+}
+
+fn parse_version_triple(s: &str) -> (u64, u64, u64) {
+    let core = s.split(['-', '+']).next().unwrap_or(s);
+    let mut parts = core.split('.').map(|p| p.parse::<u64>().unwrap_or(0));
+    (
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+    )
+}
+
+/// A version's compatibility epoch: the major component once it reaches
+/// 1, otherwise `0.<minor>` - so `0.7.x` and `0.8.x` are distinct epochs
+/// but `1.2.x` and `1.9.x` share one, matching the "leftmost nonzero
+/// component is breaking" rule `requirement_matches`'s caret handling
+/// already assumes. This is the same normalization Chromium's `gnrt`
+/// applies to decide whether two vendored crate versions are compatible.
+/// Falls back to the full version string for anything that doesn't parse
+/// as semver, so an unusual version tag still gets its own distinct group
+/// rather than silently merging with others.
+pub fn version_epoch(version: &str) -> String {
+    let Ok(parsed) = semver::Version::parse(version) else {
+        return version.to_string();
+    };
+    if parsed.major >= 1 {
+        parsed.major.to_string()
+    } else {
+        format!("0.{}", parsed.minor)
+    }
+}
+
+/// Build the dependency graph using the crate's default feature set, the
+/// way a plain `cargo build` would.
+pub fn resolve_graph(project_dir: &Utf8Path) -> Result<DependencyGraph, CargoError> {
+    resolve_graph_with_features(project_dir, &["default".to_string()])
+}
+
+/// Build the full resolved dependency graph for a project: one node per
+/// `[[package]]` in Cargo.lock, with edges recovered by re-reading each
+/// node's own Cargo.toml and matching its declared dependency requirements
+/// against the concrete versions present in the lock file (disambiguating
+/// by requirement when a name has multiple locked versions).
+///
+/// `requested_features` resolves feature unification for the root crate
+/// only - pass `&["default".to_string()]` for cargo's normal default-on
+/// behavior. Workspace members that aren't reachable as a path dependency
+/// of `project_dir`'s own crate are recorded as nodes but left with no
+/// resolved edges, since there's no manifest path to re-read for them.
+pub fn resolve_graph_with_features(
+    project_dir: &Utf8Path,
+    requested_features: &[String],
+) -> Result<DependencyGraph, CargoError> {
+    let lock_path = project_dir.join("Cargo.lock");
+    if !lock_path.exists() {
+        return Err(CargoError::NoLockFile(lock_path));
+    }
+    let contents = fs::read_to_string(&lock_path).map_err(|e| CargoError::ReadError {
+        path: lock_path.clone(),
+        source: e,
+    })?;
+    let lock: CargoLock = toml::from_str(&contents).map_err(|e| CargoError::TomlError {
+        path: lock_path,
+        source: e,
+    })?;
+    let packages = lock.package.unwrap_or_default();
+
+    let root_package = read_root_package(project_dir)?;
+    let root_id = PackageId {
+        name: root_package.0,
+        version: root_package.1,
+    };
+
+    let path_deps = parse_path_deps(project_dir)?;
+    let path_dep_map: std::collections::HashMap<&str, &Utf8PathBuf> =
+        path_deps.iter().map(|(n, p)| (n.as_str(), p)).collect();
+    let registry = list_registry_crates()?;
+
+    let mut by_name: std::collections::HashMap<&str, Vec<&LockPackage>> =
+        std::collections::HashMap::new();
+    for package in &packages {
+        by_name
+            .entry(package.name.as_str())
+            .or_default()
+            .push(package);
+    }
+
+    let mut nodes = std::collections::BTreeMap::new();
+
+    for package in &packages {
+        let id = PackageId {
+            name: package.name.clone(),
+            version: package.version.clone(),
+        };
+        let is_root = id == root_id;
+
+        let (source, manifest_dir) = if is_root {
+            (
+                PackageSource::Path(project_dir.to_path_buf()),
+                Some(project_dir.to_path_buf()),
+            )
+        } else if let Some(source_str) = &package.source {
+            if let Some(rest) = source_str.strip_prefix("git+") {
+                let (url, rev) = match rest.split_once('#') {
+                    Some((url, rev)) => (url.to_string(), Some(rev.to_string())),
+                    None => (rest.to_string(), None),
+                };
+                (PackageSource::Git { url, rev }, None)
+            } else {
+                let manifest_dir = registry
+                    .iter()
+                    .find(|c| c.name == package.name && c.version == package.version)
+                    .map(|c| c.path.clone());
+                (PackageSource::Registry, manifest_dir)
+            }
+        } else if let Some(path) = path_dep_map.get(package.name.as_str()) {
+            (PackageSource::Path((*path).clone()), Some((*path).clone()))
+        } else {
+            // A workspace member we have no path for - see the doc comment above.
+            (PackageSource::Path(project_dir.to_path_buf()), None)
+        };
+
+        let dependencies = manifest_dir
+            .as_deref()
+            .map(|dir| collect_dependency_edges(dir, &by_name))
+            .unwrap_or_default();
+
+        nodes.insert(
+            id.clone(),
+            DependencyNode {
+                id,
+                source,
+                dependencies,
+                features: vec![],
+            },
+        );
+    }
+
+    let root_dep_targets: Vec<PackageId> = nodes
+        .get(&root_id)
+        .map(|node| {
+            node.dependencies
+                .iter()
+                .map(|edge| edge.to.clone())
+                .collect()
+        })
+        .unwrap_or_default();
 
-        // Registry dependency - look up in registry
-        if let Some(krate) = registry
+    let (root_features, dep_activations) =
+        compute_enabled_features(project_dir, requested_features)?;
+    if let Some(node) = nodes.get_mut(&root_id) {
+        node.features = root_features.into_iter().collect();
+    }
+    for (dep_name, features) in dep_activations {
+        let dep_node = root_dep_targets
             .iter()
-            .find(|c| c.name == dep.name && c.version == dep.version)
-        {
-            resolved.push(krate.clone());
+            .find(|id| id.name == dep_name)
+            .and_then(|dep_id| nodes.get_mut(dep_id));
+        if let Some(dep_node) = dep_node {
+            dep_node.features = features.into_iter().collect();
         }
     }
 
-    Ok(resolved)
+    Ok(DependencyGraph {
+        root: root_id,
+        nodes,
+    })
 }
 
 #[cfg(test)]
@@ -408,4 +1618,109 @@ mod tests {
         assert_eq!(krate.name, "proc-macro2");
         assert_eq!(krate.version, "1.0.86");
     }
+
+    #[test]
+    fn test_requirement_matches_caret_default() {
+        assert!(requirement_matches("1.2.3", "1.4.0"));
+        assert!(!requirement_matches("1.2.3", "2.0.0"));
+        assert!(!requirement_matches("1.2.3", "1.2.0"));
+        assert!(requirement_matches("0.2.3", "0.2.9"));
+        assert!(!requirement_matches("0.2.3", "0.3.0"));
+    }
+
+    #[test]
+    fn test_requirement_matches_tilde_and_exact() {
+        assert!(requirement_matches("~1.2.3", "1.2.9"));
+        assert!(!requirement_matches("~1.2.3", "1.3.0"));
+        assert!(requirement_matches("=1.2.3", "1.2.3"));
+        assert!(!requirement_matches("=1.2.3", "1.2.4"));
+        assert!(requirement_matches("*", "9.9.9"));
+    }
+
+    #[test]
+    fn test_requirement_matches_comparators() {
+        assert!(requirement_matches(">=1.0, <2.0", "1.5.0"));
+        assert!(!requirement_matches(">=1.0, <2.0", "2.0.0"));
+    }
+
+    #[test]
+    fn test_index_rel_path() {
+        assert_eq!(index_rel_path("a"), std::path::PathBuf::from("1/a"));
+        assert_eq!(index_rel_path("ab"), std::path::PathBuf::from("2/ab"));
+        assert_eq!(index_rel_path("abc"), std::path::PathBuf::from("3/a/abc"));
+        assert_eq!(
+            index_rel_path("serde"),
+            std::path::PathBuf::from("se/rd/serde")
+        );
+    }
+
+    #[test]
+    fn test_version_epoch() {
+        assert_eq!(version_epoch("0.7.3"), "0.7");
+        assert_eq!(version_epoch("0.8.0"), "0.8");
+        assert_eq!(version_epoch("1.2.3"), "1");
+        assert_eq!(version_epoch("1.9.0"), "1");
+        assert_eq!(version_epoch("2.0.0"), "2");
+        assert_eq!(version_epoch("not-semver"), "not-semver");
+    }
+
+    #[test]
+    fn test_parse_yanked() {
+        let index = "\u{7}cache-header-junk\0\n{\"name\":\"serde\",\"vers\":\"1.0.0\",\"yanked\":false}\n{\"name\":\"serde\",\"vers\":\"1.0.1\",\"yanked\":true}\n";
+        assert_eq!(parse_yanked(index, "1.0.0"), Some(false));
+        assert_eq!(parse_yanked(index, "1.0.1"), Some(true));
+        assert_eq!(parse_yanked(index, "9.9.9"), None);
+    }
+
+    fn pkg(name: &str, version: &str) -> PackageId {
+        PackageId {
+            name: name.to_string(),
+            version: version.to_string(),
+        }
+    }
+
+    fn node(id: PackageId, deps: Vec<&PackageId>) -> DependencyNode {
+        DependencyNode {
+            id: id.clone(),
+            source: PackageSource::Registry,
+            dependencies: deps
+                .into_iter()
+                .map(|to| DependencyEdge {
+                    to: to.clone(),
+                    kind: DepKind::Normal,
+                })
+                .collect(),
+            features: vec![],
+        }
+    }
+
+    #[test]
+    fn test_transitive_deps_and_topological_order() {
+        let root = pkg("root", "0.1.0");
+        let a = pkg("a", "1.0.0");
+        let b = pkg("b", "1.0.0");
+
+        let mut nodes = std::collections::BTreeMap::new();
+        nodes.insert(root.clone(), node(root.clone(), vec![&a]));
+        nodes.insert(a.clone(), node(a.clone(), vec![&b]));
+        nodes.insert(b.clone(), node(b.clone(), vec![]));
+
+        let graph = DependencyGraph {
+            root: root.clone(),
+            nodes,
+        };
+
+        assert_eq!(graph.direct_deps(), vec![&a]);
+
+        let transitive = graph.transitive_deps(&root);
+        assert!(transitive.contains(&&a));
+        assert!(transitive.contains(&&b));
+
+        let order = graph.topological_order();
+        let pos_b = order.iter().position(|id| **id == b).unwrap();
+        let pos_a = order.iter().position(|id| **id == a).unwrap();
+        let pos_root = order.iter().position(|id| **id == root).unwrap();
+        assert!(pos_b < pos_a);
+        assert!(pos_a < pos_root);
+    }
 }
@@ -0,0 +1,200 @@
+//! Dot-access completion index over a parsed item set.
+//!
+//! Given a receiver type path and an optional prefix, returns the fields and
+//! methods accessible on it - the set an editor would offer after typing `.` -
+//! merging inherent methods with methods contributed by implemented traits.
+
+use crate::schema::{Item, ItemKind, Visibility};
+use std::collections::HashMap;
+
+/// Whether a [`CompletionEntry`] came from a field or a method.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompletionKind {
+    Field,
+    Method,
+}
+
+/// A single field or method accessible on some type, ready to present as a
+/// "what can I call after `.`" suggestion.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletionEntry {
+    pub name: String,
+    pub kind: CompletionKind,
+    pub doc: Option<String>,
+    /// Only set for methods.
+    pub signature: Option<String>,
+}
+
+/// An index of every type's visible fields and methods, built once from a
+/// parsed item set and queried many times.
+pub struct CompletionIndex {
+    entries_by_path: HashMap<String, Vec<CompletionEntry>>,
+}
+
+impl CompletionIndex {
+    /// Build the index. `item.methods` already holds inherent-impl methods
+    /// (see `RustParser::parse_impl`), but trait-impl methods aren't
+    /// attached there - only the trait's name lands in `item.traits`. So for
+    /// each implemented trait that's also defined in this item set, pull its
+    /// declared methods in too.
+    pub fn build(items: &[Item]) -> Self {
+        let traits_by_name: HashMap<&str, &Item> = items
+            .iter()
+            .filter(|item| item.kind == ItemKind::Trait)
+            .filter_map(|item| item.path.rsplit("::").next().map(|name| (name, item)))
+            .collect();
+
+        let mut entries_by_path = HashMap::new();
+
+        for item in items {
+            let mut entries: Vec<CompletionEntry> = item
+                .fields
+                .iter()
+                .filter(|field| field.visibility == Visibility::Public)
+                .map(|field| CompletionEntry {
+                    name: field.name.clone(),
+                    kind: CompletionKind::Field,
+                    doc: field.doc.clone(),
+                    signature: None,
+                })
+                .collect();
+
+            entries.extend(item.methods.iter().filter_map(|method| {
+                if method.visibility != Visibility::Public {
+                    return None;
+                }
+                Some(CompletionEntry {
+                    name: method.name.clone(),
+                    kind: CompletionKind::Method,
+                    doc: method.doc.clone(),
+                    signature: method.signature.clone(),
+                })
+            }));
+
+            for trait_name in &item.traits {
+                let Some(trait_item) = traits_by_name.get(trait_name.as_str()) else {
+                    continue;
+                };
+                if trait_item.visibility != Visibility::Public {
+                    continue;
+                }
+                entries.extend(trait_item.methods.iter().map(|method| CompletionEntry {
+                    name: method.name.clone(),
+                    kind: CompletionKind::Method,
+                    doc: method.doc.clone(),
+                    signature: method.signature.clone(),
+                }));
+            }
+
+            entries_by_path.insert(item.path.clone(), entries);
+        }
+
+        Self { entries_by_path }
+    }
+
+    /// Return the fields/methods visible on `receiver_path` whose name
+    /// starts with `prefix` (pass `""` for everything), exact matches first
+    /// then alphabetically.
+    pub fn complete(&self, receiver_path: &str, prefix: &str) -> Vec<CompletionEntry> {
+        let Some(entries) = self.entries_by_path.get(receiver_path) else {
+            return vec![];
+        };
+
+        let mut matches: Vec<CompletionEntry> = entries
+            .iter()
+            .filter(|entry| entry.name.starts_with(prefix))
+            .cloned()
+            .collect();
+
+        matches.sort_by(|a, b| {
+            let a_exact = a.name == prefix;
+            let b_exact = b.name == prefix;
+            b_exact.cmp(&a_exact).then_with(|| a.name.cmp(&b.name))
+        });
+
+        matches
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::languages::rust::RustParser;
+
+    #[test]
+    fn test_complete_merges_fields_and_inherent_methods() {
+        let source = r#"
+pub struct Foo {
+    pub value: i32,
+}
+
+impl Foo {
+    pub fn get_value(&self) -> i32 {
+        self.value
+    }
+
+    fn private_helper(&self) {}
+}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+        let index = CompletionIndex::build(&items);
+
+        let entries = index.complete("crate::Foo", "");
+        let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert!(names.contains(&"value"));
+        assert!(names.contains(&"get_value"));
+        assert!(!names.contains(&"private_helper"));
+    }
+
+    #[test]
+    fn test_complete_merges_trait_impl_methods() {
+        let source = r#"
+pub trait Greet {
+    fn greet(&self) -> String;
+}
+
+pub struct Foo;
+
+impl Greet for Foo {
+    fn greet(&self) -> String {
+        "hi".to_string()
+    }
+}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+        let index = CompletionIndex::build(&items);
+
+        let entries = index.complete("crate::Foo", "");
+        assert!(entries.iter().any(|e| e.name == "greet"));
+    }
+
+    #[test]
+    fn test_complete_prefix_ranks_exact_match_first() {
+        let source = r#"
+pub struct Foo {
+    pub get: i32,
+}
+
+impl Foo {
+    pub fn get_value(&self) -> i32 {
+        self.get
+    }
+}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+        let index = CompletionIndex::build(&items);
+
+        let entries = index.complete("crate::Foo", "get");
+        assert_eq!(entries[0].name, "get");
+        assert_eq!(entries[1].name, "get_value");
+    }
+
+    #[test]
+    fn test_complete_unknown_receiver_is_empty() {
+        let index = CompletionIndex::build(&[]);
+        assert!(index.complete("crate::Missing", "").is_empty());
+    }
+}
@@ -5,6 +5,7 @@
 //!
 //! The matrix is ~12KB (3,072 f32 values) and can be bundled into the binary.
 
+#[cfg(feature = "std")]
 use std::io::{Read, Write};
 
 /// The embedding dimension from all-MiniLM-L6-v2.
@@ -25,6 +26,7 @@ pub struct ContrastiveMapper {
 
 impl ContrastiveMapper {
     /// Create a new mapper with random initialization.
+    #[cfg(feature = "std")]
     pub fn new_random() -> Self {
         use std::time::{SystemTime, UNIX_EPOCH};
         let seed = SystemTime::now()
@@ -99,6 +101,7 @@ impl ContrastiveMapper {
 
     /// Train the mapper using gradient descent.
     /// Returns final loss.
+    #[cfg(feature = "std")]
     pub fn train(
         &mut self,
         embeddings: &[Vec<f32>],
@@ -164,6 +167,384 @@ impl ContrastiveMapper {
         self.compute_loss(embeddings, targets)
     }
 
+    /// Projects `embedding` through the linear layer only (no sigmoid,
+    /// no normalization) - the raw pre-normalization output `train_contrastive`
+    /// needs both to compute `project_normalized` and to build the
+    /// normalization Jacobian for backprop.
+    fn project_raw(&self, embedding: &[f32]) -> [f32; OCTO_DIM] {
+        assert_eq!(embedding.len(), EMBED_DIM, "Expected 384D embedding");
+
+        let mut output = self.bias;
+        for (i, &e) in embedding.iter().enumerate() {
+            for (j, out) in output.iter_mut().enumerate() {
+                *out += e * self.weights[i][j];
+            }
+        }
+        output
+    }
+
+    /// Projects `embedding` through the linear layer only (no sigmoid),
+    /// then L2-normalizes the result - the representation the contrastive
+    /// loss compares via cosine-like dot products, rather than `forward`'s
+    /// `[0, 1]`-clamped octonion coefficients.
+    fn project_normalized(&self, embedding: &[f32]) -> [f32; OCTO_DIM] {
+        let mut output = self.project_raw(embedding);
+        let norm = output.iter().map(|v| v * v).sum::<f32>().sqrt().max(1e-12);
+        for out in output.iter_mut() {
+            *out /= norm;
+        }
+        output
+    }
+
+    /// Computes the InfoNCE loss `train_contrastive` optimizes, without
+    /// mutating weights - mirrors `compute_loss`'s role for plain `train`.
+    /// Samples with no positives (no other target within `epsilon`) don't
+    /// contribute and are skipped; if none have any, returns `0.0`.
+    pub fn compute_contrastive_loss(
+        &self,
+        embeddings: &[Vec<f32>],
+        targets: &[[f32; OCTO_DIM]],
+        temperature: f32,
+        epsilon: f32,
+    ) -> f32 {
+        let n = embeddings.len();
+        let projections: Vec<[f32; OCTO_DIM]> = embeddings
+            .iter()
+            .map(|e| self.project_normalized(e))
+            .collect();
+
+        let mut total_loss = 0.0;
+        let mut anchor_count = 0usize;
+
+        for i in 0..n {
+            let positives = find_positives(i, targets, epsilon);
+            if positives.is_empty() {
+                continue;
+            }
+            anchor_count += 1;
+
+            let sims: Vec<f32> = (0..n)
+                .map(|k| dot(&projections[i], &projections[k]) / temperature)
+                .collect();
+            let max_sim = (0..n)
+                .filter(|&k| k != i)
+                .map(|k| sims[k])
+                .fold(f32::NEG_INFINITY, f32::max);
+            let sum_exp: f32 = (0..n)
+                .filter(|&k| k != i)
+                .map(|k| (sims[k] - max_sim).exp())
+                .sum();
+
+            let mut loss_i = 0.0;
+            for &p in &positives {
+                let prob = (sims[p] - max_sim).exp() / sum_exp;
+                loss_i -= prob.max(1e-12).ln();
+            }
+            total_loss += loss_i / positives.len() as f32;
+        }
+
+        if anchor_count == 0 {
+            0.0
+        } else {
+            total_loss / anchor_count as f32
+        }
+    }
+
+    /// Trains the mapper with a supervised InfoNCE objective instead of
+    /// `train`'s plain MSE, so the 8D outputs cluster by semantic
+    /// neighborhood rather than memorizing absolute octonion coefficients.
+    ///
+    /// Every embedding in the batch is projected via `forward` (skipping
+    /// the final sigmoid and L2-normalizing instead), forming a similarity
+    /// matrix `S[i][j] = dot(z_i, z_j) / temperature`. For anchor `i`, every
+    /// other sample whose target lies within `epsilon` of `targets[i]` is
+    /// treated as a positive, and the loss is the softmax cross-entropy of
+    /// putting probability mass on those positives among all `k != i`.
+    /// Backpropagates analytically: `d_loss/d_z_i = sum_k (softmax_ik -
+    /// y_ik) * z_k / temperature`, then through `project_normalized`'s own
+    /// L2-normalization via its Jacobian `d_z/d_raw = (I - z_i z_iᵀ) /
+    /// ‖raw_i‖` (standard unit-vector-normalization gradient) before
+    /// reaching the linear layer, since `z_i` is a normalized projection of
+    /// the raw `weights`/`bias` output, not the raw output itself:
+    /// `grad_weights[e][d] += grad_raw[d] * embedding[e]`, and each epoch
+    /// steps `weights` against the anchor-averaged gradient. Returns final
+    /// loss.
+    pub fn train_contrastive(
+        &mut self,
+        embeddings: &[Vec<f32>],
+        targets: &[[f32; OCTO_DIM]],
+        learning_rate: f32,
+        epochs: usize,
+        temperature: f32,
+        epsilon: f32,
+        verbose: bool,
+    ) -> f32 {
+        let n = embeddings.len();
+
+        for epoch in 0..epochs {
+            let raw_norms: Vec<f32> = embeddings
+                .iter()
+                .map(|e| {
+                    self.project_raw(e)
+                        .iter()
+                        .map(|v| v * v)
+                        .sum::<f32>()
+                        .sqrt()
+                        .max(1e-12)
+                })
+                .collect();
+            let projections: Vec<[f32; OCTO_DIM]> = embeddings
+                .iter()
+                .map(|e| self.project_normalized(e))
+                .collect();
+
+            let mut grad_weights = [[0.0f32; OCTO_DIM]; EMBED_DIM];
+            let mut grad_bias = [0.0f32; OCTO_DIM];
+            let mut anchor_count = 0usize;
+
+            for i in 0..n {
+                let positives = find_positives(i, targets, epsilon);
+                if positives.is_empty() {
+                    continue;
+                }
+                anchor_count += 1;
+
+                let sims: Vec<f32> = (0..n)
+                    .map(|k| dot(&projections[i], &projections[k]) / temperature)
+                    .collect();
+                let max_sim = (0..n)
+                    .filter(|&k| k != i)
+                    .map(|k| sims[k])
+                    .fold(f32::NEG_INFINITY, f32::max);
+                let sum_exp: f32 = (0..n)
+                    .filter(|&k| k != i)
+                    .map(|k| (sims[k] - max_sim).exp())
+                    .sum();
+
+                let y_value = 1.0 / positives.len() as f32;
+
+                let mut grad_z = [0.0f32; OCTO_DIM];
+                for k in 0..n {
+                    if k == i {
+                        continue;
+                    }
+                    let softmax_ik = (sims[k] - max_sim).exp() / sum_exp;
+                    let y_ik = if positives.contains(&k) { y_value } else { 0.0 };
+                    let coeff = (softmax_ik - y_ik) / temperature;
+                    for d in 0..OCTO_DIM {
+                        grad_z[d] += coeff * projections[k][d];
+                    }
+                }
+
+                // `projections[i]` is `project_normalized`'s unit-norm output, so
+                // `grad_z` (d_loss/d_z_i) still has to cross that normalization's
+                // own Jacobian, `d_z/d_raw = (I - z_i z_iᵀ) / ‖raw_i‖`, before it's
+                // a gradient w.r.t. the linear layer's raw output.
+                let zi = &projections[i];
+                let z_dot_grad_z: f32 = zi.iter().zip(grad_z.iter()).map(|(z, g)| z * g).sum();
+                let mut grad_raw = [0.0f32; OCTO_DIM];
+                for d in 0..OCTO_DIM {
+                    grad_raw[d] = (grad_z[d] - zi[d] * z_dot_grad_z) / raw_norms[i];
+                }
+
+                for (e_idx, &e_val) in embeddings[i].iter().enumerate() {
+                    for d in 0..OCTO_DIM {
+                        grad_weights[e_idx][d] += grad_raw[d] * e_val;
+                    }
+                }
+                for d in 0..OCTO_DIM {
+                    grad_bias[d] += grad_raw[d];
+                }
+            }
+
+            if anchor_count > 0 {
+                let scale = 1.0 / anchor_count as f32;
+                for i in 0..EMBED_DIM {
+                    for j in 0..OCTO_DIM {
+                        self.weights[i][j] -= learning_rate * grad_weights[i][j] * scale;
+                    }
+                }
+                for j in 0..OCTO_DIM {
+                    self.bias[j] -= learning_rate * grad_bias[j] * scale;
+                }
+            }
+
+            if verbose && (epoch % 100 == 0 || epoch == epochs - 1) {
+                let loss = self.compute_contrastive_loss(embeddings, targets, temperature, epsilon);
+                println!("Epoch {}/{}: infonce loss = {:.6}", epoch + 1, epochs, loss);
+            }
+        }
+
+        self.compute_contrastive_loss(embeddings, targets, temperature, epsilon)
+    }
+
+    /// Trains with mini-batch Adam and optional validation-based early
+    /// stopping, rather than `train`'s full-batch fixed-rate descent -
+    /// converges faster and doesn't overshoot on the sigmoid plateau.
+    /// Restores the best validation checkpoint seen (or the final epoch's
+    /// weights, if no validation split was configured) before returning.
+    pub fn train_adam(
+        &mut self,
+        embeddings: &[Vec<f32>],
+        targets: &[[f32; OCTO_DIM]],
+        config: &AdamTrainingConfig,
+    ) -> TrainingReport {
+        let n = embeddings.len();
+        let mut rng_state = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos() as u64;
+
+        let mut all_indices = shuffled_indices(n, &mut rng_state);
+        let val_len = config
+            .validation_fraction
+            .map(|f| ((n as f32) * f).round() as usize)
+            .unwrap_or(0)
+            .min(n.saturating_sub(1));
+        let val_indices = all_indices.split_off(n - val_len);
+        let train_indices = all_indices;
+
+        let train_embeddings: Vec<Vec<f32>> = train_indices
+            .iter()
+            .map(|&i| embeddings[i].clone())
+            .collect();
+        let train_targets: Vec<[f32; OCTO_DIM]> =
+            train_indices.iter().map(|&i| targets[i]).collect();
+        let val_embeddings: Vec<Vec<f32>> =
+            val_indices.iter().map(|&i| embeddings[i].clone()).collect();
+        let val_targets: Vec<[f32; OCTO_DIM]> = val_indices.iter().map(|&i| targets[i]).collect();
+
+        let mut m_weights = [[0.0f32; OCTO_DIM]; EMBED_DIM];
+        let mut v_weights = [[0.0f32; OCTO_DIM]; EMBED_DIM];
+        let mut m_bias = [0.0f32; OCTO_DIM];
+        let mut v_bias = [0.0f32; OCTO_DIM];
+        let mut step = 0i32;
+
+        let mut best_weights = self.weights;
+        let mut best_bias = self.bias;
+        let mut best_val_loss = f32::INFINITY;
+        let mut best_epoch = 0usize;
+        let mut epochs_without_improvement = 0usize;
+        let mut final_train_loss = 0.0;
+
+        for epoch in 0..config.epochs {
+            let order = shuffled_indices(train_indices.len(), &mut rng_state);
+            let batch_size = config.batch_size.max(1);
+
+            for batch in order.chunks(batch_size) {
+                let mut grad_weights = [[0.0f32; OCTO_DIM]; EMBED_DIM];
+                let mut grad_bias = [0.0f32; OCTO_DIM];
+                let batch_len = batch.len() as f32;
+
+                for &local_idx in batch {
+                    let emb = &train_embeddings[local_idx];
+                    let target = &train_targets[local_idx];
+
+                    let mut pre_sigmoid = self.bias;
+                    for (i, &e) in emb.iter().enumerate() {
+                        for (j, out) in pre_sigmoid.iter_mut().enumerate() {
+                            *out += e * self.weights[i][j];
+                        }
+                    }
+                    let mut pred = pre_sigmoid;
+                    for p in pred.iter_mut() {
+                        *p = sigmoid(*p);
+                    }
+
+                    for j in 0..OCTO_DIM {
+                        let d_loss_d_pred =
+                            2.0 * (pred[j] - target[j]) / (batch_len * OCTO_DIM as f32);
+                        let d_pred_d_pre = pred[j] * (1.0 - pred[j]);
+                        let d_loss_d_pre = d_loss_d_pred * d_pred_d_pre;
+
+                        grad_bias[j] += d_loss_d_pre;
+                        for (i, &e) in emb.iter().enumerate() {
+                            grad_weights[i][j] += d_loss_d_pre * e;
+                        }
+                    }
+                }
+
+                step += 1;
+                let bias_correction1 = 1.0 - config.beta1.powi(step);
+                let bias_correction2 = 1.0 - config.beta2.powi(step);
+
+                for i in 0..EMBED_DIM {
+                    for j in 0..OCTO_DIM {
+                        let g = grad_weights[i][j];
+                        m_weights[i][j] = config.beta1 * m_weights[i][j] + (1.0 - config.beta1) * g;
+                        v_weights[i][j] =
+                            config.beta2 * v_weights[i][j] + (1.0 - config.beta2) * g * g;
+                        let m_hat = m_weights[i][j] / bias_correction1;
+                        let v_hat = v_weights[i][j] / bias_correction2;
+                        self.weights[i][j] -=
+                            config.learning_rate * m_hat / (v_hat.sqrt() + config.epsilon);
+                    }
+                }
+                for j in 0..OCTO_DIM {
+                    let g = grad_bias[j];
+                    m_bias[j] = config.beta1 * m_bias[j] + (1.0 - config.beta1) * g;
+                    v_bias[j] = config.beta2 * v_bias[j] + (1.0 - config.beta2) * g * g;
+                    let m_hat = m_bias[j] / bias_correction1;
+                    let v_hat = v_bias[j] / bias_correction2;
+                    self.bias[j] -= config.learning_rate * m_hat / (v_hat.sqrt() + config.epsilon);
+                }
+            }
+
+            final_train_loss = self.compute_loss(&train_embeddings, &train_targets);
+
+            if val_indices.is_empty() {
+                best_epoch = epoch;
+                if config.verbose {
+                    println!(
+                        "Epoch {}/{}: train loss = {:.6}",
+                        epoch + 1,
+                        config.epochs,
+                        final_train_loss
+                    );
+                }
+                continue;
+            }
+
+            let val_loss = self.compute_loss(&val_embeddings, &val_targets);
+            if config.verbose {
+                println!(
+                    "Epoch {}/{}: train loss = {:.6}, val loss = {:.6}",
+                    epoch + 1,
+                    config.epochs,
+                    final_train_loss,
+                    val_loss
+                );
+            }
+
+            if val_loss < best_val_loss {
+                best_val_loss = val_loss;
+                best_epoch = epoch;
+                best_weights = self.weights;
+                best_bias = self.bias;
+                epochs_without_improvement = 0;
+            } else {
+                epochs_without_improvement += 1;
+                if epochs_without_improvement >= config.patience {
+                    break;
+                }
+            }
+        }
+
+        let final_val_loss = if val_indices.is_empty() {
+            None
+        } else {
+            self.weights = best_weights;
+            self.bias = best_bias;
+            Some(best_val_loss)
+        };
+
+        TrainingReport {
+            final_train_loss,
+            final_val_loss,
+            best_epoch,
+        }
+    }
+
     /// Serialize the mapper to bytes (~12KB).
     pub fn to_bytes(&self) -> Vec<u8> {
         let mut buf = Vec::with_capacity(4 + EMBED_DIM * OCTO_DIM * 4 + OCTO_DIM * 4);
@@ -187,19 +568,18 @@ impl ContrastiveMapper {
     }
 
     /// Deserialize from bytes.
-    pub fn from_bytes(data: &[u8]) -> anyhow::Result<Self> {
+    pub fn from_bytes(data: &[u8]) -> Result<Self, MapperError> {
         const EXPECTED_SIZE: usize = 4 + EMBED_DIM * OCTO_DIM * 4 + OCTO_DIM * 4;
 
         if data.len() != EXPECTED_SIZE {
-            anyhow::bail!(
-                "Invalid mapper size: expected {} bytes, got {}",
-                EXPECTED_SIZE,
-                data.len()
-            );
+            return Err(MapperError::BadLength {
+                expected: EXPECTED_SIZE,
+                got: data.len(),
+            });
         }
 
         if &data[0..4] != b"CMAP" {
-            anyhow::bail!("Invalid mapper magic bytes");
+            return Err(MapperError::BadMagic);
         }
 
         let mut mapper = Self::new_zeros();
@@ -223,6 +603,7 @@ impl ContrastiveMapper {
     }
 
     /// Save to a file.
+    #[cfg(feature = "std")]
     pub fn save(&self, path: &std::path::Path) -> anyhow::Result<()> {
         let bytes = self.to_bytes();
         let mut file = std::fs::File::create(path)?;
@@ -231,11 +612,44 @@ impl ContrastiveMapper {
     }
 
     /// Load from a file.
+    #[cfg(feature = "std")]
     pub fn load(path: &std::path::Path) -> anyhow::Result<Self> {
         let mut file = std::fs::File::open(path)?;
         let mut bytes = Vec::new();
         file.read_to_end(&mut bytes)?;
-        Self::from_bytes(&bytes)
+        Ok(Self::from_bytes(&bytes)?)
+    }
+}
+
+/// Why `ContrastiveMapper::from_bytes` rejected a buffer - kept as a plain
+/// `core`-only enum (no `anyhow`) so `no_std` callers can match on the
+/// failure mode without linking in an allocator-independent error crate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapperError {
+    /// The first 4 bytes weren't the `"CMAP"` magic.
+    BadMagic,
+    /// The buffer wasn't the fixed `4 + EMBED_DIM * OCTO_DIM * 4 + OCTO_DIM * 4` size.
+    BadLength { expected: usize, got: usize },
+}
+
+impl core::fmt::Display for MapperError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MapperError::BadMagic => write!(f, "invalid mapper magic bytes"),
+            MapperError::BadLength { expected, got } => write!(
+                f,
+                "invalid mapper size: expected {expected} bytes, got {got}"
+            ),
+        }
+    }
+}
+
+impl core::error::Error for MapperError {}
+
+#[cfg(feature = "std")]
+impl From<MapperError> for anyhow::Error {
+    fn from(err: MapperError) -> Self {
+        anyhow::anyhow!(err.to_string())
     }
 }
 
@@ -245,6 +659,44 @@ fn sigmoid(x: f32) -> f32 {
     1.0 / (1.0 + (-x).exp())
 }
 
+/// Dot product of two octonion coefficient vectors.
+#[inline]
+fn dot(a: &[f32; OCTO_DIM], b: &[f32; OCTO_DIM]) -> f32 {
+    a.iter().zip(b.iter()).map(|(x, y)| x * y).sum()
+}
+
+/// Euclidean distance between two target octonion vectors.
+#[inline]
+fn euclidean_dist(a: &[f32; OCTO_DIM], b: &[f32; OCTO_DIM]) -> f32 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f32>()
+        .sqrt()
+}
+
+/// Fisher-Yates shuffle of `0..n` driven by the same small LCG
+/// `new_random` uses, so mini-batch order varies across epochs without
+/// pulling in the `rand` crate.
+fn shuffled_indices(n: usize, state: &mut u64) -> Vec<usize> {
+    let mut indices: Vec<usize> = (0..n).collect();
+    for i in (1..n).rev() {
+        *state = state.wrapping_mul(6364136223846793005).wrapping_add(1);
+        let j = ((*state >> 33) as usize) % (i + 1);
+        indices.swap(i, j);
+    }
+    indices
+}
+
+/// Indices of every sample other than `anchor` whose target lies within
+/// `epsilon` of `targets[anchor]` - the positives `train_contrastive` and
+/// `compute_contrastive_loss` pull probability mass toward.
+fn find_positives(anchor: usize, targets: &[[f32; OCTO_DIM]], epsilon: f32) -> Vec<usize> {
+    (0..targets.len())
+        .filter(|&j| j != anchor && euclidean_dist(&targets[anchor], &targets[j]) < epsilon)
+        .collect()
+}
+
 /// Training data pair: (description text, target 8D coefficients).
 #[derive(Clone)]
 pub struct TrainingSample {
@@ -271,6 +723,51 @@ pub fn prepare_training_data(
         .collect()
 }
 
+/// Configuration for `train_adam`'s mini-batch Adam optimizer and
+/// validation-based early stopping. The `beta1`/`beta2`/`epsilon` defaults
+/// follow the values the Adam paper recommends; the default learning rate
+/// is `0.01`, ten times the paper's `0.001`.
+#[derive(Debug, Clone)]
+pub struct AdamTrainingConfig {
+    pub learning_rate: f32,
+    pub epochs: usize,
+    pub batch_size: usize,
+    pub beta1: f32,
+    pub beta2: f32,
+    pub epsilon: f32,
+    /// Fraction of samples held out for validation/early-stopping; `None`
+    /// trains on the full set and never stops early.
+    pub validation_fraction: Option<f32>,
+    /// Epochs without validation-loss improvement before stopping early.
+    pub patience: usize,
+    pub verbose: bool,
+}
+
+impl Default for AdamTrainingConfig {
+    fn default() -> Self {
+        Self {
+            learning_rate: 0.01,
+            epochs: 1000,
+            batch_size: 32,
+            beta1: 0.9,
+            beta2: 0.999,
+            epsilon: 1e-8,
+            validation_fraction: Some(0.2),
+            patience: 20,
+            verbose: false,
+        }
+    }
+}
+
+/// Result of `train_adam`: the losses at the restored checkpoint, and
+/// which epoch it came from.
+#[derive(Debug, Clone)]
+pub struct TrainingReport {
+    pub final_train_loss: f32,
+    pub final_val_loss: Option<f32>,
+    pub best_epoch: usize,
+}
+
 /// The bundled contrastive mapper, loaded at compile time.
 #[cfg(feature = "bundled-mapper")]
 pub static BUNDLED_MAPPER: std::sync::LazyLock<Option<ContrastiveMapper>> =
@@ -317,6 +814,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_from_bytes_rejects_bad_magic_and_length() {
+        let mapper = ContrastiveMapper::new_random();
+        let mut bytes = mapper.to_bytes();
+        bytes[0] = b'X';
+        assert_eq!(
+            ContrastiveMapper::from_bytes(&bytes),
+            Err(MapperError::BadMagic)
+        );
+
+        let truncated = &mapper.to_bytes()[..10];
+        assert_eq!(
+            ContrastiveMapper::from_bytes(truncated),
+            Err(MapperError::BadLength {
+                expected: 4 + EMBED_DIM * OCTO_DIM * 4 + OCTO_DIM * 4,
+                got: 10,
+            })
+        );
+    }
+
     #[test]
     fn test_simple_training() {
         let mut mapper = ContrastiveMapper::new_random();
@@ -347,4 +864,75 @@ mod tests {
         );
         assert!(final_loss < initial_loss, "Training should reduce loss");
     }
+
+    #[test]
+    fn test_contrastive_training() {
+        let mut mapper = ContrastiveMapper::new_random();
+
+        // 10 samples cycling through 8 one-hot targets, so samples 0/8 and
+        // 1/9 share a target and form positive pairs for each other.
+        let embeddings: Vec<Vec<f32>> = (0..10)
+            .map(|i| {
+                let mut emb = vec![0.0; EMBED_DIM];
+                emb[i % EMBED_DIM] = 1.0;
+                emb
+            })
+            .collect();
+
+        let targets: Vec<[f32; OCTO_DIM]> = (0..10)
+            .map(|i| {
+                let mut t = [0.0; OCTO_DIM];
+                t[i % OCTO_DIM] = 1.0;
+                t
+            })
+            .collect();
+
+        let initial_loss = mapper.compute_contrastive_loss(&embeddings, &targets, 0.1, 0.5);
+        let final_loss = mapper.train_contrastive(&embeddings, &targets, 0.1, 100, 0.1, 0.5, false);
+
+        println!(
+            "Initial InfoNCE loss: {:.6}, Final InfoNCE loss: {:.6}",
+            initial_loss, final_loss
+        );
+        assert!(
+            final_loss < initial_loss,
+            "Contrastive training should reduce loss"
+        );
+    }
+
+    #[test]
+    fn test_adam_training_reduces_loss_and_reports_checkpoint() {
+        let mut mapper = ContrastiveMapper::new_random();
+
+        let embeddings: Vec<Vec<f32>> = (0..20)
+            .map(|i| {
+                let mut emb = vec![0.0; EMBED_DIM];
+                emb[i % EMBED_DIM] = 1.0;
+                emb
+            })
+            .collect();
+
+        let targets: Vec<[f32; OCTO_DIM]> = (0..20)
+            .map(|i| {
+                let mut t = [0.0; OCTO_DIM];
+                t[i % OCTO_DIM] = 1.0;
+                t
+            })
+            .collect();
+
+        let initial_loss = mapper.compute_loss(&embeddings, &targets);
+        let config = AdamTrainingConfig {
+            epochs: 50,
+            batch_size: 4,
+            ..Default::default()
+        };
+        let report = mapper.train_adam(&embeddings, &targets, &config);
+
+        println!(
+            "Initial loss: {:.6}, Adam final train loss: {:.6}",
+            initial_loss, report.final_train_loss
+        );
+        assert!(report.final_train_loss < initial_loss);
+        assert!(report.final_val_loss.is_some());
+    }
 }
@@ -2,10 +2,13 @@
 //!
 //! Stored at ~/.cache/cratefind/index.sqlite
 
-use rusqlite::{Connection, params};
-use std::path::PathBuf;
+use rusqlite::{params, Connection, OpenFlags, OptionalExtension};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
 
-use crate::embed::Embedding;
+use crate::embed::{Embedder, Embedding};
 
 /// A symbol extracted from a crate
 #[derive(Debug, Clone)]
@@ -13,6 +16,14 @@ pub struct Symbol {
     pub path: String, // e.g. "serde::Serialize"
     pub kind: String, // e.g. "trait", "struct", "fn"
     pub signature: Option<String>,
+    /// `since`/`note` from `#[deprecated(...)]`, if the symbol is deprecated.
+    pub deprecated: Option<String>,
+    /// The `feature` name from `#[unstable(feature = "...")]`, if gated.
+    pub unstable_feature: Option<String>,
+    /// The symbol's `#[cfg(...)]` predicate, rendered back to source-like
+    /// text (e.g. `feature = "rt"` or `all(unix, feature = "mio")`). `None`
+    /// if the symbol isn't conditionally compiled.
+    pub cfg: Option<String>,
 }
 
 /// A search result
@@ -24,6 +35,9 @@ pub struct SearchResult {
     pub path: String,
     pub kind: String,
     pub signature: Option<String>,
+    pub deprecated: Option<String>,
+    pub unstable_feature: Option<String>,
+    pub cfg: Option<String>,
     pub score: f32,
 }
 
@@ -35,6 +49,65 @@ pub struct Stats {
     pub db_size_bytes: u64,
 }
 
+/// Soft budget for `Database::gc`. Crate rows are evicted oldest-accessed
+/// first until both of whichever fields are `Some` are satisfied; a `None`
+/// field means that dimension isn't a limit.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SizeTargets {
+    pub max_bytes: Option<u64>,
+    pub max_symbols: Option<u64>,
+}
+
+/// What a `gc` pass actually did.
+#[derive(Debug, Default)]
+pub struct GcStats {
+    pub crates_removed: usize,
+    pub symbols_removed: usize,
+    pub bytes_reclaimed: u64,
+}
+
+/// What an `import_snapshot` merge actually did.
+#[derive(Debug, Default)]
+pub struct ImportStats {
+    pub crates_imported: usize,
+    pub crates_skipped: usize,
+    pub symbols_imported: usize,
+}
+
+/// Errors from the batched, cache-aware indexing path. Kept separate from
+/// the plain `rusqlite::Error` the simpler query methods return, since this
+/// path also has to surface failures from the embedder itself.
+#[derive(Debug, Error)]
+pub enum DbError {
+    #[error("SQLite error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+    #[error("embedding failed: {0}")]
+    Embed(#[from] anyhow::Error),
+    #[error("crate {0}@{1} was not found after being inserted")]
+    MissingCrateId(String, String),
+    #[error("imported embedding has dimension {1}, expected {0}")]
+    DimensionMismatch(usize, usize),
+    #[error("database is busy/contended, retry the operation")]
+    Busy,
+}
+
+/// Distinguish "another connection holds the lock, try again" from a
+/// genuine SQLite error, so callers of `index_crate` know whether retrying
+/// makes sense.
+fn classify_sqlite_error(err: rusqlite::Error) -> DbError {
+    match &err {
+        rusqlite::Error::SqliteFailure(e, _)
+            if matches!(
+                e.code,
+                rusqlite::ErrorCode::DatabaseBusy | rusqlite::ErrorCode::DatabaseLocked
+            ) =>
+        {
+            DbError::Busy
+        }
+        _ => DbError::Sqlite(err),
+    }
+}
+
 pub struct Database {
     conn: Connection,
 }
@@ -59,11 +132,19 @@ impl Database {
 
         let conn = Connection::open(&path)?;
 
-        // Enable WAL mode for concurrent access
+        // Enable WAL mode for concurrent access. `foreign_keys` has to be
+        // turned on explicitly every connection - SQLite ignores `ON DELETE
+        // CASCADE` without it, which would otherwise make `gc`'s crate
+        // deletes leave orphaned symbol rows behind. `busy_timeout` makes a
+        // writer that loses a lock race (e.g. `cargo` fanning out several
+        // `fastdeps` processes across a workspace) wait and retry instead of
+        // failing the call with `SQLITE_BUSY` outright.
         conn.execute_batch(
             "PRAGMA journal_mode = WAL;
              PRAGMA synchronous = NORMAL;
-             PRAGMA cache_size = -64000;",
+             PRAGMA cache_size = -64000;
+             PRAGMA foreign_keys = ON;
+             PRAGMA busy_timeout = 5000;",
         )?;
 
         let db = Self { conn };
@@ -78,6 +159,7 @@ impl Database {
                 name TEXT NOT NULL,
                 version TEXT NOT NULL,
                 indexed_at INTEGER NOT NULL,
+                accessed_at INTEGER NOT NULL DEFAULT 0,
                 UNIQUE(name, version)
             );
 
@@ -87,12 +169,62 @@ impl Database {
                 path TEXT NOT NULL,
                 kind TEXT NOT NULL,
                 signature TEXT,
-                embedding BLOB NOT NULL
+                deprecated TEXT,
+                unstable_feature TEXT,
+                cfg TEXT,
+                embedding BLOB NOT NULL,
+                sketch BLOB
+            );
+
+            CREATE TABLE IF NOT EXISTS embedding_cache (
+                content_hash BLOB PRIMARY KEY,
+                embedding BLOB NOT NULL,
+                last_used INTEGER NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS pinned_crates (
+                crate_id INTEGER PRIMARY KEY REFERENCES crates(id) ON DELETE CASCADE
+            );
+
+            CREATE TABLE IF NOT EXISTS embedding_centroid (
+                id INTEGER PRIMARY KEY CHECK (id = 0),
+                sum BLOB NOT NULL,
+                count INTEGER NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS indexing_claims (
+                name TEXT NOT NULL,
+                version TEXT NOT NULL,
+                claimed_at INTEGER NOT NULL,
+                PRIMARY KEY (name, version)
             );
 
             CREATE INDEX IF NOT EXISTS idx_crates_name_version ON crates(name, version);
             CREATE INDEX IF NOT EXISTS idx_symbols_crate ON symbols(crate_id);",
         )?;
+
+        self.migrate_symbols_sketch_column()?;
+        Ok(())
+    }
+
+    /// Add `symbols.sketch` for databases created before that column
+    /// existed. Mirrors `SqliteBackend::migrate_to_v4`/`migrate_to_v5` in
+    /// `storage/sqlite.rs`: `CREATE TABLE IF NOT EXISTS` above only creates
+    /// `symbols` with the column for a brand new database, it's a no-op
+    /// against one that predates `sketch`, so every subsequent `INSERT INTO
+    /// symbols (..., sketch) VALUES (...)` would fail with "no such column:
+    /// sketch" until this runs.
+    fn migrate_symbols_sketch_column(&self) -> Result<(), rusqlite::Error> {
+        let has_column = self
+            .conn
+            .prepare("SELECT sketch FROM symbols LIMIT 1")
+            .is_ok();
+
+        if !has_column {
+            self.conn
+                .execute("ALTER TABLE symbols ADD COLUMN sketch BLOB", [])?;
+        }
+
         Ok(())
     }
 
@@ -121,25 +253,50 @@ impl Database {
         }
     }
 
-    /// Index a crate's symbols with their embeddings
+    /// Index a crate's symbols with their embeddings. Runs as a single
+    /// transaction, so a crash or a lock race with a concurrent process
+    /// indexing elsewhere in the database leaves either the old symbols or
+    /// the new ones in place, never an interleaved mix.
     pub fn index_crate(
         &self,
         name: &str,
         version: &str,
         symbols: &[Symbol],
         embeddings: &[Embedding],
-    ) -> Result<(), rusqlite::Error> {
+    ) -> Result<(), DbError> {
         assert_eq!(symbols.len(), embeddings.len());
 
-        let now = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs() as i64;
+        self.conn
+            .execute("BEGIN IMMEDIATE", [])
+            .map_err(classify_sqlite_error)?;
+
+        match self.index_crate_inner(name, version, symbols, embeddings) {
+            Ok(()) => {
+                self.conn
+                    .execute("COMMIT", [])
+                    .map_err(classify_sqlite_error)?;
+                Ok(())
+            }
+            Err(e) => {
+                self.conn.execute("ROLLBACK", []).ok();
+                Err(classify_sqlite_error(e))
+            }
+        }
+    }
+
+    fn index_crate_inner(
+        &self,
+        name: &str,
+        version: &str,
+        symbols: &[Symbol],
+        embeddings: &[Embedding],
+    ) -> Result<(), rusqlite::Error> {
+        let now = now_secs();
 
         // Insert crate
         self.conn.execute(
-            "INSERT OR REPLACE INTO crates (name, version, indexed_at) VALUES (?, ?, ?)",
-            params![name, version, now],
+            "INSERT OR REPLACE INTO crates (name, version, indexed_at, accessed_at) VALUES (?, ?, ?, ?)",
+            params![name, version, now, now],
         )?;
 
         let crate_id = self.conn.last_insert_rowid();
@@ -148,26 +305,233 @@ impl Database {
         self.conn
             .execute("DELETE FROM symbols WHERE crate_id = ?", params![crate_id])?;
 
+        // Sketches are computed against the centroid as it stands before
+        // `embeddings` are folded in, so they stay comparable to every
+        // sketch already on disk.
+        let sketches = self.sketch_all(embeddings)?;
+
         // Insert symbols with embeddings
         let mut stmt = self.conn.prepare(
-            "INSERT INTO symbols (crate_id, path, kind, signature, embedding) VALUES (?, ?, ?, ?, ?)",
+            "INSERT INTO symbols (crate_id, path, kind, signature, deprecated, unstable_feature, cfg, embedding, sketch) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
         )?;
 
-        for (symbol, embedding) in symbols.iter().zip(embeddings.iter()) {
+        for ((symbol, embedding), sketch) in
+            symbols.iter().zip(embeddings.iter()).zip(sketches.iter())
+        {
             let embedding_bytes = embedding_to_bytes(embedding);
             stmt.execute(params![
                 crate_id,
                 symbol.path,
                 symbol.kind,
                 symbol.signature,
-                embedding_bytes
+                symbol.deprecated,
+                symbol.unstable_feature,
+                symbol.cfg,
+                embedding_bytes,
+                sketch,
             ])?;
         }
 
+        self.accumulate_centroid(embeddings)?;
+
+        Ok(())
+    }
+
+    /// Attempt to claim `(name, version)` for indexing. Returns `true` if
+    /// this call won the claim and should go on to do the (expensive)
+    /// embedding and indexing work; `false` if another process already
+    /// holds it, in which case the caller should skip the work rather than
+    /// duplicate it. Unlike `index_crate`'s transaction, a claim is
+    /// committed immediately so it's visible to other connections before
+    /// the caller's embedding work even starts.
+    ///
+    /// A claim older than `CLAIM_STALE_SECS` is treated as abandoned (its
+    /// owner crashed, panicked, or was killed before reaching
+    /// `release_claim`) and is reclaimed rather than left to block this
+    /// crate version from ever being indexed again.
+    pub fn try_claim(&self, name: &str, version: &str) -> Result<bool, rusqlite::Error> {
+        let now = now_secs();
+
+        self.conn.execute(
+            "DELETE FROM indexing_claims WHERE name = ? AND version = ? AND claimed_at < ?",
+            params![name, version, now - CLAIM_STALE_SECS],
+        )?;
+
+        let claimed = self.conn.execute(
+            "INSERT OR IGNORE INTO indexing_claims (name, version, claimed_at) VALUES (?, ?, ?)",
+            params![name, version, now],
+        )?;
+        Ok(claimed > 0)
+    }
+
+    /// Release a claim taken by `try_claim`, whether or not the indexing
+    /// it guarded succeeded. A no-op if nothing is claimed.
+    pub fn release_claim(&self, name: &str, version: &str) -> Result<(), rusqlite::Error> {
+        self.conn.execute(
+            "DELETE FROM indexing_claims WHERE name = ? AND version = ?",
+            params![name, version],
+        )?;
+        Ok(())
+    }
+
+    /// Insert/refresh a crate's row and clear any symbols left from a
+    /// previous index of it, leaving the crate ready to receive fresh
+    /// symbol rows from `commit_symbol_batch`. Split out from `index_crate`
+    /// so `IndexQueue` can re-index a crate across several batches without
+    /// each one re-deleting what the previous batch just committed.
+    fn start_crate_reindex(&self, name: &str, version: &str) -> Result<(), DbError> {
+        let now = now_secs();
+        self.conn.execute(
+            "INSERT OR REPLACE INTO crates (name, version, indexed_at, accessed_at) VALUES (?, ?, ?, ?)",
+            params![name, version, now, now],
+        )?;
+        let crate_id = self.conn.last_insert_rowid();
+        self.conn
+            .execute("DELETE FROM symbols WHERE crate_id = ?", params![crate_id])?;
+        Ok(())
+    }
+
+    /// Look up which of `hashes` already have a cached embedding, touching
+    /// `last_used` on every hit so a future eviction pass has an LRU signal
+    /// to work from. Returns one slot per input hash, in order.
+    fn lookup_cached_embeddings(
+        &self,
+        hashes: &[[u8; 32]],
+    ) -> Result<Vec<Option<Embedding>>, rusqlite::Error> {
+        let now = now_secs();
+        let mut select_stmt = self
+            .conn
+            .prepare_cached("SELECT embedding FROM embedding_cache WHERE content_hash = ?")?;
+        let mut touch_stmt = self
+            .conn
+            .prepare_cached("UPDATE embedding_cache SET last_used = ? WHERE content_hash = ?")?;
+
+        let mut results = Vec::with_capacity(hashes.len());
+        for hash in hashes {
+            let found: Option<Vec<u8>> = select_stmt
+                .query_row(params![hash.as_slice()], |row| row.get(0))
+                .optional()?;
+            if found.is_some() {
+                touch_stmt.execute(params![now, hash.as_slice()])?;
+            }
+            results.push(found.map(|bytes| bytes_to_embedding(&bytes)));
+        }
+        Ok(results)
+    }
+
+    /// Embed whichever symbols in `batch` missed the cache, then write that
+    /// batch's symbol rows and cache entries in one transaction - so a crash
+    /// partway through a large crate leaves only whole committed batches
+    /// behind, never a half-written one.
+    fn commit_symbol_batch(
+        &self,
+        embedder: &mut Embedder,
+        crate_name: &str,
+        crate_version: &str,
+        batch: &[Symbol],
+    ) -> Result<(), DbError> {
+        if batch.is_empty() {
+            return Ok(());
+        }
+
+        let crate_id = self
+            .get_crate_id(crate_name, crate_version)?
+            .ok_or_else(|| {
+                DbError::MissingCrateId(crate_name.to_string(), crate_version.to_string())
+            })?;
+
+        let hashes: Vec<[u8; 32]> = batch.iter().map(symbol_content_hash).collect();
+        let mut embeddings = self.lookup_cached_embeddings(&hashes)?;
+
+        let miss_indices: Vec<usize> = embeddings
+            .iter()
+            .enumerate()
+            .filter(|(_, e)| e.is_none())
+            .map(|(i, _)| i)
+            .collect();
+
+        if !miss_indices.is_empty() {
+            let texts: Vec<String> = miss_indices
+                .iter()
+                .map(|&i| symbol_embed_text(&batch[i]))
+                .collect();
+            let fresh = embedder.embed(&texts)?;
+            for (slot, embedding) in miss_indices.into_iter().zip(fresh) {
+                embeddings[slot] = Some(embedding);
+            }
+        }
+
+        let resolved: Vec<Embedding> = embeddings
+            .iter()
+            .map(|e| {
+                e.as_ref()
+                    .expect("every symbol was either cached or just embedded above")
+                    .clone()
+            })
+            .collect();
+        let sketches = self.sketch_all(&resolved)?;
+
+        let now = now_secs();
+        self.conn.execute("BEGIN IMMEDIATE", [])?;
+        let write_result = self
+            .write_symbol_batch(crate_id, batch, &hashes, &embeddings, &sketches, now)
+            .and_then(|()| self.accumulate_centroid(&resolved).map_err(DbError::from));
+        match write_result {
+            Ok(()) => {
+                self.conn.execute("COMMIT", [])?;
+                Ok(())
+            }
+            Err(e) => {
+                self.conn.execute("ROLLBACK", []).ok();
+                Err(e)
+            }
+        }
+    }
+
+    fn write_symbol_batch(
+        &self,
+        crate_id: i64,
+        batch: &[Symbol],
+        hashes: &[[u8; 32]],
+        embeddings: &[Option<Embedding>],
+        sketches: &[Vec<u8>],
+        now: i64,
+    ) -> Result<(), DbError> {
+        let mut symbol_stmt = self.conn.prepare_cached(
+            "INSERT INTO symbols (crate_id, path, kind, signature, deprecated, unstable_feature, cfg, embedding, sketch) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        )?;
+        let mut cache_stmt = self.conn.prepare_cached(
+            "INSERT OR REPLACE INTO embedding_cache (content_hash, embedding, last_used) VALUES (?, ?, ?)",
+        )?;
+
+        for (((symbol, hash), embedding), sketch) in
+            batch.iter().zip(hashes).zip(embeddings).zip(sketches)
+        {
+            let embedding = embedding
+                .as_ref()
+                .expect("every symbol was either cached or just embedded above");
+            let embedding_bytes = embedding_to_bytes(embedding);
+            symbol_stmt.execute(params![
+                crate_id,
+                symbol.path,
+                symbol.kind,
+                symbol.signature,
+                symbol.deprecated,
+                symbol.unstable_feature,
+                symbol.cfg,
+                &embedding_bytes,
+                sketch,
+            ])?;
+            cache_stmt.execute(params![hash.as_slice(), &embedding_bytes, now])?;
+        }
         Ok(())
     }
 
-    /// Search for symbols similar to query embedding, scoped to given crate IDs
+    /// Search for symbols similar to query embedding, scoped to given crate
+    /// IDs. Runs in two stages: `select_candidates` narrows the scoped
+    /// symbols down using cheap Hamming distance over binary sketches, then
+    /// only that smaller set gets its full embedding decoded and reranked
+    /// with exact cosine similarity.
     pub fn search(
         &self,
         query: &Embedding,
@@ -178,21 +542,87 @@ impl Database {
             return Ok(vec![]);
         }
 
-        // Build IN clause
-        let placeholders: Vec<&str> = crate_ids.iter().map(|_| "?").collect();
-        let in_clause = placeholders.join(",");
+        let candidate_ids = self.select_candidates(query, crate_ids, limit)?;
+        if candidate_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        self.rerank_candidates(query, &candidate_ids, limit)
+    }
+
+    /// Stage 1: pick which symbol rows are worth decoding and reranking
+    /// exactly. Rows with a sketch are ranked by Hamming distance to the
+    /// (identically quantized) query and only the closest `limit *
+    /// OVERFETCH` survive; rows indexed before sketches existed (`sketch
+    /// IS NULL`) have nothing cheap to rank them by, so they always go
+    /// through to the exact stage.
+    fn select_candidates(
+        &self,
+        query: &Embedding,
+        crate_ids: &[i64],
+        limit: usize,
+    ) -> Result<Vec<i64>, rusqlite::Error> {
+        let in_clause = crate_ids.iter().map(|_| "?").collect::<Vec<_>>().join(",");
+        let sql = format!("SELECT id, sketch FROM symbols WHERE crate_id IN ({in_clause})");
+        let mut stmt = self.conn.prepare(&sql)?;
+        let params: Vec<&dyn rusqlite::ToSql> = crate_ids
+            .iter()
+            .map(|id| id as &dyn rusqlite::ToSql)
+            .collect();
+
+        let query_sketch = self.quantize(query)?;
+
+        let mut sketched: Vec<(i64, u32)> = Vec::new();
+        let mut unsketched: Vec<i64> = Vec::new();
+
+        let rows = stmt.query_map(params.as_slice(), |row| {
+            let id: i64 = row.get(0)?;
+            let sketch: Option<Vec<u8>> = row.get(1)?;
+            Ok((id, sketch))
+        })?;
+
+        for row in rows {
+            let (id, sketch) = row?;
+            match sketch {
+                Some(bytes) => sketched.push((id, hamming_distance(&bytes, &query_sketch))),
+                None => unsketched.push(id),
+            }
+        }
+
+        sketched.sort_by_key(|(_, distance)| *distance);
+        let overfetch = limit.saturating_mul(OVERFETCH).min(sketched.len());
 
+        let mut candidates: Vec<i64> = sketched
+            .into_iter()
+            .take(overfetch)
+            .map(|(id, _)| id)
+            .collect();
+        candidates.append(&mut unsketched);
+        Ok(candidates)
+    }
+
+    /// Stage 2: decode the full embeddings for `candidate_ids` and rerank
+    /// them with exact cosine similarity.
+    fn rerank_candidates(
+        &self,
+        query: &Embedding,
+        candidate_ids: &[i64],
+        limit: usize,
+    ) -> Result<Vec<SearchResult>, rusqlite::Error> {
+        let in_clause = candidate_ids
+            .iter()
+            .map(|_| "?")
+            .collect::<Vec<_>>()
+            .join(",");
         let sql = format!(
-            "SELECT s.path, s.kind, s.signature, s.embedding, c.name, c.version
+            "SELECT s.path, s.kind, s.signature, s.deprecated, s.unstable_feature, s.cfg, s.embedding, c.name, c.version, s.crate_id
              FROM symbols s
              JOIN crates c ON s.crate_id = c.id
-             WHERE s.crate_id IN ({in_clause})"
+             WHERE s.id IN ({in_clause})"
         );
 
         let mut stmt = self.conn.prepare(&sql)?;
-
-        // Bind crate IDs
-        let params: Vec<&dyn rusqlite::ToSql> = crate_ids
+        let params: Vec<&dyn rusqlite::ToSql> = candidate_ids
             .iter()
             .map(|id| id as &dyn rusqlite::ToSql)
             .collect();
@@ -201,30 +631,145 @@ impl Database {
             let path: String = row.get(0)?;
             let kind: String = row.get(1)?;
             let signature: Option<String> = row.get(2)?;
-            let embedding_bytes: Vec<u8> = row.get(3)?;
-            let crate_name: String = row.get(4)?;
-            let crate_version: String = row.get(5)?;
+            let deprecated: Option<String> = row.get(3)?;
+            let unstable_feature: Option<String> = row.get(4)?;
+            let cfg: Option<String> = row.get(5)?;
+            let embedding_bytes: Vec<u8> = row.get(6)?;
+            let crate_name: String = row.get(7)?;
+            let crate_version: String = row.get(8)?;
+            let hit_crate_id: i64 = row.get(9)?;
 
             let embedding = bytes_to_embedding(&embedding_bytes);
             let score = cosine_similarity(query, &embedding);
 
-            Ok(SearchResult {
-                crate_name,
-                crate_version,
-                path,
-                kind,
-                signature,
-                score,
-            })
+            Ok((
+                SearchResult {
+                    crate_name,
+                    crate_version,
+                    path,
+                    kind,
+                    signature,
+                    deprecated,
+                    unstable_feature,
+                    cfg,
+                    score,
+                },
+                hit_crate_id,
+            ))
         })?;
 
-        let mut results: Vec<SearchResult> = rows.filter_map(|r| r.ok()).collect();
+        let mut results: Vec<(SearchResult, i64)> = rows.filter_map(|r| r.ok()).collect();
 
         // Sort by score descending
-        results.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        results.sort_by(|a, b| b.0.score.partial_cmp(&a.0.score).unwrap());
         results.truncate(limit);
 
-        Ok(results)
+        let mut hit_crate_ids: Vec<i64> = results.iter().map(|(_, id)| *id).collect();
+        hit_crate_ids.sort_unstable();
+        hit_crate_ids.dedup();
+        self.touch_accessed(&hit_crate_ids)?;
+
+        Ok(results.into_iter().map(|(result, _)| result).collect())
+    }
+
+    /// Quantize `embedding` against the current centroid, the same way a
+    /// symbol's sketch is quantized at index time.
+    fn quantize(&self, embedding: &Embedding) -> Result<Vec<u8>, rusqlite::Error> {
+        let mean = self.centroid_mean(embedding.len())?;
+        Ok(pack_sketch(embedding, &mean))
+    }
+
+    /// The running centroid, or an all-zero vector of the requested
+    /// dimension if nothing has been indexed yet (which just makes
+    /// quantization fall back to the raw sign of each component).
+    fn centroid_mean(&self, dim: usize) -> Result<Embedding, rusqlite::Error> {
+        let row: Option<(Vec<u8>, i64)> = self
+            .conn
+            .query_row(
+                "SELECT sum, count FROM embedding_centroid WHERE id = 0",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+
+        match row {
+            Some((sum_bytes, count)) if count > 0 => {
+                let sum = bytes_to_embedding(&sum_bytes);
+                Ok(sum.iter().map(|s| s / count as f32).collect())
+            }
+            _ => Ok(vec![0.0; dim]),
+        }
+    }
+
+    /// Fold `embeddings` into the running centroid used to keep sign-bit
+    /// quantization consistent across indexing runs. This sum only ever
+    /// grows - symbols that later get deleted or re-embedded aren't
+    /// subtracted back out - so it's a useful common reference point for
+    /// sketching, not a precise population mean. That's fine: it only
+    /// affects which side of a roughly-central hyperplane a dimension's bit
+    /// falls on.
+    fn accumulate_centroid(&self, embeddings: &[Embedding]) -> Result<(), rusqlite::Error> {
+        let Some(first) = embeddings.first() else {
+            return Ok(());
+        };
+        let dim = first.len();
+
+        let existing: Option<(Vec<u8>, i64)> = self
+            .conn
+            .query_row(
+                "SELECT sum, count FROM embedding_centroid WHERE id = 0",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .optional()?;
+
+        let (mut sum, mut count) = match existing {
+            Some((bytes, count)) => (bytes_to_embedding(&bytes), count),
+            None => (vec![0.0; dim], 0),
+        };
+
+        for embedding in embeddings {
+            for (s, v) in sum.iter_mut().zip(embedding.iter()) {
+                *s += v;
+            }
+            count += 1;
+        }
+
+        self.conn.execute(
+            "INSERT INTO embedding_centroid (id, sum, count) VALUES (0, ?, ?)
+             ON CONFLICT(id) DO UPDATE SET sum = excluded.sum, count = excluded.count",
+            params![embedding_to_bytes(&sum), count],
+        )?;
+        Ok(())
+    }
+
+    /// Sketch every embedding in `embeddings` against the centroid as it
+    /// stands right now, before any of them are folded in by
+    /// `accumulate_centroid` - so sketches written in the same call stay
+    /// comparable to every sketch already on disk.
+    fn sketch_all(&self, embeddings: &[Embedding]) -> Result<Vec<Vec<u8>>, rusqlite::Error> {
+        let Some(first) = embeddings.first() else {
+            return Ok(Vec::new());
+        };
+        let mean = self.centroid_mean(first.len())?;
+        Ok(embeddings.iter().map(|e| pack_sketch(e, &mean)).collect())
+    }
+
+    /// Bump `accessed_at` for crates that placed a symbol in a search
+    /// result, so `gc`'s least-recently-used ordering reflects which
+    /// crates are actually still useful, not just recently (re-)indexed.
+    fn touch_accessed(&self, crate_ids: &[i64]) -> Result<(), rusqlite::Error> {
+        if crate_ids.is_empty() {
+            return Ok(());
+        }
+        let now = now_secs();
+        let mut stmt = self
+            .conn
+            .prepare_cached("UPDATE crates SET accessed_at = ? WHERE id = ?")?;
+        for id in crate_ids {
+            stmt.execute(params![now, id])?;
+        }
+        Ok(())
     }
 
     /// Get database statistics
@@ -233,18 +778,273 @@ impl Database {
             .conn
             .query_row("SELECT COUNT(*) FROM crates", [], |row| row.get(0))?;
 
-        let symbol_count: i64 = self
+        Ok(Stats {
+            crate_count: crate_count as usize,
+            symbol_count: self.symbol_count()? as usize,
+            db_size_bytes: self.file_size_bytes(),
+        })
+    }
+
+    fn symbol_count(&self) -> Result<u64, rusqlite::Error> {
+        let count: i64 = self
             .conn
             .query_row("SELECT COUNT(*) FROM symbols", [], |row| row.get(0))?;
+        Ok(count as u64)
+    }
 
-        let path = Self::path();
-        let db_size_bytes = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+    fn file_size_bytes(&self) -> u64 {
+        std::fs::metadata(Self::path())
+            .map(|m| m.len())
+            .unwrap_or(0)
+    }
 
-        Ok(Stats {
-            crate_count: crate_count as usize,
-            symbol_count: symbol_count as usize,
-            db_size_bytes,
-        })
+    /// Mark a crate so `gc` will never evict it.
+    pub fn pin_crate(&self, name: &str, version: &str) -> Result<(), rusqlite::Error> {
+        let crate_id = self
+            .get_crate_id(name, version)?
+            .ok_or(rusqlite::Error::QueryReturnedNoRows)?;
+        self.conn.execute(
+            "INSERT OR IGNORE INTO pinned_crates (crate_id) VALUES (?)",
+            params![crate_id],
+        )?;
+        Ok(())
+    }
+
+    /// Unmark a crate, making it eligible for `gc` again. A no-op if the
+    /// crate doesn't exist or isn't pinned.
+    pub fn unpin_crate(&self, name: &str, version: &str) -> Result<(), rusqlite::Error> {
+        let Some(crate_id) = self.get_crate_id(name, version)? else {
+            return Ok(());
+        };
+        self.conn.execute(
+            "DELETE FROM pinned_crates WHERE crate_id = ?",
+            params![crate_id],
+        )?;
+        Ok(())
+    }
+
+    pub fn is_pinned(&self, name: &str, version: &str) -> Result<bool, rusqlite::Error> {
+        let Some(crate_id) = self.get_crate_id(name, version)? else {
+            return Ok(false);
+        };
+        let count: i32 = self.conn.query_row(
+            "SELECT COUNT(*) FROM pinned_crates WHERE crate_id = ?",
+            params![crate_id],
+            |row| row.get(0),
+        )?;
+        Ok(count > 0)
+    }
+
+    /// Delete unpinned crates (cascading to their symbols) in
+    /// least-recently-used order, by `accessed_at`, until the store is back
+    /// under both of `targets`' budgets that are set.
+    ///
+    /// SQLite doesn't actually shrink its file on `DELETE` - freed pages
+    /// just go on a free list - so the budget check during eviction uses
+    /// each crate's share of the database's current file size (an even
+    /// split across all indexed symbols) as an estimate of how much
+    /// deleting it would save. Once enough crates have been marked for
+    /// eviction, a single `VACUUM` reclaims the space for real, and
+    /// `bytes_reclaimed` reports that exact, measured before/after
+    /// difference rather than the estimate.
+    pub fn gc(&self, targets: SizeTargets) -> Result<GcStats, rusqlite::Error> {
+        let mut stats = GcStats::default();
+
+        let before_bytes = self.file_size_bytes();
+        let mut symbol_count = self.symbol_count()?;
+        let avg_bytes_per_symbol = if symbol_count > 0 {
+            before_bytes as f64 / symbol_count as f64
+        } else {
+            0.0
+        };
+        let mut estimated_bytes = before_bytes;
+
+        for (crate_id, crate_symbol_count) in self.unpinned_crates_by_lru()? {
+            if !Self::over_budget(targets, estimated_bytes, symbol_count) {
+                break;
+            }
+
+            self.conn
+                .execute("DELETE FROM crates WHERE id = ?", params![crate_id])?;
+
+            stats.crates_removed += 1;
+            stats.symbols_removed += crate_symbol_count as usize;
+            symbol_count = symbol_count.saturating_sub(crate_symbol_count);
+            estimated_bytes = estimated_bytes
+                .saturating_sub((crate_symbol_count as f64 * avg_bytes_per_symbol) as u64);
+        }
+
+        if stats.crates_removed > 0 {
+            self.conn.execute_batch("VACUUM")?;
+            stats.bytes_reclaimed = before_bytes.saturating_sub(self.file_size_bytes());
+        }
+
+        Ok(stats)
+    }
+
+    fn over_budget(targets: SizeTargets, bytes: u64, symbols: u64) -> bool {
+        let bytes_over = targets.max_bytes.is_some_and(|max| bytes > max);
+        let symbols_over = targets.max_symbols.is_some_and(|max| symbols > max);
+        bytes_over || symbols_over
+    }
+
+    /// Unpinned crate ids with their symbol counts, oldest `accessed_at`
+    /// first - the eviction order `gc` walks.
+    fn unpinned_crates_by_lru(&self) -> Result<Vec<(i64, u64)>, rusqlite::Error> {
+        let mut stmt = self.conn.prepare(
+            "SELECT c.id, COUNT(s.id)
+             FROM crates c
+             LEFT JOIN symbols s ON s.crate_id = c.id
+             WHERE c.id NOT IN (SELECT crate_id FROM pinned_crates)
+             GROUP BY c.id
+             ORDER BY c.accessed_at ASC",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            let id: i64 = row.get(0)?;
+            let count: i64 = row.get(1)?;
+            Ok((id, count as u64))
+        })?;
+        rows.collect()
+    }
+
+    /// Copy this database's live contents into a standalone file at `dst`,
+    /// using SQLite's online backup API (the `backup` feature of
+    /// rusqlite). Unlike a plain file copy, this can run against a
+    /// WAL-mode connection without the database being idle.
+    pub fn export_snapshot(&self, dst: &Path) -> Result<(), rusqlite::Error> {
+        let mut dst_conn = Connection::open(dst)?;
+        let backup = rusqlite::backup::Backup::new(&self.conn, &mut dst_conn)?;
+        backup.run_to_completion(BACKUP_STEP_PAGES, BACKUP_STEP_PAUSE, None)?;
+        Ok(())
+    }
+
+    /// Merge a snapshot produced by `export_snapshot` into this database:
+    /// for each `(name, version)` in `src` that isn't already indexed here,
+    /// copies its crate row and all of its symbol rows (embeddings and
+    /// sketches included) and leaves everything else untouched. Already-
+    /// indexed versions are skipped rather than overwritten.
+    ///
+    /// Sketches are copied verbatim from `src`, so they may be quantized
+    /// against a different centroid than this database's own - that only
+    /// affects results that are already a near-tie at the sketch
+    /// prefilter's overfetch boundary, since `search`'s exact rerank stage
+    /// still recovers the true top results regardless.
+    ///
+    /// Stops with `DbError::DimensionMismatch`, before inserting anything
+    /// for that crate, at the first incoming crate whose embedding
+    /// dimensionality doesn't match whatever's already stored here, since
+    /// mixing embedding spaces would make cosine similarity meaningless.
+    /// Crates merged before that one stay imported; the merge is not one
+    /// transaction.
+    pub fn import_snapshot(&self, src: &Path) -> Result<ImportStats, DbError> {
+        let src_conn = Connection::open_with_flags(src, OpenFlags::SQLITE_OPEN_READ_ONLY)?;
+        let mut stats = ImportStats::default();
+        let mut expected_dim = self.any_embedding_dim()?;
+
+        let mut crate_stmt =
+            src_conn.prepare("SELECT id, name, version, indexed_at FROM crates")?;
+        let crates: Vec<(i64, String, String, i64)> = crate_stmt
+            .query_map([], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+            })?
+            .collect::<Result<_, _>>()?;
+
+        let mut symbol_stmt = src_conn.prepare(
+            "SELECT path, kind, signature, deprecated, unstable_feature, cfg, embedding, sketch FROM symbols WHERE crate_id = ?",
+        )?;
+
+        for (src_crate_id, name, version, indexed_at) in crates {
+            if self.is_indexed(&name, &version)? {
+                stats.crates_skipped += 1;
+                continue;
+            }
+
+            let symbols: Vec<(
+                String,
+                String,
+                Option<String>,
+                Option<String>,
+                Option<String>,
+                Option<String>,
+                Vec<u8>,
+                Option<Vec<u8>>,
+            )> = symbol_stmt
+                .query_map(params![src_crate_id], |row| {
+                    Ok((
+                        row.get(0)?,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                        row.get(5)?,
+                        row.get(6)?,
+                        row.get(7)?,
+                    ))
+                })?
+                .collect::<Result<_, _>>()?;
+
+            for (_, _, _, _, _, _, embedding_bytes, _) in &symbols {
+                let dim = embedding_bytes.len() / 4;
+                match expected_dim {
+                    Some(expected) if expected != dim => {
+                        return Err(DbError::DimensionMismatch(expected, dim));
+                    }
+                    None => expected_dim = Some(dim),
+                    _ => {}
+                }
+            }
+
+            let now = now_secs();
+            self.conn.execute(
+                "INSERT INTO crates (name, version, indexed_at, accessed_at) VALUES (?, ?, ?, ?)",
+                params![name, version, indexed_at, now],
+            )?;
+            let crate_id = self.conn.last_insert_rowid();
+
+            let mut insert_stmt = self.conn.prepare_cached(
+                "INSERT INTO symbols (crate_id, path, kind, signature, deprecated, unstable_feature, cfg, embedding, sketch) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            )?;
+            for (
+                path,
+                kind,
+                signature,
+                deprecated,
+                unstable_feature,
+                cfg,
+                embedding_bytes,
+                sketch,
+            ) in &symbols
+            {
+                insert_stmt.execute(params![
+                    crate_id,
+                    path,
+                    kind,
+                    signature,
+                    deprecated,
+                    unstable_feature,
+                    cfg,
+                    embedding_bytes,
+                    sketch
+                ])?;
+            }
+
+            stats.crates_imported += 1;
+            stats.symbols_imported += symbols.len();
+        }
+
+        Ok(stats)
+    }
+
+    /// The dimensionality of whatever embedding happens to already be
+    /// stored, or `None` if this database has no symbols yet.
+    fn any_embedding_dim(&self) -> Result<Option<usize>, rusqlite::Error> {
+        let bytes: Option<Vec<u8>> = self
+            .conn
+            .query_row("SELECT embedding FROM symbols LIMIT 1", [], |row| {
+                row.get(0)
+            })
+            .optional()?;
+        Ok(bytes.map(|b| b.len() / 4))
     }
 }
 
@@ -261,6 +1061,41 @@ fn bytes_to_embedding(bytes: &[u8]) -> Embedding {
         .collect()
 }
 
+/// Pages copied per `export_snapshot` backup step, and the pause between
+/// steps - so a large export yields the source connection's lock
+/// periodically instead of holding it for the whole copy.
+const BACKUP_STEP_PAGES: i32 = 256;
+const BACKUP_STEP_PAUSE: Duration = Duration::from_millis(10);
+
+/// How many candidates `select_candidates` keeps per requested result, so
+/// the exact rerank stage has enough margin that the sketch stage's
+/// approximate ranking rarely bumps a true top result out of contention.
+const OVERFETCH: usize = 8;
+
+/// Pack one sign bit per dimension of `embedding - mean` into `⌈d/8⌉`
+/// bytes, least-significant bit first within each byte. The same `mean`
+/// must be used at index time and query time or the bits aren't
+/// comparable.
+fn pack_sketch(embedding: &[f32], mean: &[f32]) -> Vec<u8> {
+    let mut bytes = vec![0u8; embedding.len().div_ceil(8)];
+    for (i, (&v, &m)) in embedding.iter().zip(mean.iter()).enumerate() {
+        if v - m >= 0.0 {
+            bytes[i / 8] |= 1 << (i % 8);
+        }
+    }
+    bytes
+}
+
+/// Hamming distance between two sketches: popcount of the XOR, which
+/// approximates angular distance between the embeddings they were
+/// quantized from.
+fn hamming_distance(a: &[u8], b: &[u8]) -> u32 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x ^ y).count_ones())
+        .sum()
+}
+
 /// Cosine similarity between two embeddings
 fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
     let dot: f32 = a.iter().zip(b.iter()).map(|(x, y)| x * y).sum();
@@ -271,3 +1106,98 @@ fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
     }
     dot / (norm_a * norm_b)
 }
+
+/// How long an `indexing_claims` row is honored before `try_claim` treats
+/// it as abandoned and reclaims it for a fresh attempt.
+const CLAIM_STALE_SECS: i64 = 600;
+
+fn now_secs() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs() as i64
+}
+
+/// Content address for a symbol's embedding: a symbol's `(path, kind,
+/// signature)` never changes its meaning without also changing one of
+/// these, so hashing just the three is enough to detect "this symbol is
+/// unchanged since it was last embedded." Deliberately excludes anything
+/// else `Symbol` might grow (e.g. doc text) that doesn't affect what gets
+/// embedded.
+fn symbol_content_hash(symbol: &Symbol) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    hasher.update(symbol.path.as_bytes());
+    hasher.update([0u8]);
+    hasher.update(symbol.kind.as_bytes());
+    hasher.update([0u8]);
+    if let Some(sig) = &symbol.signature {
+        hasher.update(sig.as_bytes());
+    }
+    hasher.finalize().into()
+}
+
+/// Text sent to the embedder for a cache-miss symbol.
+fn symbol_embed_text(symbol: &Symbol) -> String {
+    match &symbol.signature {
+        Some(sig) => format!("{} {}", symbol.path, sig),
+        None => symbol.path.clone(),
+    }
+}
+
+/// Accepts symbols to embed for one crate at a time and commits them in
+/// fixed-size batches, so re-indexing a large crate doesn't risk losing all
+/// of its progress to a single crash partway through. Each batch checks
+/// `embedding_cache` first and only calls the embedder for symbols whose
+/// `(path, kind, signature)` hash isn't already cached from a previous run.
+pub struct IndexQueue {
+    batch_size: usize,
+}
+
+impl IndexQueue {
+    /// `batch_size` is how many symbols go to the embedder (for cache
+    /// misses) and into one commit transaction at a time; pick it to match
+    /// the embedder's optimal throughput, not the cache-miss count.
+    pub fn new(batch_size: usize) -> Self {
+        Self { batch_size }
+    }
+
+    /// Re-index one crate: clears whatever was indexed for it before, then
+    /// embeds and commits `symbols` in `batch_size`-sized chunks.
+    ///
+    /// Claims `(name, version)` via `Database::try_claim` before doing any
+    /// embedding, so that if another process (e.g. a sibling `fastdeps`
+    /// spawned by the same `cargo` workspace build) is already indexing
+    /// this exact version, this call skips the work entirely instead of
+    /// embedding the same symbols twice.
+    pub fn index_crate(
+        &self,
+        db: &Database,
+        embedder: &mut Embedder,
+        name: &str,
+        version: &str,
+        symbols: &[Symbol],
+    ) -> Result<(), DbError> {
+        if !db.try_claim(name, version)? {
+            return Ok(());
+        }
+
+        let mut result = db.start_crate_reindex(name, version);
+        if result.is_ok() {
+            for batch in symbols.chunks(self.batch_size.max(1)) {
+                result = db.commit_symbol_batch(embedder, name, version, batch);
+                if result.is_err() {
+                    break;
+                }
+            }
+        }
+
+        // Release unconditionally and report the *indexing* outcome, not the
+        // release's - a `release_claim` failure (e.g. SQLITE_BUSY on the
+        // DELETE) must never mask a successful reindex as failed, since the
+        // symbols are already committed by the time we get here.
+        if let Err(e) = db.release_claim(name, version) {
+            eprintln!("warning: failed to release indexing claim for {name}@{version}: {e}");
+        }
+        result
+    }
+}
@@ -0,0 +1,364 @@
+//! Semver-aware API diff between two `PackageItems` snapshots.
+//!
+//! Matches items by `path` (falling back to `moved_from` for renames),
+//! classifies what changed, and grades the overall delta the way semver
+//! would - this is what makes the "migration tracking" use case named in
+//! `schema`'s module docs real instead of just a shape those fields sit in.
+
+use crate::schema::{Item, PackageItems, Visibility};
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// What happened to a single item between two snapshots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChangeKind {
+    /// Present in `new` with no matching item in `old`.
+    Added,
+    /// Present in `old` with no matching item in `new`.
+    Removed,
+    /// The flat `signature` string differs between the two snapshots.
+    SignatureChanged {
+        old_signature: Option<String>,
+        new_signature: Option<String>,
+    },
+    /// `fields` or `variants` differ between the two snapshots.
+    FieldsOrVariantsChanged,
+    /// `new` is deprecated but `old` was not.
+    Deprecated,
+}
+
+/// A single item's change, keyed by its path in the snapshot it's
+/// reported against (the `new` path for everything but `Removed`, where
+/// it's the `old` path).
+#[derive(Debug, Clone)]
+pub struct ItemChange {
+    pub path: String,
+    pub kind: ChangeKind,
+}
+
+/// Overall severity of a diff, following semver: a removed,
+/// signature-changed, or fields/variants-changed public item is major, a
+/// new public item is minor (if nothing major also changed), and anything
+/// else (a deprecation, doc-only, a non-public item, or no change at all)
+/// is patch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SemverLevel {
+    Patch,
+    Minor,
+    Major,
+}
+
+/// The diff between two `PackageItems` snapshots for one package.
+#[derive(Debug, Clone)]
+pub struct ApiDiff {
+    pub changes: Vec<ItemChange>,
+    pub level: SemverLevel,
+}
+
+/// Diff `old` against `new`, matching items by `path` and, for items with
+/// no same-path match, by `new`'s `moved_from` (so a renamed item is
+/// compared against its old self and reports only what else changed on the
+/// new path - nothing, for a pure rename - rather than a spurious
+/// Removed/Added pair).
+pub fn diff(old: &PackageItems, new: &PackageItems) -> ApiDiff {
+    let old_by_path: HashMap<&str, &Item> =
+        old.items.iter().map(|i| (i.path.as_str(), i)).collect();
+
+    let mut matched_old_paths: HashSet<&str> = HashSet::new();
+    let mut changes = Vec::new();
+
+    for new_item in &new.items {
+        let old_match = old_by_path
+            .get(new_item.path.as_str())
+            .copied()
+            .or_else(|| {
+                new_item
+                    .moved_from
+                    .as_deref()
+                    .and_then(|from| old_by_path.get(from).copied())
+            });
+
+        match old_match {
+            Some(old_item) => {
+                matched_old_paths.insert(old_item.path.as_str());
+                if let Some(change) = classify_change(old_item, new_item) {
+                    changes.push(change);
+                }
+            }
+            None => changes.push(ItemChange {
+                path: new_item.path.clone(),
+                kind: ChangeKind::Added,
+            }),
+        }
+    }
+
+    for old_item in &old.items {
+        if !matched_old_paths.contains(old_item.path.as_str()) {
+            changes.push(ItemChange {
+                path: old_item.path.clone(),
+                kind: ChangeKind::Removed,
+            });
+        }
+    }
+
+    // Every change is reported, but only public items move the level.
+    let public_changes: Vec<ItemChange> = changes
+        .iter()
+        .filter(|c| {
+            let side = if c.kind == ChangeKind::Removed { &old.items } else { &new.items };
+            side.iter()
+                .any(|i| i.path == c.path && i.visibility == Visibility::Public)
+        })
+        .cloned()
+        .collect();
+    let level = grade(&public_changes);
+    ApiDiff { changes, level }
+}
+
+/// Classify a matched old/new pair, or `None` if nothing worth reporting
+/// changed (including doc-only edits, which get no `ItemChange` and so
+/// leave `level` at patch).
+fn classify_change(old_item: &Item, new_item: &Item) -> Option<ItemChange> {
+    if old_item.signature != new_item.signature {
+        return Some(ItemChange {
+            path: new_item.path.clone(),
+            kind: ChangeKind::SignatureChanged {
+                old_signature: old_item.signature.clone(),
+                new_signature: new_item.signature.clone(),
+            },
+        });
+    }
+
+    if old_item.fields != new_item.fields || old_item.variants != new_item.variants {
+        return Some(ItemChange {
+            path: new_item.path.clone(),
+            kind: ChangeKind::FieldsOrVariantsChanged,
+        });
+    }
+
+    if old_item.deprecated.is_none() && new_item.deprecated.is_some() {
+        return Some(ItemChange {
+            path: new_item.path.clone(),
+            kind: ChangeKind::Deprecated,
+        });
+    }
+
+    None
+}
+
+/// Grade a set of changes per semver: any Removed/SignatureChanged/
+/// FieldsOrVariantsChanged on a public item is major, any Added public
+/// item is minor absent a major change, and anything else (a
+/// Deprecated-only diff, or no classified changes at all, which still
+/// covers doc-only edits) is patch.
+fn grade(changes: &[ItemChange]) -> SemverLevel {
+    let mut level = SemverLevel::Patch;
+
+    for change in changes {
+        let candidate = match &change.kind {
+            ChangeKind::Removed | ChangeKind::SignatureChanged { .. } => SemverLevel::Major,
+            ChangeKind::FieldsOrVariantsChanged => SemverLevel::Major,
+            ChangeKind::Added => SemverLevel::Minor,
+            ChangeKind::Deprecated => SemverLevel::Patch,
+        };
+        level = level.max(candidate);
+    }
+
+    level
+}
+
+/// Stamp `since` on every item in `new` that this diff classified as
+/// `Added` and that doesn't already declare one, using `new_version`.
+/// `until`/`moved_from` aren't populated here: a `Removed` item has no
+/// corresponding entry left in `new` to annotate, and `moved_from` is an
+/// input to matching rather than an output of it.
+pub fn annotate_lifecycle(new: &mut PackageItems, diff_result: &ApiDiff, new_version: &str) {
+    let added_paths: HashSet<&str> = diff_result
+        .changes
+        .iter()
+        .filter(|c| matches!(c.kind, ChangeKind::Added))
+        .map(|c| c.path.as_str())
+        .collect();
+
+    for item in &mut new.items {
+        if item.since.is_none() && added_paths.contains(item.path.as_str()) {
+            item.since = Some(new_version.to_string());
+        }
+    }
+}
+
+/// A diff report across every package present in either snapshot map,
+/// keyed the same way `Index::packages` is ("name@version" or just
+/// "name", whatever the caller's keys are).
+#[derive(Debug, Clone)]
+pub struct DiffReport {
+    pub packages: BTreeMap<String, ApiDiff>,
+}
+
+/// Diff every package that appears in `old` and/or `new`, treating a
+/// package missing from one side as an empty snapshot (so a package's
+/// entire API shows up as Added or Removed rather than being skipped).
+pub fn diff_packages(
+    old: &BTreeMap<String, PackageItems>,
+    new: &BTreeMap<String, PackageItems>,
+) -> DiffReport {
+    let empty = PackageItems { items: Vec::new() };
+    let mut names: Vec<&String> = old.keys().chain(new.keys()).collect();
+    names.sort();
+    names.dedup();
+
+    let packages = names
+        .into_iter()
+        .map(|name| {
+            let old_items = old.get(name).unwrap_or(&empty);
+            let new_items = new.get(name).unwrap_or(&empty);
+            (name.clone(), diff(old_items, new_items))
+        })
+        .collect();
+
+    DiffReport { packages }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{Generics, ItemKind, Visibility};
+
+    fn item(path: &str, signature: &str) -> Item {
+        Item {
+            path: path.to_string(),
+            kind: ItemKind::Function,
+            signature: Some(signature.to_string()),
+            signature_detail: None,
+            signatures: vec![],
+            doc: None,
+            visibility: Visibility::Public,
+            generics: Generics::default(),
+            attrs: vec![],
+            decorators: vec![],
+            fields: vec![],
+            methods: vec![],
+            traits: vec![],
+            variants: vec![],
+            related: vec![],
+            unresolved_doc_links: vec![],
+            since: None,
+            until: None,
+            moved_from: None,
+            reexport_from: None,
+            deprecated: None,
+            cfg: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_detects_added_and_removed() {
+        let old = PackageItems {
+            items: vec![item("crate::old_fn", "fn old_fn()")],
+        };
+        let new = PackageItems {
+            items: vec![item("crate::new_fn", "fn new_fn()")],
+        };
+
+        let result = diff(&old, &new);
+        assert_eq!(result.level, SemverLevel::Major);
+        assert!(result
+            .changes
+            .iter()
+            .any(|c| c.path == "crate::old_fn" && c.kind == ChangeKind::Removed));
+        assert!(result
+            .changes
+            .iter()
+            .any(|c| c.path == "crate::new_fn" && c.kind == ChangeKind::Added));
+    }
+
+    #[test]
+    fn test_diff_detects_rename_via_moved_from() {
+        let old = PackageItems {
+            items: vec![item("crate::old_name", "fn old_name()")],
+        };
+        let mut renamed = item("crate::new_name", "fn old_name()");
+        renamed.moved_from = Some("crate::old_name".to_string());
+        let new = PackageItems {
+            items: vec![renamed],
+        };
+
+        let result = diff(&old, &new);
+        assert!(result.changes.is_empty());
+        assert_eq!(result.level, SemverLevel::Patch);
+    }
+
+    #[test]
+    fn test_diff_detects_signature_change_as_major() {
+        let old = PackageItems {
+            items: vec![item("crate::f", "fn f(a: i32)")],
+        };
+        let new = PackageItems {
+            items: vec![item("crate::f", "fn f(a: i32, b: i32)")],
+        };
+
+        let result = diff(&old, &new);
+        assert_eq!(result.level, SemverLevel::Major);
+        assert_eq!(result.changes.len(), 1);
+        assert!(matches!(
+            result.changes[0].kind,
+            ChangeKind::SignatureChanged { .. }
+        ));
+    }
+
+    #[test]
+    fn test_diff_identical_items_produce_no_changes() {
+        let old = PackageItems {
+            items: vec![item("crate::f", "fn f()")],
+        };
+        let new = PackageItems {
+            items: vec![item("crate::f", "fn f()")],
+        };
+
+        let result = diff(&old, &new);
+        assert!(result.changes.is_empty());
+        assert_eq!(result.level, SemverLevel::Patch);
+    }
+
+    #[test]
+    fn test_annotate_lifecycle_stamps_since_on_added_items() {
+        let old = PackageItems { items: vec![] };
+        let mut new = PackageItems {
+            items: vec![item("crate::f", "fn f()")],
+        };
+
+        let result = diff(&old, &new);
+        annotate_lifecycle(&mut new, &result, "1.2.0");
+
+        assert_eq!(new.items[0].since.as_deref(), Some("1.2.0"));
+    }
+
+    #[test]
+    fn test_diff_packages_groups_by_package_name() {
+        let mut old = BTreeMap::new();
+        old.insert(
+            "a".to_string(),
+            PackageItems {
+                items: vec![item("a::f", "fn f()")],
+            },
+        );
+
+        let mut new = BTreeMap::new();
+        new.insert(
+            "a".to_string(),
+            PackageItems {
+                items: vec![item("a::f", "fn f()")],
+            },
+        );
+        new.insert(
+            "b".to_string(),
+            PackageItems {
+                items: vec![item("b::g", "fn g()")],
+            },
+        );
+
+        let report = diff_packages(&old, &new);
+        assert_eq!(report.packages.len(), 2);
+        assert_eq!(report.packages["a"].level, SemverLevel::Patch);
+        assert_eq!(report.packages["b"].level, SemverLevel::Minor);
+    }
+}
@@ -6,13 +6,44 @@ use fastembed::{
 
 pub type Embedding = Vec<f32>;
 
+/// Controls for `Embedder::with_config`, letting a caller trade accuracy
+/// for speed/memory on large batches (hundreds of crate descriptions from a
+/// big Cargo.lock) without editing the baked-in model loader.
+#[derive(Debug, Clone)]
+pub struct EmbedderConfig {
+    /// `QuantizationMode::Static`/`Dynamic` trade a little accuracy for
+    /// much faster INT8 inference; `None` runs the model at full precision.
+    pub quantization: QuantizationMode,
+    /// How many texts `embed` hands to the model per inference call.
+    /// `TextEmbedding::embed` batches (and concatenates the results)
+    /// internally given this, so peak memory stays bounded regardless of
+    /// how many texts are passed to `embed` at once.
+    pub batch_size: usize,
+    pub pooling: Pooling,
+}
+
+impl Default for EmbedderConfig {
+    fn default() -> Self {
+        Self {
+            quantization: QuantizationMode::None,
+            batch_size: 256,
+            pooling: Pooling::Mean,
+        }
+    }
+}
+
 pub struct Embedder {
     model: TextEmbedding,
+    batch_size: usize,
 }
 
 #[allow(dead_code)]
 impl Embedder {
     pub fn new() -> Result<Self, anyhow::Error> {
+        Self::with_config(EmbedderConfig::default())
+    }
+
+    pub fn with_config(config: EmbedderConfig) -> Result<Self, anyhow::Error> {
         #[cfg(target_arch = "aarch64")]
         let onnx_bytes = include_bytes!("../models/all-MiniLM-L6-v2/model-arm64.onnx");
         #[cfg(not(target_arch = "aarch64"))]
@@ -33,23 +64,26 @@ impl Embedder {
                 )
                 .to_vec(),
             },
-            pooling: Some(Pooling::Mean),
-            quantization: QuantizationMode::None,
+            pooling: Some(config.pooling),
+            quantization: config.quantization,
             output_key: Default::default(),
         };
 
         let model = TextEmbedding::try_new_from_user_defined(model_data, Default::default())?;
-        Ok(Self { model })
+        Ok(Self {
+            model,
+            batch_size: config.batch_size,
+        })
     }
 
     pub fn embed(&mut self, texts: &[String]) -> Result<Vec<Embedding>, anyhow::Error> {
         let texts_ref: Vec<&str> = texts.iter().map(|s| s.as_str()).collect();
-        let embeddings = self.model.embed(texts_ref, None)?;
+        let embeddings = self.model.embed(texts_ref, Some(self.batch_size))?;
         Ok(embeddings)
     }
 
     pub fn embed_one(&mut self, text: &str) -> Result<Embedding, anyhow::Error> {
-        let embeddings = self.model.embed(vec![text], None)?;
+        let embeddings = self.model.embed(vec![text], Some(self.batch_size))?;
         Ok(embeddings.into_iter().next().unwrap())
     }
 }
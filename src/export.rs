@@ -0,0 +1,135 @@
+//! Durable, versioned export/import of parsed API snapshots.
+//!
+//! Inspired by rustc's `rls-data`/save-analysis dumps: serializes the
+//! `Vec<Item>` produced by a `LanguageParser` into a stable on-disk format,
+//! and loads it back, so two snapshots taken at different revisions can be
+//! diffed (see `diff::diff`) without re-parsing sources.
+
+use crate::schema::Item;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::io::Write;
+use std::path::Path;
+
+/// On-disk envelope for a single-file JSON export, versioned independently
+/// of `schema::Index::CURRENT_VERSION` so the extracted-item format can
+/// evolve without bumping the package-index format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ItemsExport {
+    pub format_version: u32,
+    pub items: Vec<Item>,
+}
+
+impl ItemsExport {
+    pub const CURRENT_VERSION: u32 = 1;
+}
+
+/// Write `items` to `path` as a single pretty-printed, versioned JSON
+/// document.
+pub fn write_items_json(path: &Path, items: &[Item]) -> Result<()> {
+    let export = ItemsExport {
+        format_version: ItemsExport::CURRENT_VERSION,
+        items: items.to_vec(),
+    };
+    let json = serde_json::to_string_pretty(&export).context("Failed to serialize items")?;
+    std::fs::write(path, json).context("Failed to write export file")?;
+    Ok(())
+}
+
+/// Write `items` to `path` as newline-delimited JSON: a header line
+/// carrying the format version, followed by one item per line. Cheaper to
+/// stream or append to than `write_items_json` for very large snapshots.
+pub fn write_items_ndjson(path: &Path, items: &[Item]) -> Result<()> {
+    let file = std::fs::File::create(path).context("Failed to create export file")?;
+    let mut writer = std::io::BufWriter::new(file);
+
+    let header = serde_json::json!({ "format_version": ItemsExport::CURRENT_VERSION });
+    writeln!(writer, "{}", header).context("Failed to write export header")?;
+    for item in items {
+        let line = serde_json::to_string(item).context("Failed to serialize item")?;
+        writeln!(writer, "{}", line).context("Failed to write export line")?;
+    }
+    writer.flush().context("Failed to flush export file")?;
+    Ok(())
+}
+
+/// Load a snapshot written by `write_items_json` or `write_items_ndjson`,
+/// trying the single-document JSON shape first and falling back to
+/// newline-delimited (skipping the header line) if that fails to parse.
+pub fn load_items(path: &Path) -> Result<Vec<Item>> {
+    let content = std::fs::read_to_string(path).context("Failed to read export file")?;
+
+    if let Ok(export) = serde_json::from_str::<ItemsExport>(&content) {
+        return Ok(export.items);
+    }
+
+    content
+        .lines()
+        .skip(1)
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(line).context("Failed to parse ndjson item"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schema::{Generics, ItemKind, Visibility};
+
+    fn sample_items() -> Vec<Item> {
+        vec![Item {
+            path: "demo::Widget".into(),
+            kind: ItemKind::Struct,
+            signature: Some("pub struct Widget;".into()),
+            signature_detail: None,
+            signatures: vec![],
+            doc: Some("A widget.".into()),
+            visibility: Visibility::Public,
+            generics: Generics::default(),
+            attrs: vec![],
+            decorators: vec![],
+            fields: vec![],
+            methods: vec![],
+            traits: vec![],
+            variants: vec![],
+            related: vec![],
+            unresolved_doc_links: vec![],
+            since: None,
+            until: None,
+            moved_from: None,
+            reexport_from: None,
+            deprecated: None,
+            cfg: None,
+        }]
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let path =
+            std::env::temp_dir().join(format!("fastdeps_export_test_{}.json", std::process::id()));
+        let items = sample_items();
+
+        write_items_json(&path, &items).unwrap();
+        let loaded = load_items(&path).unwrap();
+
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].path, "demo::Widget");
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_ndjson_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "fastdeps_export_test_{}.ndjson",
+            std::process::id()
+        ));
+        let items = sample_items();
+
+        write_items_ndjson(&path, &items).unwrap();
+        let loaded = load_items(&path).unwrap();
+
+        assert_eq!(loaded.len(), 1);
+        assert_eq!(loaded[0].path, "demo::Widget");
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,480 @@
+//! Persistent FST-backed symbol index for fast cross-crate search.
+//!
+//! `search::SearchEngine` used to hit SQLite FTS for exact/prefix queries
+//! and fall back to a linear per-dep scan for fuzzy ones. Both get slow as
+//! the number of indexed crates grows. This module builds a single
+//! `fst::Map` over every indexed crate's public symbols (keyed on the
+//! lowercased last path segment) so exact and prefix lookups stream
+//! straight off the FST, and fuzzy lookups run a `fst::automaton::Levenshtein`
+//! automaton over it in one pass instead of looping over every dep.
+//!
+//! The index is persisted next to the cache (`.fastdeps/symbols.idx`) and
+//! rebuilt lazily whenever the cache file's mtime moves past what's stored
+//! in the index header.
+
+use crate::cache::{Cache, CacheError};
+use camino::Utf8PathBuf;
+use fst::automaton::{Levenshtein, Str};
+use fst::{IntoStreamer, Map, MapBuilder, Streamer};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::time::{SystemTime, UNIX_EPOCH};
+use thiserror::Error;
+
+const INDEX_DIR: &str = ".fastdeps";
+const INDEX_FILE: &str = "symbols.idx";
+const MAGIC: &[u8] = b"FSYM";
+const FORMAT_VERSION: u32 = 2;
+
+#[derive(Debug, Error)]
+pub enum SymbolIndexError {
+    #[error("cache error: {0}")]
+    Cache(#[from] CacheError),
+    #[error("fst error: {0}")]
+    Fst(#[from] fst::Error),
+    #[error("invalid Levenshtein query: {0}")]
+    Levenshtein(#[from] fst::automaton::LevenshteinError),
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("corrupt symbol index (bad magic or truncated header)")]
+    Corrupt,
+    #[error("unsupported symbol index format version {0}")]
+    UnsupportedVersion(u32),
+    #[error("postings (de)serialization error: {0}")]
+    Postings(#[from] serde_json::Error),
+}
+
+/// One symbol occurrence that an FST key (the lowercased last path
+/// segment) resolves to. Carries everything `ScoredResult` needs so a hit
+/// never requires a follow-up cache query.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Posting {
+    pub crate_name: String,
+    pub crate_version: String,
+    pub path: String,
+    pub kind: String,
+    pub signature: Option<String>,
+}
+
+/// A lowercased-symbol -> postings FST, with a single streaming pass doing
+/// the work the old code did with a loop over every dependency.
+pub struct SymbolIndex {
+    map: Map<Vec<u8>>,
+    postings: Vec<Posting>,
+    /// The same postings, grouped and sorted by each item's full
+    /// lowercased path instead of just its last segment - rust-analyzer
+    /// `import_map`-style, so a qualified query like `"fog::dist"` can
+    /// binary-search straight to its prefix range instead of only matching
+    /// a bare last-segment prefix like `lookup_prefix` does.
+    fqn_map: Map<Vec<u8>>,
+    fqn_postings: Vec<Posting>,
+}
+
+impl SymbolIndex {
+    /// Build a fresh index by walking every crate currently in `cache`.
+    pub fn build(cache: &Cache) -> Result<Self, SymbolIndexError> {
+        // Keyed by lowercased last path segment; a `BTreeMap` gives us the
+        // lexicographic insertion order `MapBuilder` requires for free.
+        let mut by_last_segment: BTreeMap<String, Vec<Posting>> = BTreeMap::new();
+        // Keyed by the full lowercased path instead.
+        let mut by_fqn: BTreeMap<String, Vec<Posting>> = BTreeMap::new();
+
+        for (name, version) in cache.list_indexed()? {
+            let items = cache.search_crate(&name, Some(&version), None)?;
+            for item in items {
+                let posting = Posting {
+                    crate_name: name.clone(),
+                    crate_version: version.clone(),
+                    path: item.path.clone(),
+                    kind: item.kind,
+                    signature: item.signature,
+                };
+
+                let last_segment = item
+                    .path
+                    .rsplit("::")
+                    .next()
+                    .unwrap_or(&item.path)
+                    .to_lowercase();
+                by_last_segment
+                    .entry(last_segment)
+                    .or_default()
+                    .push(posting.clone());
+
+                by_fqn
+                    .entry(item.path.to_lowercase())
+                    .or_default()
+                    .push(posting);
+            }
+        }
+
+        let (map, postings) = build_map(by_last_segment)?;
+        let (fqn_map, fqn_postings) = build_map(by_fqn)?;
+
+        Ok(Self {
+            map,
+            postings,
+            fqn_map,
+            fqn_postings,
+        })
+    }
+
+    /// Load the persisted index if it exists and is at least as fresh as
+    /// `cache`'s backend file, otherwise build and persist one from scratch.
+    pub fn open_or_build(cache: &Cache) -> Result<Self, SymbolIndexError> {
+        let cache_mtime = Cache::backend_mtime().and_then(to_unix_secs);
+
+        if let Some(mtime) = cache_mtime {
+            match Self::load(mtime) {
+                Ok(Some(index)) => return Ok(index),
+                Ok(None) => {}
+                // A corrupt or unreadable on-disk index shouldn't block
+                // search - just rebuild it.
+                Err(_) => {}
+            }
+        }
+
+        let index = Self::build(cache)?;
+        if let Some(mtime) = cache_mtime {
+            index.save(mtime)?;
+        }
+        Ok(index)
+    }
+
+    /// Postings for an exact (lowercased) symbol name.
+    pub fn lookup_exact(&self, name: &str) -> &[Posting] {
+        match self.map.get(name.to_lowercase()) {
+            Some(packed) => Self::postings_for(&self.postings, packed),
+            None => &[],
+        }
+    }
+
+    /// Postings for every symbol name starting with `prefix` (case-insensitive).
+    pub fn lookup_prefix(&self, prefix: &str) -> Vec<&Posting> {
+        let automaton = Str::new(&prefix.to_lowercase()).starts_with();
+        let mut stream = self.map.search(automaton).into_stream();
+        let mut results = Vec::new();
+        while let Some((_, packed)) = stream.next() {
+            results.extend(Self::postings_for(&self.postings, packed));
+        }
+        results
+    }
+
+    /// Postings for every item whose full path starts with `prefix`
+    /// (case-insensitive), e.g. `"fog::dist"` matching both
+    /// `fog::DistanceFog` and `fog::DistanceUnit` - `lookup_prefix` can't do
+    /// this since it only keys on the last path segment.
+    pub fn lookup_fqn_prefix(&self, prefix: &str) -> Vec<&Posting> {
+        let automaton = Str::new(&prefix.to_lowercase()).starts_with();
+        let mut stream = self.fqn_map.search(automaton).into_stream();
+        let mut results = Vec::new();
+        while let Some((_, packed)) = stream.next() {
+            results.extend(Self::postings_for(&self.fqn_postings, packed));
+        }
+        results
+    }
+
+    /// Postings for every symbol name within `max_edit_distance` of `query`
+    /// (case-insensitive), found with one streamed pass over the FST
+    /// instead of scanning every cached item.
+    pub fn lookup_fuzzy(
+        &self,
+        query: &str,
+        max_edit_distance: u32,
+    ) -> Result<Vec<&Posting>, SymbolIndexError> {
+        let automaton = Levenshtein::new(&query.to_lowercase(), max_edit_distance)?;
+        let mut stream = self.map.search(automaton).into_stream();
+        let mut results = Vec::new();
+        while let Some((_, packed)) = stream.next() {
+            results.extend(Self::postings_for(&self.postings, packed));
+        }
+        Ok(results)
+    }
+
+    fn postings_for(postings: &[Posting], packed: u64) -> &[Posting] {
+        let (start, len) = unpack(packed);
+        &postings[start as usize..(start + len) as usize]
+    }
+
+    fn index_path() -> Utf8PathBuf {
+        Utf8PathBuf::from(INDEX_DIR).join(INDEX_FILE)
+    }
+
+    /// Returns `Ok(None)` when there's no index on disk or it's stale
+    /// relative to `cache_mtime`, rather than an error - the caller just
+    /// rebuilds in that case.
+    fn load(cache_mtime: u64) -> Result<Option<Self>, SymbolIndexError> {
+        let path = Self::index_path();
+        if !path.exists() {
+            return Ok(None);
+        }
+        let data = std::fs::read(&path)?;
+
+        if data.len() < MAGIC.len() + 4 + 8 + 8 || &data[..MAGIC.len()] != MAGIC {
+            return Err(SymbolIndexError::Corrupt);
+        }
+        let mut offset = MAGIC.len();
+
+        let format_version = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap());
+        offset += 4;
+        if format_version != FORMAT_VERSION {
+            return Err(SymbolIndexError::UnsupportedVersion(format_version));
+        }
+
+        let stored_mtime = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+        offset += 8;
+        if stored_mtime < cache_mtime {
+            return Ok(None);
+        }
+
+        let fst_len = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap()) as usize;
+        offset += 8;
+        if data.len() < offset + fst_len {
+            return Err(SymbolIndexError::Corrupt);
+        }
+        let fst_bytes = data[offset..offset + fst_len].to_vec();
+        offset += fst_len;
+
+        if data.len() < offset + 8 {
+            return Err(SymbolIndexError::Corrupt);
+        }
+        let fqn_fst_len = u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap()) as usize;
+        offset += 8;
+        if data.len() < offset + fqn_fst_len {
+            return Err(SymbolIndexError::Corrupt);
+        }
+        let fqn_fst_bytes = data[offset..offset + fqn_fst_len].to_vec();
+        offset += fqn_fst_len;
+
+        if data.len() < offset + 8 {
+            return Err(SymbolIndexError::Corrupt);
+        }
+        let postings_len =
+            u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap()) as usize;
+        offset += 8;
+        if data.len() < offset + postings_len {
+            return Err(SymbolIndexError::Corrupt);
+        }
+        let postings_zstd = &data[offset..offset + postings_len];
+        let postings: Vec<Posting> = serde_json::from_slice(&zstd::decode_all(postings_zstd)?)?;
+        offset += postings_len;
+
+        let fqn_postings_zstd = &data[offset..];
+        let fqn_postings: Vec<Posting> =
+            serde_json::from_slice(&zstd::decode_all(fqn_postings_zstd)?)?;
+
+        Ok(Some(Self {
+            map: Map::new(fst_bytes)?,
+            postings,
+            fqn_map: Map::new(fqn_fst_bytes)?,
+            fqn_postings,
+        }))
+    }
+
+    /// Persist this index alongside the cache, stamped with `cache_mtime`
+    /// so a later `open_or_build` can tell it's still fresh.
+    fn save(&self, cache_mtime: u64) -> Result<(), SymbolIndexError> {
+        let dir = Utf8PathBuf::from(INDEX_DIR);
+        if !dir.exists() {
+            std::fs::create_dir_all(&dir)?;
+        }
+
+        let fst_bytes = self.map.as_fst().as_bytes();
+        let fqn_fst_bytes = self.fqn_map.as_fst().as_bytes();
+        let postings_json = serde_json::to_vec(&self.postings)?;
+        let postings_zstd = zstd::encode_all(postings_json.as_slice(), 19)?;
+        let fqn_postings_json = serde_json::to_vec(&self.fqn_postings)?;
+        let fqn_postings_zstd = zstd::encode_all(fqn_postings_json.as_slice(), 19)?;
+
+        let mut out = Vec::with_capacity(
+            MAGIC.len()
+                + 4
+                + 8
+                + 8
+                + fst_bytes.len()
+                + 8
+                + fqn_fst_bytes.len()
+                + 8
+                + postings_zstd.len()
+                + fqn_postings_zstd.len(),
+        );
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&FORMAT_VERSION.to_le_bytes());
+        out.extend_from_slice(&cache_mtime.to_le_bytes());
+        out.extend_from_slice(&(fst_bytes.len() as u64).to_le_bytes());
+        out.extend_from_slice(fst_bytes);
+        out.extend_from_slice(&(fqn_fst_bytes.len() as u64).to_le_bytes());
+        out.extend_from_slice(fqn_fst_bytes);
+        out.extend_from_slice(&(postings_zstd.len() as u64).to_le_bytes());
+        out.extend_from_slice(&postings_zstd);
+        out.extend_from_slice(&fqn_postings_zstd);
+
+        let tmp_path = Self::index_path().with_extension("idx.tmp");
+        std::fs::File::create(&tmp_path)?.write_all(&out)?;
+        std::fs::rename(&tmp_path, Self::index_path())?;
+        Ok(())
+    }
+}
+
+/// Group already-sorted `(key, postings)` pairs into an FST plus the
+/// parallel postings array its packed values index into. Shared by both of
+/// `SymbolIndex::build`'s groupings (last-segment and full-path).
+fn build_map(
+    grouped: BTreeMap<String, Vec<Posting>>,
+) -> Result<(Map<Vec<u8>>, Vec<Posting>), SymbolIndexError> {
+    let mut postings = Vec::with_capacity(grouped.values().map(Vec::len).sum());
+    let mut builder = MapBuilder::memory();
+    for (key, group) in grouped {
+        let start = postings.len() as u64;
+        let len = group.len() as u64;
+        builder.insert(key, pack(start, len))?;
+        postings.extend(group);
+    }
+
+    let map = Map::new(builder.into_inner()?)?;
+    Ok((map, postings))
+}
+
+/// Pack a `(start, len)` postings range into the single `u64` an
+/// `fst::Map` value can hold: top 40 bits for `start`, low 24 bits for
+/// `len`. Either overflowing would need a cache with over 2^24 items under
+/// one symbol name or over 2^40 items total - far beyond anything
+/// fastdeps indexes.
+fn pack(start: u64, len: u64) -> u64 {
+    (start << 24) | (len & 0xFF_FFFF)
+}
+
+fn unpack(packed: u64) -> (u64, u64) {
+    (packed >> 24, packed & 0xFF_FFFF)
+}
+
+fn to_unix_secs(t: SystemTime) -> Option<u64> {
+    t.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn posting(crate_name: &str, path: &str) -> Posting {
+        Posting {
+            crate_name: crate_name.to_string(),
+            crate_version: "1.0.0".to_string(),
+            path: path.to_string(),
+            kind: "struct".to_string(),
+            signature: None,
+        }
+    }
+
+    /// Builds a `SymbolIndex` directly from last-segment-keyed `(key,
+    /// postings)` pairs, bypassing `Cache`, so lookup behavior can be
+    /// tested without a real backend on disk. The FQN-keyed side is
+    /// derived from the same postings' own paths.
+    fn index_from(entries: Vec<(&str, Vec<Posting>)>) -> SymbolIndex {
+        let mut by_last_segment: BTreeMap<String, Vec<Posting>> = BTreeMap::new();
+        let mut by_fqn: BTreeMap<String, Vec<Posting>> = BTreeMap::new();
+        for (key, group) in entries {
+            for posting in group {
+                by_fqn
+                    .entry(posting.path.to_lowercase())
+                    .or_default()
+                    .push(posting.clone());
+                by_last_segment
+                    .entry(key.to_string())
+                    .or_default()
+                    .push(posting);
+            }
+        }
+
+        let (map, postings) = build_map(by_last_segment).unwrap();
+        let (fqn_map, fqn_postings) = build_map(by_fqn).unwrap();
+        SymbolIndex {
+            map,
+            postings,
+            fqn_map,
+            fqn_postings,
+        }
+    }
+
+    #[test]
+    fn test_pack_unpack_roundtrip() {
+        assert_eq!(unpack(pack(42, 7)), (42, 7));
+        assert_eq!(unpack(pack(0, 0)), (0, 0));
+    }
+
+    #[test]
+    fn test_lookup_exact() {
+        let index = index_from(vec![
+            ("distancefog", vec![posting("bevy_fog", "fog::DistanceFog")]),
+            ("height", vec![posting("bevy_fog", "fog::Height")]),
+        ]);
+
+        assert_eq!(index.lookup_exact("DistanceFog").len(), 1);
+        assert_eq!(
+            index.lookup_exact("distancefog")[0].path,
+            "fog::DistanceFog"
+        );
+        assert!(index.lookup_exact("nope").is_empty());
+    }
+
+    #[test]
+    fn test_lookup_prefix() {
+        let index = index_from(vec![
+            ("distancefog", vec![posting("bevy_fog", "fog::DistanceFog")]),
+            (
+                "distanceunit",
+                vec![posting("bevy_fog", "fog::DistanceUnit")],
+            ),
+            ("height", vec![posting("bevy_fog", "fog::Height")]),
+        ]);
+
+        let results = index.lookup_prefix("distance");
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn test_lookup_fuzzy_tolerates_edit_distance() {
+        let index = index_from(vec![(
+            "distancefog",
+            vec![posting("bevy_fog", "fog::DistanceFog")],
+        )]);
+
+        // "distnacefog" is "distancefog" with two characters transposed -
+        // two substitutions under Levenshtein distance.
+        let results = index.lookup_fuzzy("distnacefog", 2).unwrap();
+        assert_eq!(results.len(), 1);
+
+        let too_far = index.lookup_fuzzy("somethingtotallydifferent", 2).unwrap();
+        assert!(too_far.is_empty());
+    }
+
+    #[test]
+    fn test_grouped_postings_share_one_key() {
+        let index = index_from(vec![(
+            "new",
+            vec![posting("crate_a", "a::new"), posting("crate_b", "b::new")],
+        )]);
+
+        let results = index.lookup_exact("new");
+        assert_eq!(results.len(), 2);
+    }
+
+    #[test]
+    fn test_lookup_fqn_prefix_matches_full_path_not_just_last_segment() {
+        let index = index_from(vec![
+            ("distancefog", vec![posting("bevy_fog", "fog::DistanceFog")]),
+            (
+                "distanceunit",
+                vec![posting("bevy_fog", "fog::DistanceUnit")],
+            ),
+            ("height", vec![posting("bevy_fog", "fog::Height")]),
+        ]);
+
+        // A last-segment prefix wouldn't find these - "fog::dist" only
+        // matches on the full path.
+        let results = index.lookup_fqn_prefix("fog::dist");
+        assert_eq!(results.len(), 2);
+        assert!(index.lookup_prefix("fog::dist").is_empty());
+    }
+}
@@ -0,0 +1,86 @@
+//! Import-map resolution: the shortest path a user would actually type to
+//! name an item, following the real `pub use` re-export graph the parser
+//! already records (`RelationKind::ReExportOf`) instead of guessing from
+//! raw source the way `detect_reexport_crate` used to.
+//!
+//! Inspired by rust-analyzer's `ImportMap`: for a definition path, walk
+//! outward over every `pub use` alias that re-exports it (transitively, in
+//! case one re-export is itself re-exported), then keep the shortest
+//! (fewest `::` segments) alias, preferring one that passes through a
+//! `prelude` module on ties.
+
+use crate::cache::Cache;
+use std::collections::{BTreeSet, VecDeque};
+
+/// Every path `definition_path` can be imported through, `definition_path`
+/// itself included, found by following `pub use` re-export edges outward
+/// from the cache's cross-reference graph.
+pub fn reachable_paths(cache: &Cache, definition_path: &str) -> BTreeSet<String> {
+    let mut seen = BTreeSet::new();
+    let mut queue = VecDeque::new();
+    seen.insert(definition_path.to_string());
+    queue.push_back(definition_path.to_string());
+
+    while let Some(path) = queue.pop_front() {
+        let Ok(reexports) = cache.find_reexports_of(&path) else {
+            continue;
+        };
+        for reexport in reexports {
+            if seen.insert(reexport.path.clone()) {
+                queue.push_back(reexport.path);
+            }
+        }
+    }
+
+    seen
+}
+
+/// The path a user would actually type for `definition_path`: fewest `::`
+/// segments among `reachable_paths`, preferring one through a `prelude`
+/// module when lengths tie. `None` only if `definition_path` is empty.
+pub fn canonical_path(cache: &Cache, definition_path: &str) -> Option<String> {
+    if definition_path.is_empty() {
+        return None;
+    }
+    reachable_paths(cache, definition_path)
+        .into_iter()
+        .min_by_key(|path| (path.matches("::").count(), !has_prelude_segment(path)))
+}
+
+/// Every path tied for shortest among `reachable_paths(definition_path)`,
+/// with any path through a `prelude` module first. More than one entry
+/// means there's no single best alias - e.g. two sibling crates each
+/// re-export the item at the same depth - so the caller should show all of
+/// them rather than picking arbitrarily.
+pub fn best_import_paths(cache: &Cache, definition_path: &str) -> Vec<String> {
+    let reachable = reachable_paths(cache, definition_path);
+    let Some(min_len) = reachable
+        .iter()
+        .map(|path| path.matches("::").count())
+        .min()
+    else {
+        return Vec::new();
+    };
+
+    let mut best: Vec<String> = reachable
+        .into_iter()
+        .filter(|path| path.matches("::").count() == min_len)
+        .collect();
+    best.sort_by_key(|path| (!has_prelude_segment(path), path.clone()));
+    best
+}
+
+fn has_prelude_segment(path: &str) -> bool {
+    path.split("::").any(|segment| segment == "prelude")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_has_prelude_segment() {
+        assert!(has_prelude_segment("bevy::prelude::Component"));
+        assert!(!has_prelude_segment("bevy_ecs::component::Component"));
+    }
+}
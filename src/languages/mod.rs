@@ -3,9 +3,80 @@
 //! Each submodule implements parsing for a specific language ecosystem,
 //! producing the universal `schema::Item` format.
 
+pub mod python;
 pub mod rust;
+pub mod typescript;
 
 // Future:
-// pub mod typescript;
-// pub mod python;
 // pub mod go;
+
+use crate::schema::{Ecosystem, Item};
+use thiserror::Error;
+
+/// Failure modes shared by every language backend.
+#[derive(Debug, Error)]
+pub enum ParseError {
+    #[error("tree-sitter initialization failed")]
+    TreeSitterInit,
+    #[error("failed to parse source")]
+    ParseFailed,
+}
+
+/// A pluggable source-to-schema backend, implemented once per language so the
+/// diff and reporting subsystems can stay language-agnostic.
+pub trait LanguageParser {
+    /// Parse a source file and extract its API surface into the universal schema.
+    fn parse_source(&mut self, source: &str, module_path: &str) -> Result<Vec<Item>, ParseError>;
+
+    /// Short identifier for this backend, e.g. `"rust"` or `"typescript"`.
+    fn language_id(&self) -> &'static str;
+
+    /// Whether this parser instance handles files with the given extension
+    /// (without the leading dot).
+    fn handles_extension(&self, ext: &str) -> bool;
+}
+
+/// Build the default `LanguageParser` for `ecosystem`, so a caller that only
+/// knows a package's `Ecosystem` (e.g. from its `PackageMeta`) doesn't have to
+/// match on it and construct a concrete backend itself. `None` if no backend
+/// is wired up for that ecosystem yet (see the `Future:` list above), for
+/// `Ecosystem::TypeScript` (whose backend is picked per file dialect by
+/// `parser_for_extension` instead), or if the backend's own initialization
+/// (tree-sitter grammar setup) fails.
+pub fn extractor_for(ecosystem: Ecosystem) -> Option<Box<dyn LanguageParser>> {
+    match ecosystem {
+        Ecosystem::Rust => Some(Box::new(rust::RustParser::new().ok()?) as Box<dyn LanguageParser>),
+        Ecosystem::Python => {
+            Some(Box::new(python::PythonParser::new().ok()?) as Box<dyn LanguageParser>)
+        }
+        Ecosystem::TypeScript | Ecosystem::Go => None,
+    }
+}
+
+/// Build the `LanguageParser` backend that handles files with extension
+/// `ext` (without the leading dot), so a directory walk that only knows a
+/// file's extension - like the usage analyzer's - doesn't have to hand-roll
+/// the extension-to-backend matching scattered across `main.rs`. `None` if
+/// no backend handles `ext`, or if the backend's own initialization fails.
+pub fn parser_for_extension(ext: &str) -> Option<Box<dyn LanguageParser>> {
+    use typescript::{TsLanguage, TypeScriptParser};
+
+    match ext {
+        "rs" => Some(Box::new(rust::RustParser::new().ok()?) as Box<dyn LanguageParser>),
+        "ts" => Some(
+            Box::new(TypeScriptParser::new(TsLanguage::TypeScript).ok()?)
+                as Box<dyn LanguageParser>,
+        ),
+        "tsx" | "jsx" => {
+            Some(Box::new(TypeScriptParser::new(TsLanguage::Tsx).ok()?) as Box<dyn LanguageParser>)
+        }
+        "js" | "mjs" | "cjs" => Some(
+            Box::new(TypeScriptParser::new(TsLanguage::JavaScript).ok()?)
+                as Box<dyn LanguageParser>,
+        ),
+        "py" | "pyi" => {
+            Some(Box::new(python::PythonParser::new().ok()?) as Box<dyn LanguageParser>)
+        }
+        _ => None,
+    }
+}
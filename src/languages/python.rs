@@ -0,0 +1,301 @@
+//! Tree-sitter based Python source parser.
+//!
+//! Extracts API surface from Python source files, producing the universal
+//! `schema::Item` format.
+
+use crate::languages::{LanguageParser, ParseError};
+use crate::schema::{Generics, Item, ItemKind, Method, Visibility};
+use tree_sitter::{Node, Parser};
+
+pub struct PythonParser {
+    parser: Parser,
+}
+
+impl PythonParser {
+    pub fn new() -> Result<Self, ParseError> {
+        let mut parser = Parser::new();
+        let language = tree_sitter_python::LANGUAGE;
+        parser
+            .set_language(&language.into())
+            .map_err(|_| ParseError::TreeSitterInit)?;
+        Ok(Self { parser })
+    }
+
+    /// Parse a Python source file and extract its top-level defs/classes.
+    pub fn parse_source(
+        &mut self,
+        source: &str,
+        module_path: &str,
+    ) -> Result<Vec<Item>, ParseError> {
+        let tree = self
+            .parser
+            .parse(source, None)
+            .ok_or(ParseError::ParseFailed)?;
+
+        let mut items = Vec::new();
+        self.collect_definitions(tree.root_node(), source, module_path, &mut items);
+        Ok(items)
+    }
+
+    fn collect_definitions(
+        &self,
+        node: Node,
+        source: &str,
+        module_path: &str,
+        items: &mut Vec<Item>,
+    ) {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            match child.kind() {
+                "function_definition" => {
+                    if let Some(item) = self.parse_function(child, source, module_path, &[]) {
+                        items.push(item);
+                    }
+                }
+                "class_definition" => {
+                    if let Some(item) = self.parse_class(child, source, module_path, &[]) {
+                        items.push(item);
+                    }
+                }
+                "decorated_definition" => {
+                    let decorators = self.get_decorators(child, source);
+                    if let Some(def) = self.find_child_by_kind(child, "function_definition") {
+                        if let Some(item) =
+                            self.parse_function(def, source, module_path, &decorators)
+                        {
+                            items.push(item);
+                        }
+                    } else if let Some(def) = self.find_child_by_kind(child, "class_definition") {
+                        if let Some(item) = self.parse_class(def, source, module_path, &decorators)
+                        {
+                            items.push(item);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn parse_function(
+        &self,
+        node: Node,
+        source: &str,
+        module_path: &str,
+        decorators: &[String],
+    ) -> Option<Item> {
+        let name = self.get_child_text(node, "identifier", source)?;
+        let doc = self.get_docstring(node, source);
+        let signature = self.get_signature_line(node, source);
+
+        Some(Item {
+            path: format_path(module_path, &name),
+            kind: ItemKind::Function,
+            signature: Some(signature),
+            signature_detail: None,
+            doc,
+            visibility: self.get_visibility(&name),
+            generics: Generics::default(),
+            attrs: vec![],
+            decorators: decorators.to_vec(),
+            signatures: vec![],
+            fields: vec![],
+            methods: vec![],
+            traits: vec![],
+            variants: vec![],
+            related: vec![],
+            unresolved_doc_links: vec![],
+            since: None,
+            until: None,
+            moved_from: None,
+            reexport_from: None,
+            deprecated: None,
+            cfg: None,
+        })
+    }
+
+    fn parse_class(
+        &self,
+        node: Node,
+        source: &str,
+        module_path: &str,
+        decorators: &[String],
+    ) -> Option<Item> {
+        let name = self.get_child_text(node, "identifier", source)?;
+        let doc = self.get_docstring(node, source);
+        let signature = self.get_signature_line(node, source);
+
+        let traits = node
+            .child_by_field_name("superclasses")
+            .map(|n| {
+                self.get_node_text(n, source)
+                    .trim_start_matches('(')
+                    .trim_end_matches(')')
+                    .to_string()
+            })
+            .filter(|s| !s.is_empty())
+            .map(|s| s.split(',').map(|p| p.trim().to_string()).collect())
+            .unwrap_or_default();
+
+        let methods = node
+            .child_by_field_name("body")
+            .map(|body| self.parse_methods(body, source))
+            .unwrap_or_default();
+
+        Some(Item {
+            path: format_path(module_path, &name),
+            kind: ItemKind::Struct,
+            signature: Some(signature),
+            signature_detail: None,
+            doc,
+            visibility: self.get_visibility(&name),
+            generics: Generics::default(),
+            attrs: vec![],
+            decorators: decorators.to_vec(),
+            signatures: vec![],
+            fields: vec![],
+            methods,
+            traits,
+            variants: vec![],
+            related: vec![],
+            unresolved_doc_links: vec![],
+            since: None,
+            until: None,
+            moved_from: None,
+            reexport_from: None,
+            deprecated: None,
+            cfg: None,
+        })
+    }
+
+    fn parse_methods(&self, body: Node, source: &str) -> Vec<Method> {
+        let mut methods = Vec::new();
+        let mut cursor = body.walk();
+        for child in body.children(&mut cursor) {
+            let (def, decorators) = match child.kind() {
+                "function_definition" => (Some(child), Vec::new()),
+                "decorated_definition" => (
+                    self.find_child_by_kind(child, "function_definition"),
+                    self.get_decorators(child, source),
+                ),
+                _ => (None, Vec::new()),
+            };
+            let Some(def) = def else { continue };
+            let Some(name) = self.get_child_text(def, "identifier", source) else {
+                continue;
+            };
+
+            methods.push(Method {
+                name: name.clone(),
+                signature: Some(self.get_signature_line(def, source)),
+                signature_detail: None,
+                signatures: vec![],
+                doc: self.get_docstring(def, source),
+                visibility: self.get_visibility(&name),
+                generics: Generics::default(),
+                decorators,
+            });
+        }
+        methods
+    }
+
+    /// A function's or class's docstring: the first statement in `node`'s
+    /// body, if it's a bare string expression.
+    fn get_docstring(&self, node: Node, source: &str) -> Option<String> {
+        let body = node.child_by_field_name("body")?;
+        let first = body.named_child(0)?;
+        if first.kind() != "expression_statement" {
+            return None;
+        }
+        let string_node = first.named_child(0)?;
+        if string_node.kind() != "string" {
+            return None;
+        }
+        let text = self.get_node_text(string_node, source);
+        Some(
+            text.trim_matches(|c| c == '"' || c == '\'')
+                .trim()
+                .to_string(),
+        )
+    }
+
+    /// Preceding `decorator` siblings of a definition wrapped in a
+    /// `decorated_definition` node, in source order.
+    fn get_decorators(&self, decorated: Node, source: &str) -> Vec<String> {
+        let mut decorators = Vec::new();
+        let mut cursor = decorated.walk();
+        for child in decorated.children(&mut cursor) {
+            if child.kind() == "decorator" {
+                decorators.push(self.get_node_text(child, source));
+            }
+        }
+        decorators
+    }
+
+    /// Python has no visibility keywords; by convention a single leading
+    /// underscore marks a name module/class-private (`Visibility::Crate`),
+    /// matching `schema::Visibility`'s documented Python mapping. Dunder
+    /// names (`__init__`, `__repr__`, ...) are still public API.
+    fn get_visibility(&self, name: &str) -> Visibility {
+        if name.starts_with("__") && name.ends_with("__") {
+            Visibility::Public
+        } else if name.starts_with('_') {
+            Visibility::Crate
+        } else {
+            Visibility::Public
+        }
+    }
+
+    fn get_signature_line(&self, node: Node, source: &str) -> String {
+        let text = self.get_node_text(node, source);
+        match node.child_by_field_name("body") {
+            Some(body) => {
+                let rel_end = (body.start_byte() - node.start_byte()).min(text.len());
+                text[..rel_end].trim_end_matches(':').trim().to_string()
+            }
+            None => text,
+        }
+    }
+
+    fn get_node_text(&self, node: Node, source: &str) -> String {
+        source[node.byte_range()].to_string()
+    }
+
+    fn get_child_text(&self, node: Node, kind: &str, source: &str) -> Option<String> {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if child.kind() == kind {
+                return Some(self.get_node_text(child, source));
+            }
+        }
+        None
+    }
+
+    fn find_child_by_kind<'a>(&self, node: Node<'a>, kind: &str) -> Option<Node<'a>> {
+        let mut cursor = node.walk();
+        node.children(&mut cursor)
+            .find(|child| child.kind() == kind)
+    }
+}
+
+fn format_path(module_path: &str, name: &str) -> String {
+    if module_path.is_empty() {
+        name.to_string()
+    } else {
+        format!("{module_path}::{name}")
+    }
+}
+
+impl LanguageParser for PythonParser {
+    fn parse_source(&mut self, source: &str, module_path: &str) -> Result<Vec<Item>, ParseError> {
+        PythonParser::parse_source(self, source, module_path)
+    }
+
+    fn language_id(&self) -> &'static str {
+        "python"
+    }
+
+    fn handles_extension(&self, ext: &str) -> bool {
+        ext == "py" || ext == "pyi"
+    }
+}
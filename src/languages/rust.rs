@@ -3,22 +3,28 @@
 //! Extracts API surface from Rust source files, producing the universal
 //! `schema::Item` format.
 
-use crate::schema::{Field, Item, ItemKind, Method, Variant, Visibility};
-use thiserror::Error;
+use crate::languages::{LanguageParser, ParseError};
+use crate::schema::{
+    Attribute, Field, GenericParam, Generics, Item, ItemKind, Method, Param, Relation,
+    RelationKind, Signature, Variant, Visibility,
+};
 use tree_sitter::{Node, Parser, Tree};
 
-#[derive(Debug, Error)]
-pub enum ParseError {
-    #[error("tree-sitter initialization failed")]
-    TreeSitterInit,
-    #[error("failed to parse source")]
-    ParseFailed,
-}
-
 pub struct RustParser {
     parser: Parser,
 }
 
+/// Stability/availability metadata parsed from an item's attributes.
+#[derive(Debug, Default, Clone)]
+struct Stability {
+    /// From `#[stable(since = "...")]` or, failing that, `#[deprecated(since = "...")]`.
+    since: Option<String>,
+    /// From `#[deprecated]`'s `note`, or a generic marker if no note was given.
+    deprecated: Option<String>,
+    /// Raw predicate from `#[cfg(...)]`, e.g. `feature = "serde"`.
+    cfg: Option<String>,
+}
+
 impl RustParser {
     pub fn new() -> Result<Self, ParseError> {
         let mut parser = Parser::new();
@@ -42,6 +48,7 @@ impl RustParser {
 
         let mut items = Vec::new();
         self.extract_items(&tree, source, module_path, &mut items);
+        resolve_doc_links(&mut items);
         Ok(items)
     }
 
@@ -50,6 +57,8 @@ impl RustParser {
         // Two-pass: first collect type definitions, then process impl blocks
         self.collect_definitions(root, source, module_path, items);
         self.process_impls(root, source, module_path, items);
+        // Final pass: re-exports point at items that must already be collected.
+        self.collect_reexports(root, source, module_path, items);
     }
 
     /// First pass: collect struct, enum, trait, function, etc. definitions.
@@ -121,6 +130,9 @@ impl RustParser {
         let name = self.get_child_text(node, "type_identifier", source)?;
         let doc = self.get_doc_comment(node, source);
         let signature = self.get_node_text(node, source);
+        let stability = self.get_stability(node, source);
+        let generics = self.parse_generics(node, source);
+        let attrs = self.get_attrs(node, source);
 
         let fields = self.parse_struct_fields(node, source);
 
@@ -128,35 +140,50 @@ impl RustParser {
             path: format_path(module_path, &name),
             kind: ItemKind::Struct,
             signature: Some(signature),
+            signature_detail: None,
             doc,
             visibility: vis,
+            generics,
+            attrs,
+            decorators: vec![],
+            signatures: vec![],
             fields,
             methods: vec![],
             traits: vec![],
             variants: vec![],
             related: vec![],
-            since: None,
+            unresolved_doc_links: vec![],
+            since: stability.since,
             until: None,
             moved_from: None,
-            deprecated: None,
+            reexport_from: None,
+            deprecated: stability.deprecated,
+            cfg: stability.cfg,
         })
     }
 
     fn parse_struct_fields(&self, node: Node, source: &str) -> Vec<Field> {
         let mut fields = Vec::new();
 
-        // Look for field_declaration_list
+        // Look for field_declaration_list (named fields) or
+        // ordered_field_declaration_list (tuple structs).
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
-            if child.kind() == "field_declaration_list" {
-                let mut field_cursor = child.walk();
-                for field_node in child.children(&mut field_cursor) {
-                    if field_node.kind() == "field_declaration" {
-                        if let Some(field) = self.parse_field(field_node, source) {
-                            fields.push(field);
+            match child.kind() {
+                "field_declaration_list" => {
+                    let mut field_cursor = child.walk();
+                    for field_node in child.children(&mut field_cursor) {
+                        if field_node.kind() == "field_declaration" {
+                            if let Some(field) = self.parse_field(field_node, source) {
+                                fields.push(field);
+                            }
                         }
                     }
                 }
+                "ordered_field_declaration_list" => {
+                    fields.extend(self.parse_tuple_fields(child, source));
+                }
+                _ => {}
             }
         }
         fields
@@ -175,14 +202,48 @@ impl RustParser {
             ty,
             doc,
             visibility: vis,
+            decorators: vec![],
         })
     }
 
+    /// Parse a tuple struct's or tuple variant's positional fields,
+    /// synthesizing names `"0"`, `"1"`, … so index-based lookups (e.g. `b.0`)
+    /// work the same way a named field lookup does.
+    fn parse_tuple_fields(&self, node: Node, source: &str) -> Vec<Field> {
+        let mut fields = Vec::new();
+        let mut cursor = node.walk();
+
+        for (index, child) in node
+            .children(&mut cursor)
+            .filter(|child| child.kind() == "ordered_field_declaration")
+            .enumerate()
+        {
+            let vis = self.get_visibility(child, source);
+            let ty = self
+                .find_child_by_field(child, "type")
+                .map(|n| self.get_node_text(n, source));
+            let doc = self.get_doc_comment(child, source);
+
+            fields.push(Field {
+                name: index.to_string(),
+                ty,
+                doc,
+                visibility: vis,
+                decorators: vec![],
+            });
+        }
+
+        fields
+    }
+
     fn parse_enum(&self, node: Node, source: &str, module_path: &str) -> Option<Item> {
         let vis = self.get_visibility(node, source);
         let name = self.get_child_text(node, "type_identifier", source)?;
         let doc = self.get_doc_comment(node, source);
         let signature = self.get_node_text(node, source);
+        let stability = self.get_stability(node, source);
+        let generics = self.parse_generics(node, source);
+        let attrs = self.get_attrs(node, source);
 
         let variants = self.parse_enum_variants(node, source);
 
@@ -190,17 +251,25 @@ impl RustParser {
             path: format_path(module_path, &name),
             kind: ItemKind::Enum,
             signature: Some(signature),
+            signature_detail: None,
             doc,
             visibility: vis,
+            generics,
+            attrs,
+            decorators: vec![],
+            signatures: vec![],
             fields: vec![],
             methods: vec![],
             traits: vec![],
             variants,
             related: vec![],
-            since: None,
+            unresolved_doc_links: vec![],
+            since: stability.since,
             until: None,
             moved_from: None,
-            deprecated: None,
+            reexport_from: None,
+            deprecated: stability.deprecated,
+            cfg: stability.cfg,
         })
     }
 
@@ -227,23 +296,35 @@ impl RustParser {
         let name = self.get_child_text(node, "identifier", source)?;
         let doc = self.get_doc_comment(node, source);
 
-        // Parse variant fields if it's a struct variant
+        // Parse variant fields: named fields for a struct variant, positional
+        // fields for a tuple variant (e.g. `Ok(T)`).
         let mut fields = Vec::new();
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
-            if child.kind() == "field_declaration_list" {
-                let mut field_cursor = child.walk();
-                for field_node in child.children(&mut field_cursor) {
-                    if field_node.kind() == "field_declaration" {
-                        if let Some(field) = self.parse_field(field_node, source) {
-                            fields.push(field);
+            match child.kind() {
+                "field_declaration_list" => {
+                    let mut field_cursor = child.walk();
+                    for field_node in child.children(&mut field_cursor) {
+                        if field_node.kind() == "field_declaration" {
+                            if let Some(field) = self.parse_field(field_node, source) {
+                                fields.push(field);
+                            }
                         }
                     }
                 }
+                "ordered_field_declaration_list" => {
+                    fields.extend(self.parse_tuple_fields(child, source));
+                }
+                _ => {}
             }
         }
 
-        Some(Variant { name, doc, fields })
+        Some(Variant {
+            name,
+            doc,
+            fields,
+            value: None,
+        })
     }
 
     fn parse_trait(&self, node: Node, source: &str, module_path: &str) -> Option<Item> {
@@ -251,6 +332,9 @@ impl RustParser {
         let name = self.get_child_text(node, "type_identifier", source)?;
         let doc = self.get_doc_comment(node, source);
         let signature = self.get_signature_line(node, source);
+        let stability = self.get_stability(node, source);
+        let generics = self.parse_generics(node, source);
+        let attrs = self.get_attrs(node, source);
 
         let methods = self.parse_trait_methods(node, source);
 
@@ -258,17 +342,25 @@ impl RustParser {
             path: format_path(module_path, &name),
             kind: ItemKind::Trait,
             signature: Some(signature),
+            signature_detail: None,
             doc,
             visibility: vis,
+            generics,
+            attrs,
+            decorators: vec![],
+            signatures: vec![],
             fields: vec![],
             methods,
             traits: vec![],
             variants: vec![],
             related: vec![],
-            since: None,
+            unresolved_doc_links: vec![],
+            since: stability.since,
             until: None,
             moved_from: None,
-            deprecated: None,
+            reexport_from: None,
+            deprecated: stability.deprecated,
+            cfg: stability.cfg,
         })
     }
 
@@ -298,12 +390,18 @@ impl RustParser {
         let vis = self.get_visibility(node, source);
         let doc = self.get_doc_comment(node, source);
         let signature = self.get_signature_line(node, source);
+        let signature_detail = self.parse_signature(node, source);
+        let generics = self.parse_generics(node, source);
 
         Some(Method {
             name,
             signature: Some(signature),
+            signature_detail: Some(signature_detail),
             doc,
             visibility: vis,
+            generics,
+            decorators: vec![],
+            signatures: vec![],
         })
     }
 
@@ -312,22 +410,34 @@ impl RustParser {
         let name = self.get_child_text(node, "identifier", source)?;
         let doc = self.get_doc_comment(node, source);
         let signature = self.get_signature_line(node, source);
+        let signature_detail = self.parse_signature(node, source);
+        let stability = self.get_stability(node, source);
+        let generics = self.parse_generics(node, source);
+        let attrs = self.get_attrs(node, source);
 
         Some(Item {
             path: format_path(module_path, &name),
             kind: ItemKind::Function,
             signature: Some(signature),
+            signature_detail: Some(signature_detail),
             doc,
             visibility: vis,
+            generics,
+            attrs,
+            decorators: vec![],
+            signatures: vec![],
             fields: vec![],
             methods: vec![],
             traits: vec![],
             variants: vec![],
             related: vec![],
-            since: None,
+            unresolved_doc_links: vec![],
+            since: stability.since,
             until: None,
             moved_from: None,
-            deprecated: None,
+            reexport_from: None,
+            deprecated: stability.deprecated,
+            cfg: stability.cfg,
         })
     }
 
@@ -336,22 +446,32 @@ impl RustParser {
         let name = self.get_child_text(node, "type_identifier", source)?;
         let doc = self.get_doc_comment(node, source);
         let signature = self.get_node_text(node, source);
+        let stability = self.get_stability(node, source);
+        let attrs = self.get_attrs(node, source);
 
         Some(Item {
             path: format_path(module_path, &name),
             kind: ItemKind::TypeAlias,
             signature: Some(signature),
+            signature_detail: None,
             doc,
             visibility: vis,
+            generics: Generics::default(),
+            attrs,
+            decorators: vec![],
+            signatures: vec![],
             fields: vec![],
             methods: vec![],
             traits: vec![],
             variants: vec![],
             related: vec![],
-            since: None,
+            unresolved_doc_links: vec![],
+            since: stability.since,
             until: None,
             moved_from: None,
-            deprecated: None,
+            reexport_from: None,
+            deprecated: stability.deprecated,
+            cfg: stability.cfg,
         })
     }
 
@@ -360,22 +480,32 @@ impl RustParser {
         let name = self.get_child_text(node, "identifier", source)?;
         let doc = self.get_doc_comment(node, source);
         let signature = self.get_node_text(node, source);
+        let stability = self.get_stability(node, source);
+        let attrs = self.get_attrs(node, source);
 
         Some(Item {
             path: format_path(module_path, &name),
             kind: ItemKind::Constant,
             signature: Some(signature),
+            signature_detail: None,
             doc,
             visibility: vis,
+            generics: Generics::default(),
+            attrs,
+            decorators: vec![],
+            signatures: vec![],
             fields: vec![],
             methods: vec![],
             traits: vec![],
             variants: vec![],
             related: vec![],
-            since: None,
+            unresolved_doc_links: vec![],
+            since: stability.since,
             until: None,
             moved_from: None,
-            deprecated: None,
+            reexport_from: None,
+            deprecated: stability.deprecated,
+            cfg: stability.cfg,
         })
     }
 
@@ -383,22 +513,32 @@ impl RustParser {
         // macro_rules! macros
         let name = self.get_child_text(node, "identifier", source)?;
         let doc = self.get_doc_comment(node, source);
+        let stability = self.get_stability(node, source);
+        let attrs = self.get_attrs(node, source);
 
         Some(Item {
             path: format_path(module_path, &name),
             kind: ItemKind::Macro,
             signature: Some(format!("macro_rules! {name}")),
+            signature_detail: None,
             doc,
             visibility: Visibility::Public, // macro_rules! are pub by default if exported
+            generics: Generics::default(),
+            attrs,
+            decorators: vec![],
+            signatures: vec![],
             fields: vec![],
             methods: vec![],
             traits: vec![],
             variants: vec![],
             related: vec![],
-            since: None,
+            unresolved_doc_links: vec![],
+            since: stability.since,
             until: None,
             moved_from: None,
-            deprecated: None,
+            reexport_from: None,
+            deprecated: stability.deprecated,
+            cfg: stability.cfg,
         })
     }
 
@@ -409,6 +549,26 @@ impl RustParser {
         // Check if this is a trait impl
         let trait_name = self.find_impl_trait(node, source);
 
+        // `impl<T: Send> Foo<T>` can add bounds beyond what `struct Foo<T>`
+        // itself requires; fold those into the target's generics so a
+        // narrowed impl bound is visible to the diff engine.
+        let impl_generics = self.parse_generics(node, source);
+        if let Some(type_name) = &type_name {
+            let type_path = format_path(module_path, type_name);
+            if let Some(item) = items.iter_mut().find(|i| i.path == type_path) {
+                let param_bounds = impl_generics
+                    .params
+                    .into_iter()
+                    .filter(|p| !p.bounds.is_empty())
+                    .map(|p| format!("{}: {}", p.name, p.bounds.join(" + ")));
+                for bound in param_bounds.chain(impl_generics.where_clauses) {
+                    if !item.generics.where_clauses.contains(&bound) {
+                        item.generics.where_clauses.push(bound);
+                    }
+                }
+            }
+        }
+
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
             if child.kind() == "declaration_list" {
@@ -441,12 +601,25 @@ impl RustParser {
         }
 
         // If it's a trait impl, record the trait in the struct's traits list
+        // and as an `Implements` relation, so the cross-reference graph
+        // (`Cache::find_references_from`) can walk from a type to the
+        // traits it implements.
         if let (Some(type_name), Some(trait_name)) = (&type_name, &trait_name) {
             let type_path = format_path(module_path, type_name);
             if let Some(item) = items.iter_mut().find(|i| i.path == type_path) {
                 if !item.traits.contains(trait_name) {
                     item.traits.push(trait_name.clone());
                 }
+                if !item
+                    .related
+                    .iter()
+                    .any(|r| r.kind == RelationKind::Implements && r.path == *trait_name)
+                {
+                    item.related.push(Relation {
+                        path: trait_name.clone(),
+                        kind: RelationKind::Implements,
+                    });
+                }
             }
         }
     }
@@ -458,23 +631,33 @@ impl RustParser {
         if let Some(name) = name {
             let new_path = format_path(module_path, &name);
             let doc = self.get_doc_comment(node, source);
+            let stability = self.get_stability(node, source);
+            let attrs = self.get_attrs(node, source);
 
             // Add the module itself as an item
             items.push(Item {
                 path: new_path.clone(),
                 kind: ItemKind::Module,
                 signature: None,
+                signature_detail: None,
                 doc,
                 visibility: vis,
+                generics: Generics::default(),
+                attrs,
+                decorators: vec![],
+                signatures: vec![],
                 fields: vec![],
                 methods: vec![],
                 traits: vec![],
                 variants: vec![],
                 related: vec![],
-                since: None,
+                unresolved_doc_links: vec![],
+                since: stability.since,
                 until: None,
                 moved_from: None,
-                deprecated: None,
+                reexport_from: None,
+                deprecated: stability.deprecated,
+                cfg: stability.cfg,
             });
 
             // Parse items inside the module (two-pass for nested modules too)
@@ -488,6 +671,146 @@ impl RustParser {
         }
     }
 
+    /// Final pass: walk `use_declaration`s (recursing into nested modules like
+    /// `collect_definitions` does) and resolve any `pub use` re-exports found
+    /// along the way against the items already collected.
+    fn collect_reexports(
+        &self,
+        node: Node,
+        source: &str,
+        module_path: &str,
+        items: &mut Vec<Item>,
+    ) {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            match child.kind() {
+                "use_declaration" => {
+                    self.parse_use_declaration(child, source, module_path, items);
+                }
+                "mod_item" => {
+                    if let Some(name) = self.get_child_text(child, "identifier", source) {
+                        let new_path = format_path(module_path, &name);
+                        let mut inner_cursor = child.walk();
+                        for inner in child.children(&mut inner_cursor) {
+                            if inner.kind() == "declaration_list" {
+                                self.collect_reexports(inner, source, &new_path, items);
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Resolve a single `pub use` declaration (plain, renamed, glob, or a
+    /// braced list combining any of those) into synthetic re-export items at
+    /// the path API consumers actually see.
+    fn parse_use_declaration(
+        &self,
+        node: Node,
+        source: &str,
+        module_path: &str,
+        items: &mut Vec<Item>,
+    ) {
+        if self.get_visibility(node, source) != Visibility::Public {
+            return;
+        }
+
+        let Some(argument) = node.child_by_field_name("argument") else {
+            return;
+        };
+
+        let mut targets = Vec::new();
+        self.collect_use_targets(argument, "", source, &mut targets);
+
+        for (raw_path, alias, is_glob) in targets {
+            let target_path = normalize_use_path(&raw_path, module_path);
+
+            if is_glob {
+                let prefix = format!("{target_path}::");
+                let matches: Vec<Item> = items
+                    .iter()
+                    .filter(|item| {
+                        item.path
+                            .strip_prefix(&prefix)
+                            .is_some_and(|rest| !rest.contains("::"))
+                    })
+                    .cloned()
+                    .collect();
+                for source_item in matches {
+                    let name = source_item
+                        .path
+                        .rsplit("::")
+                        .next()
+                        .unwrap_or(&source_item.path)
+                        .to_string();
+                    push_reexport(items, &source_item, &format_path(module_path, &name));
+                }
+            } else if let Some(source_item) = items.iter().find(|i| i.path == target_path).cloned()
+            {
+                push_reexport(items, &source_item, &format_path(module_path, &alias));
+            }
+        }
+    }
+
+    /// Recursively collect `(path, alias, is_glob)` targets out of a `use`
+    /// clause, threading a `prefix` through nested `foo::{...}` lists.
+    fn collect_use_targets(
+        &self,
+        node: Node,
+        prefix: &str,
+        source: &str,
+        out: &mut Vec<(String, String, bool)>,
+    ) {
+        match node.kind() {
+            "identifier" | "self" => {
+                let name = self.get_node_text(node, source);
+                out.push((join_use_path(prefix, &name), name, false));
+            }
+            "scoped_identifier" => {
+                let path = self.get_node_text(node, source);
+                let alias = path.rsplit("::").next().unwrap_or(&path).to_string();
+                out.push((join_use_path(prefix, &path), alias, false));
+            }
+            "use_as_clause" => {
+                let path_text = node
+                    .child_by_field_name("path")
+                    .map(|n| self.get_node_text(n, source))
+                    .unwrap_or_default();
+                let alias = node
+                    .child_by_field_name("alias")
+                    .map(|n| self.get_node_text(n, source))
+                    .unwrap_or_else(|| path_text.clone());
+                out.push((join_use_path(prefix, &path_text), alias, false));
+            }
+            "use_wildcard" => {
+                let path_text = node
+                    .child_by_field_name("path")
+                    .map(|n| self.get_node_text(n, source))
+                    .unwrap_or_default();
+                out.push((join_use_path(prefix, &path_text), String::new(), true));
+            }
+            "scoped_use_list" => {
+                let path_text = node
+                    .child_by_field_name("path")
+                    .map(|n| self.get_node_text(n, source))
+                    .unwrap_or_default();
+                let new_prefix = join_use_path(prefix, &path_text);
+                if let Some(list) = node.child_by_field_name("list") {
+                    self.collect_use_targets(list, &new_prefix, source, out);
+                }
+            }
+            "use_list" => {
+                let mut cursor = node.walk();
+                for child in node.children(&mut cursor) {
+                    self.collect_use_targets(child, prefix, source, out);
+                }
+            }
+            _ => {}
+        }
+    }
+
     fn find_impl_type(&self, node: Node, source: &str) -> Option<String> {
         // The type is usually after "for" in trait impl, or the direct type in inherent impl
         // In `impl Trait for Type`, we want Type (after "for")
@@ -512,7 +835,11 @@ impl RustParser {
 
         // If we didn't find "for", the first_type is the impl target (inherent impl)
         // If we did find "for" but no type after it, something's wrong
-        if !found_for { first_type } else { None }
+        if !found_for {
+            first_type
+        } else {
+            None
+        }
     }
 
     fn find_impl_trait(&self, node: Node, source: &str) -> Option<String> {
@@ -626,6 +953,51 @@ impl RustParser {
         }
     }
 
+    fn get_stability(&self, node: Node, source: &str) -> Stability {
+        // Walk preceding attribute_item siblings, the ones get_doc_comment skips over.
+        let mut stability = Stability::default();
+        let mut current = node.prev_sibling();
+
+        while let Some(sibling) = current {
+            match sibling.kind() {
+                "attribute_item" => {
+                    let text = self.get_node_text(sibling, source);
+                    apply_attribute(&text, &mut stability);
+                }
+                "line_comment" | "block_comment" => {
+                    // Doc comments sit between attributes and the item; keep scanning past them.
+                }
+                _ => break,
+            }
+            current = sibling.prev_sibling();
+        }
+
+        stability
+    }
+
+    /// Collect every outer attribute attached to `node` (the same preceding
+    /// `attribute_item` siblings `get_stability` reads), in source order.
+    fn get_attrs(&self, node: Node, source: &str) -> Vec<Attribute> {
+        let mut attrs = Vec::new();
+        let mut current = node.prev_sibling();
+
+        while let Some(sibling) = current {
+            match sibling.kind() {
+                "attribute_item" => {
+                    if let Some(attr) = parse_attribute(&self.get_node_text(sibling, source)) {
+                        attrs.push(attr);
+                    }
+                }
+                "line_comment" | "block_comment" => {}
+                _ => break,
+            }
+            current = sibling.prev_sibling();
+        }
+
+        attrs.reverse();
+        attrs
+    }
+
     fn get_node_text(&self, node: Node, source: &str) -> String {
         source[node.byte_range()].to_string()
     }
@@ -644,6 +1016,76 @@ impl RustParser {
         node.child_by_field_name(field)
     }
 
+    fn find_child_by_kind<'a>(&self, node: Node<'a>, kind: &str) -> Option<Node<'a>> {
+        let mut cursor = node.walk();
+        node.children(&mut cursor)
+            .find(|child| child.kind() == kind)
+    }
+
+    /// Walk a node's `type_parameters` and `where_clause` children, recording
+    /// each type/const param's name and bounds, lifetime params, and the
+    /// normalized where-clause predicates.
+    fn parse_generics(&self, node: Node, source: &str) -> Generics {
+        let mut generics = Generics::default();
+
+        if let Some(type_params) = self.find_child_by_kind(node, "type_parameters") {
+            let mut cursor = type_params.walk();
+            for child in type_params.children(&mut cursor) {
+                match child.kind() {
+                    "lifetime" => generics.lifetimes.push(self.get_node_text(child, source)),
+                    "constrained_type_parameter" => {
+                        let name = child
+                            .child_by_field_name("left")
+                            .map(|n| self.get_node_text(n, source))
+                            .unwrap_or_default();
+                        let bounds = child
+                            .child_by_field_name("bounds")
+                            .map(|n| parse_bounds(&self.get_node_text(n, source)))
+                            .unwrap_or_default();
+                        generics.params.push(GenericParam {
+                            name,
+                            bounds,
+                            default: None,
+                        });
+                    }
+                    "optional_type_parameter" | "const_parameter" => {
+                        if let Some(name) = self
+                            .get_child_text(child, "identifier", source)
+                            .or_else(|| self.get_child_text(child, "type_identifier", source))
+                        {
+                            generics.params.push(GenericParam {
+                                name,
+                                bounds: vec![],
+                                default: None,
+                            });
+                        }
+                    }
+                    "type_identifier" => {
+                        generics.params.push(GenericParam {
+                            name: self.get_node_text(child, source),
+                            bounds: vec![],
+                            default: None,
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        if let Some(where_clause) = self.find_child_by_kind(node, "where_clause") {
+            let mut cursor = where_clause.walk();
+            for child in where_clause.children(&mut cursor) {
+                if child.kind() == "where_predicate" {
+                    generics
+                        .where_clauses
+                        .push(self.get_node_text(child, source).trim().to_string());
+                }
+            }
+        }
+
+        generics
+    }
+
     fn get_signature_line(&self, node: Node, source: &str) -> String {
         // Get just the signature without the body
         let text = self.get_node_text(node, source);
@@ -653,6 +1095,125 @@ impl RustParser {
             text
         }
     }
+
+    /// Parse a `function_item`/`function_signature_item`'s ordered parameters
+    /// (excluding `self`), return type, and own generics, then compute the
+    /// lifetime-elision-expanded return type per the standard rules: a
+    /// single input lifetime position propagates to elided outputs;
+    /// otherwise `&self`/`&mut self`'s lifetime wins; anything else is left
+    /// unresolved. Only the outermost reference of each parameter/return
+    /// type is modeled - a reference nested inside a generic argument (e.g.
+    /// `Vec<&T>`) isn't tracked as its own elision site.
+    fn parse_signature(&self, node: Node, source: &str) -> Signature {
+        let generics = self.parse_generics(node, source);
+
+        let mut params = Vec::new();
+        let mut self_lifetime: Option<Option<String>> = None;
+        let mut input_lifetimes: Vec<Option<String>> = Vec::new();
+
+        if let Some(parameters) = node.child_by_field_name("parameters") {
+            let mut cursor = parameters.walk();
+            for child in parameters.children(&mut cursor) {
+                match child.kind() {
+                    "self_parameter" => {
+                        if self.get_node_text(child, source).starts_with('&') {
+                            let lifetime = self
+                                .find_child_by_kind(child, "lifetime")
+                                .map(|n| self.get_node_text(n, source));
+                            input_lifetimes.push(lifetime.clone());
+                            self_lifetime = Some(lifetime);
+                        }
+                    }
+                    "parameter" => {
+                        let name = child
+                            .child_by_field_name("pattern")
+                            .map(|n| self.get_node_text(n, source))
+                            .unwrap_or_default();
+                        let ty_node = child.child_by_field_name("type");
+                        if let Some(ty_node) = ty_node.filter(|n| n.kind() == "reference_type") {
+                            let lifetime = self
+                                .find_child_by_kind(ty_node, "lifetime")
+                                .map(|n| self.get_node_text(n, source));
+                            input_lifetimes.push(lifetime);
+                        }
+                        let ty = ty_node.map(|n| self.get_node_text(n, source));
+                        params.push(Param {
+                            name,
+                            ty,
+                            optional: false,
+                            default: None,
+                            rest: false,
+                            decorators: vec![],
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let return_type_node = node.child_by_field_name("return_type");
+        let return_type = return_type_node.map(|n| self.get_node_text(n, source));
+
+        // Rule 2 (single input lifetime) takes priority over rule 3 (self's
+        // lifetime), matching the reference's stated order; rule 3 only
+        // applies when there's more than one input lifetime position.
+        let assigned_lifetime = if input_lifetimes.len() == 1 {
+            input_lifetimes[0]
+                .clone()
+                .or_else(|| Some(synthesize_lifetime(&generics.lifetimes)))
+        } else {
+            self_lifetime.clone().map(|lifetime| {
+                lifetime.unwrap_or_else(|| synthesize_lifetime(&generics.lifetimes))
+            })
+        };
+
+        let expanded_return_type = assigned_lifetime.and_then(|lifetime| {
+            let return_type_node = return_type_node?;
+            if return_type_node.kind() == "reference_type"
+                && self
+                    .find_child_by_kind(return_type_node, "lifetime")
+                    .is_none()
+            {
+                let text = self.get_node_text(return_type_node, source);
+                Some(text.replacen('&', &format!("&{lifetime} "), 1))
+            } else {
+                None
+            }
+        });
+
+        Signature {
+            params,
+            return_type,
+            generics,
+            expanded_return_type,
+        }
+    }
+}
+
+/// Pick a lifetime name not already in `existing`, for rewriting an elided
+/// lifetime into explicit form (e.g. the `'a` in `fn f(x: &Foo) -> &'a Foo`).
+fn synthesize_lifetime(existing: &[String]) -> String {
+    for letter in 'a'..='z' {
+        let candidate = format!("'{letter}");
+        if !existing.iter().any(|l| l == &candidate) {
+            return candidate;
+        }
+    }
+    "'a".to_string()
+}
+
+impl LanguageParser for RustParser {
+    fn parse_source(&mut self, source: &str, module_path: &str) -> Result<Vec<Item>, ParseError> {
+        RustParser::parse_source(self, source, module_path)
+    }
+
+    fn language_id(&self) -> &'static str {
+        "rust"
+    }
+
+    fn handles_extension(&self, ext: &str) -> bool {
+        ext == "rs"
+    }
 }
 
 fn format_path(module_path: &str, name: &str) -> String {
@@ -663,6 +1224,313 @@ fn format_path(module_path: &str, name: &str) -> String {
     }
 }
 
+fn join_use_path(prefix: &str, path: &str) -> String {
+    if prefix.is_empty() {
+        path.to_string()
+    } else {
+        format!("{prefix}::{path}")
+    }
+}
+
+/// Resolve a `use` path's leading `self::`/`super::` relative to the module
+/// it was written in; `crate::`-rooted and already-bare paths pass through.
+fn normalize_use_path(raw: &str, module_path: &str) -> String {
+    if let Some(rest) = raw.strip_prefix("self::") {
+        return format_path(module_path, rest);
+    }
+    if let Some(rest) = raw.strip_prefix("super::") {
+        let parent = module_path
+            .rsplit_once("::")
+            .map(|(parent, _)| parent)
+            .unwrap_or("");
+        return format_path(parent, rest);
+    }
+    raw.to_string()
+}
+
+/// Add a synthetic `Item` at `reexport_path` pointing back at `original` via
+/// `RelationKind::ReExportOf`, so consumers see the path they actually import
+/// rather than the private definition site.
+fn push_reexport(items: &mut Vec<Item>, original: &Item, reexport_path: &str) {
+    if reexport_path == original.path || items.iter().any(|i| i.path == reexport_path) {
+        return;
+    }
+
+    let mut reexported = original.clone();
+    reexported.path = reexport_path.to_string();
+    reexported.visibility = Visibility::Public;
+    reexported.related.push(Relation {
+        path: original.path.clone(),
+        kind: RelationKind::ReExportOf,
+    });
+    items.push(reexported);
+}
+
+/// Scan every item's doc comment for intra-doc link targets (`` [`Type`] ``,
+/// `[Type](crate::mod::Type)`, `[text][anchor]`), resolve each against the
+/// item paths produced in this run, and populate `related`/`unresolved_doc_links`.
+fn resolve_doc_links(items: &mut [Item]) {
+    let known_paths: Vec<String> = items.iter().map(|item| item.path.clone()).collect();
+
+    for idx in 0..items.len() {
+        let Some(doc) = items[idx].doc.clone() else {
+            continue;
+        };
+        let module_path = items[idx]
+            .path
+            .rsplit_once("::")
+            .map(|(module, _)| module.to_string())
+            .unwrap_or_default();
+
+        for link in extract_doc_link_targets(&doc) {
+            match resolve_doc_link(&link, &module_path, &known_paths) {
+                Some(path) if path != items[idx].path => {
+                    if !items[idx].related.iter().any(|r| r.path == path) {
+                        items[idx].related.push(Relation {
+                            path,
+                            kind: RelationKind::DocLink,
+                        });
+                    }
+                }
+                Some(_) => {}
+                None => items[idx].unresolved_doc_links.push(link),
+            }
+        }
+    }
+}
+
+/// Resolve an intra-doc link target against the known item paths: first as
+/// an already-fully-qualified path, then as a sibling in the same module,
+/// then by bare name against any item's last path segment.
+fn resolve_doc_link(raw: &str, module_path: &str, known_paths: &[String]) -> Option<String> {
+    if let Some(path) = known_paths.iter().find(|p| p.as_str() == raw) {
+        return Some(path.clone());
+    }
+
+    let sibling = format_path(module_path, raw);
+    if let Some(path) = known_paths.iter().find(|p| p.as_str() == sibling) {
+        return Some(path.clone());
+    }
+
+    known_paths
+        .iter()
+        .find(|p| p.rsplit("::").next() == Some(raw))
+        .cloned()
+}
+
+/// Extract the raw target text from each Markdown link in a doc comment:
+/// `` [`Type`] `` (shorthand), `[Type](path)` (inline), and `[text][anchor]`
+/// (reference-style, using the anchor as the target).
+fn extract_doc_link_targets(doc: &str) -> Vec<String> {
+    let chars: Vec<char> = doc.chars().collect();
+    let mut targets = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        if chars[i] != '[' {
+            i += 1;
+            continue;
+        }
+        let Some(close) = find_matching_bracket(&chars, i, '[', ']') else {
+            i += 1;
+            continue;
+        };
+        let text: String = chars[i + 1..close].iter().collect();
+        let next = close + 1;
+
+        if chars.get(next) == Some(&'(') {
+            if let Some(paren_close) = find_matching_bracket(&chars, next, '(', ')') {
+                let target: String = chars[next + 1..paren_close].iter().collect();
+                targets.push(strip_link_target(&target));
+                i = paren_close + 1;
+                continue;
+            }
+        } else if chars.get(next) == Some(&'[') {
+            if let Some(bracket_close) = find_matching_bracket(&chars, next, '[', ']') {
+                let anchor: String = chars[next + 1..bracket_close].iter().collect();
+                let target = if anchor.trim().is_empty() {
+                    &text
+                } else {
+                    &anchor
+                };
+                targets.push(strip_link_target(target));
+                i = bracket_close + 1;
+                continue;
+            }
+        } else {
+            let trimmed = text.trim();
+            if trimmed.len() >= 2 && trimmed.starts_with('`') && trimmed.ends_with('`') {
+                targets.push(strip_link_target(trimmed));
+            }
+        }
+
+        i = close + 1;
+    }
+
+    targets
+}
+
+fn strip_link_target(text: &str) -> String {
+    text.trim().trim_matches('`').to_string()
+}
+
+fn find_matching_bracket(
+    chars: &[char],
+    open_idx: usize,
+    open: char,
+    close: char,
+) -> Option<usize> {
+    let mut depth = 0;
+    for (offset, &c) in chars[open_idx..].iter().enumerate() {
+        if c == open {
+            depth += 1;
+        } else if c == close {
+            depth -= 1;
+            if depth == 0 {
+                return Some(open_idx + offset);
+            }
+        }
+    }
+    None
+}
+
+/// Split a `trait_bounds` node's raw text (e.g. `": Clone + Send"`) into
+/// normalized, individually-trimmed bound strings.
+fn parse_bounds(text: &str) -> Vec<String> {
+    text.trim_start_matches(':')
+        .split('+')
+        .map(|bound| bound.trim().to_string())
+        .filter(|bound| !bound.is_empty())
+        .collect()
+}
+
+/// Parse a single `#[...]` attribute's raw text into stability metadata,
+/// recognizing `#[stable(since = "...")]`, `#[deprecated(since = "...",
+/// note = "...")]`, and `#[cfg(...)]`. `#[unstable(...)]` items intentionally
+/// leave `since` unset, since they have no stable-since version.
+fn apply_attribute(text: &str, stability: &mut Stability) {
+    let Some(inner) = text.strip_prefix("#[").and_then(|s| s.strip_suffix(']')) else {
+        return;
+    };
+
+    if let Some(args) = inner.strip_prefix("cfg") {
+        if let Some(predicate) = args
+            .trim()
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+        {
+            stability.cfg = Some(predicate.trim().to_string());
+        }
+        return;
+    }
+
+    if let Some(args) = inner.strip_prefix("deprecated") {
+        stability.deprecated =
+            Some(attr_arg(args, "note").unwrap_or_else(|| "deprecated".to_string()));
+        // Only a fallback: a `#[stable(since)]` on the same item takes priority
+        // whichever order the two attributes are written in.
+        if stability.since.is_none() {
+            if let Some(since) = attr_arg(args, "since") {
+                stability.since = Some(since);
+            }
+        }
+        return;
+    }
+
+    if let Some(args) = inner.strip_prefix("stable") {
+        if let Some(since) = attr_arg(args, "since") {
+            stability.since = Some(since);
+        }
+    }
+}
+
+/// Extract a `key = "value"` argument from a parenthesized attribute argument list.
+fn attr_arg(args: &str, key: &str) -> Option<String> {
+    let args = args.trim().strip_prefix('(')?.strip_suffix(')')?;
+    for part in args.split(',') {
+        if let Some(value) = part
+            .trim()
+            .strip_prefix(key)
+            .and_then(|s| s.trim_start().strip_prefix('='))
+        {
+            return Some(value.trim().trim_matches('"').to_string());
+        }
+    }
+    None
+}
+
+/// Parse a single outer attribute's raw text (e.g. `#[derive(Clone, Debug)]`,
+/// `#[unsafe(no_mangle)]`) into its path, argument tokens, and whether it used
+/// the newer `#[unsafe(...)]` wrapper form.
+fn parse_attribute(text: &str) -> Option<Attribute> {
+    let inner = text.strip_prefix("#[")?.strip_suffix(']')?;
+
+    let (inner, is_unsafe) = match inner
+        .strip_prefix("unsafe")
+        .map(|rest| rest.trim_start())
+        .and_then(|rest| rest.strip_prefix('('))
+        .and_then(|rest| rest.strip_suffix(')'))
+    {
+        Some(unwrapped) => (unwrapped, true),
+        None => (inner, false),
+    };
+
+    let (path, args_text) = match inner.find('(') {
+        Some(open) => {
+            let close = inner.rfind(')').unwrap_or(inner.len());
+            (inner[..open].trim(), &inner[open + 1..close])
+        }
+        None => (inner.trim(), ""),
+    };
+
+    let args = split_top_level(args_text, ',')
+        .into_iter()
+        .map(|arg| arg.trim().to_string())
+        .filter(|arg| !arg.is_empty())
+        .collect();
+
+    Some(Attribute {
+        path: path.to_string(),
+        args,
+        is_unsafe,
+    })
+}
+
+/// Split `text` on `delim`, but only at bracket-depth 0 and outside string
+/// literals, so e.g. `derive(Clone, Debug)`'s args or a `note = "a, b"` value
+/// aren't torn apart by their own internal commas.
+fn split_top_level(text: &str, delim: char) -> Vec<String> {
+    let mut parts = Vec::new();
+    let mut current = String::new();
+    let mut depth = 0i32;
+    let mut in_string = false;
+
+    for c in text.chars() {
+        match c {
+            '"' => {
+                in_string = !in_string;
+                current.push(c);
+            }
+            '(' | '[' | '{' if !in_string => {
+                depth += 1;
+                current.push(c);
+            }
+            ')' | ']' | '}' if !in_string => {
+                depth -= 1;
+                current.push(c);
+            }
+            c if c == delim && depth == 0 && !in_string => {
+                parts.push(std::mem::take(&mut current));
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.trim().is_empty() {
+        parts.push(current);
+    }
+    parts
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -738,6 +1606,453 @@ impl Foo {
         assert_eq!(item.methods[0].name, "new");
     }
 
+    #[test]
+    fn test_parse_deprecated_and_cfg_attrs() {
+        let source = r#"
+/// A gated widget.
+#[cfg(feature = "widgets")]
+#[deprecated(since = "1.2.0", note = "use NewWidget instead")]
+pub struct Widget;
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.since, Some("1.2.0".into()));
+        assert_eq!(item.deprecated, Some("use NewWidget instead".into()));
+        assert_eq!(item.cfg, Some("feature = \"widgets\"".into()));
+    }
+
+    #[test]
+    fn test_parse_stable_attr() {
+        let source = r#"
+#[stable(feature = "rust1", since = "1.0.0")]
+pub fn stable_fn() {}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.since, Some("1.0.0".into()));
+        assert_eq!(item.deprecated, None);
+    }
+
+    #[test]
+    fn test_parse_struct_generics_and_where_clause() {
+        let source = r#"
+pub struct Cache<'a, K, V>
+where
+    K: Eq + std::hash::Hash,
+{
+    key: &'a K,
+    value: V,
+}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.generics.lifetimes, vec!["'a".to_string()]);
+        assert_eq!(item.generics.params.len(), 2);
+        assert_eq!(item.generics.params[0].name, "K");
+        assert_eq!(item.generics.params[1].name, "V");
+        assert_eq!(item.generics.where_clauses.len(), 1);
+        assert_eq!(item.generics.where_clauses[0], "K: Eq + std::hash::Hash");
+    }
+
+    #[test]
+    fn test_parse_function_constrained_type_param() {
+        let source = r#"
+pub fn largest<T: PartialOrd + Copy>(items: &[T]) -> T {
+    items[0]
+}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.generics.params.len(), 1);
+        assert_eq!(item.generics.params[0].name, "T");
+        assert_eq!(
+            item.generics.params[0].bounds,
+            vec!["PartialOrd".to_string(), "Copy".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_parse_impl_bound_folds_into_item_generics() {
+        let source = r#"
+pub struct Wrapper<T>(T);
+
+impl<T: Send> Wrapper<T> {
+    pub fn noop(&self) {}
+}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert!(item.generics.where_clauses.contains(&"T: Send".to_string()));
+    }
+
+    #[test]
+    fn test_doc_links_resolve_to_related_items() {
+        let source = r#"
+/// The primary error type. See [`Config`] and [Widget](crate::Widget) for
+/// related types, or [the widget][Widget].
+pub struct AppError;
+
+/// App configuration.
+pub struct Config;
+
+/// A UI widget.
+pub struct Widget;
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        let error = items.iter().find(|i| i.path == "crate::AppError").unwrap();
+        let related: Vec<_> = error.related.iter().map(|r| r.path.as_str()).collect();
+        assert!(related.contains(&"crate::Config"));
+        assert!(related.contains(&"crate::Widget"));
+        assert!(error.unresolved_doc_links.is_empty());
+    }
+
+    #[test]
+    fn test_unresolved_doc_link_is_kept() {
+        let source = r#"
+/// See [`Missing`] for details.
+pub struct Foo;
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        let item = &items[0];
+        assert!(item.related.is_empty());
+        assert_eq!(item.unresolved_doc_links, vec!["Missing".to_string()]);
+    }
+
+    #[test]
+    fn test_pub_use_reexport_rewrites_public_path() {
+        let source = r#"
+mod internal {
+    /// The real widget.
+    pub struct Widget;
+}
+
+pub use internal::Widget;
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        let reexport = items
+            .iter()
+            .find(|i| i.path == "crate::Widget")
+            .expect("re-export should appear at the public path");
+        assert_eq!(reexport.kind, ItemKind::Struct);
+        assert!(reexport
+            .related
+            .iter()
+            .any(|r| r.path == "crate::internal::Widget" && r.kind == RelationKind::ReExportOf));
+
+        // The private definition is still present at its own path too.
+        assert!(items.iter().any(|i| i.path == "crate::internal::Widget"));
+    }
+
+    #[test]
+    fn test_pub_use_renamed_reexport() {
+        let source = r#"
+mod internal {
+    pub struct Old;
+}
+
+pub use internal::Old as New;
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        let reexport = items
+            .iter()
+            .find(|i| i.path == "crate::New")
+            .expect("renamed re-export should appear under the alias");
+        assert!(reexport
+            .related
+            .iter()
+            .any(|r| r.path == "crate::internal::Old"));
+    }
+
+    #[test]
+    fn test_pub_use_glob_reexport() {
+        let source = r#"
+mod internal {
+    pub struct Foo;
+    pub struct Bar;
+}
+
+pub use internal::*;
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        assert!(items.iter().any(|i| i.path == "crate::Foo"));
+        assert!(items.iter().any(|i| i.path == "crate::Bar"));
+    }
+
+    #[test]
+    fn test_private_use_is_not_reexported() {
+        let source = r#"
+mod internal {
+    pub struct Foo;
+}
+
+use internal::Foo;
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        assert!(!items.iter().any(|i| i.path == "crate::Foo"));
+        assert!(items.iter().any(|i| i.path == "crate::internal::Foo"));
+    }
+
+    #[test]
+    fn test_pub_use_grouped_list_with_rename() {
+        let source = r#"
+mod internal {
+    pub struct Foo;
+    pub struct Bar;
+}
+
+pub use internal::{Foo, Bar as Baz};
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        assert!(items.iter().any(|i| i.path == "crate::Foo"));
+        let reexport = items
+            .iter()
+            .find(|i| i.path == "crate::Baz")
+            .expect("renamed member of a grouped use list should still be re-exported");
+        assert!(reexport
+            .related
+            .iter()
+            .any(|r| r.path == "crate::internal::Bar"));
+    }
+
+    #[test]
+    fn test_pub_use_super_prefix_reexport() {
+        let source = r#"
+pub struct Root;
+
+mod internal {
+    pub use super::Root as RootAlias;
+}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        let reexport = items
+            .iter()
+            .find(|i| i.path == "crate::internal::RootAlias")
+            .expect("super:: prefix should resolve against the enclosing module");
+        assert!(reexport
+            .related
+            .iter()
+            .any(|r| r.path == "crate::Root" && r.kind == RelationKind::ReExportOf));
+    }
+
+    #[test]
+    fn test_parse_tuple_struct_fields() {
+        let source = r#"
+pub struct Bar(
+    /// The label.
+    pub String,
+    i32,
+);
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.fields.len(), 2);
+        assert_eq!(item.fields[0].name, "0");
+        assert_eq!(item.fields[0].ty, Some("String".to_string()));
+        assert_eq!(item.fields[0].doc, Some("The label.".to_string()));
+        assert_eq!(item.fields[0].visibility, Visibility::Public);
+        assert_eq!(item.fields[1].name, "1");
+        assert_eq!(item.fields[1].visibility, Visibility::Private);
+    }
+
+    #[test]
+    fn test_parse_tuple_variant_fields() {
+        let source = r#"
+pub enum MyResult<T, E> {
+    Ok(T),
+    Err(E),
+}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.variants[0].fields.len(), 1);
+        assert_eq!(item.variants[0].fields[0].name, "0");
+        assert_eq!(item.variants[0].fields[0].ty, Some("T".to_string()));
+    }
+
+    #[test]
+    fn test_parse_field_and_variant_multiline_docs() {
+        let source = r#"
+pub struct Config {
+    /// The connection timeout.
+    /// Measured in milliseconds.
+    pub timeout_ms: u32,
+}
+
+pub enum Status {
+    /// Everything is fine.
+    /// No action needed.
+    Ok,
+    /// Something went wrong.
+    Err,
+}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        let config = items.iter().find(|i| i.path == "crate::Config").unwrap();
+        assert_eq!(
+            config.fields[0].doc,
+            Some("The connection timeout.\nMeasured in milliseconds.".to_string())
+        );
+
+        let status = items.iter().find(|i| i.path == "crate::Status").unwrap();
+        assert_eq!(
+            status.variants[0].doc,
+            Some("Everything is fine.\nNo action needed.".to_string())
+        );
+        assert_eq!(
+            status.variants[1].doc,
+            Some("Something went wrong.".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_derive_and_cfg_attrs() {
+        let source = r#"
+#[derive(Clone, Debug)]
+#[cfg(test)]
+pub struct Config {
+    pub timeout_ms: u32,
+}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.attrs.len(), 2);
+        assert_eq!(item.attrs[0].path, "derive");
+        assert_eq!(
+            item.attrs[0].args,
+            vec!["Clone".to_string(), "Debug".to_string()]
+        );
+        assert!(!item.attrs[0].is_unsafe);
+        assert_eq!(item.attrs[1].path, "cfg");
+        assert_eq!(item.attrs[1].args, vec!["test".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_unsafe_attribute_wrapper() {
+        let source = r#"
+#[unsafe(no_mangle)]
+pub extern "C" fn my_func() {}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.attrs.len(), 1);
+        assert_eq!(item.attrs[0].path, "no_mangle");
+        assert!(item.attrs[0].args.is_empty());
+        assert!(item.attrs[0].is_unsafe);
+    }
+
+    #[test]
+    fn test_parse_function_signature_params_and_return_type() {
+        let source = r#"
+pub fn add<T: Copy>(a: i32, b: T) -> i32 {
+    a
+}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let detail = items[0].signature_detail.as_ref().unwrap();
+        assert_eq!(detail.params.len(), 2);
+        assert_eq!(detail.params[0].name, "a");
+        assert_eq!(detail.params[0].ty, Some("i32".to_string()));
+        assert_eq!(detail.params[1].name, "b");
+        assert_eq!(detail.params[1].ty, Some("T".to_string()));
+        assert_eq!(detail.return_type, Some("i32".to_string()));
+        assert_eq!(detail.generics.params[0].name, "T");
+    }
+
+    #[test]
+    fn test_elision_single_input_lifetime_propagates_to_output() {
+        let source = r#"
+pub fn first_word(s: &str) -> &str {
+    s
+}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        let detail = items[0].signature_detail.as_ref().unwrap();
+        assert_eq!(detail.expanded_return_type, Some("&'a str".to_string()));
+    }
+
+    #[test]
+    fn test_elision_self_lifetime_wins_with_multiple_inputs() {
+        let source = r#"
+pub struct Parser;
+
+impl Parser {
+    pub fn peek(&self, other: &str) -> &str {
+        other
+    }
+}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        let item = items.iter().find(|i| i.path == "crate::Parser").unwrap();
+        let detail = item.methods[0].signature_detail.as_ref().unwrap();
+        assert_eq!(detail.expanded_return_type, Some("&'a str".to_string()));
+    }
+
+    #[test]
+    fn test_elision_ambiguous_multiple_inputs_no_self_left_unresolved() {
+        let source = r#"
+pub fn pick(a: &str, b: &str) -> &str {
+    a
+}
+"#;
+        let mut parser = RustParser::new().unwrap();
+        let items = parser.parse_source(source, "crate").unwrap();
+
+        let detail = items[0].signature_detail.as_ref().unwrap();
+        assert_eq!(detail.expanded_return_type, None);
+    }
+
     #[test]
     fn test_parse_trait_impl() {
         let source = r#"
@@ -3,17 +3,13 @@
 //! Extracts API surface from TypeScript/JavaScript source files, producing the universal
 //! `schema::Item` format.
 
-use crate::schema::{Field, Item, ItemKind, Method, Variant, Visibility};
-use thiserror::Error;
-use tree_sitter::{Node, Parser, Tree};
-
-#[derive(Debug, Error)]
-pub enum ParseError {
-    #[error("tree-sitter initialization failed")]
-    TreeSitterInit,
-    #[error("failed to parse source")]
-    ParseFailed,
-}
+use crate::languages::{LanguageParser, ParseError};
+use crate::schema::{
+    Field, GenericParam, Generics, Item, ItemKind, Method, Param, Relation, RelationKind,
+    Signature, Variant, Visibility,
+};
+use std::collections::HashMap;
+use tree_sitter::{InputEdit, Node, Parser, Tree};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TsLanguage {
@@ -22,8 +18,27 @@ pub enum TsLanguage {
     JavaScript,
 }
 
+impl TsLanguage {
+    /// Whether this language variant is the one conventionally parsed from `ext`.
+    fn handles_extension(&self, ext: &str) -> bool {
+        match self {
+            TsLanguage::TypeScript => ext == "ts",
+            TsLanguage::Tsx => ext == "tsx" || ext == "jsx",
+            TsLanguage::JavaScript => matches!(ext, "js" | "mjs" | "cjs"),
+        }
+    }
+}
+
 pub struct TypeScriptParser {
     parser: Parser,
+    language: TsLanguage,
+    /// The last successfully parsed `Tree` per module path, retained so
+    /// `parse_incremental` can reuse unchanged subtrees instead of
+    /// reparsing the whole file.
+    trees: HashMap<String, Tree>,
+    /// Whether top-level declarations without an explicit `export` keyword
+    /// are still implicitly public, as in a `.d.ts` ambient context.
+    ambient: bool,
 }
 
 impl TypeScriptParser {
@@ -37,10 +52,25 @@ impl TypeScriptParser {
         parser
             .set_language(&ts_language.into())
             .map_err(|_| ParseError::TreeSitterInit)?;
-        Ok(Self { parser })
+        Ok(Self {
+            parser,
+            language,
+            trees: HashMap::new(),
+            ambient: false,
+        })
+    }
+
+    /// Mark this parser as parsing an ambient (`.d.ts`-style) source, where
+    /// top-level declarations are implicitly part of the public API even
+    /// without an `export` keyword.
+    pub fn with_ambient(mut self, ambient: bool) -> Self {
+        self.ambient = ambient;
+        self
     }
 
     /// Parse a TypeScript source file and extract all exported API items.
+    /// Always reparses from scratch; retains the resulting tree under
+    /// `module_path` so a later `parse_incremental` call can reuse it.
     pub fn parse_source(
         &mut self,
         source: &str,
@@ -53,6 +83,48 @@ impl TypeScriptParser {
 
         let mut items = Vec::new();
         self.extract_items(&tree, source, module_path, &mut items);
+        let mut items = merge_overloads(items);
+        resolve_doc_links(&mut items);
+        self.trees.insert(module_path.to_string(), tree);
+        Ok(items)
+    }
+
+    /// Reparse `new_source` incrementally against the `Tree` retained from
+    /// the last `parse_source`/`parse_incremental` call for `module_path`,
+    /// reusing unchanged subtrees in roughly O(edit size) instead of
+    /// reparsing from scratch. Each of `edits` is applied to the retained
+    /// tree via `Tree::edit` before it's handed to the parser as a reuse
+    /// hint - callers must supply one `InputEdit` per byte range that
+    /// changed since that tree was produced, in the order the edits were
+    /// made, or the reparse can silently produce a corrupt tree. If no tree
+    /// is retained for `module_path` (or `edits` is empty), this falls back
+    /// to a full reparse, same as `parse_source`.
+    pub fn parse_incremental(
+        &mut self,
+        new_source: &str,
+        module_path: &str,
+        edits: &[InputEdit],
+    ) -> Result<Vec<Item>, ParseError> {
+        let mut old_tree = self
+            .trees
+            .remove(module_path)
+            .filter(|_| !edits.is_empty());
+        if let Some(tree) = old_tree.as_mut() {
+            for edit in edits {
+                tree.edit(edit);
+            }
+        }
+
+        let tree = self
+            .parser
+            .parse(new_source, old_tree.as_ref())
+            .ok_or(ParseError::ParseFailed)?;
+
+        let mut items = Vec::new();
+        self.extract_items(&tree, new_source, module_path, &mut items);
+        let mut items = merge_overloads(items);
+        resolve_doc_links(&mut items);
+        self.trees.insert(module_path.to_string(), tree);
         Ok(items)
     }
 
@@ -75,9 +147,23 @@ impl TypeScriptParser {
                 "export_statement" => {
                     self.parse_export_statement(child, source, module_path, items);
                 }
-                // Module/namespace declaration
+                // Module/namespace declaration, not itself exported - its
+                // contents are still walked to build paths, but every item
+                // found inside is downgraded to private.
                 "module" | "internal_module" => {
-                    self.parse_module(child, source, module_path, items);
+                    self.parse_module(child, source, module_path, false, items);
+                }
+                // `declare function/const/class/...`, `declare global { ... }`,
+                // and `declare module "name" { ... }` are ambient - the
+                // `declare` keyword itself puts them on the public API
+                // surface regardless of `export`.
+                "ambient_declaration" => {
+                    self.parse_ambient_declaration(child, source, module_path, items);
+                }
+                // In an ambient (`.d.ts`) context, top-level declarations are
+                // implicitly public even without `export` or `declare`.
+                _ if self.ambient => {
+                    self.parse_ambient_item(child, source, module_path, items);
                 }
                 // Skip standalone declarations - we only care about exports for TypeScript
                 // (non-exported items are private implementation details)
@@ -86,6 +172,89 @@ impl TypeScriptParser {
         }
     }
 
+    /// Parse a `declare ...` ambient declaration. `declare global { ... }`
+    /// augments the enclosing scope in place (its members land under
+    /// `module_path`, not a nested namespace); any other child is dispatched
+    /// through `parse_ambient_item`, which also covers `declare module
+    /// "name" { ... }` external module augmentation via `parse_module`.
+    fn parse_ambient_declaration(
+        &self,
+        node: Node,
+        source: &str,
+        module_path: &str,
+        items: &mut Vec<Item>,
+    ) {
+        let mut cursor = node.walk();
+        let mut saw_global = false;
+
+        for child in node.children(&mut cursor) {
+            match child.kind() {
+                "global" => saw_global = true,
+                "statement_block" if saw_global => {
+                    // Every member is public, with or without `export`.
+                    let mut block_cursor = child.walk();
+                    for member in child.children(&mut block_cursor) {
+                        if member.kind() == "export_statement" {
+                            self.parse_export_statement(member, source, module_path, items);
+                        } else {
+                            self.parse_ambient_item(member, source, module_path, items);
+                        }
+                    }
+                }
+                _ => self.parse_ambient_item(child, source, module_path, items),
+            }
+        }
+    }
+
+    /// Dispatch a single declaration node as a public `Item`, shared by
+    /// ambient top-level declarations (implicit `.d.ts` exports) and
+    /// `declare ...` ambient declarations.
+    fn parse_ambient_item(
+        &self,
+        node: Node,
+        source: &str,
+        module_path: &str,
+        items: &mut Vec<Item>,
+    ) {
+        match node.kind() {
+            // `function_signature` is a bodyless overload declaration for a
+            // `function_declaration` of the same name; `merge_overloads`
+            // collapses the two into one item.
+            "function_declaration" | "function_signature" => {
+                if let Some(item) = self.parse_function(node, source, module_path, true) {
+                    items.push(item);
+                }
+            }
+            "class_declaration" | "abstract_class_declaration" => {
+                if let Some(item) = self.parse_class(node, source, module_path, true) {
+                    items.push(item);
+                }
+            }
+            "interface_declaration" => {
+                if let Some(item) = self.parse_interface(node, source, module_path, true) {
+                    items.push(item);
+                }
+            }
+            "type_alias_declaration" => {
+                if let Some(item) = self.parse_type_alias(node, source, module_path, true) {
+                    items.push(item);
+                }
+            }
+            "lexical_declaration" | "variable_declaration" => {
+                self.parse_lexical_declaration(node, source, module_path, true, items);
+            }
+            "enum_declaration" => {
+                if let Some(item) = self.parse_enum(node, source, module_path, true) {
+                    items.push(item);
+                }
+            }
+            "module" | "internal_module" => {
+                self.parse_module(node, source, module_path, true, items);
+            }
+            _ => {}
+        }
+    }
+
     fn parse_export_statement(
         &self,
         node: Node,
@@ -94,42 +263,59 @@ impl TypeScriptParser {
         items: &mut Vec<Item>,
     ) {
         // Get the doc comment from the export statement itself
-        let export_doc = self.get_doc_comment(node, source);
+        let export_jsdoc = self.parse_doc_tags(node, source);
+        let export_doc = export_jsdoc.summary.clone();
+
+        // `export ... from '<source>'` forms (named re-exports and `export *`
+        // barrels) carry the module specifier as a `source` field directly on
+        // the export statement.
+        let reexport_source = node
+            .child_by_field_name("source")
+            .map(|n| self.get_string_value(n, source));
+
+        let mut has_star = false;
 
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
             match child.kind() {
+                "*" => has_star = true,
+                "export_clause" => {
+                    self.parse_export_clause(
+                        child,
+                        source,
+                        module_path,
+                        reexport_source.as_deref(),
+                        export_doc.as_deref(),
+                        items,
+                    );
+                }
                 "class_declaration" | "abstract_class_declaration" => {
                     if let Some(mut item) = self.parse_class(child, source, module_path, true) {
-                        // Use export doc if the item doesn't have its own doc
-                        if item.doc.is_none() {
-                            item.doc = export_doc.clone();
+                        apply_jsdoc_fallback(&mut item, &export_jsdoc);
+                        // `@Decorator export class X` hangs its decorators on
+                        // the export statement rather than on the class.
+                        if item.decorators.is_empty() {
+                            item.decorators = self.parse_decorators(node, source);
                         }
                         items.push(item);
                     }
                 }
                 "interface_declaration" => {
                     if let Some(mut item) = self.parse_interface(child, source, module_path, true) {
-                        if item.doc.is_none() {
-                            item.doc = export_doc.clone();
-                        }
+                        apply_jsdoc_fallback(&mut item, &export_jsdoc);
                         items.push(item);
                     }
                 }
                 "type_alias_declaration" => {
                     if let Some(mut item) = self.parse_type_alias(child, source, module_path, true)
                     {
-                        if item.doc.is_none() {
-                            item.doc = export_doc.clone();
-                        }
+                        apply_jsdoc_fallback(&mut item, &export_jsdoc);
                         items.push(item);
                     }
                 }
-                "function_declaration" => {
+                "function_declaration" | "function_signature" => {
                     if let Some(mut item) = self.parse_function(child, source, module_path, true) {
-                        if item.doc.is_none() {
-                            item.doc = export_doc.clone();
-                        }
+                        apply_jsdoc_fallback(&mut item, &export_jsdoc);
                         items.push(item);
                     }
                 }
@@ -138,15 +324,137 @@ impl TypeScriptParser {
                 }
                 "enum_declaration" => {
                     if let Some(mut item) = self.parse_enum(child, source, module_path, true) {
-                        if item.doc.is_none() {
-                            item.doc = export_doc.clone();
-                        }
+                        apply_jsdoc_fallback(&mut item, &export_jsdoc);
                         items.push(item);
                     }
                 }
+                "module" | "internal_module" => {
+                    let before = items.len();
+                    self.parse_module(child, source, module_path, true, items);
+                    if let Some(item) = items.get_mut(before) {
+                        apply_jsdoc_fallback(item, &export_jsdoc);
+                    }
+                }
                 _ => {}
             }
         }
+
+        if has_star {
+            if let Some(source_specifier) = reexport_source {
+                let alias = node
+                    .child_by_field_name("alias")
+                    .map(|n| self.get_node_text(n, source));
+
+                let (path, signature) = match &alias {
+                    Some(name) => (
+                        format_path(module_path, name),
+                        format!("export * as {} from '{}'", name, source_specifier),
+                    ),
+                    None => (
+                        module_path.to_string(),
+                        format!("export * from '{}'", source_specifier),
+                    ),
+                };
+
+                items.push(Item {
+                    path,
+                    kind: ItemKind::Module,
+                    signature: Some(signature),
+                    signature_detail: None,
+                    doc: export_doc,
+                    visibility: Visibility::Public,
+                    generics: Generics::default(),
+                    attrs: vec![],
+                    decorators: vec![],
+                    signatures: vec![],
+                    fields: vec![],
+                    methods: vec![],
+                    traits: vec![],
+                    variants: vec![],
+                    related: jsdoc_related(&export_jsdoc),
+                    unresolved_doc_links: vec![],
+                    since: export_jsdoc.since.clone(),
+                    until: None,
+                    moved_from: None,
+                    reexport_from: Some(source_specifier),
+                    deprecated: export_jsdoc.deprecated.clone(),
+                    cfg: None,
+                });
+            }
+        }
+    }
+
+    /// Resolve an `export { Foo, Bar as Baz } from '<source>'` clause into
+    /// synthetic, unresolved re-export `Item`s: one per specifier, recording
+    /// the original name via `moved_from` (when aliased) and the module
+    /// specifier via `reexport_from`. The items carry no fields/methods of
+    /// their own; nothing here resolves them against the source module.
+    fn parse_export_clause(
+        &self,
+        node: Node,
+        source: &str,
+        module_path: &str,
+        reexport_source: Option<&str>,
+        export_doc: Option<&str>,
+        items: &mut Vec<Item>,
+    ) {
+        let Some(reexport_source) = reexport_source else {
+            // A bare `export { Foo, Bar as Baz }` (no `from`) re-exports
+            // local declarations, which are already emitted by this same
+            // `parse_export_statement` pass; nothing to synthesize.
+            return;
+        };
+
+        let mut cursor = node.walk();
+        for specifier in node.children(&mut cursor) {
+            if specifier.kind() != "export_specifier" {
+                continue;
+            }
+
+            let Some(name) = specifier
+                .child_by_field_name("name")
+                .map(|n| self.get_node_text(n, source))
+            else {
+                continue;
+            };
+            let alias = specifier
+                .child_by_field_name("alias")
+                .map(|n| self.get_node_text(n, source));
+
+            let exported_name = alias.clone().unwrap_or_else(|| name.clone());
+            let signature = match &alias {
+                Some(alias) => format!(
+                    "export {{ {} as {} }} from '{}'",
+                    name, alias, reexport_source
+                ),
+                None => format!("export {{ {} }} from '{}'", name, reexport_source),
+            };
+
+            items.push(Item {
+                path: format_path(module_path, &exported_name),
+                kind: ItemKind::Module,
+                signature: Some(signature),
+                signature_detail: None,
+                doc: export_doc.map(str::to_string),
+                visibility: Visibility::Public,
+                generics: Generics::default(),
+                attrs: vec![],
+                decorators: vec![],
+                signatures: vec![],
+                fields: vec![],
+                methods: vec![],
+                traits: vec![],
+                variants: vec![],
+                related: vec![],
+                unresolved_doc_links: vec![],
+                since: None,
+                until: None,
+                moved_from: if alias.is_some() { Some(name) } else { None },
+                reexport_from: Some(reexport_source.to_string()),
+                deprecated: None,
+                cfg: None,
+            });
+        }
     }
 
     fn parse_class(
@@ -157,9 +465,11 @@ impl TypeScriptParser {
         exported: bool,
     ) -> Option<Item> {
         let name = self.get_child_text(node, "type_identifier", source)?;
-        let doc = self.get_doc_comment(node, source);
+        let jsdoc = self.parse_doc_tags(node, source);
         let signature = self.get_class_signature(node, source);
         let is_abstract = node.kind() == "abstract_class_declaration";
+        let generics = self.parse_type_parameters(node, source);
+        let decorators = self.parse_decorators(node, source);
 
         // Parse class body for methods and fields
         let (methods, fields) = self.parse_class_body(node, source);
@@ -175,21 +485,29 @@ impl TypeScriptParser {
             } else {
                 format!("class {}", signature)
             }),
-            doc,
+            signature_detail: None,
+            doc: jsdoc.summary.clone(),
             visibility: if exported {
                 Visibility::Public
             } else {
                 Visibility::Private
             },
+            generics,
+            attrs: vec![],
+            decorators,
+            signatures: vec![],
             fields,
             methods,
             traits,
             variants: vec![],
-            related: vec![],
-            since: None,
+            related: jsdoc_related(&jsdoc),
+            unresolved_doc_links: vec![],
+            since: jsdoc.since.clone(),
             until: None,
             moved_from: None,
-            deprecated: None,
+            reexport_from: None,
+            deprecated: jsdoc.deprecated.clone(),
+            cfg: None,
         })
     }
 
@@ -203,7 +521,10 @@ impl TypeScriptParser {
                 let mut body_cursor = child.walk();
                 for member in child.children(&mut body_cursor) {
                     match member.kind() {
-                        "method_definition" => {
+                        // `method_signature` is a bodyless overload
+                        // declaration for a `method_definition` of the same
+                        // name; `merge_overloads` collapses them into one.
+                        "method_definition" | "method_signature" => {
                             if let Some(method) = self.parse_method(member, source) {
                                 methods.push(method);
                             }
@@ -219,20 +540,27 @@ impl TypeScriptParser {
             }
         }
 
-        (methods, fields)
+        (merge_method_overloads(methods), fields)
     }
 
     fn parse_method(&self, node: Node, source: &str) -> Option<Method> {
         let name = self.get_method_name(node, source)?;
         let vis = self.get_member_visibility(node, source);
-        let doc = self.get_doc_comment(node, source);
+        let doc = self.doc_summary(node, source);
         let signature = self.get_method_signature(node, source);
+        let signature_detail = self.parse_signature(node, source);
+        let generics = self.parse_type_parameters(node, source);
+        let decorators = self.parse_decorators(node, source);
 
         Some(Method {
             name,
             signature: Some(signature),
+            signature_detail: Some(signature_detail),
+            signatures: vec![],
             doc,
             visibility: vis,
+            generics,
+            decorators,
         })
     }
 
@@ -240,16 +568,32 @@ impl TypeScriptParser {
         let name = self.get_property_name(node, source)?;
         let vis = self.get_member_visibility(node, source);
         let ty = self.get_type_annotation(node, source);
-        let doc = self.get_doc_comment(node, source);
+        let doc = self.doc_summary(node, source);
+        let decorators = self.parse_decorators(node, source);
 
         Some(Field {
             name,
             ty,
             doc,
             visibility: vis,
+            decorators,
         })
     }
 
+    /// Collect `@Decorator(...)` nodes attached directly to `node` (a class,
+    /// method, property, or parameter-property declaration), verbatim
+    /// including arguments, in source order.
+    fn parse_decorators(&self, node: Node, source: &str) -> Vec<String> {
+        let mut decorators = Vec::new();
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if child.kind() == "decorator" {
+                decorators.push(self.get_node_text(child, source));
+            }
+        }
+        decorators
+    }
+
     fn parse_class_heritage(&self, node: Node, source: &str) -> Vec<String> {
         let mut traits = Vec::new();
 
@@ -292,8 +636,9 @@ impl TypeScriptParser {
         exported: bool,
     ) -> Option<Item> {
         let name = self.get_child_text(node, "type_identifier", source)?;
-        let doc = self.get_doc_comment(node, source);
+        let jsdoc = self.parse_doc_tags(node, source);
         let signature = self.get_interface_signature(node, source);
+        let generics = self.parse_type_parameters(node, source);
 
         // Parse interface body for methods and properties
         let (methods, fields) = self.parse_interface_body(node, source);
@@ -305,21 +650,29 @@ impl TypeScriptParser {
             path: format_path(module_path, &name),
             kind: ItemKind::Trait,
             signature: Some(format!("interface {}", signature)),
-            doc,
+            signature_detail: None,
+            doc: jsdoc.summary.clone(),
             visibility: if exported {
                 Visibility::Public
             } else {
                 Visibility::Private
             },
+            generics,
+            attrs: vec![],
+            decorators: vec![],
+            signatures: vec![],
             fields,
             methods,
             traits,
             variants: vec![],
-            related: vec![],
-            since: None,
+            related: jsdoc_related(&jsdoc),
+            unresolved_doc_links: vec![],
+            since: jsdoc.since.clone(),
             until: None,
             moved_from: None,
-            deprecated: None,
+            reexport_from: None,
+            deprecated: jsdoc.deprecated.clone(),
+            cfg: None,
         })
     }
 
@@ -349,34 +702,40 @@ impl TypeScriptParser {
             }
         }
 
-        (methods, fields)
+        (merge_method_overloads(methods), fields)
     }
 
     fn parse_interface_method(&self, node: Node, source: &str) -> Option<Method> {
         let name = self
             .get_property_name(node, source)
             .unwrap_or_else(|| "call".to_string());
-        let doc = self.get_doc_comment(node, source);
-        let signature = self.get_node_text(node, source);
+        let doc = self.doc_summary(node, source);
+        let signature = self.signature_text_excluding_body(node, source);
+        let generics = self.parse_type_parameters(node, source);
 
         Some(Method {
             name,
             signature: Some(signature),
+            signature_detail: None,
             doc,
             visibility: Visibility::Public,
+            generics,
+            decorators: vec![],
+            signatures: vec![],
         })
     }
 
     fn parse_interface_property(&self, node: Node, source: &str) -> Option<Field> {
         let name = self.get_property_name(node, source)?;
         let ty = self.get_type_annotation(node, source);
-        let doc = self.get_doc_comment(node, source);
+        let doc = self.doc_summary(node, source);
 
         Some(Field {
             name,
             ty,
             doc,
             visibility: Visibility::Public,
+            decorators: vec![],
         })
     }
 
@@ -406,28 +765,37 @@ impl TypeScriptParser {
         exported: bool,
     ) -> Option<Item> {
         let name = self.get_child_text(node, "type_identifier", source)?;
-        let doc = self.get_doc_comment(node, source);
+        let jsdoc = self.parse_doc_tags(node, source);
         let signature = self.get_node_text(node, source);
+        let generics = self.parse_type_parameters(node, source);
 
         Some(Item {
             path: format_path(module_path, &name),
             kind: ItemKind::TypeAlias,
             signature: Some(signature),
-            doc,
+            signature_detail: None,
+            doc: jsdoc.summary.clone(),
             visibility: if exported {
                 Visibility::Public
             } else {
                 Visibility::Private
             },
+            generics,
+            attrs: vec![],
+            decorators: vec![],
+            signatures: vec![],
             fields: vec![],
             methods: vec![],
             traits: vec![],
             variants: vec![],
-            related: vec![],
-            since: None,
+            related: jsdoc_related(&jsdoc),
+            unresolved_doc_links: vec![],
+            since: jsdoc.since.clone(),
             until: None,
             moved_from: None,
-            deprecated: None,
+            reexport_from: None,
+            deprecated: jsdoc.deprecated.clone(),
+            cfg: None,
         })
     }
 
@@ -439,28 +807,38 @@ impl TypeScriptParser {
         exported: bool,
     ) -> Option<Item> {
         let name = self.get_child_text(node, "identifier", source)?;
-        let doc = self.get_doc_comment(node, source);
+        let jsdoc = self.parse_doc_tags(node, source);
         let signature = self.get_function_signature(node, source);
+        let signature_detail = self.parse_signature(node, source);
+        let generics = self.parse_type_parameters(node, source);
 
         Some(Item {
             path: format_path(module_path, &name),
             kind: ItemKind::Function,
             signature: Some(signature),
-            doc,
+            signature_detail: Some(signature_detail),
+            doc: jsdoc.summary.clone(),
             visibility: if exported {
                 Visibility::Public
             } else {
                 Visibility::Private
             },
+            generics,
+            attrs: vec![],
+            decorators: vec![],
+            signatures: vec![],
             fields: vec![],
             methods: vec![],
             traits: vec![],
             variants: vec![],
-            related: vec![],
-            since: None,
+            related: jsdoc_related(&jsdoc),
+            unresolved_doc_links: vec![],
+            since: jsdoc.since.clone(),
             until: None,
             moved_from: None,
-            deprecated: None,
+            reexport_from: None,
+            deprecated: jsdoc.deprecated.clone(),
+            cfg: None,
         })
     }
 
@@ -507,21 +885,21 @@ impl TypeScriptParser {
         exported: bool,
     ) -> Option<Item> {
         let name = self.get_child_text(node, "identifier", source)?;
-        let doc = self.get_doc_comment(node.parent()?, source);
+        let jsdoc = self.parse_doc_tags(node.parent()?, source);
 
         // Check if it's an arrow function or regular function expression
         let mut cursor = node.walk();
-        let mut is_function = false;
+        let mut function_node = None;
         let mut signature = None;
 
         for child in node.children(&mut cursor) {
             match child.kind() {
                 "arrow_function" | "function" | "function_expression" => {
-                    is_function = true;
+                    function_node = Some(child);
                     signature = Some(self.get_arrow_function_signature(child, &name, source));
                 }
                 "type_annotation" => {
-                    if !is_function {
+                    if function_node.is_none() {
                         signature = Some(format!(
                             "const {}: {}",
                             name,
@@ -533,31 +911,40 @@ impl TypeScriptParser {
             }
         }
 
-        let kind = if is_function {
+        let kind = if function_node.is_some() {
             ItemKind::Function
         } else {
             ItemKind::Constant
         };
+        let signature_detail = function_node.map(|n| self.parse_signature(n, source));
 
         Some(Item {
             path: format_path(module_path, &name),
             kind,
             signature: signature.or_else(|| Some(format!("const {}", name))),
-            doc,
+            signature_detail,
+            doc: jsdoc.summary.clone(),
             visibility: if exported {
                 Visibility::Public
             } else {
                 Visibility::Private
             },
+            generics: Generics::default(),
+            attrs: vec![],
+            decorators: vec![],
+            signatures: vec![],
             fields: vec![],
             methods: vec![],
             traits: vec![],
             variants: vec![],
-            related: vec![],
-            since: None,
+            related: jsdoc_related(&jsdoc),
+            unresolved_doc_links: vec![],
+            since: jsdoc.since.clone(),
             until: None,
             moved_from: None,
-            deprecated: None,
+            reexport_from: None,
+            deprecated: jsdoc.deprecated.clone(),
+            cfg: None,
         })
     }
 
@@ -569,28 +956,44 @@ impl TypeScriptParser {
         exported: bool,
     ) -> Option<Item> {
         let name = self.get_child_text(node, "identifier", source)?;
-        let doc = self.get_doc_comment(node, source);
+        let jsdoc = self.parse_doc_tags(node, source);
         let variants = self.parse_enum_variants(node, source);
+        let mut const_cursor = node.walk();
+        let is_const = node
+            .children(&mut const_cursor)
+            .any(|child| child.kind() == "const");
 
         Some(Item {
             path: format_path(module_path, &name),
             kind: ItemKind::Enum,
-            signature: Some(format!("enum {}", name)),
-            doc,
+            signature: Some(if is_const {
+                format!("const enum {}", name)
+            } else {
+                format!("enum {}", name)
+            }),
+            signature_detail: None,
+            doc: jsdoc.summary.clone(),
             visibility: if exported {
                 Visibility::Public
             } else {
                 Visibility::Private
             },
+            generics: Generics::default(),
+            attrs: vec![],
+            decorators: vec![],
+            signatures: vec![],
             fields: vec![],
             methods: vec![],
             traits: vec![],
             variants,
-            related: vec![],
-            since: None,
+            related: jsdoc_related(&jsdoc),
+            unresolved_doc_links: vec![],
+            since: jsdoc.since.clone(),
             until: None,
             moved_from: None,
-            deprecated: None,
+            reexport_from: None,
+            deprecated: jsdoc.deprecated.clone(),
+            cfg: None,
         })
     }
 
@@ -604,17 +1007,22 @@ impl TypeScriptParser {
                 for member in child.children(&mut body_cursor) {
                     if member.kind() == "enum_assignment" || member.kind() == "property_identifier"
                     {
-                        let name = if member.kind() == "enum_assignment" {
-                            self.get_child_text(member, "property_identifier", source)
+                        let (name, value) = if member.kind() == "enum_assignment" {
+                            let name = self.get_child_text(member, "property_identifier", source);
+                            let value = member
+                                .child_by_field_name("value")
+                                .map(|n| self.get_node_text(n, source));
+                            (name, value)
                         } else {
-                            Some(self.get_node_text(member, source))
+                            (Some(self.get_node_text(member, source)), None)
                         };
 
                         if let Some(name) = name {
                             variants.push(Variant {
                                 name,
-                                doc: self.get_doc_comment(member, source),
+                                doc: self.doc_summary(member, source),
                                 fields: vec![],
+                                value,
                             });
                         }
                     }
@@ -625,35 +1033,74 @@ impl TypeScriptParser {
         variants
     }
 
-    fn parse_module(&self, node: Node, source: &str, module_path: &str, items: &mut Vec<Item>) {
-        let name = self.get_module_name(node, source);
-        if let Some(name) = name {
-            let new_path = format_path(module_path, &name);
-            let doc = self.get_doc_comment(node, source);
+    /// Parse a `namespace`/`module` declaration, recursing into its body so
+    /// nested namespaces accumulate a fully qualified dotted path (`A.B.C`)
+    /// the same way `format_path` builds up any other nested item's path.
+    /// `exported` reflects whether this namespace itself is reachable from
+    /// outside its enclosing scope; when it isn't, every item found inside
+    /// - however deeply nested - is downgraded to `Visibility::Private`
+    /// regardless of its own `export` keyword, since an unexported
+    /// enclosing namespace hides everything inside it from outside callers.
+    fn parse_module(
+        &self,
+        node: Node,
+        source: &str,
+        module_path: &str,
+        exported: bool,
+        items: &mut Vec<Item>,
+    ) {
+        let Some((name, is_external)) = self.get_module_name(node, source) else {
+            return;
+        };
+        let new_path = format_path(module_path, &name);
+        let jsdoc = self.parse_doc_tags(node, source);
+        let signature = if is_external {
+            format!("module \"{}\"", name)
+        } else {
+            format!("namespace {}", name)
+        };
 
-            items.push(Item {
-                path: new_path.clone(),
-                kind: ItemKind::Module,
-                signature: Some(format!("namespace {}", name)),
-                doc,
-                visibility: Visibility::Public,
-                fields: vec![],
-                methods: vec![],
-                traits: vec![],
-                variants: vec![],
-                related: vec![],
-                since: None,
-                until: None,
-                moved_from: None,
-                deprecated: None,
-            });
+        items.push(Item {
+            path: new_path.clone(),
+            kind: ItemKind::Module,
+            signature: Some(signature),
+            signature_detail: None,
+            doc: jsdoc.summary.clone(),
+            visibility: if exported {
+                Visibility::Public
+            } else {
+                Visibility::Private
+            },
+            generics: Generics::default(),
+            attrs: vec![],
+            decorators: vec![],
+            signatures: vec![],
+            fields: vec![],
+            methods: vec![],
+            traits: vec![],
+            variants: vec![],
+            related: jsdoc_related(&jsdoc),
+            unresolved_doc_links: vec![],
+            since: jsdoc.since.clone(),
+            until: None,
+            moved_from: None,
+            reexport_from: None,
+            deprecated: jsdoc.deprecated.clone(),
+            cfg: None,
+        });
 
-            // Parse module body
-            let mut cursor = node.walk();
-            for child in node.children(&mut cursor) {
-                if child.kind() == "statement_block" {
-                    self.collect_definitions(child, source, &new_path, items);
-                }
+        // Parse module body
+        let before = items.len();
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            if child.kind() == "statement_block" {
+                self.collect_definitions(child, source, &new_path, items);
+            }
+        }
+
+        if !exported {
+            for item in &mut items[before..] {
+                item.visibility = Visibility::Private;
             }
         }
     }
@@ -664,6 +1111,14 @@ impl TypeScriptParser {
         source[node.byte_range()].to_string()
     }
 
+    /// A `string` node's value with its surrounding quotes stripped.
+    fn get_string_value(&self, node: Node, source: &str) -> String {
+        self.get_node_text(node, source)
+            .trim_matches('"')
+            .trim_matches('\'')
+            .to_string()
+    }
+
     fn get_child_text(&self, node: Node, kind: &str, source: &str) -> Option<String> {
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
@@ -717,6 +1172,20 @@ impl TypeScriptParser {
         }
     }
 
+    /// A node's doc comment, parsed into its free-text summary plus
+    /// `@deprecated`/`@since`/`@see` tags. See `parse_jsdoc_tags`.
+    fn parse_doc_tags(&self, node: Node, source: &str) -> JsDocTags {
+        let raw = self.get_doc_comment(node, source).unwrap_or_default();
+        parse_jsdoc_tags(&raw)
+    }
+
+    /// A node's doc comment with JSDoc `@tag`s stripped, for item kinds
+    /// (`Method`/`Field`/`Variant`) that have nowhere structured to put the
+    /// rest of the parsed tags.
+    fn doc_summary(&self, node: Node, source: &str) -> Option<String> {
+        self.parse_doc_tags(node, source).summary
+    }
+
     fn get_member_visibility(&self, node: Node, source: &str) -> Visibility {
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
@@ -781,22 +1250,107 @@ impl TypeScriptParser {
         format!("{}{}{}", name, type_params, extends)
     }
 
-    fn get_function_signature(&self, node: Node, source: &str) -> String {
-        let text = self.get_node_text(node, source);
-        // Get just the signature without the body
-        if let Some(brace) = text.find('{') {
-            text[..brace].trim().to_string()
-        } else {
-            text
+    /// Walk a node's `type_parameters` child into structured `GenericParam`s,
+    /// reading each `type_parameter`'s name, `extends` constraint, and
+    /// `= Default` sub-nodes.
+    fn parse_type_parameters(&self, node: Node, source: &str) -> Generics {
+        let mut generics = Generics::default();
+
+        let mut cursor = node.walk();
+        let type_params = node
+            .children(&mut cursor)
+            .find(|child| child.kind() == "type_parameters");
+
+        let Some(type_params) = type_params else {
+            return generics;
+        };
+
+        let mut param_cursor = type_params.walk();
+        for param in type_params.children(&mut param_cursor) {
+            if param.kind() != "type_parameter" {
+                continue;
+            }
+
+            let name = self
+                .get_child_text(param, "type_identifier", source)
+                .unwrap_or_default();
+
+            let mut bounds = Vec::new();
+            let mut default = None;
+            let mut child_cursor = param.walk();
+            for child in param.children(&mut child_cursor) {
+                match child.kind() {
+                    "constraint" => bounds.push(
+                        self.get_node_text(child, source)
+                            .trim_start_matches("extends")
+                            .trim()
+                            .to_string(),
+                    ),
+                    "default_type" => {
+                        default = Some(
+                            self.get_node_text(child, source)
+                                .trim_start_matches('=')
+                                .trim()
+                                .to_string(),
+                        )
+                    }
+                    _ => {}
+                }
+            }
+
+            generics.params.push(GenericParam {
+                name,
+                bounds,
+                default,
+            });
         }
+
+        generics
+    }
+
+    fn get_function_signature(&self, node: Node, source: &str) -> String {
+        self.signature_text_excluding_body(node, source)
     }
 
     fn get_method_signature(&self, node: Node, source: &str) -> String {
+        self.signature_text_excluding_body(node, source)
+    }
+
+    /// A function/method-like node's signature text: its own source with
+    /// the `statement_block` body subtree excluded. The body is located by
+    /// walking `node`'s direct children for the `statement_block` kind, not
+    /// by scanning the text for the first `{` - a brace can appear earlier
+    /// in the signature itself (an object-typed parameter like `{ a:
+    /// string }`, a `= {}` default, an object return type, or a generic
+    /// constraint like `<T extends { id: number }>`), which would otherwise
+    /// truncate the signature mid-way through. Nodes with no body (ambient
+    /// or abstract declarations) return their full text unchanged.
+    fn signature_text_excluding_body(&self, node: Node, source: &str) -> String {
+        let mut cursor = node.walk();
+        let body = node
+            .children(&mut cursor)
+            .find(|child| child.kind() == "statement_block");
+
         let text = self.get_node_text(node, source);
-        if let Some(brace) = text.find('{') {
-            text[..brace].trim().to_string()
-        } else {
-            text
+        match body {
+            Some(body) => {
+                let end = (body.start_byte() - node.start_byte()).min(text.len());
+                text[..end].trim().to_string()
+            }
+            None => {
+                // No body means this node is a declaration only (an
+                // ambient/abstract/interface member, or an overload
+                // signature) - the grammar doesn't always include the
+                // trailing `;` in the node's own span, so make sure it's
+                // there; `merge_overloads` depends on it to tell overload
+                // declarations apart from implementations.
+                let text = text.trim();
+                if text.ends_with(';') {
+                    text.to_string()
+                } else {
+                    format!("{text};")
+                }
+            }
         }
     }
 
@@ -868,14 +1422,124 @@ impl TypeScriptParser {
         }
     }
 
-    fn get_module_name(&self, node: Node, source: &str) -> Option<String> {
+    /// Parse a function-like node's `formal_parameters` and return type into
+    /// a structured `Signature`, alongside its own generics.
+    fn parse_signature(&self, node: Node, source: &str) -> Signature {
+        let params = self.parse_formal_parameters(node, source);
+        let return_type = self.get_type_annotation(node, source);
+        let generics = self.parse_type_parameters(node, source);
+
+        Signature {
+            params,
+            return_type,
+            generics,
+            expanded_return_type: None,
+        }
+    }
+
+    /// Walk a node's `formal_parameters` child into structured `Param`s,
+    /// handling `required_parameter`, `optional_parameter` (trailing `?`),
+    /// `rest_pattern` (`...args`), and destructured object/array patterns
+    /// (which get a synthetic name since they bind no single identifier).
+    fn parse_formal_parameters(&self, node: Node, source: &str) -> Vec<Param> {
+        let mut params = Vec::new();
+
+        let mut cursor = node.walk();
+        let Some(formal_parameters) = node
+            .children(&mut cursor)
+            .find(|child| child.kind() == "formal_parameters")
+        else {
+            return params;
+        };
+
+        let mut param_cursor = formal_parameters.walk();
+        for param in formal_parameters.children(&mut param_cursor) {
+            match param.kind() {
+                "required_parameter" | "optional_parameter" => {
+                    let name = param
+                        .child_by_field_name("pattern")
+                        .map(|n| self.param_pattern_name(n, source))
+                        .unwrap_or_default();
+                    let ty = param
+                        .child_by_field_name("type")
+                        .map(|n| self.get_type_from_annotation(n, source));
+                    let default = param
+                        .child_by_field_name("value")
+                        .map(|n| self.get_node_text(n, source));
+                    let decorators = self.parse_decorators(param, source);
+
+                    params.push(Param {
+                        name,
+                        ty,
+                        optional: param.kind() == "optional_parameter",
+                        default,
+                        rest: false,
+                        decorators,
+                    });
+                }
+                "rest_pattern" => {
+                    let mut name = String::new();
+                    let mut ty = None;
+                    let mut rest_cursor = param.walk();
+                    for child in param.children(&mut rest_cursor) {
+                        match child.kind() {
+                            "type_annotation" => {
+                                ty = Some(self.get_type_from_annotation(child, source))
+                            }
+                            "..." => {}
+                            _ => name = self.param_pattern_name(child, source),
+                        }
+                    }
+
+                    params.push(Param {
+                        name: format!("...{}", name),
+                        ty,
+                        optional: false,
+                        default: None,
+                        rest: true,
+                        decorators: vec![],
+                    });
+                }
+                "identifier" => {
+                    params.push(Param {
+                        name: self.get_node_text(param, source),
+                        ty: None,
+                        optional: false,
+                        default: None,
+                        rest: false,
+                        decorators: vec![],
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        params
+    }
+
+    /// A parameter pattern's display name: the bound identifier, or a
+    /// synthetic placeholder for a destructured object/array pattern.
+    fn param_pattern_name(&self, node: Node, source: &str) -> String {
+        match node.kind() {
+            "object_pattern" => "{ }".to_string(),
+            "array_pattern" => "[ ]".to_string(),
+            _ => self.get_node_text(node, source),
+        }
+    }
+
+    /// A module/namespace declaration's name, plus whether it was written
+    /// as a quoted string (`module "foo"`, an external module augmentation)
+    /// rather than a dotted identifier (`namespace Foo` or `namespace
+    /// Foo.Bar`, the latter parsing as a single `nested_identifier` node
+    /// whose own text is already the fully dotted name).
+    fn get_module_name(&self, node: Node, source: &str) -> Option<(String, bool)> {
         let mut cursor = node.walk();
         for child in node.children(&mut cursor) {
             match child.kind() {
-                "identifier" | "string" => {
-                    let text = self.get_node_text(child, source);
-                    return Some(text.trim_matches('"').trim_matches('\'').to_string());
+                "identifier" | "nested_identifier" => {
+                    return Some((self.get_node_text(child, source), false))
                 }
+                "string" => return Some((self.get_string_value(child, source), true)),
                 _ => {}
             }
         }
@@ -883,6 +1547,20 @@ impl TypeScriptParser {
     }
 }
 
+impl LanguageParser for TypeScriptParser {
+    fn parse_source(&mut self, source: &str, module_path: &str) -> Result<Vec<Item>, ParseError> {
+        TypeScriptParser::parse_source(self, source, module_path)
+    }
+
+    fn language_id(&self) -> &'static str {
+        "typescript"
+    }
+
+    fn handles_extension(&self, ext: &str) -> bool {
+        self.language.handles_extension(ext)
+    }
+}
+
 fn format_path(module_path: &str, name: &str) -> String {
     if module_path.is_empty() {
         name.to_string()
@@ -891,6 +1569,327 @@ fn format_path(module_path: &str, name: &str) -> String {
     }
 }
 
+/// The structured result of parsing JSDoc `@tag`s out of a flattened doc
+/// comment body (as produced by `get_doc_comment`): the free-text summary
+/// before the first tag, plus whatever tags we recognize.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct JsDocTags {
+    summary: Option<String>,
+    deprecated: Option<String>,
+    since: Option<String>,
+    related: Vec<String>,
+}
+
+/// Split a flattened JSDoc body into its free-text summary and `@tag`s,
+/// recognizing `@deprecated [reason]`, `@since <version>`, `@see <ref>`
+/// (including an inline `{@link X}`), `@param <name> <desc>`, and
+/// `@returns <desc>`. A tag's value continues across lines until the next
+/// `@tag` line. `@param`/`@returns` are recognized so they don't leak into
+/// the summary, but their descriptions have no structured home yet and are
+/// discarded. Non-JSDoc (`//`) comments have no tags, so this is a no-op
+/// beyond trimming.
+fn parse_jsdoc_tags(raw: &str) -> JsDocTags {
+    let mut tags = JsDocTags::default();
+    let mut summary_lines = Vec::new();
+    let mut current_tag: Option<(&str, Vec<&str>)> = None;
+
+    for line in raw.lines() {
+        let trimmed = line.trim();
+        if let Some(rest) = trimmed.strip_prefix('@') {
+            apply_jsdoc_tag(current_tag.take(), &mut tags);
+            let (name, value) = rest.split_once(char::is_whitespace).unwrap_or((rest, ""));
+            current_tag = Some((name, vec![value.trim()]));
+        } else if let Some((_, lines)) = current_tag.as_mut() {
+            lines.push(trimmed);
+        } else {
+            summary_lines.push(line);
+        }
+    }
+    apply_jsdoc_tag(current_tag.take(), &mut tags);
+
+    // `{@link X}` can also appear inline in running prose, not just as its
+    // own `@see` tag.
+    for target in extract_link_targets(raw) {
+        if !tags.related.contains(&target) {
+            tags.related.push(target);
+        }
+    }
+
+    let summary = summary_lines.join("\n").trim().to_string();
+    tags.summary = if summary.is_empty() {
+        None
+    } else {
+        Some(summary)
+    };
+
+    tags
+}
+
+fn apply_jsdoc_tag(tag: Option<(&str, Vec<&str>)>, tags: &mut JsDocTags) {
+    let Some((name, lines)) = tag else {
+        return;
+    };
+    let value = lines.join(" ").trim().to_string();
+
+    match name {
+        "deprecated" => {
+            tags.deprecated = Some(if value.is_empty() {
+                "deprecated".to_string()
+            } else {
+                value
+            });
+        }
+        "since" if !value.is_empty() => tags.since = Some(value),
+        "see" => {
+            if let Some(target) = extract_link_targets(&value).into_iter().next() {
+                tags.related.push(target);
+            } else if !value.is_empty() {
+                tags.related.push(value);
+            }
+        }
+        // @param/@returns (and anything else) are recognized so their text
+        // is kept out of the summary, but discarded otherwise.
+        _ => {}
+    }
+}
+
+/// Extract every `{@link Target}`/`{@linkcode Target}` reference's target
+/// text, e.g. `Foo#bar`.
+fn extract_link_targets(text: &str) -> Vec<String> {
+    let mut targets = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find("{@link") {
+        let after = &rest[start + "{@link".len()..];
+        let after = after.strip_prefix("code").unwrap_or(after);
+        let Some(end) = after.find('}') else {
+            break;
+        };
+        let target = after[..end].trim().to_string();
+        if !target.is_empty() {
+            targets.push(target);
+        }
+        rest = &after[end + 1..];
+    }
+    targets
+}
+
+/// A function overload declaration (`function_signature`, or a
+/// `method_signature` accompanying a concrete method) has no body, so its
+/// signature text - the node's own source, per `signature_text_excluding_body`
+/// - ends in `;` rather than trailing off right before a `{`.
+fn is_overload_declaration(signature: &str) -> bool {
+    signature.trim_end().ends_with(';')
+}
+
+/// Collapse consecutive top-level functions sharing the same `path` into a
+/// single item: TypeScript allows several overload signatures for one
+/// function name, optionally followed by an implementation. `signatures`
+/// collects the overload forms (semicolon trimmed); the implementation's
+/// own signature, if one is present, is dropped rather than added to that
+/// list; its `signature_detail` and `doc` become the merged item's, since
+/// only the implementation has a real body to describe.
+fn merge_overloads(items: Vec<Item>) -> Vec<Item> {
+    let mut merged: Vec<Item> = Vec::new();
+
+    for item in items {
+        if item.kind == ItemKind::Function {
+            if let Some(last) = merged.last_mut() {
+                if last.kind == ItemKind::Function && last.path == item.path {
+                    merge_overload_into(
+                        &mut last.signature,
+                        &mut last.signature_detail,
+                        &mut last.signatures,
+                        &mut last.doc,
+                        item.signature,
+                        item.signature_detail,
+                        item.doc,
+                    );
+                    continue;
+                }
+            }
+        }
+        merged.push(item);
+    }
+
+    merged
+}
+
+/// Same merge as `merge_overloads`, applied to a class's or interface's
+/// methods (grouped by name rather than a dotted path).
+fn merge_method_overloads(methods: Vec<Method>) -> Vec<Method> {
+    let mut merged: Vec<Method> = Vec::new();
+
+    for method in methods {
+        if let Some(last) = merged.last_mut() {
+            if last.name == method.name {
+                merge_overload_into(
+                    &mut last.signature,
+                    &mut last.signature_detail,
+                    &mut last.signatures,
+                    &mut last.doc,
+                    method.signature,
+                    method.signature_detail,
+                    method.doc,
+                );
+                continue;
+            }
+        }
+        merged.push(method);
+    }
+
+    merged
+}
+
+/// Fold one more declaration for an already-seen function/method name into
+/// the accumulator fields of the first. The very first time a second
+/// declaration shows up, the accumulator's own signature - if it's an
+/// overload form - is moved into `signatures` so it isn't lost. From then
+/// on, each additional overload declaration is appended to `signatures`,
+/// while an implementation (no trailing `;`) instead replaces the
+/// accumulator's `signature`/`signature_detail`/`doc` and is never itself
+/// added to `signatures`. If every declaration seen turns out to be an
+/// overload form with no implementation, the accumulator keeps the last
+/// overload's signature as its own so `signature` is never left empty.
+fn merge_overload_into(
+    acc_signature: &mut Option<String>,
+    acc_detail: &mut Option<Signature>,
+    acc_signatures: &mut Vec<String>,
+    acc_doc: &mut Option<String>,
+    next_signature: Option<String>,
+    next_detail: Option<Signature>,
+    next_doc: Option<String>,
+) {
+    if acc_signatures.is_empty() {
+        if let Some(sig) = &acc_signature {
+            if is_overload_declaration(sig) {
+                acc_signatures.push(trim_overload_terminator(sig));
+            }
+        }
+    }
+
+    match next_signature {
+        Some(sig) if is_overload_declaration(&sig) => {
+            acc_signatures.push(trim_overload_terminator(&sig));
+            *acc_signature = Some(sig);
+        }
+        Some(sig) => {
+            // An implementation: becomes the merged entry's primary
+            // signature/detail/doc, but its own signature text is dropped
+            // rather than added to `signatures`.
+            *acc_signature = Some(sig);
+            *acc_detail = next_detail;
+            *acc_doc = next_doc.or_else(|| acc_doc.take());
+        }
+        None => {}
+    }
+}
+
+fn trim_overload_terminator(signature: &str) -> String {
+    signature
+        .trim_end()
+        .trim_end_matches(';')
+        .trim()
+        .to_string()
+}
+
+/// Scan every item's `@see`/`{@link}` targets (collected into `related` as
+/// tentative `DocLink` relations during parsing) and resolve each against
+/// the item paths produced from this same source file, mirroring how the
+/// Rust parser's `resolve_doc_link` walks from a textual path to a concrete
+/// item. Resolved targets are rewritten to the item's full dotted path;
+/// anything that doesn't resolve moves to `unresolved_doc_links` instead.
+fn resolve_doc_links(items: &mut [Item]) {
+    let known_paths: Vec<String> = items.iter().map(|item| item.path.clone()).collect();
+
+    for idx in 0..items.len() {
+        let targets: Vec<String> = items[idx]
+            .related
+            .iter()
+            .filter(|relation| relation.kind == RelationKind::DocLink)
+            .map(|relation| relation.path.clone())
+            .collect();
+        if targets.is_empty() {
+            continue;
+        }
+        items[idx]
+            .related
+            .retain(|relation| relation.kind != RelationKind::DocLink);
+
+        let module_path = items[idx]
+            .path
+            .rsplit_once('.')
+            .map(|(module, _)| module.to_string())
+            .unwrap_or_default();
+
+        for target in targets {
+            match resolve_doc_link(&target, &module_path, &known_paths) {
+                Some(path) if path != items[idx].path => {
+                    if !items[idx].related.iter().any(|r| r.path == path) {
+                        items[idx].related.push(Relation {
+                            path,
+                            kind: RelationKind::DocLink,
+                        });
+                    }
+                }
+                Some(_) => {}
+                None => items[idx].unresolved_doc_links.push(target),
+            }
+        }
+    }
+}
+
+/// Resolve a `{@link Target}` target against the known item paths produced
+/// in this run: first as an already-fully-qualified dotted path, then as a
+/// sibling in the same module, then by bare name against any item's last
+/// path segment (e.g. `{@link LicenseKey}` finding `license.LicenseKey`).
+fn resolve_doc_link(raw: &str, module_path: &str, known_paths: &[String]) -> Option<String> {
+    if let Some(path) = known_paths.iter().find(|p| p.as_str() == raw) {
+        return Some(path.clone());
+    }
+
+    let sibling = format_path(module_path, raw);
+    if let Some(path) = known_paths.iter().find(|p| p.as_str() == sibling) {
+        return Some(path.clone());
+    }
+
+    known_paths
+        .iter()
+        .find(|p| p.rsplit('.').next() == Some(raw))
+        .cloned()
+}
+
+/// `@see`/`{@link X}` targets as `DocLink` relations.
+fn jsdoc_related(jsdoc: &JsDocTags) -> Vec<Relation> {
+    jsdoc
+        .related
+        .iter()
+        .map(|path| Relation {
+            path: path.clone(),
+            kind: RelationKind::DocLink,
+        })
+        .collect()
+}
+
+/// Fill in an item's doc/deprecated/since/related from the enclosing
+/// `export` statement's own JSDoc comment, wherever the item didn't already
+/// have its own.
+fn apply_jsdoc_fallback(item: &mut Item, export_jsdoc: &JsDocTags) {
+    if item.doc.is_none() {
+        item.doc = export_jsdoc.summary.clone();
+    }
+    if item.deprecated.is_none() {
+        item.deprecated = export_jsdoc.deprecated.clone();
+    }
+    if item.since.is_none() {
+        item.since = export_jsdoc.since.clone();
+    }
+    for relation in jsdoc_related(export_jsdoc) {
+        if !item.related.iter().any(|r| r.path == relation.path) {
+            item.related.push(relation);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -981,4 +1980,505 @@ export const runArchmapAi = async (root: string): Promise<string> => {
 
         assert_eq!(items.len(), 2);
     }
+
+    #[test]
+    fn test_parse_class_generics() {
+        let source = r#"
+export class Cache<K extends string, V = unknown> {
+    get(key: K): V | undefined { return undefined; }
+}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "cache").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.generics.params.len(), 2);
+        assert_eq!(item.generics.params[0].name, "K");
+        assert_eq!(item.generics.params[0].bounds, vec!["string".to_string()]);
+        assert_eq!(item.generics.params[0].default, None);
+        assert_eq!(item.generics.params[1].name, "V");
+        assert!(item.generics.params[1].bounds.is_empty());
+        assert_eq!(item.generics.params[1].default.as_deref(), Some("unknown"));
+    }
+
+    #[test]
+    fn test_parse_named_reexport_from() {
+        let source = r#"
+export { Foo, Bar as Baz } from './foo';
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "index").unwrap();
+
+        assert_eq!(items.len(), 2);
+
+        let foo = items.iter().find(|i| i.path == "index.Foo").unwrap();
+        assert_eq!(foo.reexport_from.as_deref(), Some("./foo"));
+        assert_eq!(foo.moved_from, None);
+
+        let baz = items.iter().find(|i| i.path == "index.Baz").unwrap();
+        assert_eq!(baz.reexport_from.as_deref(), Some("./foo"));
+        assert_eq!(baz.moved_from.as_deref(), Some("Bar"));
+    }
+
+    #[test]
+    fn test_parse_export_star_barrel() {
+        let source = r#"
+export * from './widgets';
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "index").unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].path, "index");
+        assert_eq!(items[0].reexport_from.as_deref(), Some("./widgets"));
+    }
+
+    #[test]
+    fn test_parse_jsdoc_tags() {
+        let source = r#"
+/**
+ * Fetches a widget by id.
+ *
+ * @deprecated use fetchWidgetV2 instead
+ * @since 1.4.0
+ * @see {@link Widget}
+ */
+export function fetchWidget(id: string): void {}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "widgets").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.doc.as_deref(), Some("Fetches a widget by id."));
+        assert_eq!(
+            item.deprecated.as_deref(),
+            Some("use fetchWidgetV2 instead")
+        );
+        assert_eq!(item.since.as_deref(), Some("1.4.0"));
+        // "Widget" doesn't name any item extracted from this file, so it
+        // can't be resolved and moves to `unresolved_doc_links`.
+        assert!(item.related.is_empty());
+        assert_eq!(item.unresolved_doc_links, vec!["Widget".to_string()]);
+    }
+
+    #[test]
+    fn test_resolve_doc_link_against_sibling_item() {
+        let source = r#"
+/** @see {@linkcode Widget} */
+export function fetchWidget(id: string): void {}
+
+/** A widget. */
+export class Widget {}
+
+/** @see {@link Missing} */
+export function orphan(): void {}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "widgets").unwrap();
+
+        let fetch_widget = items
+            .iter()
+            .find(|i| i.path == "widgets.fetchWidget")
+            .unwrap();
+        assert_eq!(fetch_widget.related.len(), 1);
+        assert_eq!(fetch_widget.related[0].path, "widgets.Widget");
+        assert_eq!(fetch_widget.related[0].kind, RelationKind::DocLink);
+        assert!(fetch_widget.unresolved_doc_links.is_empty());
+
+        let orphan = items.iter().find(|i| i.path == "widgets.orphan").unwrap();
+        assert!(orphan.related.is_empty());
+        assert_eq!(orphan.unresolved_doc_links, vec!["Missing".to_string()]);
+    }
+
+    #[test]
+    fn test_parse_function_params() {
+        let source = r#"
+export function search(query: string, limit?: number, ...rest: string[]): boolean {
+    return true;
+}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "search").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let detail = items[0].signature_detail.as_ref().unwrap();
+        assert_eq!(detail.return_type.as_deref(), Some("boolean"));
+        assert_eq!(detail.params.len(), 3);
+
+        assert_eq!(detail.params[0].name, "query");
+        assert_eq!(detail.params[0].ty.as_deref(), Some("string"));
+        assert!(!detail.params[0].optional);
+
+        assert_eq!(detail.params[1].name, "limit");
+        assert!(detail.params[1].optional);
+        assert_eq!(detail.params[1].ty.as_deref(), Some("number"));
+
+        assert_eq!(detail.params[2].name, "...rest");
+        assert!(detail.params[2].rest);
+        assert_eq!(detail.params[2].ty.as_deref(), Some("string[]"));
+    }
+
+    #[test]
+    fn test_parse_function_param_default_and_destructure() {
+        let source = r#"
+export function greet({ name }: Person, timeout = 30): void {}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "greet").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let detail = items[0].signature_detail.as_ref().unwrap();
+        assert_eq!(detail.params.len(), 2);
+        assert_eq!(detail.params[0].name, "{ }");
+        assert_eq!(detail.params[0].ty.as_deref(), Some("Person"));
+        assert_eq!(detail.params[1].name, "timeout");
+        assert_eq!(detail.params[1].default.as_deref(), Some("30"));
+    }
+
+    #[test]
+    fn test_signature_not_truncated_by_brace_in_params_or_generics() {
+        let source = r#"
+export function configure<T extends { id: number }>(
+    opts: { a: string },
+    extra = {},
+): void {
+    return;
+}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "configure").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        let signature = item.signature.as_deref().unwrap();
+        assert!(signature.contains("<T extends { id: number }>"));
+        assert!(signature.contains("opts: { a: string }"));
+        assert!(signature.contains("extra = {}"));
+        assert!(signature.ends_with("): void"));
+        assert!(!signature.contains("return"));
+
+        let detail = item.signature_detail.as_ref().unwrap();
+        assert_eq!(detail.return_type.as_deref(), Some("void"));
+        assert_eq!(detail.generics.params[0].name, "T");
+        assert_eq!(detail.generics.params[0].bounds, vec!["{ id: number }"]);
+        assert_eq!(detail.params[0].name, "opts");
+        assert_eq!(detail.params[0].ty.as_deref(), Some("{ a: string }"));
+        assert_eq!(detail.params[1].name, "extra");
+        assert_eq!(detail.params[1].default.as_deref(), Some("{}"));
+    }
+
+    #[test]
+    fn test_method_signature_with_object_return_type_not_truncated() {
+        let source = r#"
+export class Config {
+    load(): { ready: boolean } {
+        return { ready: true };
+    }
+}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "config").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let method = &items[0].methods[0];
+        let signature = method.signature.as_deref().unwrap();
+        assert_eq!(signature, "load(): { ready: boolean }");
+    }
+
+    #[test]
+    fn test_merge_function_overloads_with_implementation() {
+        let source = r#"
+export function parseValue(input: string): string;
+export function parseValue(input: number): number;
+export function parseValue(input: string | number): string | number {
+    return input;
+}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "values").unwrap();
+
+        let matches: Vec<_> = items
+            .iter()
+            .filter(|i| i.path == "values.parseValue")
+            .collect();
+        assert_eq!(matches.len(), 1);
+
+        let item = matches[0];
+        assert_eq!(item.signatures.len(), 2);
+        assert!(item.signatures[0].contains("parseValue(input: string): string"));
+        assert!(item.signatures[1].contains("parseValue(input: number): number"));
+        assert!(item.signatures.iter().all(|s| !s.ends_with(';')));
+
+        let signature = item.signature.as_deref().unwrap();
+        assert!(signature.contains("string | number"));
+        assert!(!signature.ends_with(';'));
+    }
+
+    #[test]
+    fn test_merge_method_overloads_in_class_and_interface() {
+        let source = r#"
+export class Formatter {
+    format(value: string): string;
+    format(value: number): string;
+    format(value: string | number): string {
+        return String(value);
+    }
+}
+
+export interface Loader {
+    load(id: string): Promise<string>;
+    load(id: string, fallback: string): Promise<string>;
+}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "io").unwrap();
+
+        let formatter = items.iter().find(|i| i.path == "io.Formatter").unwrap();
+        assert_eq!(formatter.methods.len(), 1);
+        let format = &formatter.methods[0];
+        assert_eq!(
+            format.signatures,
+            vec![
+                "format(value: string): string".to_string(),
+                "format(value: number): string".to_string(),
+            ]
+        );
+        assert!(!format.signature.as_deref().unwrap().ends_with(';'));
+
+        let loader = items.iter().find(|i| i.path == "io.Loader").unwrap();
+        assert_eq!(loader.methods.len(), 1);
+        let load = &loader.methods[0];
+        assert_eq!(load.signatures.len(), 2);
+        assert!(
+            load.signatures[0].trim_end_matches(';').trim() == "load(id: string): Promise<string>"
+        );
+        assert!(load
+            .signature
+            .as_deref()
+            .unwrap()
+            .trim_end_matches(';')
+            .trim()
+            .ends_with("load(id: string, fallback: string): Promise<string>"));
+    }
+
+    #[test]
+    fn test_parse_incremental_reuses_unedited_items() {
+        let old_source = "export function greet(name: string): void {}\n";
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(old_source, "greet").unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].path, "greet.greet");
+
+        // Rename `greet` -> `hello` in the `identifier` right after `function `.
+        let new_source = "export function hello(name: string): void {}\n";
+        let start_byte = "export function ".len();
+        let old_end_byte = start_byte + "greet".len();
+        let new_end_byte = start_byte + "hello".len();
+        let edit = tree_sitter::InputEdit {
+            start_byte,
+            old_end_byte,
+            new_end_byte,
+            start_position: tree_sitter::Point::new(0, start_byte),
+            old_end_position: tree_sitter::Point::new(0, old_end_byte),
+            new_end_position: tree_sitter::Point::new(0, new_end_byte),
+        };
+
+        let items = parser
+            .parse_incremental(new_source, "greet", &[edit])
+            .unwrap();
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].path, "greet.hello");
+    }
+
+    #[test]
+    fn test_parse_incremental_without_retained_tree_falls_back_to_full_reparse() {
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser
+            .parse_incremental("export function greet(): void {}\n", "fresh", &[])
+            .unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].path, "fresh.greet");
+    }
+
+    #[test]
+    fn test_parse_ambient_declare_function() {
+        let source = r#"
+declare function readFile(path: string): string;
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "fs").unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].path, "fs.readFile");
+        assert_eq!(items[0].kind, ItemKind::Function);
+    }
+
+    #[test]
+    fn test_parse_ambient_declare_global() {
+        let source = r#"
+declare global {
+    function fetch(url: string): Promise<Response>;
+}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "globals").unwrap();
+
+        assert_eq!(items.len(), 1);
+        assert_eq!(items[0].path, "globals.fetch");
+    }
+
+    #[test]
+    fn test_parse_ambient_declare_external_module() {
+        let source = r#"
+declare module "widgets" {
+    export function createWidget(): void;
+}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "index").unwrap();
+
+        let module = items.iter().find(|i| i.path == "index.widgets").unwrap();
+        assert_eq!(module.signature.as_deref(), Some("module \"widgets\""));
+
+        let function = items
+            .iter()
+            .find(|i| i.path == "index.widgets.createWidget")
+            .unwrap();
+        assert_eq!(function.kind, ItemKind::Function);
+    }
+
+    #[test]
+    fn test_parse_nested_namespace_dotted_path() {
+        let source = r#"
+export namespace A {
+    export namespace B {
+        export interface C {}
+    }
+}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "widgets").unwrap();
+
+        let a = items.iter().find(|i| i.path == "widgets.A").unwrap();
+        assert_eq!(a.visibility, Visibility::Public);
+        let b = items.iter().find(|i| i.path == "widgets.A.B").unwrap();
+        assert_eq!(b.visibility, Visibility::Public);
+        let c = items.iter().find(|i| i.path == "widgets.A.B.C").unwrap();
+        assert_eq!(c.kind, ItemKind::Trait);
+        assert_eq!(c.visibility, Visibility::Public);
+    }
+
+    #[test]
+    fn test_parse_namespace_dotted_name_form() {
+        let source = r#"
+export namespace A.B {
+    export interface C {}
+}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "widgets").unwrap();
+
+        assert!(items.iter().any(|i| i.path == "widgets.A.B"));
+        assert!(items.iter().any(|i| i.path == "widgets.A.B.C"));
+    }
+
+    #[test]
+    fn test_unexported_namespace_downgrades_nested_visibility() {
+        let source = r#"
+namespace Internal {
+    export interface Hidden {}
+}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "widgets").unwrap();
+
+        let ns = items.iter().find(|i| i.path == "widgets.Internal").unwrap();
+        assert_eq!(ns.visibility, Visibility::Private);
+        let hidden = items
+            .iter()
+            .find(|i| i.path == "widgets.Internal.Hidden")
+            .unwrap();
+        assert_eq!(hidden.visibility, Visibility::Private);
+    }
+
+    #[test]
+    fn test_parse_declaration_file_implicit_export() {
+        let source = r#"
+function parse(input: string): void {}
+class Parser {}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript)
+            .unwrap()
+            .with_ambient(true);
+        let items = parser.parse_source(source, "types").unwrap();
+
+        assert_eq!(items.len(), 2);
+        assert!(items.iter().any(|i| i.path == "types.parse"));
+        assert!(items.iter().any(|i| i.path == "types.Parser"));
+    }
+
+    #[test]
+    fn test_parse_enum_with_values() {
+        let source = r#"
+export const enum Direction {
+    Up = 1,
+    Down,
+    Left = "left",
+}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "direction").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(item.kind, ItemKind::Enum);
+        assert_eq!(item.signature.as_deref(), Some("const enum Direction"));
+        assert_eq!(item.visibility, Visibility::Public);
+
+        assert_eq!(item.variants.len(), 3);
+        assert_eq!(item.variants[0].name, "Up");
+        assert_eq!(item.variants[0].value.as_deref(), Some("1"));
+        assert_eq!(item.variants[1].name, "Down");
+        assert_eq!(item.variants[1].value, None);
+        assert_eq!(item.variants[2].name, "Left");
+        assert_eq!(item.variants[2].value.as_deref(), Some("\"left\""));
+    }
+
+    #[test]
+    fn test_parse_class_decorators() {
+        let source = r#"
+@Component({
+    selector: 'app-widget',
+})
+export class WidgetComponent {
+    @HostListener('click')
+    onClick(): void {}
+
+    constructor(@Inject(WIDGET_CONFIG) private config: WidgetConfig) {}
+}
+"#;
+        let mut parser = TypeScriptParser::new(TsLanguage::TypeScript).unwrap();
+        let items = parser.parse_source(source, "widget_component").unwrap();
+
+        assert_eq!(items.len(), 1);
+        let item = &items[0];
+        assert_eq!(
+            item.decorators,
+            vec!["@Component({\n    selector: 'app-widget',\n})"]
+        );
+
+        let on_click = item.methods.iter().find(|m| m.name == "onClick").unwrap();
+        assert_eq!(on_click.decorators, vec!["@HostListener('click')"]);
+
+        let constructor = item
+            .methods
+            .iter()
+            .find(|m| m.name == "constructor")
+            .unwrap();
+        let config_param = &constructor.signature_detail.as_ref().unwrap().params[0];
+        assert_eq!(config_param.decorators, vec!["@Inject(WIDGET_CONFIG)"]);
+    }
 }
@@ -7,7 +7,9 @@ pub mod contrastive;
 pub mod db;
 pub mod embed;
 pub mod octo_index;
+pub mod octo_mmap;
 pub mod parse;
 pub mod profile;
 pub mod project;
+pub mod remote_index;
 pub mod usage;
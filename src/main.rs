@@ -1,12 +1,22 @@
 mod cache;
 mod cargo;
+mod completion;
+mod diff;
+mod export;
+mod fst_index;
+mod import_map;
 mod languages;
 mod mcp;
 mod npm;
+mod registry_index;
 mod schema;
+mod storage;
 
-use crate::cache::{Cache, parallel_index};
-use crate::cargo::{RegistryCrate, find_crate, list_registry_crates, resolve_project_deps};
+use crate::cache::{parallel_index, Cache};
+use crate::cargo::{
+    find_crate, list_registry_crates, resolve_dependency_provenance, resolve_project_deps,
+    DependencyProvenance, RegistryCrate,
+};
 use crate::languages::rust::RustParser;
 use crate::languages::typescript::{TsLanguage, TypeScriptParser};
 use crate::npm::parse_package_json;
@@ -38,6 +48,11 @@ enum Commands {
         /// List ALL crates in cargo registry (not just project deps)
         #[arg(short, long)]
         all: bool,
+
+        /// With --all, list every version known to the registry index, not
+        /// just the ones already extracted locally
+        #[arg(short = 'V', long)]
+        versions: bool,
     },
 
     /// List dependencies of the current project
@@ -85,6 +100,14 @@ enum Commands {
         name: String,
     },
 
+    /// Show where each dependency's source actually comes from (registry,
+    /// git, or path)
+    Info {
+        /// Path to project directory (defaults to current dir)
+        #[arg(short, long)]
+        path: Option<Utf8PathBuf>,
+    },
+
     /// Parse a single Rust source file (for debugging)
     Parse {
         /// Path to the .rs file
@@ -133,6 +156,12 @@ enum CacheAction {
         /// Re-index even if already cached
         #[arg(short, long)]
         force: bool,
+
+        /// Parse post-macro-expansion source instead of raw files, so
+        /// derive impls and macro-generated items are captured too
+        /// (requires nightly and a buildable crate; falls back silently)
+        #[arg(short, long)]
+        expand_macros: bool,
     },
     /// Show cache statistics
     Stats,
@@ -140,6 +169,18 @@ enum CacheAction {
     Clear,
     /// List all indexed crates
     List,
+    /// Export the cache to a portable NDJSON dump
+    Export {
+        /// Output file path
+        #[arg(short, long, default_value = "fastdeps-cache.ndjson")]
+        output: Utf8PathBuf,
+    },
+    /// Import a previously exported dump, reindexing every crate it contains
+    Import {
+        /// Input file path
+        #[arg(short, long)]
+        input: Utf8PathBuf,
+    },
 }
 
 fn main() {
@@ -150,7 +191,8 @@ fn main() {
             filter,
             latest,
             all,
-        } => cmd_list(filter, latest, all),
+            versions,
+        } => cmd_list(filter, latest, all, versions),
         Commands::Deps { path } => cmd_deps(path),
         Commands::Peek {
             name,
@@ -164,14 +206,20 @@ fn main() {
             no_cache,
         } => cmd_find(&query, all, no_cache),
         Commands::Where { name } => cmd_where(&name),
+        Commands::Info { path } => cmd_info(path),
         Commands::Parse { file, module } => cmd_parse(&file, &module),
         Commands::ParseTs { file, module } => cmd_parse_ts(&file, &module),
         Commands::PeekTs { path, full } => cmd_peek_ts(&path, full),
         Commands::Cache { action } => match action {
-            CacheAction::Build { force } => cmd_cache_build(force),
+            CacheAction::Build {
+                force,
+                expand_macros,
+            } => cmd_cache_build(force, expand_macros),
             CacheAction::Stats => cmd_cache_stats(),
             CacheAction::Clear => cmd_cache_clear(),
             CacheAction::List => cmd_cache_list(),
+            CacheAction::Export { output } => cmd_cache_export(&output),
+            CacheAction::Import { input } => cmd_cache_import(&input),
         },
         Commands::Mcp => {
             std::process::exit(mcp::cmd_mcp());
@@ -188,6 +236,7 @@ fn cmd_list(
     filter: Option<String>,
     latest: bool,
     all: bool,
+    versions: bool,
 ) -> Result<(), Box<dyn std::error::Error>> {
     let mut crates = if all {
         // List all crates in the cargo registry
@@ -234,6 +283,40 @@ fn cmd_list(
         crates.retain(|c| c.name.contains(f));
     }
 
+    if all && versions {
+        // The extracted-source scan above only sees versions that have
+        // actually been downloaded; consult the registry index directly so
+        // versions that were never fetched show up too.
+        let names: std::collections::BTreeSet<String> =
+            crates.iter().map(|c| c.name.clone()).collect();
+
+        let mut all_versions: std::collections::BTreeSet<(String, String)> =
+            std::collections::BTreeSet::new();
+        for name in &names {
+            let indexed = registry_index::list_all_versions(name, false);
+            if indexed.is_empty() {
+                for krate in crates.iter().filter(|c| &c.name == name) {
+                    all_versions.insert((krate.name.clone(), krate.version.clone()));
+                }
+            } else {
+                for v in indexed {
+                    all_versions.insert((v.name, v.version));
+                }
+            }
+        }
+
+        for (name, version) in &all_versions {
+            println!("{}@{}", name, version);
+        }
+
+        eprintln!(
+            "\n{} versions found across {} crates",
+            all_versions.len(),
+            names.len()
+        );
+        return Ok(());
+    }
+
     if latest {
         // Keep only the latest version of each crate
         let mut latest_map: std::collections::BTreeMap<String, RegistryCrate> =
@@ -283,7 +366,7 @@ fn cmd_peek(
     // Try cache first
     if !no_cache && Cache::exists() {
         if let Ok(cache) = Cache::open_existing() {
-            let items = cache.search_crate(crate_name, version)?;
+            let items = cache.search_crate(crate_name, version, None)?;
             if !items.is_empty() {
                 eprintln!("(from cache)");
                 for item in &items {
@@ -300,7 +383,31 @@ fn cmd_peek(
     }
 
     // Fall back to parsing - try project path deps first, then registry
-    let krate = find_specific_crate(crate_name, version, project.as_ref())?;
+    let krate = match find_specific_crate(crate_name, version, project.as_ref()) {
+        Ok(krate) => krate,
+        Err(e) => {
+            // Not cached locally - check the registry index before giving
+            // up, since the crate may simply never have been downloaded.
+            let indexed = registry_index::list_all_versions(crate_name, false);
+            if indexed.is_empty() {
+                return Err(e);
+            }
+
+            eprintln!(
+                "'{}' isn't cached locally; listing versions from the registry index:\n",
+                crate_name
+            );
+            for v in &indexed {
+                println!("{}@{}", v.name, v.version);
+                if !v.features.is_empty() {
+                    let feature_names: Vec<&str> = v.features.keys().map(String::as_str).collect();
+                    println!("  features: {}", feature_names.join(", "));
+                }
+            }
+            eprintln!("\n{} versions found", indexed.len());
+            return Ok(());
+        }
+    };
     eprintln!("Parsing {}@{} ...", krate.name, krate.version);
 
     let mut parser = RustParser::new()?;
@@ -349,7 +456,7 @@ fn cmd_find(
     // Try cache first
     if !no_cache && Cache::exists() {
         if let Ok(cache) = Cache::open_existing() {
-            let results = cache.search(query)?;
+            let results = cache.search(query, None)?;
             if !results.is_empty() {
                 // Default: filter to project deps (unless --all)
                 let results = if !search_all {
@@ -374,6 +481,11 @@ fn cmd_find(
                         "{}@{}: {} ({})",
                         r.crate_name, r.crate_version, r.path, r.kind
                     );
+                    if let Some(canonical) = import_map::canonical_path(&cache, &r.path) {
+                        if canonical != r.path {
+                            println!("    use {};", canonical);
+                        }
+                    }
                 }
                 eprintln!("\n{} matches found", results.len());
                 return Ok(());
@@ -449,6 +561,49 @@ fn cmd_where(name: &str) -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+fn cmd_info(path: Option<Utf8PathBuf>) -> Result<(), Box<dyn std::error::Error>> {
+    let project_dir = path.unwrap_or_else(|| Utf8PathBuf::from("."));
+    let provenance = resolve_dependency_provenance(&project_dir)?;
+
+    for (dep, source) in &provenance {
+        match source {
+            DependencyProvenance::Registry { host } => {
+                println!(
+                    "{}@{} - registry ({})",
+                    dep.name,
+                    dep.version,
+                    host.as_deref().unwrap_or("unknown")
+                );
+            }
+            DependencyProvenance::Git {
+                url,
+                branch,
+                rev,
+                checkout,
+            } => {
+                print!("{}@{} - git {}", dep.name, dep.version, url);
+                if let Some(branch) = branch {
+                    print!(" (branch: {})", branch);
+                }
+                if let Some(rev) = rev {
+                    print!(" (rev: {})", rev);
+                }
+                println!();
+                match checkout {
+                    Some(path) => println!("  checked out at: {}", path),
+                    None => println!("  checkout not found locally"),
+                }
+            }
+            DependencyProvenance::Path(path) => {
+                println!("{}@{} - path {}", dep.name, dep.version, path);
+            }
+        }
+    }
+
+    eprintln!("\n{} dependencies", provenance.len());
+    Ok(())
+}
+
 fn cmd_parse(file: &Utf8PathBuf, module: &str) -> Result<(), Box<dyn std::error::Error>> {
     let source = fs::read_to_string(file)?;
     let mut parser = RustParser::new()?;
@@ -469,7 +624,8 @@ fn cmd_parse_ts(file: &Utf8PathBuf, module: &str) -> Result<(), Box<dyn std::err
         _ => TsLanguage::TypeScript,
     };
 
-    let mut parser = TypeScriptParser::new(language)?;
+    let is_declaration = file.as_str().ends_with(".d.ts");
+    let mut parser = TypeScriptParser::new(language)?.with_ambient(is_declaration);
     let items = parser.parse_source(&source, module)?;
     let package = PackageItems { items };
     println!("{}", serde_json::to_string_pretty(&package)?);
@@ -481,10 +637,11 @@ fn cmd_peek_ts(path: &Utf8PathBuf, full: bool) -> Result<(), Box<dyn std::error:
     eprintln!("Parsing {}@{} ...", pkg.name, pkg.version);
 
     let mut all_items: Vec<Item> = Vec::new();
+    let tsconfig = npm::TsConfig::load(&pkg.path);
 
     for source_file in pkg.source_files() {
         let relative = source_file.strip_prefix(&pkg.path).unwrap_or(&source_file);
-        let module_path = npm::path_to_module(&pkg.name, relative);
+        let module_path = npm::path_to_module(&pkg.name, relative, tsconfig.as_ref());
 
         // Determine language from extension
         let language = match source_file.extension() {
@@ -493,8 +650,11 @@ fn cmd_peek_ts(path: &Utf8PathBuf, full: bool) -> Result<(), Box<dyn std::error:
             _ => TsLanguage::TypeScript,
         };
 
+        let is_declaration = source_file.as_str().ends_with(".d.ts");
+
         if let Ok(source) = fs::read_to_string(&source_file) {
-            if let Ok(mut parser) = TypeScriptParser::new(language) {
+            if let Ok(parser) = TypeScriptParser::new(language) {
+                let mut parser = parser.with_ambient(is_declaration);
                 if let Ok(items) = parser.parse_source(&source, &module_path) {
                     all_items.extend(items);
                 }
@@ -526,11 +686,12 @@ fn cmd_peek_ts(path: &Utf8PathBuf, full: bool) -> Result<(), Box<dyn std::error:
 
 // === Cache commands ===
 
-fn cmd_cache_build(force: bool) -> Result<(), Box<dyn std::error::Error>> {
+fn cmd_cache_build(force: bool, expand_macros: bool) -> Result<(), Box<dyn std::error::Error>> {
     let deps = resolve_project_deps(&Utf8PathBuf::from("."))?;
     eprintln!("Found {} dependencies", deps.len());
 
-    let stats = parallel_index(&deps, force).map_err(|e| -> Box<dyn std::error::Error> { e })?;
+    let stats = parallel_index(&deps, force, expand_macros)
+        .map_err(|e| -> Box<dyn std::error::Error> { e })?;
 
     eprintln!(
         "\nDone! Indexed {} crates ({} items), skipped {}, failed {}",
@@ -572,6 +733,22 @@ fn cmd_cache_list() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
+fn cmd_cache_export(output: &Utf8PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+    let cache = Cache::open_existing()?;
+    let file = fs::File::create(output)?;
+    let count = cache.export(std::io::BufWriter::new(file))?;
+    eprintln!("Exported {} crates to {}", count, output);
+    Ok(())
+}
+
+fn cmd_cache_import(input: &Utf8PathBuf) -> Result<(), Box<dyn std::error::Error>> {
+    let cache = Cache::open()?;
+    let file = fs::File::open(input)?;
+    let count = cache.import(std::io::BufReader::new(file))?;
+    eprintln!("Imported {} crates from {}", count, input);
+    Ok(())
+}
+
 // === Helpers ===
 
 /// Parse "crate@version" or just "crate".
@@ -652,17 +829,22 @@ pub fn path_to_module(crate_name: &str, path: &camino::Utf8Path) -> String {
     format!("{}::{}", crate_name, module_part)
 }
 
-/// Simple semver comparison (handles most common cases).
+/// Compares two version strings for "latest wins" selection: real semver
+/// ordering (which correctly ranks a prerelease below its release and
+/// ignores build metadata) when both parse, falling back to the coarse
+/// digit-group comparison when either doesn't.
 fn version_cmp(a: &str, b: &str) -> std::cmp::Ordering {
-    let parse = |v: &str| -> Vec<u64> {
-        v.split(|c: char| !c.is_ascii_digit())
-            .filter_map(|s| s.parse().ok())
-            .collect()
-    };
-
-    let a_parts = parse(a);
-    let b_parts = parse(b);
-    a_parts.cmp(&b_parts)
+    match (semver::Version::parse(a), semver::Version::parse(b)) {
+        (Ok(a), Ok(b)) => (a.major, a.minor, a.patch, &a.pre).cmp(&(b.major, b.minor, b.patch, &b.pre)),
+        _ => {
+            let parse = |v: &str| -> Vec<u64> {
+                v.split(|c: char| !c.is_ascii_digit())
+                    .filter_map(|s| s.parse().ok())
+                    .collect()
+            };
+            parse(a).cmp(&parse(b))
+        }
+    }
 }
 
 #[cfg(test)]
@@ -699,5 +881,13 @@ mod tests {
         assert_eq!(version_cmp("1.0.0", "1.0.1"), std::cmp::Ordering::Less);
         assert_eq!(version_cmp("1.0.10", "1.0.9"), std::cmp::Ordering::Greater);
         assert_eq!(version_cmp("2.0.0", "1.9.9"), std::cmp::Ordering::Greater);
+        assert_eq!(
+            version_cmp("1.0.0-alpha", "1.0.0"),
+            std::cmp::Ordering::Less
+        );
+        assert_eq!(
+            version_cmp("1.0.0-rc.2", "1.0.0-rc.11"),
+            std::cmp::Ordering::Less
+        );
     }
 }
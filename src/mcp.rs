@@ -3,8 +3,10 @@
 //! Provides smart search with fuzzy matching, pagination, and crate-aware results.
 
 use crate::cache::Cache;
-use crate::cargo::{RegistryCrate, resolve_project_deps};
+use crate::cargo::{resolve_graph, resolve_project_deps, RegistryCrate};
+use crate::import_map;
 use crate::languages::rust::RustParser;
+use crate::registry_index;
 use crate::schema::Item;
 use crate::search::{CrateRelationship, SearchEngine, SearchOptions, SearchResponse};
 use camino::Utf8PathBuf;
@@ -19,6 +21,8 @@ use rmcp::{ErrorData as McpError, ServerHandler, ServiceExt};
 use schemars::JsonSchema;
 use serde::Deserialize;
 use std::fs;
+use std::io::{BufRead, BufReader};
+use std::process::{Command, Stdio};
 use std::sync::Arc;
 
 pub fn cmd_mcp() -> i32 {
@@ -126,12 +130,88 @@ impl FastdepsService {
         ))
     }
 
+    /// One-shot package summary: resolved version, description, license,
+    /// doc/repo/homepage links, declared features with default status, MSRV
+    /// and immediate dependency count - a cheap overview before committing
+    /// to `peek`/`expand`'s heavier item-indexing traversals, mirroring
+    /// `cargo info`.
+    fn info_impl(&self, params: InfoParams) -> Result<String, String> {
+        let (crate_name, version) = parse_crate_spec(&params.name);
+        let krate = find_specific_crate(
+            crate_name,
+            version,
+            params.registry.as_deref(),
+            params.allow_prerelease.unwrap_or(false),
+            params.include_yanked.unwrap_or(false),
+        )?;
+        let metadata = krate.package_metadata().unwrap_or_default();
+
+        let mut output = format!("# {}@{}\n\n", krate.name, krate.version);
+
+        if let Some(description) = &metadata.description {
+            output.push_str(&format!("{}\n\n", description));
+        }
+        if let Some(license) = &metadata.license {
+            output.push_str(&format!("License: {}\n", license));
+        }
+        if let Some(rust_version) = &metadata.rust_version {
+            output.push_str(&format!("MSRV: {}\n", rust_version));
+        }
+        if let Some(documentation) = &metadata.documentation {
+            output.push_str(&format!("Documentation: {}\n", documentation));
+        }
+        if let Some(repository) = &metadata.repository {
+            output.push_str(&format!("Repository: {}\n", repository));
+        }
+        if let Some(homepage) = &metadata.homepage {
+            output.push_str(&format!("Homepage: {}\n", homepage));
+        }
+        output.push_str(&format!("Dependencies: {}\n", metadata.dependency_count));
+
+        if !metadata.features.is_empty() {
+            let default_features: std::collections::HashSet<&str> = metadata
+                .features
+                .iter()
+                .find(|(name, _)| name == "default")
+                .map(|(_, subs)| subs.iter().map(String::as_str).collect())
+                .unwrap_or_default();
+
+            output.push_str("\nFeatures:\n");
+            for (name, _) in &metadata.features {
+                if name == "default" {
+                    continue;
+                }
+                let marker = if default_features.contains(name.as_str()) {
+                    " (default)"
+                } else {
+                    ""
+                };
+                output.push_str(&format!("  - {}{}\n", name, marker));
+            }
+        }
+
+        Ok(output)
+    }
+
     fn peek_impl(&self, params: PeekParams) -> Result<String, String> {
         let (crate_name, version) = parse_crate_spec(&params.name);
 
         // Use search engine for smart crate lookup
         let engine = SearchEngine::new(&Utf8PathBuf::from(".")).map_err(|e| e.to_string())?;
-        let crate_info = engine.get_crate_info(crate_name)?;
+        let crate_info = match engine.get_crate_info(crate_name) {
+            Ok(info) => info,
+            Err(e) => {
+                // Not resolvable locally - before giving up, check whether
+                // the registry index knows about it anyway (never
+                // downloaded rather than nonexistent).
+                if let Some(listing) =
+                    describe_uncached_crate(crate_name, params.include_yanked.unwrap_or(false))
+                {
+                    return Ok(listing);
+                }
+                return Err(append_crate_suggestions(e, &engine, crate_name));
+            }
+        };
 
         let mut output = String::new();
 
@@ -171,7 +251,7 @@ impl FastdepsService {
         if Cache::exists() {
             if let Ok(cache) = Cache::open_existing() {
                 let items = cache
-                    .search_crate(crate_name, version)
+                    .search_crate(crate_name, version, None)
                     .map_err(|e| e.to_string())?;
 
                 if !items.is_empty() {
@@ -251,7 +331,13 @@ impl FastdepsService {
         }
 
         // Fall back to parsing
-        let krate = find_specific_crate(crate_name, version)?;
+        let krate = find_specific_crate(
+            crate_name,
+            version,
+            params.registry.as_deref(),
+            params.allow_prerelease.unwrap_or(false),
+            params.include_yanked.unwrap_or(false),
+        )?;
         output.push_str("\n(parsed fresh - not cached)\n\n");
 
         let mut parser = RustParser::new().map_err(|e| e.to_string())?;
@@ -322,7 +408,7 @@ impl FastdepsService {
         if !Cache::exists() {
             let deps =
                 resolve_project_deps(&Utf8PathBuf::from("."), false).map_err(|e| e.to_string())?;
-            crate::cache::parallel_index(&deps, false).map_err(|e| e.to_string())?;
+            crate::cache::parallel_index(&deps, false, false).map_err(|e| e.to_string())?;
         }
 
         let response = engine.search(&params.query, &options)?;
@@ -386,6 +472,9 @@ impl FastdepsService {
                     result.kind,
                     score_info
                 ));
+                if let Some(ref canonical) = result.canonical_import {
+                    output.push_str(&format!("    use {};\n", canonical));
+                }
             }
 
             if response.pagination.has_more() {
@@ -417,20 +506,235 @@ impl FastdepsService {
         Ok(output)
     }
 
-    fn where_impl(&self, name: String) -> Result<String, String> {
-        let (crate_name, version) = parse_crate_spec(&name);
-        let krate = find_specific_crate(crate_name, version)?;
+    fn import_impl(&self, params: ImportParams) -> Result<String, String> {
+        if !Cache::exists() {
+            return Err("No cache found - run `fastdeps index` first".to_string());
+        }
+        let cache = Cache::open_existing().map_err(|e| e.to_string())?;
+
+        // Resolve the symbol to its fully-qualified definition path via the
+        // same search engine `find` uses, rather than requiring the caller
+        // to already know the exact path.
+        let engine = SearchEngine::new(&Utf8PathBuf::from(".")).map_err(|e| e.to_string())?;
+        let mut options = SearchOptions::new().with_limit(1);
+        if let Some(ref crate_name) = params.crate_filter {
+            options = options.with_crate(crate_name);
+        }
+        let response = engine.search(&params.name, &options)?;
+
+        let Some(top) = response.results.into_iter().next() else {
+            return Ok(format!("No symbol found matching '{}'", params.name));
+        };
+
+        // An item only counts as "publicly importable" through a path that
+        // isn't just its own private definition.
+        let is_private = cache
+            .search_crate(&top.crate_name, Some(&top.crate_version), None)
+            .unwrap_or_default()
+            .into_iter()
+            .find(|item| item.path == top.path)
+            .is_some_and(|item| item.visibility != "public");
+
+        let candidates = import_map::best_import_paths(&cache, &top.path);
+        let public_candidates: Vec<_> = if is_private {
+            candidates.into_iter().filter(|p| p != &top.path).collect()
+        } else {
+            candidates
+        };
+
+        let mut output = String::new();
+        if public_candidates.is_empty() {
+            output.push_str(&format!(
+                "use {}; // not publicly importable - only reachable via a private module\n",
+                top.path
+            ));
+        } else {
+            for path in &public_candidates {
+                output.push_str(&format!("use {};\n", path));
+            }
+            if public_candidates.len() > 1 {
+                output.push_str(&format!(
+                    "\n{} equally short public paths found.\n",
+                    public_candidates.len()
+                ));
+            }
+        }
+
+        Ok(output)
+    }
+
+    /// Bidirectional trait/type implementation lookup: `name` is first
+    /// tried as a trait (listing implementing types), falling back to
+    /// resolving it as a type (listing implemented traits) if that comes up
+    /// empty. Cross-crate impls aren't resolved - the parser only records
+    /// `RelationKind::Implements` edges within the crate being parsed, the
+    /// same limitation `find_reexports_of` has for cross-crate re-exports.
+    fn impls_impl(&self, params: ImplsParams) -> Result<String, String> {
+        if !Cache::exists() {
+            return Err("No cache found - run `fastdeps index` first".to_string());
+        }
+        let cache = Cache::open_existing().map_err(|e| e.to_string())?;
+        let engine = SearchEngine::new(&Utf8PathBuf::from(".")).map_err(|e| e.to_string())?;
+
+        let offset = params.offset.unwrap_or(0);
+        let limit = params.limit.unwrap_or(25);
+
+        let trait_name = params.name.rsplit("::").next().unwrap_or(&params.name);
+        let mut implementors = cache
+            .find_implementors(trait_name)
+            .map_err(|e| e.to_string())?;
+
+        if let Some(ref crate_name) = params.crate_filter {
+            implementors.retain(|r| &r.crate_name == crate_name);
+        }
+        if params.direct_only.unwrap_or(false) {
+            implementors.retain(|r| engine.is_direct_dep(&r.crate_name));
+        }
+
+        let mut output = String::new();
+
+        if !implementors.is_empty() {
+            implementors.sort_by(|a, b| (&a.crate_name, &a.path).cmp(&(&b.crate_name, &b.path)));
+            let total = implementors.len();
+            let page: Vec<_> = implementors.into_iter().skip(offset).take(limit).collect();
+
+            output.push_str(&format!(
+                "Types implementing '{}' (showing {}-{} of {}):\n\n",
+                trait_name,
+                offset + 1,
+                offset + page.len(),
+                total
+            ));
+            for result in &page {
+                let marker = if engine.is_direct_dep(&result.crate_name) {
+                    "●"
+                } else {
+                    "○"
+                };
+                output.push_str(&format!(
+                    "{} {}@{}: {} ({})\n",
+                    marker, result.crate_name, result.crate_version, result.path, result.kind
+                ));
+            }
+            if offset + page.len() < total {
+                output.push_str(&format!(
+                    "\nUse offset={} for next page",
+                    offset + page.len()
+                ));
+            }
+
+            return Ok(output);
+        }
+
+        // No implementors found treating `name` as a trait - try it as a
+        // type instead, resolving to a canonical item path first.
+        let mut search_options = SearchOptions::new().with_limit(1);
+        if let Some(ref crate_name) = params.crate_filter {
+            search_options = search_options.with_crate(crate_name);
+        }
+        let response = engine.search(&params.name, &search_options)?;
+
+        let Some(top) = response.results.into_iter().next() else {
+            return Ok(format!(
+                "No trait implementors or resolvable type found for '{}'",
+                params.name
+            ));
+        };
+
+        let mut traits = cache
+            .find_implemented_traits(&top.path)
+            .map_err(|e| e.to_string())?;
+        if traits.is_empty() {
+            return Ok(format!("{} implements no indexed traits", top.path));
+        }
+
+        let total = traits.len();
+        traits.truncate(offset + limit);
+        let page: Vec<_> = traits.into_iter().skip(offset).collect();
+
+        let marker = if engine.is_direct_dep(&top.crate_name) {
+            "●"
+        } else {
+            "○"
+        };
+        output.push_str(&format!(
+            "Traits implemented by {} ({} {}@{}, showing {}-{} of {}):\n\n",
+            top.path,
+            marker,
+            top.crate_name,
+            top.crate_version,
+            offset + 1,
+            offset + page.len(),
+            total
+        ));
+        for trait_path in &page {
+            output.push_str(&format!("  - {}\n", trait_path));
+        }
+        if offset + page.len() < total {
+            output.push_str(&format!(
+                "\nUse offset={} for next page",
+                offset + page.len()
+            ));
+        }
+
+        Ok(output)
+    }
+
+    fn where_impl(&self, params: WhereParams) -> Result<String, String> {
+        let (crate_name, version) = parse_crate_spec(&params.name);
+        let krate = find_specific_crate(
+            crate_name,
+            version,
+            params.registry.as_deref(),
+            params.allow_prerelease.unwrap_or(false),
+            params.include_yanked.unwrap_or(false),
+        )
+        .map_err(|e| match SearchEngine::new(&Utf8PathBuf::from(".")) {
+            Ok(engine) => append_crate_suggestions(e, &engine, crate_name),
+            Err(_) => e,
+        })?;
 
         let mut result = krate.path.to_string();
         if let Some(lib) = krate.lib_path() {
             result.push_str(&format!("\nEntry point: {}", lib));
         }
+
+        if let Ok(graph) = resolve_graph(&Utf8PathBuf::from(".")) {
+            let epochs = graph.epoch_groups(crate_name);
+            if epochs.len() > 1 {
+                result.push_str(&format!(
+                    "\n\n{} incompatible epochs of '{}' in the resolved graph:\n",
+                    epochs.len(),
+                    crate_name
+                ));
+                for (epoch, versions) in &epochs {
+                    for (id, dependents) in versions {
+                        let dependent_names = if dependents.is_empty() {
+                            "(root)".to_string()
+                        } else {
+                            dependents
+                                .iter()
+                                .map(|d| d.name.as_str())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        };
+                        result.push_str(&format!(
+                            "  epoch {} ({}@{}): used by {}\n",
+                            epoch, id.name, id.version, dependent_names
+                        ));
+                    }
+                }
+            }
+        }
+
         Ok(result)
     }
 
     fn expand_impl(&self, params: ExpandParams) -> Result<String, String> {
         let engine = SearchEngine::new(&Utf8PathBuf::from(".")).map_err(|e| e.to_string())?;
-        let crate_info = engine.get_crate_info(&params.name)?;
+        let crate_info = engine
+            .get_crate_info(&params.name)
+            .map_err(|e| append_crate_suggestions(e, &engine, &params.name))?;
 
         let mut output = String::new();
 
@@ -468,6 +772,158 @@ impl FastdepsService {
 
         Ok(output)
     }
+
+    /// Runs `cargo check` (or `cargo clippy` when `clippy: true`) with
+    /// `--message-format=json`, streaming its stdout line-by-line and
+    /// parsing each `compiler-message` into a flat `Diagnostic` instead of
+    /// forwarding cargo's raw human-formatted output - callers get
+    /// severity, the primary span, the lint/error code and any
+    /// machine-applicable suggested replacement without shelling out to
+    /// cargo themselves.
+    fn check_impl(&self, params: CheckParams) -> Result<String, String> {
+        let project_dir = Utf8PathBuf::from(params.path.unwrap_or_else(|| ".".to_string()));
+        let subcommand = if params.clippy.unwrap_or(false) {
+            "clippy"
+        } else {
+            "check"
+        };
+
+        let mut child = Command::new("cargo")
+            .arg(subcommand)
+            .arg("--message-format=json")
+            .current_dir(&project_dir)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::null())
+            .spawn()
+            .map_err(|e| format!("failed to run `cargo {}`: {}", subcommand, e))?;
+
+        let stdout = child.stdout.take().expect("stdout was piped");
+        let reader = BufReader::new(stdout);
+
+        let mut by_file: std::collections::BTreeMap<String, Vec<Diagnostic>> =
+            std::collections::BTreeMap::new();
+        let mut total = 0usize;
+        for line in reader.lines() {
+            let Ok(line) = line else { continue };
+            let Ok(value) = serde_json::from_str::<serde_json::Value>(&line) else {
+                continue;
+            };
+            if value.get("reason").and_then(|r| r.as_str()) != Some("compiler-message") {
+                continue;
+            }
+            let Some(message) = value.get("message") else {
+                continue;
+            };
+            let Some(diagnostic) = Diagnostic::from_message(message) else {
+                continue;
+            };
+            total += 1;
+            by_file
+                .entry(diagnostic.file.clone())
+                .or_default()
+                .push(diagnostic);
+        }
+        let _ = child.wait();
+
+        if total == 0 {
+            return Ok(format!("cargo {} found no diagnostics", subcommand));
+        }
+
+        let limit = params.limit.unwrap_or(50);
+        let mut output = String::new();
+        let mut shown = 0;
+        'files: for (file, diagnostics) in &by_file {
+            output.push_str(&format!("{}:\n", file));
+            for diagnostic in diagnostics {
+                if shown >= limit {
+                    break 'files;
+                }
+                output.push_str(&format!(
+                    "  {} [{}] {}:{}: {}\n",
+                    diagnostic.level,
+                    diagnostic.code.as_deref().unwrap_or("-"),
+                    diagnostic.line,
+                    diagnostic.column,
+                    diagnostic.text,
+                ));
+                if let Some(ref replacement) = diagnostic.suggested_replacement {
+                    output.push_str(&format!("    suggested fix: {}\n", replacement));
+                }
+                shown += 1;
+            }
+            output.push('\n');
+        }
+
+        output.push_str(&format!("{} of {} diagnostics shown", shown, total));
+        if shown < total {
+            output.push_str(" (raise `limit` to see more)");
+        }
+
+        Ok(output)
+    }
+}
+
+/// One flattened `compiler-message`: the primary span's location plus the
+/// level, lint/error code and message text an LLM client needs to act on
+/// it, and the first machine-applicable suggested replacement found across
+/// the primary span or any child (`help:`) span, if cargo offered one.
+struct Diagnostic {
+    level: String,
+    code: Option<String>,
+    file: String,
+    line: u64,
+    column: u64,
+    /// Multi-line diagnostic text collapsed to its first line, to keep
+    /// noisy multi-line notes to a single summary line per diagnostic.
+    text: String,
+    suggested_replacement: Option<String>,
+}
+
+impl Diagnostic {
+    fn from_message(message: &serde_json::Value) -> Option<Self> {
+        let spans = message.get("spans")?.as_array()?;
+        let primary = spans
+            .iter()
+            .find(|s| s["is_primary"].as_bool().unwrap_or(false))?;
+
+        let child_spans = message
+            .get("children")
+            .and_then(|c| c.as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|child| child.get("spans"))
+            .filter_map(|s| s.as_array())
+            .flatten();
+        let suggested_replacement = spans
+            .iter()
+            .chain(child_spans)
+            .filter(|s| {
+                s.get("suggestion_applicability").and_then(|a| a.as_str())
+                    == Some("MachineApplicable")
+            })
+            .find_map(|s| s.get("suggested_replacement").and_then(|r| r.as_str()))
+            .map(|s| s.to_string());
+
+        Some(Self {
+            level: message.get("level")?.as_str()?.to_string(),
+            code: message
+                .get("code")
+                .and_then(|c| c.get("code"))
+                .and_then(|c| c.as_str())
+                .map(|s| s.to_string()),
+            file: primary.get("file_name")?.as_str()?.to_string(),
+            line: primary.get("line_start")?.as_u64()?,
+            column: primary.get("column_start")?.as_u64()?,
+            text: message
+                .get("message")?
+                .as_str()?
+                .lines()
+                .next()
+                .unwrap_or("")
+                .to_string(),
+            suggested_replacement,
+        })
+    }
 }
 
 // === Parameter structs ===
@@ -494,6 +950,28 @@ struct DepsParams {
     path: Option<String>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+struct InfoParams {
+    /// Crate name (e.g., "serde" or "serde@1.0.200")
+    #[schemars(
+        description = "Crate name, optionally with version (e.g., 'serde' or 'serde@1.0.200')"
+    )]
+    name: String,
+    /// Resolve against a non-default registry (name from `.cargo/config.toml`,
+    /// a `CARGO_REGISTRIES_*` env var, or a bare index URL)
+    #[schemars(description = "Resolve against a non-default registry (name or index URL)")]
+    registry: Option<String>,
+    /// Allow resolving to a prerelease when picking "latest" (ignored for
+    /// an explicit `@version`, which always resolves as asked)
+    #[schemars(description = "Allow prereleases when picking the latest version (default false)")]
+    allow_prerelease: Option<bool>,
+    /// Allow resolving to a yanked version when picking "latest"
+    #[schemars(
+        description = "Allow yanked versions when picking the latest version (default false)"
+    )]
+    include_yanked: Option<bool>,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 struct PeekParams {
     /// Crate name (e.g., "serde" or "serde@1.0.200")
@@ -515,6 +993,19 @@ struct PeekParams {
         description = "Filter by item kind: struct, trait, function, enum, macro, constant, module"
     )]
     kind: Option<String>,
+    /// Resolve against a non-default registry (name from `.cargo/config.toml`,
+    /// a `CARGO_REGISTRIES_*` env var, or a bare index URL)
+    #[schemars(description = "Resolve against a non-default registry (name or index URL)")]
+    registry: Option<String>,
+    /// Allow resolving to a prerelease when picking "latest" (ignored for
+    /// an explicit `@version`, which always resolves as asked)
+    #[schemars(description = "Allow prereleases when picking the latest version (default false)")]
+    allow_prerelease: Option<bool>,
+    /// Allow resolving to a yanked version when picking "latest"
+    #[schemars(
+        description = "Allow yanked versions when picking the latest version (default false)"
+    )]
+    include_yanked: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -545,10 +1036,54 @@ struct FindParams {
     show_scores: Option<bool>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ImportParams {
+    /// Symbol to resolve, e.g. "Serialize" or "serde::ser::Serialize"
+    #[schemars(description = "Symbol name to compute the shortest public import path for")]
+    name: String,
+    /// Filter to a specific crate
+    #[schemars(description = "Filter to a specific crate")]
+    crate_filter: Option<String>,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+struct ImplsParams {
+    /// Trait name (lists implementing types) or type name (lists implemented traits)
+    #[schemars(
+        description = "Trait name to list implementing types, or type name to list implemented traits"
+    )]
+    name: String,
+    /// Filter to a specific crate
+    #[schemars(description = "Filter results to a specific crate")]
+    crate_filter: Option<String>,
+    /// Only show implementors from direct dependencies
+    #[schemars(description = "Only show implementors from direct dependencies (not transitive)")]
+    direct_only: Option<bool>,
+    /// Maximum results to return (default: 25)
+    #[schemars(description = "Maximum results to return")]
+    limit: Option<usize>,
+    /// Offset for pagination
+    #[schemars(description = "Offset for pagination")]
+    offset: Option<usize>,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 struct WhereParams {
     /// Crate name (e.g., "serde" or "serde@1.0.200")
     name: String,
+    /// Resolve against a non-default registry (name from `.cargo/config.toml`,
+    /// a `CARGO_REGISTRIES_*` env var, or a bare index URL)
+    #[schemars(description = "Resolve against a non-default registry (name or index URL)")]
+    registry: Option<String>,
+    /// Allow resolving to a prerelease when picking "latest" (ignored for
+    /// an explicit `@version`, which always resolves as asked)
+    #[schemars(description = "Allow prereleases when picking the latest version (default false)")]
+    allow_prerelease: Option<bool>,
+    /// Allow resolving to a yanked version when picking "latest"
+    #[schemars(
+        description = "Allow yanked versions when picking the latest version (default false)"
+    )]
+    include_yanked: Option<bool>,
 }
 
 #[derive(Debug, Deserialize, JsonSchema)]
@@ -558,6 +1093,19 @@ struct ExpandParams {
     name: String,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+struct CheckParams {
+    /// Run `cargo clippy` instead of `cargo check`
+    #[schemars(description = "Run `cargo clippy` instead of `cargo check`")]
+    clippy: Option<bool>,
+    /// Path to project directory (defaults to current dir)
+    #[schemars(description = "Path to project directory (defaults to current dir)")]
+    path: Option<String>,
+    /// Maximum diagnostics to return (default: 50)
+    #[schemars(description = "Maximum diagnostics to return")]
+    limit: Option<usize>,
+}
+
 impl ServerHandler for FastdepsService {
     fn get_info(&self) -> ServerInfo {
         ServerInfo {
@@ -575,13 +1123,18 @@ impl ServerHandler for FastdepsService {
                  Tools:\n\
                  - list: List project dependencies (with pagination)\n\
                  - deps: Show Cargo.lock dependencies\n\
+                 - info: One-shot package summary (version, license, links, features)\n\
                  - peek: View a crate's API (structs, traits, functions)\n\
                  - find: Search symbols with fuzzy matching and scoring\n\
                  - expand: Show related crates (e.g., bevy → bevy_ecs, bevy_app)\n\
-                 - where: Locate crate source on disk\n\n\
+                 - where: Locate crate source on disk\n\
+                 - import: Compute the shortest public `use` path for a symbol\n\
+                 - impls: List types implementing a trait, or traits a type implements\n\
+                 - check: Run cargo check/clippy and return structured diagnostics\n\n\
                  Tips:\n\
                  - Use crate_filter to narrow search to one crate\n\
                  - Use kind filter for struct/trait/function/etc.\n\
+                 - Use registry on peek/where to resolve against a non-default registry\n\
                  - ● = direct dependency, ○ = transitive\n\
                  - Pagination: use limit/offset to navigate large results"
                     .to_string(),
@@ -607,6 +1160,11 @@ impl ServerHandler for FastdepsService {
                         "List dependencies of a project from its Cargo.lock",
                         cached_schema_for_type::<DepsParams>(),
                     ),
+                    Tool::new(
+                        "info",
+                        "One-shot package summary: version, description, license, links, features, MSRV",
+                        cached_schema_for_type::<InfoParams>(),
+                    ),
                     Tool::new(
                         "peek",
                         "View a crate's API surface (structs, functions, traits, etc.)",
@@ -622,11 +1180,26 @@ impl ServerHandler for FastdepsService {
                         "Expand a crate to show related crates (e.g., bevy → bevy_ecs)",
                         cached_schema_for_type::<ExpandParams>(),
                     ),
+                    Tool::new(
+                        "import",
+                        "Compute the shortest public `use` path for a found symbol",
+                        cached_schema_for_type::<ImportParams>(),
+                    ),
                     Tool::new(
                         "where",
                         "Show the source path for a crate on disk",
                         cached_schema_for_type::<WhereParams>(),
                     ),
+                    Tool::new(
+                        "impls",
+                        "List types implementing a trait, or traits a type implements",
+                        cached_schema_for_type::<ImplsParams>(),
+                    ),
+                    Tool::new(
+                        "check",
+                        "Run cargo check (or clippy) and return structured diagnostics",
+                        cached_schema_for_type::<CheckParams>(),
+                    ),
                 ],
                 next_cursor: None,
             })
@@ -669,6 +1242,16 @@ impl ServerHandler for FastdepsService {
                         Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
                     }
                 }
+                "info" => {
+                    let params: InfoParams = serde_json::from_value(args_value).map_err(|e| {
+                        McpError::invalid_params(format!("Invalid parameters: {}", e), None)
+                    })?;
+
+                    match this.info_impl(params) {
+                        Ok(output) => Ok(CallToolResult::success(vec![Content::text(output)])),
+                        Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+                    }
+                }
                 "peek" => {
                     let params: PeekParams = serde_json::from_value(args_value).map_err(|e| {
                         McpError::invalid_params(format!("Invalid parameters: {}", e), None)
@@ -704,7 +1287,37 @@ impl ServerHandler for FastdepsService {
                         McpError::invalid_params(format!("Invalid parameters: {}", e), None)
                     })?;
 
-                    match this.where_impl(params.name) {
+                    match this.where_impl(params) {
+                        Ok(output) => Ok(CallToolResult::success(vec![Content::text(output)])),
+                        Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+                    }
+                }
+                "import" => {
+                    let params: ImportParams = serde_json::from_value(args_value).map_err(|e| {
+                        McpError::invalid_params(format!("Invalid parameters: {}", e), None)
+                    })?;
+
+                    match this.import_impl(params) {
+                        Ok(output) => Ok(CallToolResult::success(vec![Content::text(output)])),
+                        Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+                    }
+                }
+                "impls" => {
+                    let params: ImplsParams = serde_json::from_value(args_value).map_err(|e| {
+                        McpError::invalid_params(format!("Invalid parameters: {}", e), None)
+                    })?;
+
+                    match this.impls_impl(params) {
+                        Ok(output) => Ok(CallToolResult::success(vec![Content::text(output)])),
+                        Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
+                    }
+                }
+                "check" => {
+                    let params: CheckParams = serde_json::from_value(args_value).map_err(|e| {
+                        McpError::invalid_params(format!("Invalid parameters: {}", e), None)
+                    })?;
+
+                    match this.check_impl(params) {
                         Ok(output) => Ok(CallToolResult::success(vec![Content::text(output)])),
                         Err(e) => Ok(CallToolResult::error(vec![Content::text(e)])),
                     }
@@ -720,6 +1333,53 @@ impl ServerHandler for FastdepsService {
 
 // === Helpers ===
 
+/// When a crate can't be resolved locally (no extracted source, so it
+/// isn't a project dependency as far as `get_crate_info` can tell), check
+/// the registry index directly before giving up - it may simply never
+/// have been downloaded. Returns `None` if the index has no record of
+/// `name` either, so the caller can fall back to typo suggestions.
+fn describe_uncached_crate(name: &str, include_yanked: bool) -> Option<String> {
+    let versions = registry_index::list_all_versions(name, include_yanked);
+    if versions.is_empty() {
+        return None;
+    }
+
+    let mut output = format!(
+        "# {}\n(not cached locally - listing from the registry index)\n\n",
+        name
+    );
+    for v in &versions {
+        output.push_str(&format!("{}@{}", v.name, v.version));
+        if v.yanked {
+            output.push_str(" (yanked)");
+        }
+        output.push('\n');
+        if !v.features.is_empty() {
+            let feature_names: Vec<&str> = v.features.keys().map(String::as_str).collect();
+            output.push_str(&format!("  features: {}\n", feature_names.join(", ")));
+        }
+    }
+    output.push_str(&format!("\n{} versions found\n", versions.len()));
+    Some(output)
+}
+
+/// Append a "Did you mean?" block of Levenshtein-close dependency names to
+/// `error`, mirroring `find`'s suggestion UX for crate-name typos in
+/// `peek`/`where`/`expand`. Returns `error` unchanged if nothing's close.
+fn append_crate_suggestions(error: String, engine: &SearchEngine, query: &str) -> String {
+    let suggestions = engine.suggest_crate_names(query);
+    if suggestions.is_empty() {
+        return error;
+    }
+
+    let mut message = error;
+    message.push_str("\n\nDid you mean?\n");
+    for suggestion in &suggestions {
+        message.push_str(&format!("  - {}\n", suggestion));
+    }
+    message
+}
+
 fn parse_crate_spec(spec: &str) -> (&str, Option<&str>) {
     if let Some((name, version)) = spec.split_once('@') {
         (name, Some(version))
@@ -728,36 +1388,124 @@ fn parse_crate_spec(spec: &str) -> (&str, Option<&str>) {
     }
 }
 
-fn find_specific_crate(name: &str, version: Option<&str>) -> Result<RegistryCrate, String> {
-    use crate::cargo::find_crate;
-
-    let crates = find_crate(name).map_err(|e| e.to_string())?;
+/// Resolves a crate name (and optional version requirement) to a single
+/// cached copy. `allow_prerelease` and `include_yanked` gate which versions
+/// are eligible when picking "latest" (no version given) - matching
+/// cargo-edit's `get_latest_dependency`, where an unadorned `cargo add foo`
+/// skips prereleases and yanked releases but `foo@1.2.3-beta.1` resolves
+/// the exact version asked for regardless, since the caller already said
+/// exactly what they want.
+fn find_specific_crate(
+    name: &str,
+    version: Option<&str>,
+    registry: Option<&str>,
+    allow_prerelease: bool,
+    include_yanked: bool,
+) -> Result<RegistryCrate, String> {
+    use crate::cargo::find_crate_in_registry;
+
+    let crates = find_crate_in_registry(name, registry).map_err(|e| e.to_string())?;
 
     if crates.is_empty() {
-        return Err(format!("Crate '{}' not found in registry", name));
+        if let Some(krate) = find_git_sourced_crate(name) {
+            return Ok(krate);
+        }
+        return Err(match registry {
+            Some(registry) => format!("Crate '{}' not found in registry '{}'", name, registry),
+            None => format!("Crate '{}' not found in registry", name),
+        });
     }
 
-    if let Some(v) = version {
-        crates
-            .into_iter()
-            .find(|c| c.version == v)
-            .ok_or_else(|| format!("Version {} of '{}' not found", v, name))
-    } else {
-        crates
+    match version {
+        Some(requirement) => find_matching_version(&crates, name, requirement),
+        None => crates
             .into_iter()
+            .filter(|c| allow_prerelease || !is_prerelease(&c.version))
+            .filter(|c| include_yanked || !c.is_yanked())
             .max_by(|a, b| version_cmp(&a.version, &b.version))
-            .ok_or_else(|| format!("No versions found for '{}'", name))
+            .ok_or_else(|| format!("No versions found for '{}'", name)),
     }
 }
 
-fn version_cmp(a: &str, b: &str) -> std::cmp::Ordering {
-    let parse = |v: &str| -> Vec<u64> {
-        v.split(|c: char| !c.is_ascii_digit())
-            .filter_map(|s| s.parse().ok())
-            .collect()
+/// Fallback for crates that only exist as a `git` dependency of the
+/// current project - `find_crate_in_registry` only scans
+/// `~/.cargo/registry/src`, so a git-sourced crate is otherwise invisible
+/// to `find_specific_crate` even though its checkout sits on disk.
+fn find_git_sourced_crate(name: &str) -> Option<RegistryCrate> {
+    use crate::cargo::{resolve_dependency_provenance, DependencyProvenance};
+
+    let provenance = resolve_dependency_provenance(&Utf8PathBuf::from(".")).ok()?;
+    provenance.into_iter().find_map(|(dep, source)| {
+        if dep.name != name {
+            return None;
+        }
+        match source {
+            DependencyProvenance::Git {
+                checkout: Some(path),
+                ..
+            } => Some(RegistryCrate {
+                name: dep.name,
+                version: dep.version,
+                path,
+                registry: None,
+            }),
+            _ => None,
+        }
+    })
+}
+
+/// Whether `version`'s semver core carries prerelease identifiers (e.g.
+/// `1.0.0-beta.1`). Versions that don't parse as semver are treated as not
+/// prerelease, matching `version_cmp`'s fallback of trusting non-semver
+/// tags at face value.
+fn is_prerelease(version: &str) -> bool {
+    semver::Version::parse(version).is_ok_and(|v| !v.pre.is_empty())
+}
+
+/// Resolves a version *requirement* (`^1.2`, `>=1.20, <2`, or a bare exact
+/// version) against `crates`, picking the highest match - mirrors
+/// cargo-edit's `get_latest_dependency` resolving a dependency spec against
+/// a registry rather than only accepting an exact version string. An exact
+/// string match always wins first: `semver::VersionReq::parse("1.2.3")`
+/// succeeds (it's equivalent to `^1.2.3`), so checking the requirement
+/// itself first - before ever treating it as a range - is the only way
+/// `foo@1.2.3` keeps resolving to `1.2.3` instead of the highest
+/// caret-compatible version installed.
+fn find_matching_version(
+    crates: &[RegistryCrate],
+    name: &str,
+    requirement: &str,
+) -> Result<RegistryCrate, String> {
+    if let Some(exact) = crates.iter().find(|c| c.version == requirement) {
+        return Ok(exact.clone());
+    }
+
+    let Ok(req) = semver::VersionReq::parse(requirement) else {
+        return Err(format!("Version {} of '{}' not found", requirement, name));
     };
 
-    let a_parts = parse(a);
-    let b_parts = parse(b);
-    a_parts.cmp(&b_parts)
+    crates
+        .iter()
+        .filter(|c| semver::Version::parse(&c.version).is_ok_and(|v| req.matches(&v)))
+        .max_by(|a, b| version_cmp(&a.version, &b.version))
+        .cloned()
+        .ok_or_else(|| format!("No version of '{}' satisfies '{}'", name, requirement))
+}
+
+/// Compares two version strings for "latest wins" selection: real semver
+/// ordering (which correctly ranks a prerelease below its release and
+/// ignores build metadata) when both parse, falling back to the coarse
+/// digit-group comparison when either doesn't.
+fn version_cmp(a: &str, b: &str) -> std::cmp::Ordering {
+    match (semver::Version::parse(a), semver::Version::parse(b)) {
+        (Ok(a), Ok(b)) => (a.major, a.minor, a.patch, &a.pre).cmp(&(b.major, b.minor, b.patch, &b.pre)),
+        _ => {
+            let parse = |v: &str| -> Vec<u64> {
+                v.split(|c: char| !c.is_ascii_digit())
+                    .filter_map(|s| s.parse().ok())
+                    .collect()
+            };
+            parse(a).cmp(&parse(b))
+        }
+    }
 }
@@ -3,6 +3,7 @@
 //! Handles finding TypeScript/JavaScript packages and their source files.
 
 use camino::{Utf8Path, Utf8PathBuf};
+use rayon::prelude::*;
 use serde::Deserialize;
 use std::fs;
 use thiserror::Error;
@@ -17,6 +18,8 @@ pub enum NpmError {
     PackageJsonNotFound,
     #[error("node_modules not found")]
     NodeModulesNotFound,
+    #[error("no package-lock.json, yarn.lock, or pnpm-lock.yaml found")]
+    LockfileNotFound,
 }
 
 /// A discovered npm package.
@@ -27,6 +30,12 @@ pub struct NpmPackage {
     pub path: Utf8PathBuf,
     pub main: Option<String>,
     pub types: Option<String>,
+    /// The raw `"exports"` map, kept as JSON since its shape (string,
+    /// subpath object, or condition object) is recursive.
+    pub exports: Option<serde_json::Value>,
+    /// The raw `"imports"` map (internal `#subpath` imports), same shape as
+    /// `exports`.
+    pub imports: Option<serde_json::Value>,
 }
 
 impl NpmPackage {
@@ -37,20 +46,20 @@ impl NpmPackage {
         // Check src/ directory first (most common)
         let src_dir = self.path.join("src");
         if src_dir.exists() && src_dir.is_dir() {
-            collect_ts_files(&src_dir, &mut files);
+            files.extend(collect_ts_files(&src_dir));
         }
 
         // If no files in src/, try lib/ or root
         if files.is_empty() {
             let lib_dir = self.path.join("lib");
             if lib_dir.exists() && lib_dir.is_dir() {
-                collect_ts_files(&lib_dir, &mut files);
+                files.extend(collect_ts_files(&lib_dir));
             }
         }
 
         // If still empty, check root (but exclude src/lib/dist/node_modules)
         if files.is_empty() {
-            collect_ts_files(&self.path, &mut files);
+            files.extend(collect_ts_files(&self.path));
         }
 
         // If we have a types field, make sure to include those
@@ -61,11 +70,39 @@ impl NpmPackage {
             }
         }
 
+        // Every concrete target reachable through `exports`/`imports`,
+        // expanding `*` wildcards against the filesystem.
+        for map in [&self.exports, &self.imports].into_iter().flatten() {
+            let mut targets = Vec::new();
+            collect_export_leaves(map, &mut targets);
+            for target in targets {
+                for path in expand_export_target(&self.path, &target) {
+                    if !files.contains(&path) {
+                        files.push(path);
+                    }
+                }
+            }
+        }
+
         files
     }
 
     /// Get the main entry point file.
     pub fn entry_point(&self) -> Option<Utf8PathBuf> {
+        // Prefer the conditional `"exports"` map's "." subpath, if present.
+        if let Some(exports) = &self.exports {
+            if let Some(value) = export_subpath_value(exports, ".") {
+                if let Some(target) =
+                    resolve_export_conditions(value, &["types", "default", "import"])
+                {
+                    let path = self.path.join(target.trim_start_matches("./"));
+                    if path.exists() {
+                        return Some(path);
+                    }
+                }
+            }
+        }
+
         // Try types first (for TS definitions)
         if let Some(types) = &self.types {
             let path = self.path.join(types);
@@ -104,9 +141,113 @@ impl NpmPackage {
     }
 }
 
-fn collect_ts_files(dir: &Utf8Path, files: &mut Vec<Utf8PathBuf>) {
-    if let Ok(entries) = fs::read_dir(dir) {
-        for entry in entries.flatten() {
+/// Look up `subpath` (e.g. `"."` or `"./sub"`) in an `exports`/`imports`
+/// map. Handles the shorthand where the whole map is a single string or
+/// condition object applying to `"."`, as well as an explicit subpath
+/// object keyed by `"."`/`"./sub"`.
+fn export_subpath_value<'a>(
+    map: &'a serde_json::Value,
+    subpath: &str,
+) -> Option<&'a serde_json::Value> {
+    match map {
+        serde_json::Value::String(_) => (subpath == ".").then_some(map),
+        serde_json::Value::Object(fields) => {
+            let is_subpath_map = fields
+                .keys()
+                .any(|k| k.starts_with('.') || k.starts_with('#'));
+            if is_subpath_map {
+                fields.get(subpath)
+            } else if subpath == "." {
+                Some(map)
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Resolve a condition object (or plain string/array target) into a target
+/// path string, trying `conditions` in order and falling back to
+/// `"default"`.
+fn resolve_export_conditions(value: &serde_json::Value, conditions: &[&str]) -> Option<String> {
+    match value {
+        serde_json::Value::String(target) => Some(target.clone()),
+        serde_json::Value::Object(fields) => conditions
+            .iter()
+            .chain(["default"].iter())
+            .find_map(|cond| fields.get(*cond))
+            .and_then(|v| resolve_export_conditions(v, conditions)),
+        serde_json::Value::Array(items) => items
+            .iter()
+            .find_map(|v| resolve_export_conditions(v, conditions)),
+        _ => None,
+    }
+}
+
+/// Flatten every string leaf (a direct target path) out of an
+/// `exports`/`imports` map, regardless of how deeply it's nested under
+/// subpaths and conditions.
+fn collect_export_leaves(value: &serde_json::Value, out: &mut Vec<String>) {
+    match value {
+        serde_json::Value::String(s) => out.push(s.clone()),
+        serde_json::Value::Object(fields) => {
+            for v in fields.values() {
+                collect_export_leaves(v, out);
+            }
+        }
+        serde_json::Value::Array(items) => {
+            for v in items {
+                collect_export_leaves(v, out);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Resolve a single export target string to on-disk files, expanding a
+/// `"*"` wildcard (one path segment, e.g. `"./dist/*.js"`) against the
+/// package directory's filesystem contents.
+fn expand_export_target(pkg_path: &Utf8Path, target: &str) -> Vec<Utf8PathBuf> {
+    let relative = target.trim_start_matches("./");
+
+    let Some(star_idx) = relative.find('*') else {
+        let path = pkg_path.join(relative);
+        return if path.exists() { vec![path] } else { vec![] };
+    };
+
+    let prefix = &relative[..star_idx];
+    let suffix = &relative[star_idx + 1..];
+    let dir = pkg_path.join(prefix.trim_end_matches('/'));
+
+    let Ok(entries) = fs::read_dir(&dir) else {
+        return vec![];
+    };
+
+    entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter_map(|p| Utf8PathBuf::from_path_buf(p).ok())
+        .filter(|p| p.is_file())
+        .filter(|p| {
+            p.file_name()
+                .is_some_and(|name| suffix.is_empty() || name.ends_with(suffix))
+        })
+        .collect()
+}
+
+/// Walk `dir` for TypeScript/JavaScript source files, fanning out across
+/// directory entries with rayon so large `node_modules`-adjacent trees don't
+/// pay for one `read_dir` at a time on cold-cache I/O.
+fn collect_ts_files(dir: &Utf8Path) -> Vec<Utf8PathBuf> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .flatten()
+        .par_bridge()
+        .flat_map(|entry| {
             let path = Utf8PathBuf::from_path_buf(entry.path())
                 .unwrap_or_else(|p| Utf8PathBuf::from(p.to_string_lossy().to_string()));
 
@@ -125,24 +266,30 @@ fn collect_ts_files(dir: &Utf8Path, files: &mut Vec<Utf8PathBuf>) {
                 ]
                 .contains(&name)
                 {
-                    collect_ts_files(&path, files);
+                    collect_ts_files(&path)
+                } else {
+                    Vec::new()
                 }
-            } else if let Some(ext) = path.extension() {
-                if ["ts", "tsx", "js", "jsx", "mts", "cts", "mjs", "cjs"].contains(&ext) {
-                    // Skip test files and declaration files for now
-                    let file_name = path.file_name().unwrap_or("");
-                    if !file_name.ends_with(".test.ts")
-                        && !file_name.ends_with(".spec.ts")
-                        && !file_name.ends_with(".test.tsx")
-                        && !file_name.ends_with(".spec.tsx")
-                        && !file_name.ends_with(".d.ts")
-                    {
-                        files.push(path);
-                    }
+            } else if path.extension().is_some_and(|ext| {
+                ["ts", "tsx", "js", "jsx", "mts", "cts", "mjs", "cjs"].contains(&ext)
+            }) {
+                // Skip test files and declaration files for now
+                let file_name = path.file_name().unwrap_or("");
+                if !file_name.ends_with(".test.ts")
+                    && !file_name.ends_with(".spec.ts")
+                    && !file_name.ends_with(".test.tsx")
+                    && !file_name.ends_with(".spec.tsx")
+                    && !file_name.ends_with(".d.ts")
+                {
+                    vec![path]
+                } else {
+                    Vec::new()
                 }
+            } else {
+                Vec::new()
             }
-        }
-    }
+        })
+        .collect()
 }
 
 #[derive(Debug, Deserialize)]
@@ -152,16 +299,21 @@ struct PackageJson {
     main: Option<String>,
     types: Option<String>,
     typings: Option<String>,
+    exports: Option<serde_json::Value>,
+    imports: Option<serde_json::Value>,
     dependencies: Option<std::collections::BTreeMap<String, String>>,
     #[serde(rename = "devDependencies")]
     dev_dependencies: Option<std::collections::BTreeMap<String, String>>,
 }
 
-/// A locked dependency from package-lock.json.
+/// A locked dependency with its exact resolved version.
 #[derive(Debug, Clone)]
 pub struct LockedDep {
     pub name: String,
     pub version: String,
+    /// `true` if this is a direct dependency of the project's own
+    /// `package.json`; `false` if it's only pulled in transitively.
+    pub is_direct: bool,
 }
 
 /// Parse package.json to get project info.
@@ -180,6 +332,8 @@ pub fn parse_package_json(project_dir: &Utf8Path) -> Result<NpmPackage, NpmError
         path: project_dir.to_owned(),
         main: pkg.main,
         types: pkg.types.or(pkg.typings),
+        exports: pkg.exports,
+        imports: pkg.imports,
     })
 }
 
@@ -203,6 +357,7 @@ pub fn get_project_deps(project_dir: &Utf8Path) -> Result<Vec<LockedDep>, NpmErr
                     .trim_start_matches('^')
                     .trim_start_matches('~')
                     .to_string(),
+                is_direct: true,
             });
         }
     }
@@ -215,6 +370,7 @@ pub fn get_project_deps(project_dir: &Utf8Path) -> Result<Vec<LockedDep>, NpmErr
                     .trim_start_matches('^')
                     .trim_start_matches('~')
                     .to_string(),
+                is_direct: true,
             });
         }
     }
@@ -222,6 +378,232 @@ pub fn get_project_deps(project_dir: &Utf8Path) -> Result<Vec<LockedDep>, NpmErr
     Ok(deps)
 }
 
+/// The names of a project's direct dependencies (regular and dev), read from
+/// `package.json`. Used to tell direct from transitive deps when a lockfile
+/// itself doesn't make the distinction explicit (yarn.lock, pnpm-lock.yaml).
+fn direct_dependency_names(project_dir: &Utf8Path) -> std::collections::BTreeSet<String> {
+    get_project_deps(project_dir)
+        .map(|deps| deps.into_iter().map(|d| d.name).collect())
+        .unwrap_or_default()
+}
+
+/// Parse whichever npm lockfile is present in `project_dir` and return the
+/// fully-resolved dependency set with exact versions, direct and transitive
+/// alike. Tries `package-lock.json`, then `yarn.lock`, then
+/// `pnpm-lock.yaml`, in that order.
+pub fn parse_lockfile(project_dir: &Utf8Path) -> Result<Vec<LockedDep>, NpmError> {
+    if project_dir.join("package-lock.json").exists() {
+        return parse_package_lock(project_dir);
+    }
+    if project_dir.join("yarn.lock").exists() {
+        return parse_yarn_lock(project_dir);
+    }
+    if project_dir.join("pnpm-lock.yaml").exists() {
+        return parse_pnpm_lock(project_dir);
+    }
+    Err(NpmError::LockfileNotFound)
+}
+
+#[derive(Debug, Deserialize)]
+struct PackageLockJson {
+    packages: Option<std::collections::BTreeMap<String, PackageLockEntry>>,
+    dependencies: Option<std::collections::BTreeMap<String, PackageLockV1Entry>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PackageLockEntry {
+    version: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PackageLockV1Entry {
+    version: String,
+    dependencies: Option<std::collections::BTreeMap<String, PackageLockV1Entry>>,
+}
+
+/// Parse `package-lock.json`, handling both the flat `packages` map used by
+/// lockfileVersion 2/3 and the nested `dependencies` tree used by
+/// lockfileVersion 1.
+pub fn parse_package_lock(project_dir: &Utf8Path) -> Result<Vec<LockedDep>, NpmError> {
+    let lock_path = project_dir.join("package-lock.json");
+    if !lock_path.exists() {
+        return Err(NpmError::LockfileNotFound);
+    }
+
+    let contents = fs::read_to_string(&lock_path)?;
+    let lock: PackageLockJson = serde_json::from_str(&contents)?;
+
+    if let Some(packages) = lock.packages {
+        let mut deps = Vec::new();
+        for (key, entry) in packages {
+            // The root project itself is keyed by the empty string.
+            if key.is_empty() {
+                continue;
+            }
+            let Some(name) = package_path_key_name(&key) else {
+                continue;
+            };
+            let Some(version) = entry.version else {
+                continue;
+            };
+            deps.push(LockedDep {
+                name,
+                version,
+                is_direct: is_top_level_package_path_key(&key),
+            });
+        }
+        return Ok(deps);
+    }
+
+    if let Some(dependencies) = lock.dependencies {
+        let mut deps = Vec::new();
+        collect_v1_deps(&dependencies, true, &mut deps);
+        return Ok(deps);
+    }
+
+    Ok(Vec::new())
+}
+
+/// Extract the package name from a lockfileVersion 2/3 `packages` map key,
+/// e.g. `node_modules/lodash` -> `lodash`, or
+/// `node_modules/@scope/name/node_modules/nested` -> `nested`.
+fn package_path_key_name(key: &str) -> Option<String> {
+    let name = key.rsplit("node_modules/").next()?;
+    if name.is_empty() {
+        return None;
+    }
+    Some(name.to_string())
+}
+
+/// Whether a `packages` map key refers to a direct (top-level) dependency,
+/// as opposed to one nested under another package's own `node_modules/`.
+fn is_top_level_package_path_key(key: &str) -> bool {
+    key.matches("node_modules/").count() == 1
+}
+
+/// Recursively flatten a lockfileVersion 1 nested `dependencies` tree.
+fn collect_v1_deps(
+    dependencies: &std::collections::BTreeMap<String, PackageLockV1Entry>,
+    is_direct: bool,
+    out: &mut Vec<LockedDep>,
+) {
+    for (name, entry) in dependencies {
+        out.push(LockedDep {
+            name: name.clone(),
+            version: entry.version.clone(),
+            is_direct,
+        });
+        if let Some(nested) = &entry.dependencies {
+            collect_v1_deps(nested, false, out);
+        }
+    }
+}
+
+/// Parse `yarn.lock`'s custom block format: entry headers listing one or
+/// more comma-separated `name@range` descriptors, followed by an indented
+/// `version "x.y.z"` line.
+pub fn parse_yarn_lock(project_dir: &Utf8Path) -> Result<Vec<LockedDep>, NpmError> {
+    let lock_path = project_dir.join("yarn.lock");
+    if !lock_path.exists() {
+        return Err(NpmError::LockfileNotFound);
+    }
+
+    let contents = fs::read_to_string(&lock_path)?;
+    let direct_names = direct_dependency_names(project_dir);
+
+    let mut deps = Vec::new();
+    let mut current_names: Vec<String> = Vec::new();
+
+    for line in contents.lines() {
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if !line.starts_with(' ') {
+            // A new entry header, e.g. `"@babel/core@^7.0.0", lodash@^4.17.21:`
+            current_names = line
+                .trim_end_matches(':')
+                .split(',')
+                .filter_map(|descriptor| yarn_descriptor_name(descriptor.trim()))
+                .collect();
+            continue;
+        }
+
+        let trimmed = line.trim_start();
+        if let Some(version) = trimmed.strip_prefix("version ") {
+            let version = version.trim().trim_matches('"');
+            for name in &current_names {
+                deps.push(LockedDep {
+                    name: name.clone(),
+                    version: version.to_string(),
+                    is_direct: direct_names.contains(name),
+                });
+            }
+        }
+    }
+
+    Ok(deps)
+}
+
+/// Extract the package name from a yarn.lock descriptor like
+/// `"@babel/core@^7.0.0"` or `lodash@^4.17.21`, by stripping the trailing
+/// `@<range>` at the last `@` that isn't the leading `@` of a scope.
+fn yarn_descriptor_name(descriptor: &str) -> Option<String> {
+    let descriptor = descriptor.trim_matches('"');
+    let (at, _) = descriptor.rmatch_indices('@').find(|(i, _)| *i > 0)?;
+    Some(descriptor[..at].to_string())
+}
+
+/// Parse a `pnpm-lock.yaml`'s `packages:` section, whose keys take the form
+/// `/name/version` or `/@scope/name/version`. This targets the older,
+/// slash-separated pnpm lockfile key format rather than the `name@version`
+/// form used by newer pnpm versions.
+pub fn parse_pnpm_lock(project_dir: &Utf8Path) -> Result<Vec<LockedDep>, NpmError> {
+    let lock_path = project_dir.join("pnpm-lock.yaml");
+    if !lock_path.exists() {
+        return Err(NpmError::LockfileNotFound);
+    }
+
+    let contents = fs::read_to_string(&lock_path)?;
+    let direct_names = direct_dependency_names(project_dir);
+
+    let mut deps = Vec::new();
+    let mut in_packages = false;
+
+    for line in contents.lines() {
+        if line.trim_end() == "packages:" {
+            in_packages = true;
+            continue;
+        }
+        if !in_packages || line.is_empty() {
+            continue;
+        }
+        // Any other top-level (unindented) section ends the packages block.
+        if !line.starts_with(' ') {
+            break;
+        }
+
+        let Some(key) = line
+            .trim()
+            .strip_suffix(':')
+            .and_then(|k| k.strip_prefix('/'))
+        else {
+            continue;
+        };
+        let Some((name, version)) = key.rsplit_once('/') else {
+            continue;
+        };
+
+        deps.push(LockedDep {
+            name: name.to_string(),
+            version: version.to_string(),
+            is_direct: direct_names.contains(name),
+        });
+    }
+
+    Ok(deps)
+}
+
 /// Find a package in node_modules.
 pub fn find_package(project_dir: &Utf8Path, name: &str) -> Result<NpmPackage, NpmError> {
     let node_modules = project_dir.join("node_modules");
@@ -284,18 +666,190 @@ pub fn list_packages(project_dir: &Utf8Path) -> Result<Vec<NpmPackage>, NpmError
     Ok(packages)
 }
 
-/// Convert a file path to a module path.
-/// e.g., "src/license/api.ts" -> "package.license.api"
-pub fn path_to_module(package_name: &str, path: &Utf8Path) -> String {
-    let path_str = path.as_str();
+/// A resolved `tsconfig.json`, with its `extends` chain already merged.
+#[derive(Debug, Clone, Default)]
+pub struct TsConfig {
+    pub base_url: Option<String>,
+    pub root_dir: Option<String>,
+    pub paths: std::collections::BTreeMap<String, Vec<String>>,
+}
 
-    // Strip src/ or lib/ prefix
-    let path_str = path_str
-        .strip_prefix("src/")
-        .or_else(|| path_str.strip_prefix("lib/"))
-        .unwrap_or(path_str);
+impl TsConfig {
+    /// Load and resolve `tsconfig.json` (following its `extends` chain) from
+    /// `project_dir`. Returns `None` if there's no tsconfig.json or it can't
+    /// be parsed; an `extends` target that is missing, unparseable or part of
+    /// a cycle contributes nothing and the rest of the chain still applies.
+    pub fn load(project_dir: &Utf8Path) -> Option<TsConfig> {
+        load_tsconfig_file(
+            &project_dir.join("tsconfig.json"),
+            &mut std::collections::HashSet::new(),
+        )
+    }
+
+    /// Resolve `path` (relative to the project root) against the `paths`
+    /// alias table, itself relative to `baseUrl`. Returns the
+    /// alias-qualified module string if a pattern matches.
+    fn resolve_alias(&self, path: &Utf8Path) -> Option<String> {
+        let base = self.base_url.as_deref().unwrap_or(".");
+        let path_str = path.as_str();
+
+        for (alias, targets) in &self.paths {
+            for target in targets {
+                let target_rel = join_under_base(base, target);
+                match target_rel.find('*') {
+                    Some(star) => {
+                        let prefix = &target_rel[..star];
+                        let suffix = &target_rel[star + 1..];
+                        if let Some(captured) = path_str
+                            .strip_prefix(prefix)
+                            .and_then(|rest| rest.strip_suffix(suffix))
+                        {
+                            return Some(alias.replacen('*', captured, 1));
+                        }
+                    }
+                    None if path_str == target_rel => return Some(alias.clone()),
+                    None => {}
+                }
+            }
+        }
+
+        None
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TsConfigJson {
+    extends: Option<String>,
+    #[serde(rename = "compilerOptions")]
+    compiler_options: Option<TsCompilerOptions>,
+}
+
+#[derive(Debug, Deserialize)]
+struct TsCompilerOptions {
+    #[serde(rename = "baseUrl")]
+    base_url: Option<String>,
+    #[serde(rename = "rootDir")]
+    root_dir: Option<String>,
+    paths: Option<std::collections::BTreeMap<String, Vec<String>>>,
+}
+
+fn load_tsconfig_file(
+    path: &Utf8Path,
+    visited: &mut std::collections::HashSet<Utf8PathBuf>,
+) -> Option<TsConfig> {
+    let canonical = path.canonicalize_utf8().unwrap_or_else(|_| path.to_owned());
+    if !visited.insert(canonical) {
+        return None; // extends cycle
+    }
+
+    let contents = fs::read_to_string(path).ok()?;
+    let raw: TsConfigJson = serde_json::from_str(&strip_jsonc_comments(&contents)).ok()?;
+
+    let mut config = match &raw.extends {
+        Some(extends) => {
+            let dir = path.parent().unwrap_or_else(|| Utf8Path::new("."));
+            load_tsconfig_file(&resolve_extends_path(dir, extends), visited).unwrap_or_default()
+        }
+        None => TsConfig::default(),
+    };
+
+    if let Some(opts) = raw.compiler_options {
+        if opts.base_url.is_some() {
+            config.base_url = opts.base_url;
+        }
+        if opts.root_dir.is_some() {
+            config.root_dir = opts.root_dir;
+        }
+        if let Some(paths) = opts.paths {
+            config.paths.extend(paths);
+        }
+    }
+
+    Some(config)
+}
+
+/// Resolve a (possibly extension-less) `extends` value relative to `dir`,
+/// the way tsconfig's resolution tries `<extends>` then `<extends>.json`.
+fn resolve_extends_path(dir: &Utf8Path, extends: &str) -> Utf8PathBuf {
+    let candidate = dir.join(extends);
+    if candidate.exists() {
+        return candidate;
+    }
+    let with_json = dir.join(format!("{extends}.json"));
+    if with_json.exists() {
+        with_json
+    } else {
+        candidate
+    }
+}
+
+/// Join a `paths`-table target (relative to `baseUrl`) under `base`,
+/// normalizing away a leading `./` and redundant slashes.
+fn join_under_base(base: &str, target: &str) -> String {
+    let base = base.trim_start_matches("./").trim_end_matches('/');
+    let target = target.trim_start_matches("./");
+    if base.is_empty() || base == "." {
+        target.to_string()
+    } else {
+        format!("{base}/{target}")
+    }
+}
+
+/// Strip `//` and `/* */` comments from a JSONC document - tsconfig.json
+/// commonly has them - respecting string literals so a `//` inside a quoted
+/// value isn't mistaken for a comment start.
+fn strip_jsonc_comments(input: &str) -> String {
+    let mut out = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    let mut in_string = false;
+    let mut escaped = false;
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            out.push(c);
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+            continue;
+        }
 
-    // Strip extension
+        match c {
+            '"' => {
+                in_string = true;
+                out.push(c);
+            }
+            '/' if chars.peek() == Some(&'/') => {
+                for c in chars.by_ref() {
+                    if c == '\n' {
+                        out.push('\n');
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut prev = '\0';
+                for c in chars.by_ref() {
+                    if prev == '*' && c == '/' {
+                        break;
+                    }
+                    prev = c;
+                }
+            }
+            _ => out.push(c),
+        }
+    }
+
+    out
+}
+
+/// Strip a known TS/JS extension and a trailing `/index`, the way an import
+/// specifier collapses to its module name.
+fn strip_module_suffix(path_str: &str) -> &str {
     let path_str = path_str
         .strip_suffix(".ts")
         .or_else(|| path_str.strip_suffix(".tsx"))
@@ -305,13 +859,39 @@ pub fn path_to_module(package_name: &str, path: &Utf8Path) -> String {
         .or_else(|| path_str.strip_suffix(".cts"))
         .unwrap_or(path_str);
 
-    // Handle index files -> parent module
-    let path_str = path_str.strip_suffix("/index").unwrap_or(path_str);
-    let path_str = if path_str == "index" {
+    path_str.strip_suffix("/index").unwrap_or(path_str)
+}
+
+/// Convert a file path to a module path.
+/// e.g., "src/license/api.ts" -> "package.license.api"
+///
+/// When `tsconfig` resolves `path` against a `paths` alias (relative to its
+/// `baseUrl`), the module name uses the alias instead of the raw relative
+/// path - e.g. `"@app/*": ["source/*"]` maps `source/foo.ts` to
+/// `package.@app.foo` rather than `package.source.foo`.
+pub fn path_to_module(package_name: &str, path: &Utf8Path, tsconfig: Option<&TsConfig>) -> String {
+    if let Some(alias) = tsconfig.and_then(|config| config.resolve_alias(path)) {
+        let alias = strip_module_suffix(&alias);
+        return format!("{}.{}", package_name, alias.replace('/', "."));
+    }
+
+    let path_str = path.as_str();
+
+    // Strip src/ or lib/ prefix (or the tsconfig rootDir, if configured)
+    let root_prefix = tsconfig
+        .and_then(|c| c.root_dir.as_deref())
+        .map(|dir| format!("{}/", dir.trim_start_matches("./").trim_end_matches('/')));
+    let path_str = root_prefix
+        .as_deref()
+        .and_then(|prefix| path_str.strip_prefix(prefix))
+        .or_else(|| path_str.strip_prefix("src/"))
+        .or_else(|| path_str.strip_prefix("lib/"))
+        .unwrap_or(path_str);
+
+    let path_str = strip_module_suffix(path_str);
+    if path_str == "index" {
         return package_name.to_string();
-    } else {
-        path_str
-    };
+    }
 
     // Convert path separators to dots
     let module_part = path_str.replace('/', ".");
@@ -326,16 +906,101 @@ mod tests {
     #[test]
     fn test_path_to_module() {
         assert_eq!(
-            path_to_module("lesstokens", &Utf8PathBuf::from("src/index.ts")),
+            path_to_module("lesstokens", &Utf8PathBuf::from("src/index.ts"), None),
             "lesstokens"
         );
         assert_eq!(
-            path_to_module("lesstokens", &Utf8PathBuf::from("src/license/api.ts")),
+            path_to_module("lesstokens", &Utf8PathBuf::from("src/license/api.ts"), None),
             "lesstokens.license.api"
         );
         assert_eq!(
-            path_to_module("lesstokens", &Utf8PathBuf::from("src/license/index.ts")),
+            path_to_module(
+                "lesstokens",
+                &Utf8PathBuf::from("src/license/index.ts"),
+                None
+            ),
             "lesstokens.license"
         );
     }
+
+    #[test]
+    fn test_path_to_module_with_tsconfig_alias() {
+        let mut paths = std::collections::BTreeMap::new();
+        paths.insert(
+            "@app/license/*".to_string(),
+            vec!["source/license/*".to_string()],
+        );
+        let tsconfig = TsConfig {
+            base_url: Some(".".to_string()),
+            root_dir: Some("source".to_string()),
+            paths,
+        };
+
+        assert_eq!(
+            path_to_module(
+                "lesstokens",
+                &Utf8PathBuf::from("source/license/api.ts"),
+                Some(&tsconfig)
+            ),
+            "lesstokens.@app.license.api"
+        );
+
+        // A path outside the alias's target falls back to rootDir stripping.
+        assert_eq!(
+            path_to_module(
+                "lesstokens",
+                &Utf8PathBuf::from("source/other/thing.ts"),
+                Some(&tsconfig)
+            ),
+            "lesstokens.other.thing"
+        );
+    }
+
+    #[test]
+    fn test_strip_jsonc_comments() {
+        let input = "{\n  // a comment\n  \"a\": 1, /* inline */ \"b\": \"http://x\"\n}";
+        let stripped = strip_jsonc_comments(input);
+        let parsed: serde_json::Value = serde_json::from_str(&stripped).unwrap();
+        assert_eq!(parsed["a"], 1);
+        assert_eq!(parsed["b"], "http://x");
+    }
+
+    #[test]
+    fn test_export_subpath_value_and_conditions() {
+        let exports: serde_json::Value = serde_json::json!({
+            ".": {
+                "types": "./dist/index.d.ts",
+                "import": "./dist/index.mjs",
+                "default": "./dist/index.js"
+            },
+            "./sub": "./dist/sub.js"
+        });
+
+        let root = export_subpath_value(&exports, ".").unwrap();
+        assert_eq!(
+            resolve_export_conditions(root, &["types", "default", "import"]).as_deref(),
+            Some("./dist/index.d.ts")
+        );
+        assert_eq!(
+            resolve_export_conditions(root, &["import", "default"]).as_deref(),
+            Some("./dist/index.mjs")
+        );
+
+        let sub = export_subpath_value(&exports, "./sub").unwrap();
+        assert_eq!(
+            resolve_export_conditions(sub, &["default"]).as_deref(),
+            Some("./dist/sub.js")
+        );
+    }
+
+    #[test]
+    fn test_export_shorthand_string_applies_to_dot() {
+        let exports: serde_json::Value = serde_json::json!("./index.js");
+        let root = export_subpath_value(&exports, ".").unwrap();
+        assert_eq!(
+            resolve_export_conditions(root, &["default"]).as_deref(),
+            Some("./index.js")
+        );
+        assert!(export_subpath_value(&exports, "./sub").is_none());
+    }
 }
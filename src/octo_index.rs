@@ -27,6 +27,10 @@ pub struct OctonionProfile {
     pub coeffs: [f32; 8],
     /// Raw metrics for transparency
     pub raw: RawMetrics,
+    /// Names of this crate's direct dependencies (production + target-specific),
+    /// used to build the reverse "who depends on X" graph.
+    #[serde(default)]
+    pub deps: Vec<String>,
 }
 
 /// Raw metrics extracted from static analysis and db-dump.
@@ -44,16 +48,31 @@ pub struct RawMetrics {
     pub unsafe_blocks: u32,
     /// Total lines of code
     pub total_loc: u32,
+    /// Lines classified as real code (not blank, not comment)
+    pub code_loc: u32,
+    /// Lines classified as comments (line or block)
+    pub comment_loc: u32,
+    /// Lines that trim to empty
+    pub blank_loc: u32,
     /// Number of async functions
     pub async_fns: u32,
     /// Total functions
     pub total_fns: u32,
     /// Whether crate is no_std
     pub is_no_std: bool,
-    /// Direct dependency count
+    /// Direct dependency count ([dependencies] + target-specific deps)
     pub dep_count: u32,
+    /// Dev-dependency count ([dev-dependencies])
+    pub dev_dep_count: u32,
+    /// Build-dependency count ([build-dependencies])
+    pub build_dep_count: u32,
     /// Heap-allocating type usage count
     pub heap_types: u32,
+    /// Published `.crate` tarball size in bytes, from the db-dump's
+    /// `versions.csv` `crate_size` column.
+    pub tarball_bytes: u64,
+    /// Total size in bytes of the extracted/unpacked crate source tree.
+    pub uncompressed_bytes: u64,
 }
 
 impl RawMetrics {
@@ -119,6 +138,11 @@ pub struct OctoIndex {
     pub count: usize,
     /// Map from crate name to profile
     pub profiles: HashMap<String, OctonionProfile>,
+    /// Optional LSH acceleration structure for `search_approx`, built with
+    /// `build_lsh`. Never serialized - it's a derived index over
+    /// `profiles` and is cheap enough to rebuild after a `load`.
+    #[serde(skip)]
+    lsh: Option<OctoLsh>,
 }
 
 impl OctoIndex {
@@ -137,6 +161,7 @@ impl OctoIndex {
                 .as_secs(),
             count: 0,
             profiles: HashMap::new(),
+            lsh: None,
         }
     }
 
@@ -151,6 +176,35 @@ impl OctoIndex {
         self.profiles.get(name)
     }
 
+    /// Whether this index's on-disk format version matches the version this
+    /// build of fastdeps knows how to read. `from_bytes` already rejects a
+    /// bad magic number; this additionally catches a structurally valid but
+    /// newer/older index that a remote fetch shouldn't silently accept.
+    pub fn version_supported(&self) -> bool {
+        self.version == Self::FORMAT_VERSION
+    }
+
+    /// The format version this build of fastdeps reads and writes.
+    pub fn current_format_version() -> u32 {
+        Self::FORMAT_VERSION
+    }
+
+    /// Find all indexed crates that directly depend on `name` ("fan-in").
+    pub fn dependents_of(&self, name: &str) -> Vec<&OctonionProfile> {
+        self.profiles
+            .values()
+            .filter(|p| p.deps.iter().any(|d| d == name))
+            .collect()
+    }
+
+    /// Number of indexed crates that directly depend on `name`.
+    pub fn fan_in(&self, name: &str) -> usize {
+        self.profiles
+            .values()
+            .filter(|p| p.deps.iter().any(|d| d == name))
+            .count()
+    }
+
     /// Serialize to Zstd-compressed bytes.
     pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
         let mut buf = Vec::new();
@@ -201,6 +255,21 @@ impl OctoIndex {
         Self::from_bytes(&bytes)
     }
 
+    /// Build the bytes for the memory-mapped, zero-copy variant of this
+    /// index (see `crate::octo_mmap`), suitable for writing to disk and
+    /// later opening with `load_mmap` instead of `load`.
+    pub fn to_mmap_bytes(&self) -> anyhow::Result<Vec<u8>> {
+        crate::octo_mmap::to_mmap_bytes(&self.profiles)
+    }
+
+    /// Open a previously-written mmap-format index for zero-copy reads.
+    /// Unlike `load`, this doesn't decompress or deserialize the whole
+    /// index up front - `OctoMmapIndex::get` resolves a single crate name
+    /// straight to its record.
+    pub fn load_mmap(path: &std::path::Path) -> anyhow::Result<crate::octo_mmap::OctoMmapIndex> {
+        crate::octo_mmap::OctoMmapIndex::open(path)
+    }
+
     /// Get all profiles sorted by utility (e0) descending.
     pub fn top_by_utility(&self, limit: usize) -> Vec<&OctonionProfile> {
         let mut profiles: Vec<_> = self.profiles.values().collect();
@@ -222,6 +291,223 @@ impl OctoIndex {
         scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
         scored.into_iter().take(limit).collect()
     }
+
+    /// Find the profiles nearest to `coeffs` by Euclidean distance in the 8D
+    /// octonion space, excluding `exclude_name` itself (typically the crate
+    /// the query coefficients were taken from).
+    pub fn nearest(
+        &self,
+        coeffs: &[f32; 8],
+        exclude_name: &str,
+        limit: usize,
+    ) -> Vec<(&OctonionProfile, f32)> {
+        let mut scored: Vec<_> = self
+            .profiles
+            .values()
+            .filter(|p| p.name != exclude_name)
+            .map(|p| (p, euclidean_distance(&p.coeffs, coeffs)))
+            .collect();
+
+        scored.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
+        scored.into_iter().take(limit).collect()
+    }
+
+    /// Build an LSH acceleration structure over this index's profiles and
+    /// store it for `search_approx` to use: `l` independent hash tables,
+    /// each with `k` random hyperplanes in the 8D coefficient space. A
+    /// crate's k-bit signature in a table is the sign of its coefficients'
+    /// dot product against each of that table's planes; crates sharing a
+    /// signature land in the same bucket. The hyperplanes are drawn from a
+    /// fixed seed, so the same `(l, k)` always rebuilds an identical
+    /// structure.
+    pub fn build_lsh(&mut self, l: usize, k: usize) {
+        let mut rng = SplitMix64::new(0x4F43544F_4C534821);
+        let tables = (0..l)
+            .map(|_| {
+                let planes: Vec<[f32; 8]> = (0..k).map(|_| rng.next_unit_vector()).collect();
+                LshTable::build(planes, &self.profiles)
+            })
+            .collect();
+
+        self.lsh = Some(OctoLsh {
+            tables,
+            max_hamming: 2,
+        });
+    }
+
+    /// Approximate nearest-neighbour search using the LSH structure built
+    /// by `build_lsh`, falling back to the exact `search` when no LSH
+    /// structure has been built yet or the candidate set it gathers is
+    /// smaller than `limit` - a sparse bucket should never cause `search`
+    /// to return fewer results than a caller could get from a full scan.
+    ///
+    /// `combined_score` treats a negative query entry as friction to avoid
+    /// rather than a direction to search towards, so the probe used to
+    /// pick candidate buckets clamps negative entries to zero: bucketing
+    /// is driven only by the desired/magnitude axes, while the exact
+    /// `combined_score` (over the unmodified `query`) still ranks the
+    /// resulting candidates.
+    pub fn search_approx(&self, query: &[f32; 8], limit: usize) -> Vec<(&OctonionProfile, f32)> {
+        let Some(lsh) = self.lsh.as_ref() else {
+            return self.search(query, limit);
+        };
+
+        let mut probe = *query;
+        for v in probe.iter_mut() {
+            *v = v.max(0.0);
+        }
+
+        let mut candidates: std::collections::HashSet<&str> = std::collections::HashSet::new();
+        for table in &lsh.tables {
+            let sig = LshTable::signature(&table.planes, &probe);
+            for nearby in hamming_neighbors(sig, table.planes.len(), lsh.max_hamming) {
+                if let Some(names) = table.buckets.get(&nearby) {
+                    candidates.extend(names.iter().map(String::as_str));
+                }
+            }
+        }
+
+        if candidates.len() < limit {
+            return self.search(query, limit);
+        }
+
+        let mut scored: Vec<_> = candidates
+            .into_iter()
+            .filter_map(|name| self.profiles.get(name))
+            .map(|p| (p, combined_score(&p.coeffs, query)))
+            .collect();
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        scored.truncate(limit);
+        scored
+    }
+}
+
+/// Euclidean distance between two 8D octonion coefficient vectors.
+fn euclidean_distance(a: &[f32; 8], b: &[f32; 8]) -> f32 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f32>()
+        .sqrt()
+}
+
+/// One LSH hash table over the 8D coefficient space: `k` random hyperplanes
+/// whose sign pattern against a crate's coefficients gives a k-bit bucket
+/// key, plus the buckets themselves (crate names sharing a signature).
+#[derive(Debug, Clone)]
+struct LshTable {
+    planes: Vec<[f32; 8]>,
+    buckets: HashMap<u64, Vec<String>>,
+}
+
+impl LshTable {
+    fn build(planes: Vec<[f32; 8]>, profiles: &HashMap<String, OctonionProfile>) -> Self {
+        let mut buckets: HashMap<u64, Vec<String>> = HashMap::new();
+        for profile in profiles.values() {
+            let sig = Self::signature(&planes, &profile.coeffs);
+            buckets.entry(sig).or_default().push(profile.name.clone());
+        }
+        Self { planes, buckets }
+    }
+
+    /// A point's k-bit signature: bit `i` is set when it falls on the
+    /// positive side of plane `i`.
+    fn signature(planes: &[[f32; 8]], point: &[f32; 8]) -> u64 {
+        let mut sig = 0u64;
+        for (i, plane) in planes.iter().enumerate() {
+            let dot: f32 = point.iter().zip(plane.iter()).map(|(x, p)| x * p).sum();
+            if dot >= 0.0 {
+                sig |= 1 << i;
+            }
+        }
+        sig
+    }
+}
+
+/// LSH acceleration structure built by `OctoIndex::build_lsh` and consumed
+/// by `OctoIndex::search_approx`.
+#[derive(Debug, Clone)]
+struct OctoLsh {
+    tables: Vec<LshTable>,
+    /// Widen the bucket search to signatures within this Hamming distance
+    /// of the query's, so a query landing just across a hyperplane from a
+    /// dense bucket still finds it.
+    max_hamming: u32,
+}
+
+/// Every k-bit signature within Hamming distance `max_r` of `sig` (`sig`
+/// itself included), generated by flipping each combination of up to
+/// `max_r` of the low `k` bits - bounded by the number of nearby buckets,
+/// not by how many crates or distinct buckets the index holds.
+fn hamming_neighbors(sig: u64, k: usize, max_r: u32) -> Vec<u64> {
+    let mut combo = Vec::new();
+    let mut out = vec![sig];
+    for dist in 1..=max_r as usize {
+        combo.clear();
+        hamming_combinations(k, dist, 0, &mut combo, &mut |bits| {
+            out.push(bits.iter().fold(sig, |acc, &bit| acc ^ (1 << bit)));
+        });
+    }
+    out
+}
+
+fn hamming_combinations(
+    k: usize,
+    remaining: usize,
+    start: usize,
+    combo: &mut Vec<usize>,
+    emit: &mut impl FnMut(&[usize]),
+) {
+    if remaining == 0 {
+        emit(combo);
+        return;
+    }
+    for bit in start..k {
+        combo.push(bit);
+        hamming_combinations(k, remaining - 1, bit + 1, combo, emit);
+        combo.pop();
+    }
+}
+
+/// A small, dependency-free splitmix64 PRNG, used only to generate
+/// reproducible random hyperplanes for `OctoIndex::build_lsh` without
+/// pulling in the `rand` crate.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// A value uniformly distributed in `[-1.0, 1.0)`.
+    fn next_f32(&mut self) -> f32 {
+        let bits = (self.next_u64() >> 40) as f32; // 24 bits of entropy
+        (bits / (1u32 << 24) as f32) * 2.0 - 1.0
+    }
+
+    /// A random unit vector in R^8, for use as an LSH hyperplane normal.
+    fn next_unit_vector(&mut self) -> [f32; 8] {
+        let mut v = [0.0f32; 8];
+        for x in v.iter_mut() {
+            *x = self.next_f32();
+        }
+        let norm = v.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm > f32::EPSILON {
+            for x in v.iter_mut() {
+                *x /= norm;
+            }
+        }
+        v
+    }
 }
 
 impl Default for OctoIndex {
@@ -299,11 +585,18 @@ mod tests {
             send_sync_count: 5,
             unsafe_blocks: 10,
             total_loc: 5000,
+            code_loc: 3800,
+            comment_loc: 900,
+            blank_loc: 300,
             async_fns: 25,
             total_fns: 100,
             is_no_std: false,
             dep_count: 10,
+            dev_dep_count: 3,
+            build_dep_count: 0,
             heap_types: 30,
+            tarball_bytes: 50_000,
+            uncompressed_bytes: 200_000,
         };
 
         let coeffs = raw.to_coeffs();
@@ -331,6 +624,7 @@ mod tests {
             version: "1.0.0".into(),
             coeffs: raw.to_coeffs(),
             raw,
+            deps: vec![],
         });
 
         // Serialize and deserialize
@@ -352,6 +646,7 @@ mod tests {
             version: "1.0.0".into(),
             coeffs: [0.5, 0.8, 0.1, 0.9, 0.3, 0.2, 0.0, 0.1],
             raw: RawMetrics::default(),
+            deps: vec![],
         });
 
         // no_std crate
@@ -360,6 +655,7 @@ mod tests {
             version: "1.0.0".into(),
             coeffs: [0.3, 0.0, 0.0, 0.0, 0.0, 0.1, 1.0, 0.05],
             raw: RawMetrics::default(),
+            deps: vec![],
         });
 
         // Query for async
@@ -368,4 +664,97 @@ mod tests {
 
         assert_eq!(results[0].0.name, "async-crate");
     }
+
+    #[test]
+    fn test_search_approx_falls_back_when_candidates_sparse() {
+        let mut index = OctoIndex::new();
+        index.insert(OctonionProfile {
+            name: "async-crate".into(),
+            version: "1.0.0".into(),
+            coeffs: [0.5, 0.8, 0.1, 0.9, 0.3, 0.2, 0.0, 0.1],
+            raw: RawMetrics::default(),
+            deps: vec![],
+        });
+        index.insert(OctonionProfile {
+            name: "embedded-crate".into(),
+            version: "1.0.0".into(),
+            coeffs: [0.3, 0.0, 0.0, 0.0, 0.0, 0.1, 1.0, 0.05],
+            raw: RawMetrics::default(),
+            deps: vec![],
+        });
+
+        // No LSH built yet: search_approx must behave exactly like search.
+        let query = build_query(true, true, false, false, false);
+        let exact = index.search(&query, 10);
+        let approx = index.search_approx(&query, 10);
+        assert_eq!(exact.len(), approx.len());
+        assert_eq!(exact[0].0.name, approx[0].0.name);
+
+        // Too few crates for 2 tables of 4 planes each to leave a bucket
+        // with `limit` candidates - search_approx should fall back to the
+        // exact scan rather than returning a truncated result.
+        index.build_lsh(2, 4);
+        let fallback = index.search_approx(&query, 10);
+        assert_eq!(fallback.len(), exact.len());
+        assert_eq!(fallback[0].0.name, "async-crate");
+    }
+
+    #[test]
+    fn test_search_approx_matches_search_on_larger_index() {
+        let mut index = OctoIndex::new();
+        for i in 0..64u32 {
+            let t = i as f32 / 64.0;
+            index.insert(OctonionProfile {
+                name: format!("crate-{i}"),
+                version: "1.0.0".into(),
+                coeffs: [t, 1.0 - t, t * 0.5, t, 0.1, t * 0.2, 0.0, t],
+                raw: RawMetrics::default(),
+                deps: vec![],
+            });
+        }
+        index.build_lsh(4, 3);
+
+        let query = build_query(true, false, false, false, false);
+        let exact = index.search(&query, 5);
+        let approx = index.search_approx(&query, 5);
+
+        assert_eq!(exact.len(), approx.len());
+        assert_eq!(exact[0].0.name, approx[0].0.name);
+    }
+
+    #[test]
+    fn test_fan_in() {
+        let mut index = OctoIndex::new();
+
+        index.insert(OctonionProfile {
+            name: "tokio".into(),
+            version: "1.0.0".into(),
+            coeffs: [0.9, 0.9, 0.1, 0.95, 0.4, 0.3, 0.0, 0.2],
+            raw: RawMetrics::default(),
+            deps: vec![],
+        });
+        index.insert(OctonionProfile {
+            name: "hyper".into(),
+            version: "1.0.0".into(),
+            coeffs: [0.8, 0.8, 0.1, 0.9, 0.3, 0.2, 0.0, 0.2],
+            raw: RawMetrics::default(),
+            deps: vec!["tokio".into()],
+        });
+        index.insert(OctonionProfile {
+            name: "reqwest".into(),
+            version: "1.0.0".into(),
+            coeffs: [0.7, 0.7, 0.1, 0.85, 0.3, 0.2, 0.0, 0.2],
+            raw: RawMetrics::default(),
+            deps: vec!["tokio".into(), "hyper".into()],
+        });
+
+        assert_eq!(index.fan_in("tokio"), 2);
+        assert_eq!(index.fan_in("hyper"), 1);
+        assert_eq!(index.fan_in("nonexistent"), 0);
+
+        let dependents = index.dependents_of("tokio");
+        let mut names: Vec<_> = dependents.iter().map(|p| p.name.as_str()).collect();
+        names.sort();
+        assert_eq!(names, vec!["hyper", "reqwest"]);
+    }
 }
@@ -0,0 +1,499 @@
+//! Memory-mapped, zero-copy on-disk format for `OctoIndex`.
+//!
+//! `OctoIndex::to_bytes`/`from_bytes` round-trip through JSON + Zstd, which
+//! means `load` has to decompress and deserialize every one of the ~10k
+//! bundled crate profiles into a `HashMap` before a single `get` can run.
+//! This format instead lays out fixed-width records plus a minimal perfect
+//! hash (MPH) table over crate names, so `OctoMmapIndex::get` maps a name
+//! straight to its record's byte offset - two hashes and one array index -
+//! without touching, decompressing, or allocating for the rest of the file.
+//!
+//! Layout (all integers little-endian):
+//!
+//! ```text
+//! [ magic "OCTM" ][ version: u32 ][ count: u32 ][ num_buckets: u32 ]
+//! [ displacement table: num_buckets x u32 ]
+//! [ records table: count x Record (RECORD_SIZE bytes each) ]
+//! [ names blob: name/version bytes, referenced by the records table ]
+//! ```
+//!
+//! Each record holds an offset/length pair into the names blob for both the
+//! crate name and version, the 8 coefficient `f32`s, and `RawMetrics`
+//! packed field-by-field - everything `OctonionProfile` has except `deps`,
+//! which is variable-length and not needed for a name lookup.
+
+use crate::octo_index::{OctonionProfile, RawMetrics};
+use std::collections::HashMap;
+use std::fs::File;
+use std::path::Path;
+
+const MAGIC: &[u8; 4] = b"OCTM";
+const FORMAT_VERSION: u32 = 1;
+const HEADER_SIZE: usize = 16;
+const RECORD_SIZE: usize = 125;
+
+/// Average keys per bucket the MPH construction aims for; lower wastes more
+/// space on the displacement table, higher makes construction slower.
+const MPH_LOAD_FACTOR: usize = 4;
+
+/// Safety valve on how many displacement values a bucket tries before
+/// giving up - CHD's expected case finds one within a handful of tries, so
+/// hitting this would mean a pathological hash collision pattern, not a
+/// dataset that's merely large.
+const MAX_DISPLACEMENT_ATTEMPTS: u32 = 1_000_000;
+
+/// FNV-1a, salted with `seed` by folding it into the offset basis. Good
+/// enough for bucket/slot assignment without pulling in an external hasher
+/// crate; no relation to the hashers used for the octonion search index.
+fn fnv1a64(seed: u64, bytes: &[u8]) -> u64 {
+    let mut hash = 0xcbf29ce484222325u64 ^ seed;
+    for &b in bytes {
+        hash ^= b as u64;
+        hash = hash.wrapping_mul(0x100000001b3);
+    }
+    hash
+}
+
+/// A minimal perfect hash over a fixed set of names, built via the CHD
+/// (Compress, Hash, Displace) algorithm: names are bucketed by a first
+/// hash, then each bucket (largest first) is assigned a displacement value
+/// whose second hash sends every name in that bucket to a distinct,
+/// not-yet-taken slot in `0..names.len()`. Looking a name up needs only the
+/// bucket's displacement, not the original key set.
+struct Mph {
+    /// One displacement value per bucket.
+    displacement: Vec<u32>,
+}
+
+impl Mph {
+    fn num_buckets(count: usize) -> usize {
+        count.div_ceil(MPH_LOAD_FACTOR).max(1)
+    }
+
+    fn bucket_of(name: &str, num_buckets: usize) -> usize {
+        (fnv1a64(0, name.as_bytes()) % num_buckets as u64) as usize
+    }
+
+    fn slot_of(name: &str, displacement: u32, slot_count: usize) -> usize {
+        (fnv1a64(0x9E3779B97F4A7C15 ^ displacement as u64, name.as_bytes()) % slot_count as u64)
+            as usize
+    }
+
+    /// Build the MPH for `names`, returning the displacement table and,
+    /// for each name's original index, which final slot it was assigned.
+    fn build(names: &[&str]) -> anyhow::Result<(Self, Vec<usize>)> {
+        let count = names.len();
+        let num_buckets = Self::num_buckets(count);
+
+        let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); num_buckets];
+        for (i, name) in names.iter().enumerate() {
+            buckets[Self::bucket_of(name, num_buckets)].push(i);
+        }
+
+        let mut bucket_order: Vec<usize> = (0..num_buckets).collect();
+        bucket_order.sort_by_key(|&b| std::cmp::Reverse(buckets[b].len()));
+
+        let mut displacement = vec![0u32; num_buckets];
+        let mut slot_taken = vec![false; count.max(1)];
+        let mut slot_of_index = vec![0usize; count];
+
+        for bucket_id in bucket_order {
+            let members = &buckets[bucket_id];
+            if members.is_empty() {
+                continue;
+            }
+
+            let mut candidate_slots = Vec::with_capacity(members.len());
+            let mut found = None;
+            for d in 0..MAX_DISPLACEMENT_ATTEMPTS {
+                candidate_slots.clear();
+                let mut ok = true;
+                for &idx in members {
+                    let slot = Self::slot_of(names[idx], d, count);
+                    if slot_taken[slot] || candidate_slots.contains(&slot) {
+                        ok = false;
+                        break;
+                    }
+                    candidate_slots.push(slot);
+                }
+                if ok {
+                    found = Some(d);
+                    break;
+                }
+            }
+
+            let d = found.ok_or_else(|| {
+                anyhow::anyhow!("MPH construction failed to place bucket {bucket_id}")
+            })?;
+            displacement[bucket_id] = d;
+            for (&idx, &slot) in members.iter().zip(candidate_slots.iter()) {
+                slot_taken[slot] = true;
+                slot_of_index[idx] = slot;
+            }
+        }
+
+        Ok((Self { displacement }, slot_of_index))
+    }
+
+    fn lookup(&self, name: &str, slot_count: usize) -> usize {
+        let bucket = Self::bucket_of(name, self.displacement.len());
+        let displacement = self.displacement[bucket];
+        Self::slot_of(name, displacement, slot_count)
+    }
+}
+
+fn write_u16(buf: &mut Vec<u8>, v: u16) {
+    buf.extend_from_slice(&v.to_le_bytes());
+}
+fn write_u32(buf: &mut Vec<u8>, v: u32) {
+    buf.extend_from_slice(&v.to_le_bytes());
+}
+fn write_u64(buf: &mut Vec<u8>, v: u64) {
+    buf.extend_from_slice(&v.to_le_bytes());
+}
+fn write_f32(buf: &mut Vec<u8>, v: f32) {
+    buf.extend_from_slice(&v.to_le_bytes());
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes(bytes[offset..offset + 2].try_into().unwrap())
+}
+fn read_u32(bytes: &[u8], offset: usize) -> u32 {
+    u32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+fn read_u64(bytes: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(bytes[offset..offset + 8].try_into().unwrap())
+}
+fn read_f32(bytes: &[u8], offset: usize) -> f32 {
+    f32::from_le_bytes(bytes[offset..offset + 4].try_into().unwrap())
+}
+
+/// Build the mmap-format bytes for `profiles`, keyed by crate name.
+pub fn to_mmap_bytes(profiles: &HashMap<String, OctonionProfile>) -> anyhow::Result<Vec<u8>> {
+    let mut entries: Vec<&OctonionProfile> = profiles.values().collect();
+    entries.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let names: Vec<&str> = entries.iter().map(|p| p.name.as_str()).collect();
+    let (mph, slots) = Mph::build(&names)?;
+    let count = entries.len();
+
+    // Each entry's record goes at the slot the MPH assigned its name, and
+    // its name/version bytes are appended to the names blob in that same
+    // order, so name_offset/version_offset below line up with where the
+    // names blob built below actually places them.
+    let mut ordered: Vec<&OctonionProfile> = Vec::new();
+    if count > 0 {
+        ordered = vec![entries[0]; count];
+        for (i, &slot) in slots.iter().enumerate() {
+            ordered[slot] = entries[i];
+        }
+    }
+
+    let mut names_blob = Vec::new();
+    let mut name_spans = Vec::with_capacity(count);
+    for profile in &ordered[..count] {
+        let name_offset = names_blob.len() as u32;
+        names_blob.extend_from_slice(profile.name.as_bytes());
+        let version_offset = names_blob.len() as u32;
+        names_blob.extend_from_slice(profile.version.as_bytes());
+        name_spans.push((
+            name_offset,
+            profile.name.len() as u16,
+            version_offset,
+            profile.version.len() as u16,
+        ));
+    }
+
+    let mut buf = Vec::with_capacity(
+        HEADER_SIZE + mph.displacement.len() * 4 + count * RECORD_SIZE + names_blob.len(),
+    );
+
+    buf.extend_from_slice(MAGIC);
+    write_u32(&mut buf, FORMAT_VERSION);
+    write_u32(&mut buf, count as u32);
+    write_u32(&mut buf, mph.displacement.len() as u32);
+
+    for &d in &mph.displacement {
+        write_u32(&mut buf, d);
+    }
+
+    for (profile, &(name_offset, name_len, version_offset, version_len)) in
+        ordered[..count].iter().zip(name_spans.iter())
+    {
+        write_u32(&mut buf, name_offset);
+        write_u16(&mut buf, name_len);
+        write_u32(&mut buf, version_offset);
+        write_u16(&mut buf, version_len);
+        for &c in &profile.coeffs {
+            write_f32(&mut buf, c);
+        }
+        let raw = &profile.raw;
+        write_u64(&mut buf, raw.downloads);
+        write_u32(&mut buf, raw.age_days);
+        write_u32(&mut buf, raw.version_count);
+        write_u32(&mut buf, raw.send_sync_count);
+        write_u32(&mut buf, raw.unsafe_blocks);
+        write_u32(&mut buf, raw.total_loc);
+        write_u32(&mut buf, raw.code_loc);
+        write_u32(&mut buf, raw.comment_loc);
+        write_u32(&mut buf, raw.blank_loc);
+        write_u32(&mut buf, raw.async_fns);
+        write_u32(&mut buf, raw.total_fns);
+        buf.push(raw.is_no_std as u8);
+        write_u32(&mut buf, raw.dep_count);
+        write_u32(&mut buf, raw.dev_dep_count);
+        write_u32(&mut buf, raw.build_dep_count);
+        write_u32(&mut buf, raw.heap_types);
+        write_u64(&mut buf, raw.tarball_bytes);
+        write_u64(&mut buf, raw.uncompressed_bytes);
+    }
+
+    buf.extend_from_slice(&names_blob);
+
+    Ok(buf)
+}
+
+/// One crate's data as read directly out of the mmap'd file - no
+/// allocation beyond the `RawMetrics` copy, which is plain integers and
+/// cheaper to copy than to chase a pointer for.
+#[derive(Debug, Clone)]
+pub struct MmapRecord<'a> {
+    pub name: &'a str,
+    pub version: &'a str,
+    pub coeffs: [f32; 8],
+    pub raw: RawMetrics,
+}
+
+impl MmapRecord<'_> {
+    /// Convert to an owned `OctonionProfile`, with an empty `deps` list -
+    /// dependency edges aren't part of this format (see the module docs).
+    pub fn to_profile(&self) -> OctonionProfile {
+        OctonionProfile {
+            name: self.name.to_string(),
+            version: self.version.to_string(),
+            coeffs: self.coeffs,
+            raw: self.raw.clone(),
+            deps: Vec::new(),
+        }
+    }
+}
+
+/// A memory-mapped Octo-Index, opened read-only via `OctoIndex::load_mmap`.
+/// `get` looks a crate up by one MPH lookup and one bounds-checked record
+/// read, without decompressing or parsing anything else in the file.
+pub struct OctoMmapIndex {
+    mmap: memmap2::Mmap,
+    count: usize,
+    num_buckets: usize,
+    records_offset: usize,
+}
+
+impl OctoMmapIndex {
+    pub fn open(path: &Path) -> anyhow::Result<Self> {
+        let file = File::open(path)?;
+        // Safe as long as nothing truncates or mutates the file out from
+        // under this mapping for the lifetime of `Self` - the inherent
+        // contract of a file-backed mmap, not something this crate can
+        // enforce on its own.
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+
+        if mmap.len() < HEADER_SIZE || &mmap[0..4] != MAGIC {
+            anyhow::bail!("not an Octo-Index mmap file (bad magic)");
+        }
+        let version = read_u32(&mmap, 4);
+        if version != FORMAT_VERSION {
+            anyhow::bail!("unsupported mmap format version {version}");
+        }
+        let count = read_u32(&mmap, 8) as usize;
+        let num_buckets = read_u32(&mmap, 12) as usize;
+
+        let records_offset = HEADER_SIZE + num_buckets * 4;
+        let names_offset = records_offset + count * RECORD_SIZE;
+        if mmap.len() < names_offset {
+            anyhow::bail!("truncated Octo-Index mmap file");
+        }
+
+        Ok(Self {
+            mmap,
+            count,
+            num_buckets,
+            records_offset,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.count
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
+    fn displacement(&self, bucket: usize) -> u32 {
+        read_u32(&self.mmap, HEADER_SIZE + bucket * 4)
+    }
+
+    fn record_bytes(&self, slot: usize) -> &[u8] {
+        let start = self.records_offset + slot * RECORD_SIZE;
+        &self.mmap[start..start + RECORD_SIZE]
+    }
+
+    fn names_blob(&self) -> &[u8] {
+        &self.mmap[self.records_offset + self.count * RECORD_SIZE..]
+    }
+
+    /// Look a crate up by name: one hash to find its bucket's displacement,
+    /// one more (salted by that displacement) to find its record slot, then
+    /// a direct name-byte comparison to confirm it's actually there (the
+    /// MPH only guarantees no collisions among the names it was built
+    /// from - an unknown name can still hash to an occupied slot).
+    pub fn get(&self, name: &str) -> Option<MmapRecord<'_>> {
+        if self.count == 0 {
+            return None;
+        }
+
+        let bucket = Mph::bucket_of(name, self.num_buckets);
+        let displacement = self.displacement(bucket);
+        let slot = Mph::slot_of(name, displacement, self.count);
+
+        let record = self.record_bytes(slot);
+        let name_offset = read_u32(record, 0) as usize;
+        let name_len = read_u16(record, 4) as usize;
+
+        let names = self.names_blob();
+        let found_name = std::str::from_utf8(&names[name_offset..name_offset + name_len]).ok()?;
+        if found_name != name {
+            return None;
+        }
+
+        let version_offset = read_u32(record, 6) as usize;
+        let version_len = read_u16(record, 10) as usize;
+        let version =
+            std::str::from_utf8(&names[version_offset..version_offset + version_len]).ok()?;
+
+        let mut coeffs = [0.0f32; 8];
+        for (i, c) in coeffs.iter_mut().enumerate() {
+            *c = read_f32(record, 12 + i * 4);
+        }
+
+        let raw = RawMetrics {
+            downloads: read_u64(record, 44),
+            age_days: read_u32(record, 52),
+            version_count: read_u32(record, 56),
+            send_sync_count: read_u32(record, 60),
+            unsafe_blocks: read_u32(record, 64),
+            total_loc: read_u32(record, 68),
+            code_loc: read_u32(record, 72),
+            comment_loc: read_u32(record, 76),
+            blank_loc: read_u32(record, 80),
+            async_fns: read_u32(record, 84),
+            total_fns: read_u32(record, 88),
+            is_no_std: record[92] != 0,
+            dep_count: read_u32(record, 93),
+            dev_dep_count: read_u32(record, 97),
+            build_dep_count: read_u32(record, 101),
+            heap_types: read_u32(record, 105),
+            tarball_bytes: read_u64(record, 109),
+            uncompressed_bytes: read_u64(record, 117),
+        };
+
+        Some(MmapRecord {
+            name: found_name,
+            version,
+            coeffs,
+            raw,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_profiles() -> HashMap<String, OctonionProfile> {
+        let mut profiles = HashMap::new();
+        for (i, name) in ["tokio", "serde", "hyper", "rand", "anyhow"]
+            .iter()
+            .enumerate()
+        {
+            profiles.insert(
+                name.to_string(),
+                OctonionProfile {
+                    name: name.to_string(),
+                    version: format!("1.{i}.0"),
+                    coeffs: [i as f32 * 0.1; 8],
+                    raw: RawMetrics {
+                        downloads: 1000 * i as u64,
+                        age_days: 100,
+                        is_no_std: i % 2 == 0,
+                        ..Default::default()
+                    },
+                    deps: vec!["dep-a".to_string()],
+                },
+            );
+        }
+        profiles
+    }
+
+    #[test]
+    fn test_mph_assigns_distinct_slots() {
+        let names = ["tokio", "serde", "hyper", "rand", "anyhow", "log", "bytes"];
+        let (_mph, slots) = Mph::build(&names).unwrap();
+        let mut sorted = slots.clone();
+        sorted.sort_unstable();
+        sorted.dedup();
+        assert_eq!(sorted.len(), names.len());
+        assert!(slots.iter().all(|&s| s < names.len()));
+    }
+
+    #[test]
+    fn test_to_mmap_bytes_roundtrips_via_in_memory_lookup() {
+        let profiles = sample_profiles();
+        let bytes = to_mmap_bytes(&profiles).unwrap();
+
+        assert_eq!(&bytes[0..4], MAGIC);
+        let count = read_u32(&bytes, 8) as usize;
+        assert_eq!(count, profiles.len());
+
+        // Exercise the same lookup path `OctoMmapIndex::get` uses, directly
+        // against the in-memory buffer, so this test doesn't depend on
+        // writing a temp file.
+        let num_buckets = read_u32(&bytes, 12) as usize;
+        let records_offset = HEADER_SIZE + num_buckets * 4;
+        let names_offset = records_offset + count * RECORD_SIZE;
+
+        for profile in profiles.values() {
+            let displacement_bucket = Mph::bucket_of(&profile.name, num_buckets);
+            let displacement = read_u32(&bytes, HEADER_SIZE + displacement_bucket * 4);
+            let slot = Mph::slot_of(&profile.name, displacement, count);
+
+            let record = &bytes[records_offset + slot * RECORD_SIZE..][..RECORD_SIZE];
+            let name_offset = read_u32(record, 0) as usize;
+            let name_len = read_u16(record, 4) as usize;
+            let found = std::str::from_utf8(
+                &bytes[names_offset + name_offset..names_offset + name_offset + name_len],
+            )
+            .unwrap();
+            assert_eq!(found, profile.name);
+
+            for (i, &c) in profile.coeffs.iter().enumerate() {
+                assert_eq!(read_f32(record, 12 + i * 4), c);
+            }
+            assert_eq!(read_u64(record, 44), profile.raw.downloads);
+        }
+    }
+
+    #[test]
+    fn test_unknown_name_is_rejected() {
+        let names = ["tokio", "serde", "hyper"];
+        let (mph, slots) = Mph::build(&names).unwrap();
+        // A minimal perfect hash leaves no slot empty, so
+        // "definitely-not-a-crate" lands on a real crate's slot; that
+        // crate's name bytes don't match, which is exactly what
+        // `OctoMmapIndex::get`'s verification step catches.
+        let slot = mph.lookup("definitely-not-a-crate", names.len());
+        assert!(slot < names.len());
+        let occupant = slots.iter().position(|&s| s == slot).map(|i| names[i]);
+        assert!(occupant.is_some_and(|name| name != "definitely-not-a-crate"));
+    }
+}
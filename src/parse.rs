@@ -9,6 +9,7 @@
 //! - Macros
 
 use anyhow::{Context, Result, bail};
+use serde::Deserialize;
 use std::path::{Path, PathBuf};
 use syn::{FnArg, GenericParam, Item, ReturnType, Type, Visibility};
 
@@ -22,6 +23,10 @@ pub struct CrateApi {
     pub symbols: Vec<Symbol>,
     /// Doc comments extracted for richer embeddings
     pub docs: Vec<SymbolDoc>,
+    /// Feature flags declared in the crate's own `Cargo.toml`, as `(name,
+    /// subfeatures)` pairs. Empty if the manifest is missing, unparsable, or
+    /// declares no `[features]`.
+    pub declared_features: Vec<(String, Vec<String>)>,
 }
 
 /// Symbol with its documentation for embedding
@@ -31,6 +36,16 @@ pub struct SymbolDoc {
     pub kind: String,
     pub signature: Option<String>,
     pub doc: Option<String>,
+    /// `since`/`note` from `#[deprecated(...)]`, joined as "since: note".
+    pub deprecated: Option<String>,
+    /// The `feature` name from `#[unstable(feature = "...")]`, if the item
+    /// is gated behind one. `None` for stable items, including ones
+    /// explicitly marked `#[stable(...)]`.
+    pub unstable_feature: Option<String>,
+    /// The item's `#[cfg(...)]` predicate, rendered back to source-like
+    /// text (e.g. `feature = "rt"` or `all(unix, feature = "mio")`). `None`
+    /// if the item isn't conditionally compiled.
+    pub cfg: Option<String>,
 }
 
 impl SymbolDoc {
@@ -47,18 +62,85 @@ impl SymbolDoc {
                 parts.push(first_para.to_string());
             }
         }
+        if let Some(deprecated) = &self.deprecated {
+            parts.push(format!("[deprecated: {deprecated}]"));
+        }
         parts.join(" ")
     }
 
+    /// Feature names gating this symbol, pulled out of its raw `cfg`
+    /// predicate rather than tracked separately during parsing, so
+    /// `all(feature = "a", feature = "b")` or `any(feature = "a", not(feature
+    /// = "b"))` all surface every feature name mentioned regardless of how
+    /// they're combined. Empty if the symbol isn't feature-gated.
+    pub fn feature_gates(&self) -> Vec<String> {
+        let Some(cfg) = &self.cfg else {
+            return Vec::new();
+        };
+
+        let mut gates = Vec::new();
+        let mut rest = cfg.as_str();
+        while let Some(idx) = rest.find("feature") {
+            rest = &rest[idx + "feature".len()..];
+            let Some(start) = rest.find('"') else {
+                break;
+            };
+            let Some(len) = rest[start + 1..].find('"') else {
+                break;
+            };
+            gates.push(rest[start + 1..start + 1 + len].to_string());
+            rest = &rest[start + 1 + len + 1..];
+        }
+        gates
+    }
+
     pub fn to_symbol(&self) -> Symbol {
         Symbol {
             path: self.path.clone(),
             kind: self.kind.clone(),
             signature: self.signature.clone(),
+            deprecated: self.deprecated.clone(),
+            unstable_feature: self.unstable_feature.clone(),
+            cfg: self.cfg.clone(),
         }
     }
 }
 
+/// Feature flags declared in `source_dir`'s own `Cargo.toml`, as `(name,
+/// subfeatures)` pairs - mirrors `RegistryCrate::features` in the cache's
+/// own dependency-graph module, since both read the same `[features]` shape.
+fn read_declared_features(source_dir: &Path) -> Vec<(String, Vec<String>)> {
+    #[derive(Debug, Deserialize)]
+    struct CargoToml {
+        features: Option<toml::Table>,
+    }
+
+    let toml_path = source_dir.join("Cargo.toml");
+    let Ok(contents) = std::fs::read_to_string(&toml_path) else {
+        return Vec::new();
+    };
+    let Ok(manifest) = toml::from_str::<CargoToml>(&contents) else {
+        return Vec::new();
+    };
+    let Some(features) = manifest.features else {
+        return Vec::new();
+    };
+
+    features
+        .into_iter()
+        .map(|(name, value)| {
+            let subfeatures = match value {
+                toml::Value::Array(items) => items
+                    .into_iter()
+                    .filter_map(|v| v.as_str().map(str::to_string))
+                    .collect(),
+                _ => Vec::new(),
+            };
+            (name, subfeatures)
+        })
+        .collect()
+}
+
 /// Find the source directory for a crate in the cargo registry
 pub fn find_crate_source(name: &str, version: &str) -> Result<PathBuf> {
     let home = dirs::home_dir().context("No home directory")?;
@@ -112,96 +194,99 @@ pub fn parse_crate(name: &str, version: &str) -> Result<CrateApi> {
     };
 
     if main_entry.exists() {
-        parse_file(&main_entry, name, &mut docs)?;
-    }
-
-    // Also parse any modules declared at top level
-    let src_dir = source_dir.join("src");
-    if src_dir.exists() {
-        parse_directory(&src_dir, name, &mut docs)?;
+        parse_module_file(&main_entry, name, &mut docs)?;
     }
 
     let symbols = docs.iter().map(|d| d.to_symbol()).collect();
+    let declared_features = read_declared_features(&source_dir);
 
     Ok(CrateApi {
         name: name.to_string(),
         version: version.to_string(),
         symbols,
         docs,
+        declared_features,
     })
 }
 
-/// Parse all .rs files in a directory
-fn parse_directory(dir: &Path, crate_name: &str, docs: &mut Vec<SymbolDoc>) -> Result<()> {
-    for entry in std::fs::read_dir(dir)? {
-        let entry = entry?;
-        let path = entry.path();
-
-        if path.is_file() && path.extension().map(|e| e == "rs").unwrap_or(false) {
-            // Skip lib.rs as we handle it specially
-            if path.file_name().map(|n| n == "lib.rs").unwrap_or(false) {
-                continue;
-            }
-            let _ = parse_file(&path, crate_name, docs);
-        } else if path.is_dir() {
-            // Recurse into subdirectories
-            let _ = parse_directory(&path, crate_name, docs);
-        }
-    }
-    Ok(())
-}
-
-/// Parse a single .rs file and extract public symbols
-fn parse_file(path: &Path, crate_name: &str, docs: &mut Vec<SymbolDoc>) -> Result<()> {
+/// Parse a single source file, extracting items at `module_path` and
+/// following any `mod name;` declaration found in it to the child file it
+/// actually refers to. `module_path` is supplied by the caller rather than
+/// derived from `path`, since the two only coincide for the crate root -
+/// everywhere else the module path comes from the `mod` item that led here.
+fn parse_module_file(path: &Path, module_path: &str, docs: &mut Vec<SymbolDoc>) -> Result<()> {
     let content = std::fs::read_to_string(path)?;
     let syntax = syn::parse_file(&content).context("Failed to parse Rust file")?;
-
-    // Derive module path from file path
-    let module_path = file_to_module_path(path, crate_name);
+    let dir = path.parent().unwrap_or_else(|| Path::new("."));
 
     for item in &syntax.items {
-        extract_item(item, &module_path, docs);
+        extract_item(item, module_path, dir, docs);
     }
 
     Ok(())
 }
 
-/// Convert a file path to a module path
-fn file_to_module_path(path: &Path, crate_name: &str) -> String {
-    let file_name = path.file_stem().unwrap_or_default().to_string_lossy();
+/// Resolve the source file a `mod name;` declaration (no inline body) refers
+/// to, the way rustc does: an explicit `#[path = "..."]` wins outright,
+/// otherwise try `<name>.rs` then `<name>/mod.rs`, both relative to the
+/// declaring file's own directory.
+fn resolve_mod_file(dir: &Path, mod_name: &str, attrs: &[syn::Attribute]) -> Option<PathBuf> {
+    if let Some(path_override) = extract_path_attr(attrs) {
+        let candidate = dir.join(path_override);
+        if candidate.is_file() {
+            return Some(candidate);
+        }
+    }
 
-    // lib.rs and main.rs are the crate root
-    if file_name == "lib" || file_name == "main" {
-        return crate_name.to_string();
+    let sibling = dir.join(format!("{mod_name}.rs"));
+    if sibling.is_file() {
+        return Some(sibling);
     }
 
-    // mod.rs uses parent directory name
-    if file_name == "mod" {
-        if let Some(parent) = path.parent() {
-            let parent_name = parent.file_name().unwrap_or_default().to_string_lossy();
-            if parent_name != "src" {
-                return format!("{}::{}", crate_name, parent_name);
-            }
-        }
-        return crate_name.to_string();
+    let nested = dir.join(mod_name).join("mod.rs");
+    if nested.is_file() {
+        return Some(nested);
     }
 
-    // Regular file.rs -> crate::file
-    format!("{}::{}", crate_name, file_name)
+    None
+}
+
+/// Pull the path string out of a `#[path = "..."]` attribute, if present.
+fn extract_path_attr(attrs: &[syn::Attribute]) -> Option<String> {
+    attrs.iter().find_map(|attr| {
+        if !attr.path().is_ident("path") {
+            return None;
+        }
+        if let syn::Meta::NameValue(nv) = &attr.meta {
+            if let syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(s),
+                ..
+            }) = &nv.value
+            {
+                return Some(s.value());
+            }
+        }
+        None
+    })
 }
 
 /// Extract symbols from an item
-fn extract_item(item: &Item, module_path: &str, docs: &mut Vec<SymbolDoc>) {
+fn extract_item(item: &Item, module_path: &str, dir: &Path, docs: &mut Vec<SymbolDoc>) {
     match item {
         Item::Fn(f) if is_public(&f.vis) => {
             let name = f.sig.ident.to_string();
             let sig = format_fn_signature(&f.sig);
             let doc = extract_doc_attrs(&f.attrs);
+            let (deprecated, unstable_feature) = extract_stability_attrs(&f.attrs);
+            let cfg = extract_cfg_attr(&f.attrs);
             docs.push(SymbolDoc {
                 path: format!("{}::{}", module_path, name),
                 kind: "fn".to_string(),
                 signature: Some(sig),
                 doc,
+                deprecated,
+                unstable_feature,
+                cfg,
             });
         }
 
@@ -209,11 +294,16 @@ fn extract_item(item: &Item, module_path: &str, docs: &mut Vec<SymbolDoc>) {
             let name = s.ident.to_string();
             let doc = extract_doc_attrs(&s.attrs);
             let sig = format_struct_signature(s);
+            let (deprecated, unstable_feature) = extract_stability_attrs(&s.attrs);
+            let cfg = extract_cfg_attr(&s.attrs);
             docs.push(SymbolDoc {
                 path: format!("{}::{}", module_path, name),
                 kind: "struct".to_string(),
                 signature: Some(sig),
                 doc,
+                deprecated,
+                unstable_feature,
+                cfg,
             });
 
             // Extract impl methods for this struct (if in same file)
@@ -225,22 +315,33 @@ fn extract_item(item: &Item, module_path: &str, docs: &mut Vec<SymbolDoc>) {
             let doc = extract_doc_attrs(&e.attrs);
             let variants: Vec<String> = e.variants.iter().map(|v| v.ident.to_string()).collect();
             let sig = format!("enum {} {{ {} }}", name, variants.join(", "));
+            let (deprecated, unstable_feature) = extract_stability_attrs(&e.attrs);
+            let cfg = extract_cfg_attr(&e.attrs);
             docs.push(SymbolDoc {
                 path: format!("{}::{}", module_path, name),
                 kind: "enum".to_string(),
                 signature: Some(sig),
                 doc,
+                deprecated,
+                unstable_feature,
+                cfg,
             });
 
             // Add each variant as a symbol too
             for variant in &e.variants {
                 let variant_name = variant.ident.to_string();
                 let variant_doc = extract_doc_attrs(&variant.attrs);
+                let (variant_deprecated, variant_unstable) =
+                    extract_stability_attrs(&variant.attrs);
+                let variant_cfg = extract_cfg_attr(&variant.attrs);
                 docs.push(SymbolDoc {
                     path: format!("{}::{}::{}", module_path, name, variant_name),
                     kind: "variant".to_string(),
                     signature: None,
                     doc: variant_doc,
+                    deprecated: variant_deprecated,
+                    unstable_feature: variant_unstable,
+                    cfg: variant_cfg,
                 });
             }
         }
@@ -250,25 +351,83 @@ fn extract_item(item: &Item, module_path: &str, docs: &mut Vec<SymbolDoc>) {
             let doc = extract_doc_attrs(&t.attrs);
             let generics = format_generics(&t.generics);
             let sig = format!("trait {}{}", name, generics);
+            let (deprecated, unstable_feature) = extract_stability_attrs(&t.attrs);
+            let cfg = extract_cfg_attr(&t.attrs);
             docs.push(SymbolDoc {
                 path: format!("{}::{}", module_path, name),
                 kind: "trait".to_string(),
                 signature: Some(sig),
                 doc,
+                deprecated,
+                unstable_feature,
+                cfg,
             });
 
-            // Extract trait methods
+            // Extract trait methods and associated types/consts
             for item in &t.items {
-                if let syn::TraitItem::Fn(method) = item {
-                    let method_name = method.sig.ident.to_string();
-                    let method_sig = format_fn_signature(&method.sig);
-                    let method_doc = extract_doc_attrs(&method.attrs);
-                    docs.push(SymbolDoc {
-                        path: format!("{}::{}::{}", module_path, name, method_name),
-                        kind: "trait_method".to_string(),
-                        signature: Some(method_sig),
-                        doc: method_doc,
-                    });
+                match item {
+                    syn::TraitItem::Fn(method) => {
+                        let method_name = method.sig.ident.to_string();
+                        let method_sig = format_fn_signature(&method.sig);
+                        let method_doc = extract_doc_attrs(&method.attrs);
+                        let (method_deprecated, method_unstable) =
+                            extract_stability_attrs(&method.attrs);
+                        let method_cfg = extract_cfg_attr(&method.attrs);
+                        docs.push(SymbolDoc {
+                            path: format!("{}::{}::{}", module_path, name, method_name),
+                            kind: "trait_method".to_string(),
+                            signature: Some(method_sig),
+                            doc: method_doc,
+                            deprecated: method_deprecated,
+                            unstable_feature: method_unstable,
+                            cfg: method_cfg,
+                        });
+                    }
+                    syn::TraitItem::Type(assoc_type) => {
+                        let assoc_name = assoc_type.ident.to_string();
+                        let sig = if assoc_type.bounds.is_empty() {
+                            format!("type {}", assoc_name)
+                        } else {
+                            let bounds: Vec<String> = assoc_type
+                                .bounds
+                                .iter()
+                                .map(type_param_bound_to_string)
+                                .collect();
+                            format!("type {}: {}", assoc_name, bounds.join(" + "))
+                        };
+                        let assoc_doc = extract_doc_attrs(&assoc_type.attrs);
+                        let (assoc_deprecated, assoc_unstable) =
+                            extract_stability_attrs(&assoc_type.attrs);
+                        let assoc_cfg = extract_cfg_attr(&assoc_type.attrs);
+                        docs.push(SymbolDoc {
+                            path: format!("{}::{}::{}", module_path, name, assoc_name),
+                            kind: "assoc_type".to_string(),
+                            signature: Some(sig),
+                            doc: assoc_doc,
+                            deprecated: assoc_deprecated,
+                            unstable_feature: assoc_unstable,
+                            cfg: assoc_cfg,
+                        });
+                    }
+                    syn::TraitItem::Const(assoc_const) => {
+                        let assoc_name = assoc_const.ident.to_string();
+                        let sig =
+                            format!("const {}: {}", assoc_name, type_to_string(&assoc_const.ty));
+                        let assoc_doc = extract_doc_attrs(&assoc_const.attrs);
+                        let (assoc_deprecated, assoc_unstable) =
+                            extract_stability_attrs(&assoc_const.attrs);
+                        let assoc_cfg = extract_cfg_attr(&assoc_const.attrs);
+                        docs.push(SymbolDoc {
+                            path: format!("{}::{}::{}", module_path, name, assoc_name),
+                            kind: "assoc_const".to_string(),
+                            signature: Some(sig),
+                            doc: assoc_doc,
+                            deprecated: assoc_deprecated,
+                            unstable_feature: assoc_unstable,
+                            cfg: assoc_cfg,
+                        });
+                    }
+                    _ => {}
                 }
             }
         }
@@ -277,11 +436,16 @@ fn extract_item(item: &Item, module_path: &str, docs: &mut Vec<SymbolDoc>) {
             let name = t.ident.to_string();
             let doc = extract_doc_attrs(&t.attrs);
             let sig = format!("type {} = {}", name, type_to_string(&t.ty));
+            let (deprecated, unstable_feature) = extract_stability_attrs(&t.attrs);
+            let cfg = extract_cfg_attr(&t.attrs);
             docs.push(SymbolDoc {
                 path: format!("{}::{}", module_path, name),
                 kind: "type".to_string(),
                 signature: Some(sig),
                 doc,
+                deprecated,
+                unstable_feature,
+                cfg,
             });
         }
 
@@ -289,11 +453,16 @@ fn extract_item(item: &Item, module_path: &str, docs: &mut Vec<SymbolDoc>) {
             let name = c.ident.to_string();
             let doc = extract_doc_attrs(&c.attrs);
             let sig = format!("const {}: {}", name, type_to_string(&c.ty));
+            let (deprecated, unstable_feature) = extract_stability_attrs(&c.attrs);
+            let cfg = extract_cfg_attr(&c.attrs);
             docs.push(SymbolDoc {
                 path: format!("{}::{}", module_path, name),
                 kind: "const".to_string(),
                 signature: Some(sig),
                 doc,
+                deprecated,
+                unstable_feature,
+                cfg,
             });
         }
 
@@ -306,11 +475,16 @@ fn extract_item(item: &Item, module_path: &str, docs: &mut Vec<SymbolDoc>) {
                 ""
             };
             let sig = format!("static {}{}: {}", mutability, name, type_to_string(&s.ty));
+            let (deprecated, unstable_feature) = extract_stability_attrs(&s.attrs);
+            let cfg = extract_cfg_attr(&s.attrs);
             docs.push(SymbolDoc {
                 path: format!("{}::{}", module_path, name),
                 kind: "static".to_string(),
                 signature: Some(sig),
                 doc,
+                deprecated,
+                unstable_feature,
+                cfg,
             });
         }
 
@@ -325,18 +499,64 @@ fn extract_item(item: &Item, module_path: &str, docs: &mut Vec<SymbolDoc>) {
                     .unwrap_or_default();
 
                 for impl_item in &i.items {
-                    if let syn::ImplItem::Fn(method) = impl_item {
-                        if is_public(&method.vis) {
+                    match impl_item {
+                        syn::ImplItem::Fn(method) if is_public(&method.vis) => {
                             let method_name = method.sig.ident.to_string();
                             let method_sig = format_fn_signature(&method.sig);
                             let method_doc = extract_doc_attrs(&method.attrs);
+                            let (method_deprecated, method_unstable) =
+                                extract_stability_attrs(&method.attrs);
+                            let method_cfg = extract_cfg_attr(&method.attrs);
                             docs.push(SymbolDoc {
                                 path: format!("{}::{}::{}", module_path, type_name, method_name),
                                 kind: "method".to_string(),
                                 signature: Some(method_sig),
                                 doc: method_doc,
+                                deprecated: method_deprecated,
+                                unstable_feature: method_unstable,
+                                cfg: method_cfg,
+                            });
+                        }
+                        syn::ImplItem::Type(assoc_type) if is_public(&assoc_type.vis) => {
+                            let assoc_name = assoc_type.ident.to_string();
+                            let sig =
+                                format!("type {} = {}", assoc_name, type_to_string(&assoc_type.ty));
+                            let assoc_doc = extract_doc_attrs(&assoc_type.attrs);
+                            let (assoc_deprecated, assoc_unstable) =
+                                extract_stability_attrs(&assoc_type.attrs);
+                            let assoc_cfg = extract_cfg_attr(&assoc_type.attrs);
+                            docs.push(SymbolDoc {
+                                path: format!("{}::{}::{}", module_path, type_name, assoc_name),
+                                kind: "assoc_type".to_string(),
+                                signature: Some(sig),
+                                doc: assoc_doc,
+                                deprecated: assoc_deprecated,
+                                unstable_feature: assoc_unstable,
+                                cfg: assoc_cfg,
+                            });
+                        }
+                        syn::ImplItem::Const(assoc_const) if is_public(&assoc_const.vis) => {
+                            let assoc_name = assoc_const.ident.to_string();
+                            let sig = format!(
+                                "const {}: {}",
+                                assoc_name,
+                                type_to_string(&assoc_const.ty)
+                            );
+                            let assoc_doc = extract_doc_attrs(&assoc_const.attrs);
+                            let (assoc_deprecated, assoc_unstable) =
+                                extract_stability_attrs(&assoc_const.attrs);
+                            let assoc_cfg = extract_cfg_attr(&assoc_const.attrs);
+                            docs.push(SymbolDoc {
+                                path: format!("{}::{}::{}", module_path, type_name, assoc_name),
+                                kind: "assoc_const".to_string(),
+                                signature: Some(sig),
+                                doc: assoc_doc,
+                                deprecated: assoc_deprecated,
+                                unstable_feature: assoc_unstable,
+                                cfg: assoc_cfg,
                             });
                         }
+                        _ => {}
                     }
                 }
             }
@@ -346,20 +566,28 @@ fn extract_item(item: &Item, module_path: &str, docs: &mut Vec<SymbolDoc>) {
             let mod_name = m.ident.to_string();
             let new_module_path = format!("{}::{}", module_path, mod_name);
 
-            // If the module has inline content, parse it
+            // If the module has inline content, parse it; otherwise follow
+            // the `mod name;` declaration to the file it actually refers to.
             if let Some((_, items)) = &m.content {
                 for item in items {
-                    extract_item(item, &new_module_path, docs);
+                    extract_item(item, &new_module_path, dir, docs);
                 }
+            } else if let Some(child_file) = resolve_mod_file(dir, &mod_name, &m.attrs) {
+                let _ = parse_module_file(&child_file, &new_module_path, docs);
             }
 
             // Add the module itself as a symbol
             let doc = extract_doc_attrs(&m.attrs);
+            let (deprecated, unstable_feature) = extract_stability_attrs(&m.attrs);
+            let cfg = extract_cfg_attr(&m.attrs);
             docs.push(SymbolDoc {
                 path: new_module_path,
                 kind: "mod".to_string(),
                 signature: None,
                 doc,
+                deprecated,
+                unstable_feature,
+                cfg,
             });
         }
 
@@ -368,11 +596,16 @@ fn extract_item(item: &Item, module_path: &str, docs: &mut Vec<SymbolDoc>) {
             if let Some(ident) = &m.ident {
                 let name = ident.to_string();
                 let doc = extract_doc_attrs(&m.attrs);
+                let (deprecated, unstable_feature) = extract_stability_attrs(&m.attrs);
+                let cfg = extract_cfg_attr(&m.attrs);
                 docs.push(SymbolDoc {
                     path: format!("{}::{}!", module_path, name),
                     kind: "macro".to_string(),
                     signature: None,
                     doc,
+                    deprecated,
+                    unstable_feature,
+                    cfg,
                 });
             }
         }
@@ -413,6 +646,154 @@ fn extract_doc_attrs(attrs: &[syn::Attribute]) -> Option<String> {
     }
 }
 
+/// Parse `#[deprecated(...)]` and `#[unstable(feature = "...")]` into the
+/// same kind of API-stability signal rustdoc tracks as `Deprecation` and
+/// `Stability`. `#[stable(...)]` is recognized but carries nothing worth
+/// keeping - its only effect here is that the item isn't `#[unstable(...)]`.
+fn extract_stability_attrs(attrs: &[syn::Attribute]) -> (Option<String>, Option<String>) {
+    let mut deprecated = None;
+    let mut unstable_feature = None;
+
+    for attr in attrs {
+        if attr.path().is_ident("deprecated") {
+            deprecated = Some(parse_deprecated_attr(attr));
+        } else if attr.path().is_ident("unstable") {
+            unstable_feature = parse_meta_string_field(attr, "feature");
+        }
+    }
+
+    (deprecated, unstable_feature)
+}
+
+/// Render a `#[deprecated]` attribute as a single "since: note" string,
+/// falling back to whatever of the two pieces is actually present.
+fn parse_deprecated_attr(attr: &syn::Attribute) -> String {
+    let since = parse_meta_string_field(attr, "since");
+    let note = parse_meta_string_field(attr, "note");
+
+    match (since, note) {
+        (Some(since), Some(note)) => format!("{since}: {note}"),
+        (Some(since), None) => since,
+        (None, Some(note)) => note,
+        (None, None) => "deprecated".to_string(),
+    }
+}
+
+/// Pull a `name = "value"` string field out of a `#[attr(name = "value", ...)]`
+/// list, e.g. `since`/`note` out of `#[deprecated(...)]`.
+fn parse_meta_string_field(attr: &syn::Attribute, field: &str) -> Option<String> {
+    let mut value = None;
+    if matches!(&attr.meta, syn::Meta::List(_)) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(field) {
+                let expr: syn::Expr = meta.value()?.parse()?;
+                if let syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(s),
+                    ..
+                }) = expr
+                {
+                    value = Some(s.value());
+                }
+            }
+            Ok(())
+        });
+    }
+    value
+}
+
+/// A parsed `#[cfg(...)]` predicate, kept just structured enough to render
+/// back out to a canonical string - we don't evaluate it, only normalize it.
+enum CfgPredicate {
+    Flag(String),
+    Feature(String),
+    Not(Box<CfgPredicate>),
+    All(Vec<CfgPredicate>),
+    Any(Vec<CfgPredicate>),
+    Other(String),
+}
+
+impl syn::parse::Parse for CfgPredicate {
+    fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let ident: syn::Ident = input.parse()?;
+        let name = ident.to_string();
+
+        if input.peek(syn::Token![=]) {
+            input.parse::<syn::Token![=]>()?;
+            let lit: syn::LitStr = input.parse()?;
+            return Ok(if name == "feature" {
+                CfgPredicate::Feature(lit.value())
+            } else {
+                CfgPredicate::Other(format!("{name} = \"{}\"", lit.value()))
+            });
+        }
+
+        if input.peek(syn::token::Paren) {
+            let content;
+            syn::parenthesized!(content in input);
+            let inner: syn::punctuated::Punctuated<CfgPredicate, syn::Token![,]> =
+                content.parse_terminated(CfgPredicate::parse, syn::Token![,])?;
+            let inner: Vec<CfgPredicate> = inner.into_iter().collect();
+            return Ok(match name.as_str() {
+                "not" => CfgPredicate::Not(Box::new(
+                    inner
+                        .into_iter()
+                        .next()
+                        .unwrap_or(CfgPredicate::Other(String::new())),
+                )),
+                "all" => CfgPredicate::All(inner),
+                "any" => CfgPredicate::Any(inner),
+                _ => CfgPredicate::Other(format!(
+                    "{name}({})",
+                    inner
+                        .iter()
+                        .map(CfgPredicate::render)
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )),
+            });
+        }
+
+        Ok(CfgPredicate::Flag(name))
+    }
+}
+
+impl CfgPredicate {
+    fn render(&self) -> String {
+        match self {
+            CfgPredicate::Flag(name) => name.clone(),
+            CfgPredicate::Feature(name) => format!("feature = \"{name}\""),
+            CfgPredicate::Not(inner) => format!("not({})", inner.render()),
+            CfgPredicate::All(items) => format!(
+                "all({})",
+                items
+                    .iter()
+                    .map(CfgPredicate::render)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            CfgPredicate::Any(items) => format!(
+                "any({})",
+                items
+                    .iter()
+                    .map(CfgPredicate::render)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            CfgPredicate::Other(text) => text.clone(),
+        }
+    }
+}
+
+/// Render a symbol's `#[cfg(...)]` attribute, if any, as a canonical string
+/// (e.g. `feature = "rt"`, `all(unix, feature = "mio")`).
+fn extract_cfg_attr(attrs: &[syn::Attribute]) -> Option<String> {
+    attrs
+        .iter()
+        .find(|attr| attr.path().is_ident("cfg"))
+        .and_then(|attr| attr.parse_args::<CfgPredicate>().ok())
+        .map(|pred| pred.render())
+}
+
 /// Format a function signature
 fn format_fn_signature(sig: &syn::Signature) -> String {
     let mut parts = Vec::new();
@@ -461,10 +842,15 @@ fn format_fn_signature(sig: &syn::Signature) -> String {
         parts.push(format!(" -> {}", type_to_string(ty)));
     }
 
+    // Where clause
+    parts.push(format_where_clause(&sig.generics));
+
     parts.concat()
 }
 
-/// Format generics
+/// Format generics, including the bounds on each type/lifetime param
+/// (`T: Clone + Send`, `'a: 'b`) - a bare `T` loses exactly the information
+/// that tells two overloaded-looking APIs apart.
 fn format_generics(generics: &syn::Generics) -> String {
     if generics.params.is_empty() {
         return String::new();
@@ -474,15 +860,73 @@ fn format_generics(generics: &syn::Generics) -> String {
         .params
         .iter()
         .map(|p| match p {
-            GenericParam::Type(t) => t.ident.to_string(),
-            GenericParam::Lifetime(l) => format!("'{}", l.lifetime.ident),
-            GenericParam::Const(c) => format!("const {}", c.ident),
+            GenericParam::Type(t) => {
+                let name = t.ident.to_string();
+                if t.bounds.is_empty() {
+                    name
+                } else {
+                    let bounds: Vec<String> =
+                        t.bounds.iter().map(type_param_bound_to_string).collect();
+                    format!("{}: {}", name, bounds.join(" + "))
+                }
+            }
+            GenericParam::Lifetime(l) => {
+                let name = format!("'{}", l.lifetime.ident);
+                if l.bounds.is_empty() {
+                    name
+                } else {
+                    let bounds: Vec<String> =
+                        l.bounds.iter().map(|lt| format!("'{}", lt.ident)).collect();
+                    format!("{}: {}", name, bounds.join(" + "))
+                }
+            }
+            GenericParam::Const(c) => format!("const {}: {}", c.ident, type_to_string(&c.ty)),
         })
         .collect();
 
     format!("<{}>", params.join(", "))
 }
 
+/// Render a `where` clause (`where T: Clone, U: Send`), or an empty string
+/// if the generics have none.
+fn format_where_clause(generics: &syn::Generics) -> String {
+    let Some(where_clause) = &generics.where_clause else {
+        return String::new();
+    };
+
+    let predicates: Vec<String> = where_clause
+        .predicates
+        .iter()
+        .map(|pred| match pred {
+            syn::WherePredicate::Type(t) => {
+                let bounds: Vec<String> = t.bounds.iter().map(type_param_bound_to_string).collect();
+                format!("{}: {}", type_to_string(&t.bounded_ty), bounds.join(" + "))
+            }
+            syn::WherePredicate::Lifetime(l) => {
+                let bounds: Vec<String> =
+                    l.bounds.iter().map(|lt| format!("'{}", lt.ident)).collect();
+                format!("'{}: {}", l.lifetime.ident, bounds.join(" + "))
+            }
+            _ => "_".to_string(),
+        })
+        .collect();
+
+    if predicates.is_empty() {
+        String::new()
+    } else {
+        format!(" where {}", predicates.join(", "))
+    }
+}
+
+/// Render a single trait/lifetime bound, e.g. `Clone` or `'a`.
+fn type_param_bound_to_string(bound: &syn::TypeParamBound) -> String {
+    match bound {
+        syn::TypeParamBound::Trait(t) => path_to_string(&t.path),
+        syn::TypeParamBound::Lifetime(l) => format!("'{}", l.ident),
+        _ => "_".to_string(),
+    }
+}
+
 /// Format a struct signature (showing fields for tuple/unit structs)
 fn format_struct_signature(s: &syn::ItemStruct) -> String {
     let name = s.ident.to_string();
@@ -514,41 +958,64 @@ fn pat_to_string(pat: &syn::Pat) -> String {
     }
 }
 
+/// Render a path segment, including any angle-bracketed or parenthesized
+/// generic arguments (`Vec<T>`, `Fn(A) -> B`).
+fn path_segment_to_string(s: &syn::PathSegment) -> String {
+    let name = s.ident.to_string();
+    match &s.arguments {
+        syn::PathArguments::None => name,
+        syn::PathArguments::AngleBracketed(args) => {
+            let args_str: Vec<String> = args
+                .args
+                .iter()
+                .map(|a| match a {
+                    syn::GenericArgument::Type(t) => type_to_string(t),
+                    syn::GenericArgument::Lifetime(l) => format!("'{}", l.ident),
+                    _ => "_".to_string(),
+                })
+                .collect();
+            format!("{}<{}>", name, args_str.join(", "))
+        }
+        syn::PathArguments::Parenthesized(args) => {
+            let inputs: Vec<String> = args.inputs.iter().map(type_to_string).collect();
+            let output = match &args.output {
+                ReturnType::Default => String::new(),
+                ReturnType::Type(_, t) => format!(" -> {}", type_to_string(t)),
+            };
+            format!("{}({}){}", name, inputs.join(", "), output)
+        }
+    }
+}
+
+/// Render a full path as `a::b::c`, each segment via `path_segment_to_string`.
+fn path_to_string(path: &syn::Path) -> String {
+    path.segments
+        .iter()
+        .map(path_segment_to_string)
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
 /// Convert a type to string (simplified)
 fn type_to_string(ty: &Type) -> String {
     match ty {
-        Type::Path(p) => p
-            .path
-            .segments
-            .iter()
-            .map(|s| {
-                let name = s.ident.to_string();
-                match &s.arguments {
-                    syn::PathArguments::None => name,
-                    syn::PathArguments::AngleBracketed(args) => {
-                        let args_str: Vec<String> = args
-                            .args
-                            .iter()
-                            .map(|a| match a {
-                                syn::GenericArgument::Type(t) => type_to_string(t),
-                                syn::GenericArgument::Lifetime(l) => format!("'{}", l.ident),
-                                _ => "_".to_string(),
-                            })
-                            .collect();
-                        format!("{}<{}>", name, args_str.join(", "))
-                    }
-                    syn::PathArguments::Parenthesized(args) => {
-                        let inputs: Vec<String> = args.inputs.iter().map(type_to_string).collect();
-                        let output = match &args.output {
-                            ReturnType::Default => String::new(),
-                            ReturnType::Type(_, t) => format!(" -> {}", type_to_string(t)),
-                        };
-                        format!("{}({}){}", name, inputs.join(", "), output)
-                    }
+        Type::Path(p) => match &p.qself {
+            // Qualified-self projection: `<T as Trait>::Item`, or just
+            // `<T>::Item` when there's no explicit trait (position == 0).
+            Some(qself) => {
+                let self_ty = type_to_string(&qself.ty);
+                let segments: Vec<String> =
+                    p.path.segments.iter().map(path_segment_to_string).collect();
+                let rest = segments[qself.position..].join("::");
+                if qself.position == 0 {
+                    format!("<{}>::{}", self_ty, rest)
+                } else {
+                    let trait_part = segments[..qself.position].join("::");
+                    format!("<{} as {}>::{}", self_ty, trait_part, rest)
                 }
-            })
-            .collect::<Vec<_>>()
-            .join("::"),
+            }
+            None => path_to_string(&p.path),
+        },
         Type::Reference(r) => {
             let mut s = String::from("&");
             if let Some(lt) = &r.lifetime {
@@ -575,37 +1042,11 @@ fn type_to_string(ty: &Type) -> String {
             format!("*{}{}", mutability, type_to_string(&p.elem))
         }
         Type::ImplTrait(i) => {
-            let bounds: Vec<String> = i
-                .bounds
-                .iter()
-                .map(|b| match b {
-                    syn::TypeParamBound::Trait(t) => t
-                        .path
-                        .segments
-                        .iter()
-                        .map(|s| s.ident.to_string())
-                        .collect::<Vec<_>>()
-                        .join("::"),
-                    _ => "_".to_string(),
-                })
-                .collect();
+            let bounds: Vec<String> = i.bounds.iter().map(type_param_bound_to_string).collect();
             format!("impl {}", bounds.join(" + "))
         }
         Type::TraitObject(t) => {
-            let bounds: Vec<String> = t
-                .bounds
-                .iter()
-                .map(|b| match b {
-                    syn::TypeParamBound::Trait(tr) => tr
-                        .path
-                        .segments
-                        .iter()
-                        .map(|s| s.ident.to_string())
-                        .collect::<Vec<_>>()
-                        .join("::"),
-                    _ => "_".to_string(),
-                })
-                .collect();
+            let bounds: Vec<String> = t.bounds.iter().map(type_param_bound_to_string).collect();
             format!("dyn {}", bounds.join(" + "))
         }
         Type::Never(_) => "!".to_string(),
@@ -639,4 +1080,240 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_extract_stability_attrs_deprecated() {
+        let item: syn::ItemFn = syn::parse_str(
+            r#"#[deprecated(since = "1.2.0", note = "use new_fn instead")]
+            pub fn old_fn() {}"#,
+        )
+        .unwrap();
+        let (deprecated, unstable_feature) = extract_stability_attrs(&item.attrs);
+        assert_eq!(deprecated.as_deref(), Some("1.2.0: use new_fn instead"));
+        assert_eq!(unstable_feature, None);
+    }
+
+    #[test]
+    fn test_extract_stability_attrs_unstable() {
+        let item: syn::ItemFn = syn::parse_str(
+            r#"#[unstable(feature = "nightly_thing", issue = "12345")]
+            pub fn nightly_fn() {}"#,
+        )
+        .unwrap();
+        let (deprecated, unstable_feature) = extract_stability_attrs(&item.attrs);
+        assert_eq!(deprecated, None);
+        assert_eq!(unstable_feature.as_deref(), Some("nightly_thing"));
+    }
+
+    #[test]
+    fn test_embedding_text_flags_deprecated_symbol() {
+        let doc = SymbolDoc {
+            path: "crate::old_fn".to_string(),
+            kind: "fn".to_string(),
+            signature: Some("fn old_fn()".to_string()),
+            doc: None,
+            deprecated: Some("1.2.0: use new_fn instead".to_string()),
+            unstable_feature: None,
+            cfg: None,
+        };
+        assert!(doc
+            .embedding_text()
+            .ends_with("[deprecated: 1.2.0: use new_fn instead]"));
+    }
+
+    #[test]
+    fn test_extract_cfg_attr_simple_feature() {
+        let item: syn::ItemFn = syn::parse_str(
+            r#"#[cfg(feature = "rt")]
+            pub fn rt_only() {}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            extract_cfg_attr(&item.attrs).as_deref(),
+            Some("feature = \"rt\"")
+        );
+    }
+
+    #[test]
+    fn test_extract_cfg_attr_all_predicate() {
+        let item: syn::ItemFn = syn::parse_str(
+            r#"#[cfg(all(unix, feature = "mio"))]
+            pub fn unix_mio_only() {}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            extract_cfg_attr(&item.attrs).as_deref(),
+            Some("all(unix, feature = \"mio\")")
+        );
+    }
+
+    #[test]
+    fn test_extract_cfg_attr_not_predicate() {
+        let item: syn::ItemFn = syn::parse_str(
+            r#"#[cfg(not(windows))]
+            pub fn not_windows() {}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            extract_cfg_attr(&item.attrs).as_deref(),
+            Some("not(windows)")
+        );
+    }
+
+    #[test]
+    fn test_extract_path_attr() {
+        let item: syn::ItemMod = syn::parse_str(r#"#[path = "imp/real.rs"] mod shim;"#).unwrap();
+        assert_eq!(
+            extract_path_attr(&item.attrs).as_deref(),
+            Some("imp/real.rs")
+        );
+    }
+
+    #[test]
+    fn test_resolve_mod_file_prefers_path_attr_then_sibling_then_nested() {
+        let dir = std::env::temp_dir().join(format!(
+            "fastdeps_resolve_mod_file_test_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(dir.join("nested_only")).unwrap();
+        std::fs::write(dir.join("sibling.rs"), "").unwrap();
+        std::fs::write(dir.join("nested_only").join("mod.rs"), "").unwrap();
+        std::fs::write(dir.join("overridden.rs"), "").unwrap();
+
+        assert_eq!(
+            resolve_mod_file(&dir, "sibling", &[]),
+            Some(dir.join("sibling.rs"))
+        );
+        assert_eq!(
+            resolve_mod_file(&dir, "nested_only", &[]),
+            Some(dir.join("nested_only").join("mod.rs"))
+        );
+        assert_eq!(resolve_mod_file(&dir, "missing", &[]), None);
+
+        let path_attr: syn::ItemMod =
+            syn::parse_str(r#"#[path = "overridden.rs"] mod sibling;"#).unwrap();
+        assert_eq!(
+            resolve_mod_file(&dir, "sibling", &path_attr.attrs),
+            Some(dir.join("overridden.rs"))
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_feature_gates_extracts_from_nested_predicate() {
+        let doc = SymbolDoc {
+            path: "crate::unix_mio_only".to_string(),
+            kind: "fn".to_string(),
+            signature: Some("fn unix_mio_only()".to_string()),
+            doc: None,
+            deprecated: None,
+            unstable_feature: None,
+            cfg: Some("all(unix, feature = \"mio\")".to_string()),
+        };
+        assert_eq!(doc.feature_gates(), vec!["mio".to_string()]);
+    }
+
+    #[test]
+    fn test_format_generics_renders_type_and_lifetime_bounds() {
+        let item: syn::ItemFn =
+            syn::parse_str("pub fn f<'a: 'b, 'b, T: Clone + Send, const N: usize>() {}").unwrap();
+        assert_eq!(
+            format_generics(&item.sig.generics),
+            "<'a: 'b, 'b, T: Clone + Send, const N: usize>"
+        );
+    }
+
+    #[test]
+    fn test_format_where_clause_renders_type_and_lifetime_predicates() {
+        let item: syn::ItemFn =
+            syn::parse_str("pub fn f<T, 'a>() where T: Clone + Send, 'a: 'static {}").unwrap();
+        assert_eq!(
+            format_where_clause(&item.sig.generics),
+            " where T: Clone + Send, 'a: 'static"
+        );
+    }
+
+    #[test]
+    fn test_format_where_clause_empty_when_absent() {
+        let item: syn::ItemFn = syn::parse_str("pub fn f<T>() {}").unwrap();
+        assert_eq!(format_where_clause(&item.sig.generics), "");
+    }
+
+    #[test]
+    fn test_type_to_string_qualified_self_projection_with_trait() {
+        let ty: syn::Type = syn::parse_str("<T as Iterator>::Item").unwrap();
+        assert_eq!(type_to_string(&ty), "<T as Iterator>::Item");
+    }
+
+    #[test]
+    fn test_type_to_string_qualified_self_projection_without_trait() {
+        let ty: syn::Type = syn::parse_str("<T>::Item").unwrap();
+        assert_eq!(type_to_string(&ty), "<T>::Item");
+    }
+
+    #[test]
+    fn test_extract_item_trait_assoc_type_and_const() {
+        let item: Item = syn::parse_str(
+            r#"pub trait Store {
+                type Key: Clone + Send;
+                const CAPACITY: usize;
+                fn get(&self) -> Self::Key;
+            }"#,
+        )
+        .unwrap();
+        let mut docs = Vec::new();
+        extract_item(&item, "crate", Path::new("."), &mut docs);
+
+        let assoc_type = docs
+            .iter()
+            .find(|d| d.kind == "assoc_type")
+            .expect("assoc_type symbol");
+        assert_eq!(assoc_type.path, "crate::Store::Key");
+        assert_eq!(
+            assoc_type.signature.as_deref(),
+            Some("type Key: Clone + Send")
+        );
+
+        let assoc_const = docs
+            .iter()
+            .find(|d| d.kind == "assoc_const")
+            .expect("assoc_const symbol");
+        assert_eq!(assoc_const.path, "crate::Store::CAPACITY");
+        assert_eq!(
+            assoc_const.signature.as_deref(),
+            Some("const CAPACITY: usize")
+        );
+    }
+
+    #[test]
+    fn test_extract_item_impl_assoc_type_and_const() {
+        let item: Item = syn::parse_str(
+            r#"impl Store for MemStore {
+                pub type Key = String;
+                pub const CAPACITY: usize = 64;
+                pub fn get(&self) -> Self::Key { self.key.clone() }
+            }"#,
+        )
+        .unwrap();
+        let mut docs = Vec::new();
+        extract_item(&item, "crate", Path::new("."), &mut docs);
+
+        let assoc_type = docs
+            .iter()
+            .find(|d| d.kind == "assoc_type")
+            .expect("assoc_type symbol");
+        assert_eq!(assoc_type.path, "crate::MemStore::Key");
+        assert_eq!(assoc_type.signature.as_deref(), Some("type Key = String"));
+
+        let assoc_const = docs
+            .iter()
+            .find(|d| d.kind == "assoc_const")
+            .expect("assoc_const symbol");
+        assert_eq!(assoc_const.path, "crate::MemStore::CAPACITY");
+        assert_eq!(
+            assoc_const.signature.as_deref(),
+            Some("const CAPACITY: usize")
+        );
+    }
 }
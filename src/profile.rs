@@ -3,11 +3,12 @@
 //! Each crate is represented as an 8-dimensional octonion where:
 //! - e0 (real): Utility score (downloads, maintenance)
 //! - e1: Concurrency (Send/Sync implementations)
-//! - e2: Safety (unsafe block density)
+//! - e2: Safety (unsafe density - blocks, fns, impls, and traits, weighted
+//!   by how much of the public API surface each one taints)
 //! - e3: Async (async fn ratio)
 //! - e4: Memory (heap allocation patterns)
-//! - e5: Friction (dependency count, compile time proxy)
-//! - e6: Environment (no_std, WASM compatibility)
+//! - e5: Friction (direct + transitive dependency count, compile time proxy)
+//! - e6: Environment (unconditional/conditional no_std, wasm32 support)
 //! - e7: Entropy (API volatility, semver changes)
 //!
 //! The Fano plane structure encodes conflict triads:
@@ -20,7 +21,8 @@
 //! - (e7, e1, e3): Unstable async
 
 use octonion::Octonion;
-use std::path::Path;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 use syn::visit::Visit;
 
 /// Octonion profile for a crate, computed from static analysis.
@@ -37,19 +39,50 @@ pub struct CrateProfile {
 }
 
 /// Raw extracted metrics before normalization to [0, 1].
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RawProfile {
-    pub utility: f32,         // e0: placeholder for now
-    pub send_sync_count: u32, // e1: count of Send/Sync impls
-    pub unsafe_blocks: u32,   // e2: count of unsafe blocks
-    pub total_loc: u32,       // for e2 density
-    pub async_fns: u32,       // e3: async fn count
-    pub total_fns: u32,       // for e3 ratio
-    pub heap_types: u32,      // e4: Box, Vec, Rc, Arc usage
-    pub dep_count: u32,       // e5: direct dependency count
-    pub is_no_std: bool,      // e6: no_std flag
-    pub has_wasm: bool,       // e6: wasm target support
-                              // e7 (entropy) requires version history - skip for MVP
+    pub utility: f32,                  // e0: placeholder for now
+    pub send_sync_count: u32,          // e1: count of Send/Sync impls
+    pub unsafe_blocks: u32,            // e2: count of bare `unsafe { }` blocks
+    pub unsafe_fns: u32,               // e2: count of `unsafe fn` declarations
+    pub unsafe_impls: u32,             // e2: count of `unsafe impl`/`unsafe trait` items
+    pub total_loc: u32,                // for e2 density
+    pub async_fns: u32,                // e3: async fn count
+    pub total_fns: u32,                // for e3 ratio
+    pub heap_types: u32,               // e4: Box, Vec, Rc, Arc usage
+    pub direct_dep_count: u32,         // e5: runtime ([dependencies]) count, dev/build excluded
+    pub transitive_dep_count: u32,     // e5: resolved closure size from Cargo.lock
+    pub is_no_std: bool,               // e6: unconditional `#![no_std]`
+    pub is_conditionally_no_std: bool, // e6: `#![cfg_attr(not(feature = "std"), no_std)]`
+    pub has_wasm: bool,                // e6: wasm32 target/cfg support
+    pub entropy: f32,                  // e7: version-history volatility, see `compute_entropy`
+}
+
+/// The seven Fano-plane conflict triads documented at the top of this
+/// module, as (dimension indices, human-readable name) pairs. Each triad is
+/// `{i, i+1, i+3} mod 7` (1-indexed) - the standard quadratic-residue
+/// construction of a Fano plane's lines.
+const CONFLICT_TRIADS: [([usize; 3], &str); 7] = [
+    ([1, 2, 4], "Unsafe concurrency"),
+    ([2, 3, 5], "Blocking in async"),
+    ([3, 4, 6], "Environment leak (async + heap in no_std)"),
+    ([4, 5, 7], "Volatility bloat"),
+    ([5, 6, 1], "Runtime friction"),
+    ([6, 7, 2], "Experimental unsafe"),
+    ([7, 1, 3], "Unstable async"),
+];
+
+/// One Fano-plane conflict triad's contribution to a `CrateProfile::score`
+/// query, as returned by `CrateProfile::conflict_report`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TriadScore {
+    /// The three e-dimension indices (1-7) that make up this triad.
+    pub dims: [usize; 3],
+    /// Human-readable name matching the module doc's conflict-triad list.
+    pub label: &'static str,
+    /// This triad's share of the friction norm: the norm of the query/self
+    /// product's imaginary coefficients restricted to `dims`.
+    pub magnitude: f32,
 }
 
 impl CrateProfile {
@@ -60,18 +93,31 @@ impl CrateProfile {
         // Walk all .rs files
         analyze_directory(source_dir, &mut raw)?;
 
-        // Check for no_std in lib.rs
+        // Check for (conditional) no_std in lib.rs's inner attributes.
         let lib_rs = source_dir.join("src/lib.rs");
         if lib_rs.exists() {
             let content = std::fs::read_to_string(&lib_rs)?;
-            raw.is_no_std = content.contains("#![no_std]");
+            let (unconditional, conditional) = detect_no_std(&content);
+            raw.is_no_std = unconditional;
+            raw.is_conditionally_no_std = conditional;
         }
 
-        // Read Cargo.toml for dependency count
+        // Read Cargo.toml for the direct (runtime-only) dependency count
+        // and wasm32 target/dependency support.
         let cargo_toml = source_dir.join("Cargo.toml");
         if cargo_toml.exists() {
             if let Ok(content) = std::fs::read_to_string(&cargo_toml) {
-                raw.dep_count = count_dependencies(&content);
+                raw.direct_dep_count = count_direct_deps(&content);
+                raw.has_wasm = raw.has_wasm || manifest_targets_wasm(&content);
+            }
+        }
+
+        // Read Cargo.lock, if present, for the resolved transitive closure
+        // size - a much better compile-time proxy than direct deps alone.
+        let cargo_lock = source_dir.join("Cargo.lock");
+        if cargo_lock.exists() {
+            if let Ok(content) = std::fs::read_to_string(&cargo_lock) {
+                raw.transitive_dep_count = count_lockfile_packages(&content);
             }
         }
 
@@ -110,6 +156,354 @@ impl CrateProfile {
         let (similarity, friction) = self.score(query);
         similarity / (1.0 + friction)
     }
+
+    /// Break `score`'s single friction norm down by the seven Fano-plane
+    /// conflict triads documented at the top of this module, so a caller
+    /// can explain *why* a crate conflicts with a query instead of just
+    /// reporting a scalar. Each triad's magnitude is the norm of `query *
+    /// conj(self.octonion)`'s imaginary coefficients restricted to that
+    /// triad's three dimensions.
+    pub fn conflict_report(&self, query: &Octonion) -> [TriadScore; 7] {
+        let product = *query * self.octonion.conj();
+
+        CONFLICT_TRIADS.map(|(dims, label)| {
+            let magnitude = dims
+                .iter()
+                .map(|&i| {
+                    let c = product.coeff(i);
+                    c * c
+                })
+                .sum::<f64>()
+                .sqrt() as f32;
+            TriadScore {
+                dims,
+                label,
+                magnitude,
+            }
+        })
+    }
+
+    /// The single triad contributing the most friction against `query`,
+    /// e.g. for a message like "rejected: high friction from 'async + heap
+    /// in no_std' environment leak" rather than a bare `friction = 0.62`.
+    pub fn dominant_conflict(&self, query: &Octonion) -> TriadScore {
+        self.conflict_report(query)
+            .into_iter()
+            .max_by(|a, b| a.magnitude.total_cmp(&b.magnitude))
+            .expect("CONFLICT_TRIADS is non-empty")
+    }
+
+    /// Like `from_source`, but also fetches `name`'s release history from
+    /// `index_base` (a sparse-index-shaped HTTP host or a supplied local
+    /// index mirror directory) and folds its volatility into e7, which
+    /// `from_source` alone always leaves at 0.
+    pub async fn from_source_with_history(
+        name: &str,
+        version: &str,
+        source_dir: &Path,
+        index_base: &str,
+    ) -> anyhow::Result<Self> {
+        let mut profile = Self::from_source(name, version, source_dir)?;
+
+        let releases = fetch_release_history(name, index_base).await?;
+        profile.raw.entropy = compute_entropy(&releases);
+        profile.octonion = profile.raw.to_octonion();
+
+        Ok(profile)
+    }
+}
+
+/// One release of a crate, as needed to compute `compute_entropy`.
+///
+/// Real crates.io sparse index entries don't carry a publish timestamp, so
+/// `published_at` is only meaningful when `releases` came from a supplied
+/// local index mirror that records one (e.g. derived from the crates.io
+/// db-dump, like `octo_sleeper`'s `VersionMeta::created_at`). Entries
+/// without one still contribute to `breaking_rate`, just not to cadence
+/// churn.
+#[derive(Debug, Clone)]
+pub struct ReleaseInfo {
+    pub version: String,
+    pub published_at: Option<i64>,
+}
+
+/// Compute a 0..1 volatility score for e7 from a crate's release history.
+///
+/// `breaking_rate` is the fraction of chronological version transitions
+/// that are breaking per Cargo's semver rules: a major bump once the crate
+/// has reached 1.0, or *any* minor bump while still pre-1.0 (since Cargo
+/// treats `0.x -> 0.(x+1)` as breaking). `cadence_churn` is the coefficient
+/// of variation (std-dev / mean) of the gaps between releases that have a
+/// timestamp, as a proxy for how erratically a crate ships. A crate with a
+/// single release, or with no unyanked releases at all, gets entropy 0 -
+/// there's no history to be volatile about yet.
+fn compute_entropy(releases: &[ReleaseInfo]) -> f32 {
+    let mut versions: Vec<(semver::Version, &ReleaseInfo)> = releases
+        .iter()
+        .filter_map(|r| {
+            let v = r.version.strip_prefix('v').unwrap_or(&r.version);
+            semver::Version::parse(v).ok().map(|v| (v, r))
+        })
+        .collect();
+    versions.sort_by(|a, b| a.0.cmp(&b.0));
+
+    if versions.len() < 2 {
+        return 0.0;
+    }
+
+    let mut breaking = 0u32;
+    for pair in versions.windows(2) {
+        let (prev, _) = &pair[0];
+        let (next, _) = &pair[1];
+        let is_breaking = if prev.major > 0 {
+            next.major > prev.major
+        } else {
+            // Pre-1.0: Cargo treats a minor bump (0.x -> 0.(x+1)) as
+            // breaking, same as a major bump once stable.
+            next.major > prev.major || next.minor > prev.minor
+        };
+        if is_breaking {
+            breaking += 1;
+        }
+    }
+    let transitions = (versions.len() - 1) as f32;
+    let breaking_rate = breaking as f32 / transitions;
+
+    let gaps: Vec<i64> = versions
+        .windows(2)
+        .filter_map(|pair| {
+            let a = pair[0].1.published_at?;
+            let b = pair[1].1.published_at?;
+            Some((b - a).max(0))
+        })
+        .collect();
+    let cadence_churn = if gaps.len() >= 2 {
+        let mean = gaps.iter().sum::<i64>() as f64 / gaps.len() as f64;
+        if mean > 0.0 {
+            let variance = gaps
+                .iter()
+                .map(|g| {
+                    let d = *g as f64 - mean;
+                    d * d
+                })
+                .sum::<f64>()
+                / gaps.len() as f64;
+            ((variance.sqrt() / mean) as f32).min(1.0)
+        } else {
+            0.0
+        }
+    } else {
+        0.0
+    };
+
+    (0.6 * breaking_rate + 0.4 * cadence_churn).clamp(0.0, 1.0)
+}
+
+/// Build the path a crates.io-shaped sparse index serves a crate's release
+/// metadata at, per Cargo's own layout rules: https://doc.rust-lang.org/cargo/reference/registry-index.html#index-files
+fn sparse_index_path(name: &str) -> String {
+    let lower = name.to_lowercase();
+    match lower.len() {
+        1 => format!("1/{lower}"),
+        2 => format!("2/{lower}"),
+        3 => format!("3/{}/{lower}", &lower[..1]),
+        _ => format!("{}/{}/{lower}", &lower[..2], &lower[2..4]),
+    }
+}
+
+/// NDJSON line shape served by a crates.io-shaped sparse index. `vers` is
+/// the only field every real index guarantees; `published_at` (Unix
+/// seconds) is specific to the local-mirror variant described on
+/// `ReleaseInfo`.
+#[derive(Debug, Deserialize)]
+struct SparseIndexLine {
+    vers: String,
+    #[serde(default)]
+    yanked: bool,
+    published_at: Option<i64>,
+}
+
+/// Fetch `name`'s release history from `index_base`, which is treated as an
+/// HTTP(S) sparse-index host if it starts with `http://`/`https://`, or
+/// otherwise as a local index mirror directory laid out the same way.
+/// Yanked releases are excluded, since a yanked version was never a real
+/// upgrade target and shouldn't count toward volatility.
+async fn fetch_release_history(name: &str, index_base: &str) -> anyhow::Result<Vec<ReleaseInfo>> {
+    let rel_path = sparse_index_path(name);
+
+    let body = if index_base.starts_with("http://") || index_base.starts_with("https://") {
+        let url = format!("{}/{rel_path}", index_base.trim_end_matches('/'));
+        reqwest::get(url).await?.error_for_status()?.text().await?
+    } else {
+        let path = Path::new(index_base).join(&rel_path);
+        std::fs::read_to_string(path)?
+    };
+
+    Ok(body
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str::<SparseIndexLine>(line).ok())
+        .filter(|entry| !entry.yanked)
+        .map(|entry| ReleaseInfo {
+            version: entry.vers,
+            published_at: entry.published_at,
+        })
+        .collect())
+}
+
+/// On-disk cache of `CrateProfile`s keyed by a BLAKE3 hash of their source
+/// tree, so re-analyzing an unchanged crate is a file read instead of a
+/// full `syn` walk. `Octonion` has no serde support (same problem
+/// `octo_index::OctonionProfile` solves for the index), so cache entries
+/// store `CachedProfile` - a plain-data shadow of `CrateProfile` with the
+/// octonion reduced to its raw `[f64; 8]` coefficients - and are
+/// reconstructed into a real `CrateProfile` on read.
+pub struct ProfileCache {
+    cache_dir: PathBuf,
+}
+
+impl ProfileCache {
+    pub fn new(cache_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            cache_dir: cache_dir.into(),
+        }
+    }
+
+    /// Return the cached profile for `name`/`version` if `source_dir`'s
+    /// tree hash still matches what was cached, otherwise analyze
+    /// `source_dir` from scratch and write the result back to the cache.
+    pub fn get_or_compute(
+        &self,
+        name: &str,
+        version: &str,
+        source_dir: &Path,
+    ) -> anyhow::Result<CrateProfile> {
+        let tree_hash = hash_source_tree(source_dir)?.to_hex().to_string();
+
+        if let Some(entry) = self.read_cached(name, version) {
+            if entry.tree_hash == tree_hash {
+                return Ok(entry.profile.into_crate_profile());
+            }
+        }
+
+        let profile = CrateProfile::from_source(name, version, source_dir)?;
+        self.write_cached(
+            name,
+            version,
+            &CachedEntry {
+                tree_hash,
+                profile: CachedProfile::from_crate_profile(&profile),
+            },
+        );
+        Ok(profile)
+    }
+
+    fn cache_path(&self, name: &str, version: &str) -> PathBuf {
+        self.cache_dir.join(format!("{name}-{version}.json"))
+    }
+
+    fn read_cached(&self, name: &str, version: &str) -> Option<CachedEntry> {
+        let content = std::fs::read(self.cache_path(name, version)).ok()?;
+        serde_json::from_slice(&content).ok()
+    }
+
+    fn write_cached(&self, name: &str, version: &str, entry: &CachedEntry) {
+        // Caching is a speed optimization, not a correctness requirement -
+        // if the cache dir can't be created or written to, just skip it and
+        // let the next lookup re-analyze from source.
+        if std::fs::create_dir_all(&self.cache_dir).is_err() {
+            return;
+        }
+        if let Ok(json) = serde_json::to_vec(entry) {
+            let _ = std::fs::write(self.cache_path(name, version), json);
+        }
+    }
+}
+
+/// Hash the contents of a crate's source tree (`.rs` files plus
+/// `Cargo.toml` and `Cargo.lock` - exactly what `CrateProfile::from_source`
+/// reads) into a
+/// single BLAKE3 digest, so the result changes whenever anything that
+/// would affect the analysis changes. Paths are hashed in sorted order
+/// alongside their contents so the result depends only on tree contents,
+/// not directory-listing order.
+fn hash_source_tree(dir: &Path) -> anyhow::Result<blake3::Hash> {
+    let mut files = collect_source_files(dir)?;
+    files.sort();
+
+    let mut hasher = blake3::Hasher::new();
+    for path in &files {
+        let relative = path.strip_prefix(dir).unwrap_or(path);
+        hasher.update(relative.to_string_lossy().as_bytes());
+        hasher.update(b"\0");
+
+        let mut file = std::fs::File::open(path)?;
+        hasher.update_reader(&mut file)?;
+    }
+
+    Ok(hasher.finalize())
+}
+
+/// Recursively collect the `.rs` files, `Cargo.toml` and `Cargo.lock` under
+/// `dir`, i.e. every file `CrateProfile::from_source` actually consumes.
+fn collect_source_files(dir: &Path) -> anyhow::Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    if !dir.exists() {
+        return Ok(files);
+    }
+
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            files.extend(collect_source_files(&path)?);
+        } else if path.extension().map(|e| e == "rs").unwrap_or(false)
+            || path
+                .file_name()
+                .map(|n| n == "Cargo.toml" || n == "Cargo.lock")
+                .unwrap_or(false)
+        {
+            files.push(path);
+        }
+    }
+
+    Ok(files)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedEntry {
+    tree_hash: String,
+    profile: CachedProfile,
+}
+
+/// Serde-friendly shadow of `CrateProfile` - see `ProfileCache` docs for
+/// why the octonion is stored as raw coefficients instead.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedProfile {
+    name: String,
+    version: String,
+    coeffs: [f64; 8],
+    raw: RawProfile,
+}
+
+impl CachedProfile {
+    fn from_crate_profile(profile: &CrateProfile) -> Self {
+        Self {
+            name: profile.name.clone(),
+            version: profile.version.clone(),
+            coeffs: octonion_coeffs(&profile.octonion),
+            raw: profile.raw.clone(),
+        }
+    }
+
+    fn into_crate_profile(self) -> CrateProfile {
+        let [e0, e1, e2, e3, e4, e5, e6, e7] = self.coeffs;
+        CrateProfile {
+            name: self.name,
+            version: self.version,
+            octonion: Octonion::new(e0, e1, e2, e3, e4, e5, e6, e7),
+            raw: self.raw,
+        }
+    }
 }
 
 impl RawProfile {
@@ -125,10 +519,15 @@ impl RawProfile {
             0.0
         };
 
-        // e2: safety - unsafe density per 1000 LoC
+        // e2: safety - unsafe density per 1000 LoC. `unsafe fn`/`unsafe impl`/
+        // `unsafe trait` weigh more than a bare block: they taint an entire
+        // public API surface rather than one internal implementation detail.
         let e2 = if self.total_loc > 0 {
-            let density = (self.unsafe_blocks as f64 / self.total_loc as f64) * 1000.0;
-            (density / 50.0).min(1.0) // 50 unsafe per 1000 LoC = max
+            let weighted_unsafe = self.unsafe_blocks as f64
+                + self.unsafe_fns as f64 * 2.0
+                + self.unsafe_impls as f64 * 3.0;
+            let density = (weighted_unsafe / self.total_loc as f64) * 1000.0;
+            (density / 50.0).min(1.0) // 50 weighted-unsafe per 1000 LoC = max
         } else {
             0.0
         };
@@ -143,19 +542,29 @@ impl RawProfile {
         // e4: memory/heap usage (normalized)
         let e4 = (self.heap_types as f64 / 100.0).min(1.0);
 
-        // e5: friction - dependency count
-        let e5 = (self.dep_count as f64 / 50.0).min(1.0); // 50 deps = max friction
+        // e5: friction - transitive fan-out is a better compile-time proxy
+        // than direct deps alone, so it dominates the blend.
+        let direct = (self.direct_dep_count as f64 / 50.0).min(1.0); // 50 direct = max
+        let transitive = (self.transitive_dep_count as f64 / 200.0).min(1.0); // 200 resolved = max
+        let e5 = (0.3 * direct + 0.7 * transitive).min(1.0);
 
-        // e6: environment (no_std/wasm)
-        let e6 = match (self.is_no_std, self.has_wasm) {
-            (true, true) => 1.0,
-            (true, false) => 0.7,
-            (false, true) => 0.5,
-            (false, false) => 0.0,
+        // e6: environment - unconditional no_std counts for more than
+        // conditional (feature-gated) no_std, since the crate always works
+        // without std rather than only under a non-default feature.
+        let no_std_score: f64 = if self.is_no_std {
+            1.0
+        } else if self.is_conditionally_no_std {
+            0.5
+        } else {
+            0.0
         };
+        let wasm_score: f64 = if self.has_wasm { 1.0 } else { 0.0 };
+        let e6 = (0.7 * no_std_score + 0.3 * wasm_score).min(1.0);
 
-        // e7: entropy (placeholder - would need version history)
-        let e7 = 0.0;
+        // e7: entropy - volatility score from version history, computed by
+        // `compute_entropy` and populated via `from_source_with_history`.
+        // Stays 0 for plain `from_source`, which has no release list to go on.
+        let e7 = self.entropy.clamp(0.0, 1.0) as f64;
 
         Octonion::new(e0, e1, e2, e3, e4, e5, e6, e7)
     }
@@ -195,22 +604,93 @@ fn analyze_file(path: &Path, raw: &mut RawProfile) -> anyhow::Result<()> {
     visitor.visit_file(&syntax);
 
     raw.unsafe_blocks += visitor.unsafe_blocks;
+    raw.unsafe_fns += visitor.unsafe_fns;
+    raw.unsafe_impls += visitor.unsafe_impls;
     raw.async_fns += visitor.async_fns;
     raw.total_fns += visitor.total_fns;
     raw.send_sync_count += visitor.send_sync_impls;
     raw.heap_types += visitor.heap_types;
+    raw.has_wasm = raw.has_wasm || visitor.has_wasm_cfg;
 
     Ok(())
 }
 
+/// Default heap/collection type names counted toward e4 (memory): the full
+/// `alloc`/`std::collections` surface, not just the handful originally
+/// tracked. Callers that want a narrower or project-specific set can build
+/// a `ProfileVisitor` with `ProfileVisitor::with_heap_types` instead.
+const DEFAULT_HEAP_TYPES: &[&str] = &[
+    "Box",
+    "Vec",
+    "String",
+    "Rc",
+    "Arc",
+    "Weak",
+    "Cow",
+    "HashMap",
+    "BTreeMap",
+    "HashSet",
+    "BTreeSet",
+    "VecDeque",
+    "BinaryHeap",
+    "LinkedList",
+];
+
 /// AST visitor to extract profile metrics.
-#[derive(Default)]
 struct ProfileVisitor {
     unsafe_blocks: u32,
+    unsafe_fns: u32,
+    unsafe_impls: u32,
     async_fns: u32,
     total_fns: u32,
     send_sync_impls: u32,
     heap_types: u32,
+    /// Type names (e.g. `Box`, `Vec`) that count toward `heap_types`.
+    /// Defaults to `DEFAULT_HEAP_TYPES`; see `with_heap_types`.
+    heap_type_names: &'static [&'static str],
+    /// Set when any item carries `#[cfg(target_arch = "wasm32")]` (or a
+    /// `cfg` predicate mentioning it, e.g. `any(target_arch = "wasm32", ...)`).
+    has_wasm_cfg: bool,
+}
+
+impl Default for ProfileVisitor {
+    fn default() -> Self {
+        Self::with_heap_types(DEFAULT_HEAP_TYPES)
+    }
+}
+
+impl ProfileVisitor {
+    /// Build a visitor that counts `names` toward `heap_types` instead of
+    /// `DEFAULT_HEAP_TYPES`.
+    fn with_heap_types(names: &'static [&'static str]) -> Self {
+        Self {
+            unsafe_blocks: 0,
+            unsafe_fns: 0,
+            unsafe_impls: 0,
+            async_fns: 0,
+            total_fns: 0,
+            send_sync_impls: 0,
+            heap_types: 0,
+            heap_type_names: names,
+            has_wasm_cfg: false,
+        }
+    }
+}
+
+/// True for expressions that allocate/leak directly against the global
+/// allocator rather than going through a tracked heap type's constructor,
+/// e.g. `Box::leak(b)` or `std::alloc::alloc(layout)`.
+fn is_direct_allocator_call(segments: &[String]) -> bool {
+    match segments {
+        [.., receiver, method] => {
+            (receiver == "Box" && method == "leak")
+                || matches!(
+                    method.as_str(),
+                    "alloc" | "alloc_zeroed" | "dealloc" | "realloc"
+                )
+        }
+        _ => false,
+    }
 }
 
 impl<'ast> Visit<'ast> for ProfileVisitor {
@@ -219,6 +699,9 @@ impl<'ast> Visit<'ast> for ProfileVisitor {
         if node.sig.asyncness.is_some() {
             self.async_fns += 1;
         }
+        if node.sig.unsafety.is_some() {
+            self.unsafe_fns += 1;
+        }
         syn::visit::visit_item_fn(self, node);
     }
 
@@ -227,6 +710,9 @@ impl<'ast> Visit<'ast> for ProfileVisitor {
         if node.sig.asyncness.is_some() {
             self.async_fns += 1;
         }
+        if node.sig.unsafety.is_some() {
+            self.unsafe_fns += 1;
+        }
         syn::visit::visit_impl_item_fn(self, node);
     }
 
@@ -236,6 +722,9 @@ impl<'ast> Visit<'ast> for ProfileVisitor {
     }
 
     fn visit_item_impl(&mut self, node: &'ast syn::ItemImpl) {
+        if node.unsafety.is_some() {
+            self.unsafe_impls += 1;
+        }
         // Check if implementing Send or Sync
         if let Some((_, trait_path, _)) = &node.trait_ {
             if let Some(last) = trait_path.segments.last() {
@@ -248,51 +737,140 @@ impl<'ast> Visit<'ast> for ProfileVisitor {
         syn::visit::visit_item_impl(self, node);
     }
 
+    fn visit_item_trait(&mut self, node: &'ast syn::ItemTrait) {
+        if node.unsafety.is_some() {
+            self.unsafe_impls += 1;
+        }
+        syn::visit::visit_item_trait(self, node);
+    }
+
     fn visit_type_path(&mut self, node: &'ast syn::TypePath) {
         // Check for heap-allocating types
         if let Some(last) = node.path.segments.last() {
             let name = last.ident.to_string();
-            if matches!(
-                name.as_str(),
-                "Box" | "Vec" | "String" | "Rc" | "Arc" | "HashMap" | "BTreeMap"
-            ) {
+            if self.heap_type_names.contains(&name.as_str()) {
                 self.heap_types += 1;
             }
         }
         syn::visit::visit_type_path(self, node);
     }
+
+    fn visit_expr_call(&mut self, node: &'ast syn::ExprCall) {
+        if let syn::Expr::Path(expr_path) = &*node.func {
+            let segments: Vec<String> = expr_path
+                .path
+                .segments
+                .iter()
+                .map(|segment| segment.ident.to_string())
+                .collect();
+            if is_direct_allocator_call(&segments) {
+                self.heap_types += 1;
+            }
+        }
+        syn::visit::visit_expr_call(self, node);
+    }
+
+    fn visit_attribute(&mut self, node: &'ast syn::Attribute) {
+        if node.path().is_ident("cfg") {
+            if let syn::Meta::List(list) = &node.meta {
+                let predicate = list.tokens.to_string().replace(' ', "");
+                if predicate.contains("target_arch=\"wasm32\"") {
+                    self.has_wasm_cfg = true;
+                }
+            }
+        }
+        syn::visit::visit_attribute(self, node);
+    }
 }
 
-/// Count dependencies from Cargo.toml content (simple heuristic).
-fn count_dependencies(cargo_toml: &str) -> u32 {
-    let mut count = 0;
-    let mut in_deps = false;
+/// Shape of a `Cargo.toml` manifest, just enough to pull out the runtime
+/// `[dependencies]` table and any `[target.*]` sections.
+/// `dev-dependencies`/`build-dependencies` are deliberately left undeclared
+/// (and so silently ignored by serde) rather than parsed and discarded,
+/// since they never ship to consumers and shouldn't count toward a crate's
+/// friction score.
+#[derive(Debug, Deserialize)]
+struct ManifestToml {
+    dependencies: Option<toml::Table>,
+    target: Option<toml::Table>,
+}
 
-    for line in cargo_toml.lines() {
-        let trimmed = line.trim();
+/// Count direct runtime dependencies from Cargo.toml content. Falls back to
+/// 0 on a malformed or absent manifest rather than failing the whole
+/// analysis.
+fn count_direct_deps(cargo_toml: &str) -> u32 {
+    toml::from_str::<ManifestToml>(cargo_toml)
+        .ok()
+        .and_then(|manifest| manifest.dependencies)
+        .map(|deps| deps.len() as u32)
+        .unwrap_or(0)
+}
 
-        if trimmed.starts_with("[dependencies]")
-            || trimmed.starts_with("[dev-dependencies]")
-            || trimmed.starts_with("[build-dependencies]")
-        {
-            in_deps = true;
-            continue;
-        }
+/// Shape of a `Cargo.lock` file, just enough to count resolved packages.
+#[derive(Debug, Deserialize)]
+struct LockToml {
+    package: Option<Vec<toml::Value>>,
+}
 
-        if trimmed.starts_with('[') {
-            in_deps = false;
-            continue;
-        }
+/// Count the size of the resolved transitive dependency closure from a
+/// Cargo.lock's `[[package]]` entries, excluding the crate's own entry.
+/// Falls back to 0 on a malformed lockfile.
+fn count_lockfile_packages(cargo_lock: &str) -> u32 {
+    let total = toml::from_str::<LockToml>(cargo_lock)
+        .ok()
+        .and_then(|lock| lock.package)
+        .map(|packages| packages.len())
+        .unwrap_or(0);
+    total.saturating_sub(1) as u32
+}
+
+/// Detect wasm32 support declared in Cargo.toml: either a
+/// `[target.'cfg(target_arch = "wasm32")'...]` section (tables are keyed on
+/// the raw cfg-predicate string, so this matches on the `wasm32` substring
+/// rather than parsing the predicate) or a `wasm-bindgen`-family dependency.
+fn manifest_targets_wasm(cargo_toml: &str) -> bool {
+    let Ok(manifest) = toml::from_str::<ManifestToml>(cargo_toml) else {
+        return false;
+    };
+
+    let target_matches = manifest
+        .target
+        .as_ref()
+        .is_some_and(|target| target.keys().any(|key| key.contains("wasm32")));
 
-        if in_deps && !trimmed.is_empty() && !trimmed.starts_with('#') {
-            // Check if it's a dependency line (contains = or starts without whitespace)
-            if trimmed.contains('=') || !trimmed.starts_with(char::is_whitespace) {
-                count += 1;
+    let dep_matches = manifest
+        .dependencies
+        .as_ref()
+        .is_some_and(|deps| deps.keys().any(|name| name.contains("wasm")));
+
+    target_matches || dep_matches
+}
+
+/// Detect `no_std` in a lib.rs's inner attributes via `syn`, distinguishing
+/// an unconditional `#![no_std]` from a conditional
+/// `#![cfg_attr(not(feature = "std"), no_std)]`. Returns
+/// `(unconditional, conditional)`; a malformed file reports neither.
+fn detect_no_std(content: &str) -> (bool, bool) {
+    let Ok(syntax) = syn::parse_file(content) else {
+        return (false, false);
+    };
+
+    let mut unconditional = false;
+    let mut conditional = false;
+
+    for attr in &syntax.attrs {
+        if attr.path().is_ident("no_std") {
+            unconditional = true;
+        } else if attr.path().is_ident("cfg_attr") {
+            if let syn::Meta::List(list) = &attr.meta {
+                if list.tokens.to_string().replace(' ', "").contains("no_std") {
+                    conditional = true;
+                }
             }
         }
     }
 
-    count
+    (unconditional, conditional)
 }
 
 /// Build a query octonion from semantic requirements.
@@ -400,4 +978,375 @@ mod tests {
         // Should have higher friction due to unsafe+async combination
         assert!(friction > 0.3, "Should detect friction from unsafe+async");
     }
+
+    #[test]
+    fn test_conflict_report_covers_all_seven_triads_and_sums_to_friction() {
+        let risky = Octonion::new(0.7, 0.0, 0.9, 0.9, 0.5, 0.3, 0.0, 0.5);
+        let profile = CrateProfile {
+            name: "risky".into(),
+            version: "1.0.0".into(),
+            octonion: risky,
+            raw: RawProfile::default(),
+        };
+
+        let query = query_octonion(true, true, false, true, false);
+        let report = profile.conflict_report(&query);
+        assert_eq!(report.len(), 7);
+        assert!(report.iter().all(|t| t.magnitude >= 0.0));
+
+        // Every dimension e1..e7 appears in exactly 3 of the 7 triads, so
+        // summing squared magnitudes across all triads triple-counts the
+        // friction norm's own squared magnitude.
+        let (_, friction) = profile.score(&query);
+        let triad_sq_sum: f32 = report.iter().map(|t| t.magnitude * t.magnitude).sum();
+        assert!((triad_sq_sum - 3.0 * friction * friction).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_dominant_conflict_picks_max_magnitude_triad() {
+        let risky = Octonion::new(0.7, 0.0, 0.9, 0.9, 0.5, 0.3, 0.0, 0.5);
+        let profile = CrateProfile {
+            name: "risky".into(),
+            version: "1.0.0".into(),
+            octonion: risky,
+            raw: RawProfile::default(),
+        };
+
+        let query = query_octonion(true, true, false, true, false);
+        let dominant = profile.dominant_conflict(&query);
+        let report = profile.conflict_report(&query);
+        assert!(report.iter().all(|t| t.magnitude <= dominant.magnitude));
+    }
+
+    #[test]
+    fn test_count_direct_deps_excludes_dev_and_build() {
+        let manifest = r#"
+            [package]
+            name = "fixture"
+
+            [dependencies]
+            serde = "1"
+            anyhow = "1"
+
+            [dev-dependencies]
+            tempfile = "3"
+
+            [build-dependencies]
+            cc = "1"
+        "#;
+
+        assert_eq!(count_direct_deps(manifest), 2);
+    }
+
+    #[test]
+    fn test_count_direct_deps_on_malformed_manifest_is_zero() {
+        assert_eq!(count_direct_deps("not valid toml {{{"), 0);
+    }
+
+    #[test]
+    fn test_count_lockfile_packages_excludes_own_entry() {
+        let lockfile = r#"
+            version = 3
+
+            [[package]]
+            name = "fixture"
+            version = "0.1.0"
+
+            [[package]]
+            name = "serde"
+            version = "1.0.0"
+
+            [[package]]
+            name = "anyhow"
+            version = "1.0.0"
+        "#;
+
+        assert_eq!(count_lockfile_packages(lockfile), 2);
+    }
+
+    fn release(version: &str, published_at: Option<i64>) -> ReleaseInfo {
+        ReleaseInfo {
+            version: version.to_string(),
+            published_at,
+        }
+    }
+
+    #[test]
+    fn test_compute_entropy_single_release_is_zero() {
+        assert_eq!(compute_entropy(&[release("1.0.0", None)]), 0.0);
+    }
+
+    #[test]
+    fn test_compute_entropy_counts_pre_1_0_minor_bumps_as_breaking() {
+        // Every transition is a 0.x minor bump, so breaking_rate = 1.0 and
+        // (with no timestamps, so no cadence signal) entropy = 0.6.
+        let releases = vec![
+            release("0.1.0", None),
+            release("0.2.0", None),
+            release("0.3.0", None),
+        ];
+        assert!((compute_entropy(&releases) - 0.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_compute_entropy_stable_patch_releases_are_not_breaking() {
+        let releases = vec![
+            release("1.0.0", None),
+            release("1.0.1", None),
+            release("1.0.2", None),
+        ];
+        assert_eq!(compute_entropy(&releases), 0.0);
+    }
+
+    #[test]
+    fn test_compute_entropy_blends_in_cadence_churn() {
+        // Stable patch releases (no breaking_rate contribution) with wildly
+        // uneven gaps should still register nonzero entropy from churn.
+        let releases = vec![
+            release("1.0.0", Some(0)),
+            release("1.0.1", Some(10)),
+            release("1.0.2", Some(1_000_000)),
+        ];
+        assert!(compute_entropy(&releases) > 0.0);
+    }
+
+    #[test]
+    fn test_sparse_index_path_layout() {
+        assert_eq!(sparse_index_path("a"), "1/a");
+        assert_eq!(sparse_index_path("ab"), "2/ab");
+        assert_eq!(sparse_index_path("abc"), "3/a/abc");
+        assert_eq!(sparse_index_path("serde"), "se/rd/serde");
+    }
+
+    #[test]
+    fn test_detect_no_std_unconditional() {
+        let (unconditional, conditional) = detect_no_std("#![no_std]\n\nfn f() {}\n");
+        assert!(unconditional);
+        assert!(!conditional);
+    }
+
+    #[test]
+    fn test_detect_no_std_conditional_cfg_attr() {
+        let (unconditional, conditional) =
+            detect_no_std("#![cfg_attr(not(feature = \"std\"), no_std)]\n\nfn f() {}\n");
+        assert!(!unconditional);
+        assert!(conditional);
+    }
+
+    #[test]
+    fn test_detect_no_std_absent() {
+        let (unconditional, conditional) = detect_no_std("fn f() {}\n");
+        assert!(!unconditional);
+        assert!(!conditional);
+    }
+
+    #[test]
+    fn test_manifest_targets_wasm_via_target_section() {
+        let manifest = r#"
+            [package]
+            name = "fixture"
+
+            [target.'cfg(target_arch = "wasm32")'.dependencies]
+            wasm-bindgen = "0.2"
+        "#;
+        assert!(manifest_targets_wasm(manifest));
+    }
+
+    #[test]
+    fn test_manifest_targets_wasm_via_direct_dependency() {
+        let manifest = r#"
+            [dependencies]
+            wasm-bindgen = "0.2"
+        "#;
+        assert!(manifest_targets_wasm(manifest));
+    }
+
+    #[test]
+    fn test_manifest_targets_wasm_absent() {
+        let manifest = r#"
+            [dependencies]
+            serde = "1"
+        "#;
+        assert!(!manifest_targets_wasm(manifest));
+    }
+
+    fn write_fixture_crate(dir: &Path, lib_body: &str) {
+        std::fs::create_dir_all(dir.join("src")).unwrap();
+        std::fs::write(dir.join("Cargo.toml"), "[package]\nname = \"fixture\"\n").unwrap();
+        std::fs::write(dir.join("src/lib.rs"), lib_body).unwrap();
+    }
+
+    #[test]
+    fn test_from_source_detects_wasm_cfg_in_source() {
+        let dir =
+            std::env::temp_dir().join(format!("fastdeps_wasm_cfg_source_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        write_fixture_crate(
+            &dir,
+            "#[cfg(target_arch = \"wasm32\")]\npub fn only_on_wasm() {}\n",
+        );
+
+        let profile = CrateProfile::from_source("fixture", "0.1.0", &dir).unwrap();
+        assert!(profile.raw.has_wasm);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_hash_source_tree_is_deterministic_and_content_sensitive() {
+        let dir =
+            std::env::temp_dir().join(format!("fastdeps_hash_source_tree_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        write_fixture_crate(&dir, "pub fn a() {}\n");
+
+        let first = hash_source_tree(&dir).unwrap();
+        let second = hash_source_tree(&dir).unwrap();
+        assert_eq!(first, second, "hash should be stable across repeat calls");
+
+        std::fs::write(dir.join("src/lib.rs"), "pub fn a() {}\npub fn b() {}\n").unwrap();
+        let changed = hash_source_tree(&dir).unwrap();
+        assert_ne!(
+            first, changed,
+            "hash should change when file contents change"
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_profile_cache_round_trips_and_invalidates_on_change() {
+        let source_dir =
+            std::env::temp_dir().join(format!("fastdeps_profile_cache_src_{}", std::process::id()));
+        let cache_dir =
+            std::env::temp_dir().join(format!("fastdeps_profile_cache_dir_{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&source_dir);
+        let _ = std::fs::remove_dir_all(&cache_dir);
+        write_fixture_crate(&source_dir, "pub async fn a() {}\n");
+
+        let cache = ProfileCache::new(&cache_dir);
+        let first = cache
+            .get_or_compute("fixture", "0.1.0", &source_dir)
+            .unwrap();
+        assert_eq!(first.raw.async_fns, 1);
+
+        // Second call should hit the cache and return an equivalent profile.
+        let cached = cache
+            .get_or_compute("fixture", "0.1.0", &source_dir)
+            .unwrap();
+        assert_eq!(cached.raw.async_fns, first.raw.async_fns);
+        assert_eq!(
+            octonion_coeffs(&cached.octonion),
+            octonion_coeffs(&first.octonion)
+        );
+
+        // Changing the source tree should invalidate the cache.
+        std::fs::write(
+            source_dir.join("src/lib.rs"),
+            "pub async fn a() {}\npub async fn b() {}\n",
+        )
+        .unwrap();
+        let recomputed = cache
+            .get_or_compute("fixture", "0.1.0", &source_dir)
+            .unwrap();
+        assert_eq!(recomputed.raw.async_fns, 2);
+
+        std::fs::remove_dir_all(&source_dir).unwrap();
+        std::fs::remove_dir_all(&cache_dir).unwrap();
+    }
+
+    fn visit_source(src: &str) -> ProfileVisitor {
+        let syntax = syn::parse_file(src).unwrap();
+        let mut visitor = ProfileVisitor::default();
+        visitor.visit_file(&syntax);
+        visitor
+    }
+
+    #[test]
+    fn test_visitor_counts_unsafe_fn_and_impl_and_trait() {
+        let visitor = visit_source(
+            r#"
+            unsafe fn raw() {}
+            struct S;
+            unsafe impl Send for S {}
+            unsafe trait Marker {}
+            fn safe() {
+                unsafe {}
+            }
+            "#,
+        );
+        assert_eq!(visitor.unsafe_fns, 1);
+        assert_eq!(
+            visitor.unsafe_impls, 2,
+            "one unsafe impl + one unsafe trait"
+        );
+        assert_eq!(visitor.unsafe_blocks, 1);
+    }
+
+    #[test]
+    fn test_visitor_counts_broadened_heap_types() {
+        let visitor = visit_source(
+            r#"
+            use std::collections::{VecDeque, HashSet, BTreeSet};
+            use std::borrow::Cow;
+            struct Pools {
+                a: VecDeque<u8>,
+                b: HashSet<u8>,
+                c: BTreeSet<u8>,
+                d: Cow<'static, str>,
+            }
+            "#,
+        );
+        assert_eq!(visitor.heap_types, 4);
+    }
+
+    #[test]
+    fn test_visitor_counts_direct_allocator_calls() {
+        let visitor = visit_source(
+            r#"
+            fn leak_it(b: Box<u8>) {
+                Box::leak(b);
+            }
+            unsafe fn raw_alloc(layout: std::alloc::Layout) {
+                std::alloc::alloc(layout);
+            }
+            "#,
+        );
+        // `Box` (the parameter type) + `Box::leak` + `alloc` = 3.
+        assert_eq!(visitor.heap_types, 3);
+    }
+
+    #[test]
+    fn test_with_heap_types_overrides_default_set() {
+        let syntax =
+            syn::parse_file("struct S { v: Vec<u8>, c: std::borrow::Cow<'static, str> }").unwrap();
+        let mut visitor = ProfileVisitor::with_heap_types(&["Cow"]);
+        visitor.visit_file(&syntax);
+        assert_eq!(visitor.heap_types, 1, "Vec is excluded by the custom set");
+    }
+
+    #[test]
+    fn test_unsafe_fn_and_impl_weigh_more_than_bare_block_in_e2() {
+        let mut bare_block = RawProfile {
+            total_loc: 1000,
+            unsafe_blocks: 1,
+            ..Default::default()
+        };
+        let mut unsafe_fn = RawProfile {
+            total_loc: 1000,
+            unsafe_fns: 1,
+            ..Default::default()
+        };
+        let mut unsafe_impl = RawProfile {
+            total_loc: 1000,
+            unsafe_impls: 1,
+            ..Default::default()
+        };
+        bare_block.utility = 0.0;
+        unsafe_fn.utility = 0.0;
+        unsafe_impl.utility = 0.0;
+
+        let e2_of = |raw: &RawProfile| octonion_coeffs(&raw.to_octonion())[2];
+        assert!(e2_of(&unsafe_fn) > e2_of(&bare_block));
+        assert!(e2_of(&unsafe_impl) > e2_of(&unsafe_fn));
+    }
 }
@@ -1,12 +1,34 @@
 //! Rust project discovery and dependency resolution.
 
+use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
+/// Where a dependency's source code comes from, parsed from Cargo.lock's
+/// `source = "..."` line (or its absence).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DependencyOrigin {
+    /// Pulled from a registry index, e.g. crates.io.
+    Registry { index_url: String },
+    /// Pulled from a git repository at a specific revision.
+    Git { url: String, rev: String },
+    /// A path dependency outside the discovered project.
+    Path,
+    /// The project's own root package - Cargo.lock carries no `source` for
+    /// it, the same as a path dependency, but it isn't one.
+    Local,
+}
+
 /// A resolved dependency from Cargo.lock
 #[derive(Debug, Clone)]
 pub struct Dependency {
     pub name: String,
     pub version: String,
+    pub origin: DependencyOrigin,
+    pub checksum: Option<String>,
+    /// This dependency's normalized SPDX license expression, read from its
+    /// cached manifest's `license`/`license-file` field - `None` if no
+    /// cached manifest was found or neither field was present.
+    pub license: Option<SpdxExpr>,
 }
 
 /// A discovered Rust project
@@ -48,13 +70,22 @@ impl RustProject {
         let toml_content = std::fs::read_to_string(&cargo_toml)
             .map_err(|e| format!("can't read Cargo.toml: {e}"))?;
 
-        let name = parse_package_name(&toml_content).unwrap_or_else(|| "unknown".to_string());
+        let name = match parse_package_name(&toml_content) {
+            Some(name) => name,
+            None if has_section(&toml_content, "[workspace]") => {
+                return Err(
+                    "this Cargo.toml is a workspace root with no [package] table; use Workspace::discover_from instead"
+                        .to_string(),
+                );
+            }
+            None => "unknown".to_string(),
+        };
 
         // Parse Cargo.lock for dependencies
         let deps = if cargo_lock.exists() {
             let lock_content = std::fs::read_to_string(&cargo_lock)
                 .map_err(|e| format!("can't read Cargo.lock: {e}"))?;
-            parse_cargo_lock(&lock_content)
+            parse_cargo_lock(&lock_content, &name)
         } else {
             eprintln!("warning: no Cargo.lock found, run `cargo build` first");
             vec![]
@@ -66,6 +97,528 @@ impl RustProject {
             deps,
         })
     }
+
+    /// Re-resolve this project's lockfile under `-Z minimal-versions`, which
+    /// picks the lowest version satisfying each dependency's range instead
+    /// of the highest. Requires a nightly toolchain -
+    /// `RUSTC_BOOTSTRAP=1` stands in for `+nightly` the same way
+    /// `cache::expand_crate_source` does it for `--pretty=expanded` - and
+    /// runs in a scratch copy of the project so the real Cargo.lock is
+    /// never touched. `None` on any failure (no nightly, no network, cargo
+    /// not on `PATH`, ...), so this degrades gracefully.
+    pub fn resolve_minimal_versions(&self) -> Option<Vec<Dependency>> {
+        let scratch = copy_to_scratch_dir(&self.root)?;
+
+        let output = std::process::Command::new("cargo")
+            .args(["generate-lockfile", "-Z", "minimal-versions"])
+            .env("RUSTC_BOOTSTRAP", "1")
+            .current_dir(&scratch)
+            .output()
+            .ok();
+        let lock_content = output
+            .filter(|output| output.status.success())
+            .and_then(|_| std::fs::read_to_string(scratch.join("Cargo.lock")).ok());
+
+        let _ = std::fs::remove_dir_all(&scratch);
+        Some(parse_cargo_lock(&lock_content?, &self.name))
+    }
+
+    /// Diff `self.deps` (as actually locked) against a
+    /// `resolve_minimal_versions` re-resolution, surfacing every dependency
+    /// whose minimal-versions version differs from what's committed - a
+    /// strong signal of a `^`-range loose enough that nothing has actually
+    /// exercised the lower bound. `None` propagates straight from
+    /// `resolve_minimal_versions` when that resolution isn't available.
+    pub fn resolved_vs_minimal(&self) -> Option<Vec<MinimalVersionDrift>> {
+        let minimal = self.resolve_minimal_versions()?;
+        Some(diff_resolved_vs_minimal(&self.deps, &minimal))
+    }
+
+    /// Fill in `license` on every dependency by locating its cached
+    /// manifest under `$CARGO_HOME/registry/src/*/{name}-{version}/Cargo.toml`
+    /// and parsing its `license`/`license-file` field. Leaves `license` as
+    /// `None` for any dependency whose cache entry isn't found (not yet
+    /// fetched, a path/git dep with no registry cache at all, ...).
+    pub fn load_licenses(&mut self) {
+        for dep in &mut self.deps {
+            dep.license = find_cached_license(&dep.name, &dep.version);
+        }
+    }
+
+    /// Flag every dependency whose license is copyleft while this project's
+    /// own declared license (`root_license`) is purely permissive - the
+    /// common case where licensing friction could force relicensing the
+    /// whole project or isolating the offending dependency. Dependencies
+    /// with no known license, or whose expression is entirely `Unknown`
+    /// ids, are silently skipped rather than guessed at. Call `load_licenses`
+    /// first, or every dependency's `license` will be `None` and nothing
+    /// will be flagged.
+    pub fn license_conflicts(
+        &self,
+        root_license: &SpdxExpr,
+    ) -> Vec<(Dependency, LicenseConflictReason)> {
+        let root_categories = expr_categories(root_license);
+        if root_categories.contains(&LicenseCategory::Copyleft)
+            || !root_categories.contains(&LicenseCategory::Permissive)
+        {
+            return Vec::new();
+        }
+
+        self.deps
+            .iter()
+            .filter_map(|dep| {
+                let license = dep.license.as_ref()?;
+                if expr_categories(license).contains(&LicenseCategory::Copyleft) {
+                    Some((
+                        dep.clone(),
+                        LicenseConflictReason::CopyleftUnderPermissiveRoot {
+                            dependency_license: license.clone(),
+                        },
+                    ))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// A normalized SPDX license expression. Doesn't implement the full SPDX
+/// grammar - no parenthesized sub-expressions - just what a real-world
+/// Cargo.toml `license` field actually contains: a single id, or a handful
+/// of ids joined by `AND`/`OR`, each optionally qualified with a `WITH
+/// <exception>` clause.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpdxExpr {
+    /// A single license id, e.g. `"MIT"`, optionally with a `WITH`
+    /// exception, e.g. `"Apache-2.0 WITH LLVM-exception"`.
+    Id {
+        license: String,
+        exception: Option<String>,
+    },
+    And(Vec<SpdxExpr>),
+    Or(Vec<SpdxExpr>),
+}
+
+/// Parse a `license` field value into an `SpdxExpr`. `OR` has the lowest
+/// precedence, `AND` next, `WITH` binds tightest to a single id - matching
+/// SPDX's own operator precedence, just without parenthesized grouping.
+pub fn parse_spdx_expr(expr: &str) -> SpdxExpr {
+    let or_terms: Vec<&str> = expr.split(" OR ").map(str::trim).collect();
+    if or_terms.len() > 1 {
+        return SpdxExpr::Or(or_terms.into_iter().map(parse_and_expr).collect());
+    }
+    parse_and_expr(expr.trim())
+}
+
+fn parse_and_expr(expr: &str) -> SpdxExpr {
+    let and_terms: Vec<&str> = expr.split(" AND ").map(str::trim).collect();
+    if and_terms.len() > 1 {
+        return SpdxExpr::And(and_terms.into_iter().map(parse_id_expr).collect());
+    }
+    parse_id_expr(expr.trim())
+}
+
+fn parse_id_expr(expr: &str) -> SpdxExpr {
+    match expr.split_once(" WITH ") {
+        Some((license, exception)) => SpdxExpr::Id {
+            license: license.trim().to_string(),
+            exception: Some(exception.trim().to_string()),
+        },
+        None => SpdxExpr::Id {
+            license: expr.trim().to_string(),
+            exception: None,
+        },
+    }
+}
+
+/// Whether a single SPDX license id is copyleft (derivative works must be
+/// released under the same or a compatible license), permissive (no such
+/// requirement), or unrecognized - based on a fixed list of common
+/// identifiers, not a general SPDX license database.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LicenseCategory {
+    Permissive,
+    Copyleft,
+    Unknown,
+}
+
+const COPYLEFT_LICENSE_IDS: &[&str] = &[
+    "GPL-2.0",
+    "GPL-2.0-only",
+    "GPL-2.0-or-later",
+    "GPL-3.0",
+    "GPL-3.0-only",
+    "GPL-3.0-or-later",
+    "AGPL-3.0",
+    "AGPL-3.0-only",
+    "AGPL-3.0-or-later",
+    "LGPL-2.1",
+    "LGPL-2.1-only",
+    "LGPL-2.1-or-later",
+    "LGPL-3.0",
+    "LGPL-3.0-only",
+    "LGPL-3.0-or-later",
+    "MPL-2.0",
+    "EPL-2.0",
+];
+
+const PERMISSIVE_LICENSE_IDS: &[&str] = &[
+    "MIT",
+    "Apache-2.0",
+    "BSD-2-Clause",
+    "BSD-3-Clause",
+    "ISC",
+    "Unlicense",
+    "CC0-1.0",
+    "Zlib",
+    "0BSD",
+];
+
+fn classify_license_id(id: &str) -> LicenseCategory {
+    if COPYLEFT_LICENSE_IDS.contains(&id) {
+        LicenseCategory::Copyleft
+    } else if PERMISSIVE_LICENSE_IDS.contains(&id) {
+        LicenseCategory::Permissive
+    } else {
+        LicenseCategory::Unknown
+    }
+}
+
+/// Every `LicenseCategory` appearing among an expression's leaf ids (an
+/// `Or`/`And` node can mix categories, e.g. `"MIT OR GPL-3.0"`, so this
+/// returns a set rather than a single verdict).
+fn expr_categories(expr: &SpdxExpr) -> HashSet<LicenseCategory> {
+    let mut categories = HashSet::new();
+    collect_categories(expr, &mut categories);
+    categories
+}
+
+fn collect_categories(expr: &SpdxExpr, out: &mut HashSet<LicenseCategory>) {
+    match expr {
+        SpdxExpr::Id { license, .. } => {
+            out.insert(classify_license_id(license));
+        }
+        SpdxExpr::And(terms) | SpdxExpr::Or(terms) => {
+            for term in terms {
+                collect_categories(term, out);
+            }
+        }
+    }
+}
+
+/// Why a dependency was flagged by `RustProject::license_conflicts`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LicenseConflictReason {
+    /// The dependency carries a copyleft license while the root crate
+    /// declares a purely permissive one.
+    CopyleftUnderPermissiveRoot { dependency_license: SpdxExpr },
+}
+
+/// Locate `name`-`version`'s cached manifest under
+/// `$CARGO_HOME/registry/src/*/{name}-{version}/Cargo.toml` (falling back to
+/// `~/.cargo` when `CARGO_HOME` isn't set, the same as `cargo::find_registry_src`)
+/// and parse its `license`/`license-file` field into an `SpdxExpr`. `None` if
+/// `CARGO_HOME` can't be found, no matching cache entry exists, or the
+/// manifest declares neither field.
+fn find_cached_license(name: &str, version: &str) -> Option<SpdxExpr> {
+    let cargo_home = std::env::var("CARGO_HOME")
+        .map(PathBuf::from)
+        .ok()
+        .or_else(|| home::home_dir().map(|home| home.join(".cargo")))?;
+
+    let registry_src = cargo_home.join("registry/src");
+    let crate_dir_name = format!("{name}-{version}");
+
+    let manifest = std::fs::read_dir(&registry_src)
+        .ok()?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path().join(&crate_dir_name).join("Cargo.toml"))
+        .find(|path| path.exists())?;
+
+    let content = std::fs::read_to_string(&manifest).ok()?;
+    parse_license_field(&content)
+}
+
+/// Parse a manifest's `license` (an SPDX expression) or, failing that,
+/// `license-file` (the path to a license file, reported back verbatim as a
+/// single `SpdxExpr::Id` since there's no expression to normalize) field
+/// from its `[package]` table.
+fn parse_license_field(content: &str) -> Option<SpdxExpr> {
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(value) = line.strip_prefix("license") {
+            let value = value.trim_start();
+            if let Some(value) = value.strip_prefix('=') {
+                return Some(parse_spdx_expr(value.trim().trim_matches('"')));
+            }
+        }
+    }
+
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(value) = line.strip_prefix("license-file") {
+            let value = value.trim_start();
+            if let Some(value) = value.strip_prefix('=') {
+                let path = value.trim().trim_matches('"');
+                return Some(SpdxExpr::Id {
+                    license: path.to_string(),
+                    exception: None,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+/// A dependency whose minimal-versions resolution differs from what's
+/// actually locked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MinimalVersionDrift {
+    pub name: String,
+    pub resolved_version: String,
+    pub minimal_version: String,
+}
+
+/// Compare a normal resolution against a minimal-versions one, returning one
+/// `MinimalVersionDrift` per name present in both whose version differs. A
+/// dependency only `resolved` (not found under minimal-versions, e.g. a
+/// target-specific dep minimal-versions didn't pull in) is left out rather
+/// than reported as drift.
+fn diff_resolved_vs_minimal(
+    resolved: &[Dependency],
+    minimal: &[Dependency],
+) -> Vec<MinimalVersionDrift> {
+    let minimal_by_name: HashMap<&str, &str> = minimal
+        .iter()
+        .map(|dep| (dep.name.as_str(), dep.version.as_str()))
+        .collect();
+
+    resolved
+        .iter()
+        .filter_map(|dep| {
+            let minimal_version = *minimal_by_name.get(dep.name.as_str())?;
+            if minimal_version == dep.version {
+                return None;
+            }
+            Some(MinimalVersionDrift {
+                name: dep.name.clone(),
+                resolved_version: dep.version.clone(),
+                minimal_version: minimal_version.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Copy `root`'s project tree (skipping `target/` and `.git/`, which cargo
+/// doesn't need to re-resolve a lockfile and could be large) into a fresh
+/// temp directory, so `cargo generate-lockfile` has a manifest and source
+/// tree to run against without touching the real one. `None` on any I/O
+/// failure.
+fn copy_to_scratch_dir(root: &Path) -> Option<PathBuf> {
+    let scratch = std::env::temp_dir().join(format!(
+        "fastdeps_minimal_versions_{}_{}",
+        std::process::id(),
+        root.file_name()?.to_string_lossy()
+    ));
+    copy_dir_recursive(root, &scratch).ok()?;
+    Some(scratch)
+}
+
+fn copy_dir_recursive(src: &Path, dst: &Path) -> std::io::Result<()> {
+    std::fs::create_dir_all(dst)?;
+
+    for entry in std::fs::read_dir(src)? {
+        let entry = entry?;
+        let name = entry.file_name();
+        if name == "target" || name == ".git" {
+            continue;
+        }
+
+        let src_path = entry.path();
+        let dst_path = dst.join(&name);
+        if entry.file_type()?.is_dir() {
+            copy_dir_recursive(&src_path, &dst_path)?;
+        } else {
+            std::fs::copy(&src_path, &dst_path)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// A Cargo workspace: every member crate named (directly or via a glob)
+/// under the root manifest's `[workspace]` table, minus anything matched by
+/// `exclude`, all resolved against the single Cargo.lock the workspace
+/// shares - this module has no notion of which locked packages belong to
+/// which member, so every member's `deps` is the same full, shared list.
+#[derive(Debug)]
+pub struct Workspace {
+    pub root: PathBuf,
+    pub members: Vec<RustProject>,
+}
+
+impl Workspace {
+    /// Discover a workspace from the current directory (or parent dirs)
+    pub fn discover() -> Result<Self, String> {
+        let cwd = std::env::current_dir().map_err(|e| format!("can't get cwd: {e}"))?;
+        Self::discover_from(&cwd)
+    }
+
+    /// Discover a workspace starting from a given path. Walks upward for a
+    /// Cargo.toml the same way `RustProject::discover_from` does, but only
+    /// succeeds if that manifest has a `[workspace]` table - a plain
+    /// single-crate manifest should go through `RustProject` instead.
+    pub fn discover_from(start: &Path) -> Result<Self, String> {
+        let mut dir = start.to_path_buf();
+
+        loop {
+            let cargo_toml = dir.join("Cargo.toml");
+            if cargo_toml.exists() {
+                return Self::load(&dir);
+            }
+
+            if !dir.pop() {
+                return Err("not a Rust project (no Cargo.toml found)".to_string());
+            }
+        }
+    }
+
+    fn load(root: &Path) -> Result<Self, String> {
+        let cargo_toml = root.join("Cargo.toml");
+        let toml_content = std::fs::read_to_string(&cargo_toml)
+            .map_err(|e| format!("can't read Cargo.toml: {e}"))?;
+
+        if !has_section(&toml_content, "[workspace]") {
+            return Err("not a Cargo workspace (no [workspace] table)".to_string());
+        }
+
+        let member_patterns = parse_string_array(&toml_content, "members");
+        let exclude_patterns = parse_string_array(&toml_content, "exclude");
+
+        let excluded: HashSet<PathBuf> = exclude_patterns
+            .iter()
+            .flat_map(|pattern| expand_member_glob(root, pattern))
+            .collect();
+
+        let member_dirs: Vec<PathBuf> = member_patterns
+            .iter()
+            .flat_map(|pattern| expand_member_glob(root, pattern))
+            .filter(|dir| !excluded.contains(dir))
+            .collect();
+
+        let mut local_names = HashSet::new();
+        let mut member_names = Vec::with_capacity(member_dirs.len());
+        for dir in &member_dirs {
+            let manifest = dir.join("Cargo.toml");
+            let content = std::fs::read_to_string(&manifest)
+                .map_err(|e| format!("can't read {}: {e}", manifest.display()))?;
+            let name = parse_package_name(&content).unwrap_or_else(|| "unknown".to_string());
+            local_names.insert(name.clone());
+            member_names.push(name);
+        }
+
+        // Parse the one shared Cargo.lock
+        let cargo_lock = root.join("Cargo.lock");
+        let shared_deps = if cargo_lock.exists() {
+            let lock_content = std::fs::read_to_string(&cargo_lock)
+                .map_err(|e| format!("can't read Cargo.lock: {e}"))?;
+            parse_cargo_lock_with_locals(&lock_content, &local_names)
+        } else {
+            eprintln!("warning: no Cargo.lock found, run `cargo build` first");
+            vec![]
+        };
+
+        let members = member_dirs
+            .into_iter()
+            .zip(member_names)
+            .map(|(root, name)| RustProject {
+                root,
+                name,
+                deps: shared_deps.clone(),
+            })
+            .collect();
+
+        Ok(Self {
+            root: root.to_path_buf(),
+            members,
+        })
+    }
+}
+
+/// Whether `content` has a top-level `[header]` table (an exact, whole-line
+/// match after trimming - this doesn't need to distinguish a genuine table
+/// header from one nested under `[[package]]` etc., since `[workspace]`/
+/// `[package]` never appear as anything but top-level tables).
+fn has_section(content: &str, header: &str) -> bool {
+    content.lines().any(|line| line.trim() == header)
+}
+
+/// Parse a `key = [...]` string array from Cargo.toml, tolerating the array
+/// spanning multiple lines. Only handles plain string entries (what
+/// `[workspace]` `members`/`exclude` actually are); a line that merely
+/// starts with `key` but isn't followed by `=` is skipped rather than
+/// misread (e.g. `default-members` won't match a lookup for `members`).
+fn parse_string_array(content: &str, key: &str) -> Vec<String> {
+    let mut lines = content.lines();
+
+    while let Some(line) = lines.next() {
+        let Some(rest) = line.trim().strip_prefix(key) else {
+            continue;
+        };
+        let Some(rest) = rest.trim_start().strip_prefix('=') else {
+            continue;
+        };
+        let Some(rest) = rest.trim_start().strip_prefix('[') else {
+            continue;
+        };
+
+        let mut array_src = rest.to_string();
+        while !array_src.contains(']') {
+            match lines.next() {
+                Some(next) => {
+                    array_src.push('\n');
+                    array_src.push_str(next);
+                }
+                None => break,
+            }
+        }
+
+        let body = array_src.split(']').next().unwrap_or(&array_src);
+        return body
+            .split(',')
+            .map(|item| item.trim().trim_matches('"').trim_matches('\''))
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect();
+    }
+
+    Vec::new()
+}
+
+/// Resolve a `[workspace]` `members`/`exclude` glob pattern, relative to
+/// `root`, into the on-disk directories it names. Only a trailing `/*`
+/// wildcard (one path segment) is supported, covering the common
+/// `"crates/*"`-style patterns; anything else is treated as a literal path.
+fn expand_member_glob(root: &Path, pattern: &str) -> Vec<PathBuf> {
+    if let Some(prefix) = pattern.strip_suffix("/*") {
+        let base = root.join(prefix);
+        return std::fs::read_dir(&base)
+            .into_iter()
+            .flatten()
+            .filter_map(Result::ok)
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect();
+    }
+
+    let dir = root.join(pattern);
+    if dir.is_dir() {
+        vec![dir]
+    } else {
+        vec![]
+    }
 }
 
 /// Parse package name from Cargo.toml (simple approach)
@@ -83,31 +636,448 @@ fn parse_package_name(content: &str) -> Option<String> {
     None
 }
 
-/// Parse dependencies from Cargo.lock
-fn parse_cargo_lock(content: &str) -> Vec<Dependency> {
+/// Parse dependencies from Cargo.lock. `project_name` is the root package's
+/// name (from Cargo.toml), used to tell the project's own lockfile entry
+/// apart from a genuine path dependency - both carry no `source` line.
+fn parse_cargo_lock(content: &str, project_name: &str) -> Vec<Dependency> {
+    let mut local_names = HashSet::new();
+    local_names.insert(project_name.to_string());
+    parse_cargo_lock_with_locals(content, &local_names)
+}
+
+/// Same as `parse_cargo_lock`, but for a workspace with more than one
+/// locally-owned package: any lockfile entry whose name is in
+/// `local_names` is classified `Local` rather than `Path` when it has no
+/// `source` line.
+fn parse_cargo_lock_with_locals(content: &str, local_names: &HashSet<String>) -> Vec<Dependency> {
     let mut deps = Vec::new();
     let mut current_name: Option<String> = None;
     let mut current_version: Option<String> = None;
+    let mut current_source: Option<String> = None;
+    let mut current_checksum: Option<String> = None;
+
+    let mut flush = |name: Option<String>,
+                     version: Option<String>,
+                     source: Option<String>,
+                     checksum: Option<String>,
+                     deps: &mut Vec<Dependency>| {
+        if let (Some(name), Some(version)) = (name, version) {
+            let origin = parse_origin(source.as_deref(), local_names.contains(&name));
+            deps.push(Dependency {
+                name,
+                version,
+                origin,
+                checksum,
+                license: None,
+            });
+        }
+    };
 
     for line in content.lines() {
         let line = line.trim();
 
         if line == "[[package]]" {
             // Save previous package if complete
-            if let (Some(name), Some(version)) = (current_name.take(), current_version.take()) {
-                deps.push(Dependency { name, version });
-            }
+            flush(
+                current_name.take(),
+                current_version.take(),
+                current_source.take(),
+                current_checksum.take(),
+                &mut deps,
+            );
         } else if let Some(name) = line.strip_prefix("name = ") {
             current_name = Some(name.trim_matches('"').to_string());
         } else if let Some(version) = line.strip_prefix("version = ") {
             current_version = Some(version.trim_matches('"').to_string());
+        } else if let Some(source) = line.strip_prefix("source = ") {
+            current_source = Some(source.trim_matches('"').to_string());
+        } else if let Some(checksum) = line.strip_prefix("checksum = ") {
+            current_checksum = Some(checksum.trim_matches('"').to_string());
         }
     }
 
     // Don't forget the last package
-    if let (Some(name), Some(version)) = (current_name, current_version) {
-        deps.push(Dependency { name, version });
-    }
+    flush(
+        current_name,
+        current_version,
+        current_source,
+        current_checksum,
+        &mut deps,
+    );
 
     deps
 }
+
+/// Classify a dependency's `source` line into a `DependencyOrigin`.
+/// `is_root_package` disambiguates the two cases with no `source` at all:
+/// the project's own package (`Local`) vs. an actual path dependency
+/// (`Path`) - Cargo.lock doesn't distinguish them itself.
+fn parse_origin(source: Option<&str>, is_root_package: bool) -> DependencyOrigin {
+    match source {
+        Some(source) => {
+            if let Some(index_url) = source.strip_prefix("registry+") {
+                DependencyOrigin::Registry {
+                    index_url: index_url.to_string(),
+                }
+            } else if let Some(rest) = source.strip_prefix("git+") {
+                let (url, rev) = rest.rsplit_once('#').unwrap_or((rest, ""));
+                DependencyOrigin::Git {
+                    url: url.to_string(),
+                    rev: rev.to_string(),
+                }
+            } else {
+                DependencyOrigin::Path
+            }
+        }
+        None if is_root_package => DependencyOrigin::Local,
+        None => DependencyOrigin::Path,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dep(name: &str, version: &str) -> Dependency {
+        Dependency {
+            name: name.to_string(),
+            version: version.to_string(),
+            origin: DependencyOrigin::Registry {
+                index_url: "https://github.com/rust-lang/crates.io-index".to_string(),
+            },
+            checksum: None,
+            license: None,
+        }
+    }
+
+    #[test]
+    fn test_diff_resolved_vs_minimal_reports_only_version_drift() {
+        let resolved = vec![dep("serde", "1.0.200"), dep("anyhow", "1.0.80")];
+        let minimal = vec![dep("serde", "1.0.0"), dep("anyhow", "1.0.80")];
+
+        let drift = diff_resolved_vs_minimal(&resolved, &minimal);
+        assert_eq!(drift.len(), 1);
+        assert_eq!(drift[0].name, "serde");
+        assert_eq!(drift[0].resolved_version, "1.0.200");
+        assert_eq!(drift[0].minimal_version, "1.0.0");
+    }
+
+    #[test]
+    fn test_diff_resolved_vs_minimal_ignores_deps_missing_from_minimal() {
+        let resolved = vec![dep("serde", "1.0.200"), dep("target-only", "0.5.0")];
+        let minimal = vec![dep("serde", "1.0.200")];
+
+        assert!(diff_resolved_vs_minimal(&resolved, &minimal).is_empty());
+    }
+
+    #[test]
+    fn test_copy_dir_recursive_skips_target_and_git() {
+        let src =
+            std::env::temp_dir().join(format!("fastdeps_copy_scratch_src_{}", std::process::id()));
+        let dst =
+            std::env::temp_dir().join(format!("fastdeps_copy_scratch_dst_{}", std::process::id()));
+        std::fs::create_dir_all(src.join("target")).unwrap();
+        std::fs::create_dir_all(src.join(".git")).unwrap();
+        std::fs::create_dir_all(src.join("src")).unwrap();
+        std::fs::write(src.join("Cargo.toml"), "[package]\nname = \"x\"\n").unwrap();
+        std::fs::write(src.join("target").join("debug.bin"), "").unwrap();
+        std::fs::write(src.join("src").join("lib.rs"), "").unwrap();
+
+        copy_dir_recursive(&src, &dst).unwrap();
+
+        assert!(dst.join("Cargo.toml").exists());
+        assert!(dst.join("src").join("lib.rs").exists());
+        assert!(!dst.join("target").exists());
+        assert!(!dst.join(".git").exists());
+
+        std::fs::remove_dir_all(&src).unwrap();
+        std::fs::remove_dir_all(&dst).unwrap();
+    }
+
+    #[test]
+    fn test_parse_cargo_lock_registry_dep() {
+        let lock = r#"
+[[package]]
+name = "serde"
+version = "1.0.200"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+checksum = "abc123"
+"#;
+
+        let deps = parse_cargo_lock(lock, "my-project");
+        assert_eq!(deps.len(), 1);
+        assert_eq!(deps[0].name, "serde");
+        assert_eq!(deps[0].checksum.as_deref(), Some("abc123"));
+        assert_eq!(
+            deps[0].origin,
+            DependencyOrigin::Registry {
+                index_url: "https://github.com/rust-lang/crates.io-index".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_cargo_lock_git_dep_splits_url_and_rev() {
+        let lock = r#"
+[[package]]
+name = "some-fork"
+version = "0.1.0"
+source = "git+https://github.com/example/some-fork?branch=main#abcdef1234567890"
+"#;
+
+        let deps = parse_cargo_lock(lock, "my-project");
+        assert_eq!(
+            deps[0].origin,
+            DependencyOrigin::Git {
+                url: "https://github.com/example/some-fork?branch=main".to_string(),
+                rev: "abcdef1234567890".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_cargo_lock_distinguishes_local_root_from_path_dep() {
+        let lock = r#"
+[[package]]
+name = "my-project"
+version = "0.1.0"
+
+[[package]]
+name = "local-helper"
+version = "0.1.0"
+"#;
+
+        let deps = parse_cargo_lock(lock, "my-project");
+        assert_eq!(deps[0].origin, DependencyOrigin::Local);
+        assert_eq!(deps[1].origin, DependencyOrigin::Path);
+    }
+
+    #[test]
+    fn test_parse_cargo_lock_no_checksum_is_none() {
+        let lock = r#"
+[[package]]
+name = "local-helper"
+version = "0.1.0"
+"#;
+
+        let deps = parse_cargo_lock(lock, "my-project");
+        assert_eq!(deps[0].checksum, None);
+    }
+
+    #[test]
+    fn test_parse_string_array_single_line() {
+        let toml = r#"
+[workspace]
+members = ["crates/a", "crates/b"]
+"#;
+        assert_eq!(
+            parse_string_array(toml, "members"),
+            vec!["crates/a".to_string(), "crates/b".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_parse_string_array_multi_line_and_distinguishes_prefix() {
+        let toml = r#"
+[workspace]
+default-members = ["crates/a"]
+members = [
+    "crates/a",
+    "crates/b",
+]
+exclude = ["crates/broken"]
+"#;
+        assert_eq!(
+            parse_string_array(toml, "members"),
+            vec!["crates/a".to_string(), "crates/b".to_string()]
+        );
+        assert_eq!(
+            parse_string_array(toml, "exclude"),
+            vec!["crates/broken".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_expand_member_glob_trailing_star_lists_subdirs() {
+        let root = std::env::temp_dir().join(format!(
+            "fastdeps_workspace_glob_test_{}",
+            std::process::id()
+        ));
+        let crates_dir = root.join("crates");
+        std::fs::create_dir_all(crates_dir.join("a")).unwrap();
+        std::fs::create_dir_all(crates_dir.join("b")).unwrap();
+        std::fs::write(crates_dir.join("not-a-dir.txt"), "").unwrap();
+
+        let mut found = expand_member_glob(&root, "crates/*");
+        found.sort();
+        assert_eq!(found, vec![crates_dir.join("a"), crates_dir.join("b")]);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_expand_member_glob_literal_path() {
+        let root = std::env::temp_dir().join(format!(
+            "fastdeps_workspace_glob_literal_test_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(root.join("tools/xtask")).unwrap();
+
+        assert_eq!(
+            expand_member_glob(&root, "tools/xtask"),
+            vec![root.join("tools/xtask")]
+        );
+        assert!(expand_member_glob(&root, "tools/missing").is_empty());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_workspace_discover_from_resolves_members_and_excludes() {
+        let root = std::env::temp_dir().join(format!(
+            "fastdeps_workspace_discover_test_{}",
+            std::process::id()
+        ));
+        let crates_dir = root.join("crates");
+        std::fs::create_dir_all(crates_dir.join("a")).unwrap();
+        std::fs::create_dir_all(crates_dir.join("broken")).unwrap();
+
+        std::fs::write(
+            root.join("Cargo.toml"),
+            r#"
+[workspace]
+members = ["crates/*"]
+exclude = ["crates/broken"]
+"#,
+        )
+        .unwrap();
+        std::fs::write(
+            crates_dir.join("a").join("Cargo.toml"),
+            "[package]\nname = \"a\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            crates_dir.join("broken").join("Cargo.toml"),
+            "[package]\nname = \"broken\"\nversion = \"0.1.0\"\n",
+        )
+        .unwrap();
+        std::fs::write(
+            root.join("Cargo.lock"),
+            r#"
+[[package]]
+name = "a"
+version = "0.1.0"
+
+[[package]]
+name = "serde"
+version = "1.0.200"
+source = "registry+https://github.com/rust-lang/crates.io-index"
+"#,
+        )
+        .unwrap();
+
+        let workspace = Workspace::discover_from(&root).unwrap();
+        assert_eq!(workspace.members.len(), 1);
+        assert_eq!(workspace.members[0].name, "a");
+        assert!(workspace.members[0]
+            .deps
+            .iter()
+            .any(|d| d.name == "a" && d.origin == DependencyOrigin::Local));
+        assert!(workspace.members[0].deps.iter().any(|d| d.name == "serde"));
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_parse_spdx_expr_single_id() {
+        assert_eq!(
+            parse_spdx_expr("MIT"),
+            SpdxExpr::Id {
+                license: "MIT".to_string(),
+                exception: None,
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_spdx_expr_with_exception() {
+        assert_eq!(
+            parse_spdx_expr("Apache-2.0 WITH LLVM-exception"),
+            SpdxExpr::Id {
+                license: "Apache-2.0".to_string(),
+                exception: Some("LLVM-exception".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_spdx_expr_or() {
+        assert_eq!(
+            parse_spdx_expr("MIT OR Apache-2.0"),
+            SpdxExpr::Or(vec![
+                SpdxExpr::Id {
+                    license: "MIT".to_string(),
+                    exception: None,
+                },
+                SpdxExpr::Id {
+                    license: "Apache-2.0".to_string(),
+                    exception: None,
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_parse_spdx_expr_and_binds_tighter_than_or() {
+        let parsed = parse_spdx_expr("MIT AND Apache-2.0 OR GPL-3.0");
+        match parsed {
+            SpdxExpr::Or(terms) => {
+                assert_eq!(terms.len(), 2);
+                assert!(matches!(terms[0], SpdxExpr::And(_)));
+                assert!(matches!(terms[1], SpdxExpr::Id { .. }));
+            }
+            other => panic!("expected Or at the top level, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_license_conflicts_flags_copyleft_dep_under_permissive_root() {
+        let mut project = RustProject {
+            root: PathBuf::from("/tmp/doesnt-matter"),
+            name: "my-project".to_string(),
+            deps: vec![
+                Dependency {
+                    license: Some(parse_spdx_expr("MIT")),
+                    ..dep("serde", "1.0.200")
+                },
+                Dependency {
+                    license: Some(parse_spdx_expr("GPL-3.0")),
+                    ..dep("copyleft-thing", "0.1.0")
+                },
+            ],
+        };
+
+        let root_license = parse_spdx_expr("MIT");
+        let conflicts = project.license_conflicts(&root_license);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].0.name, "copyleft-thing");
+
+        project.deps.clear();
+        assert!(project.license_conflicts(&root_license).is_empty());
+    }
+
+    #[test]
+    fn test_license_conflicts_empty_when_root_itself_copyleft() {
+        let project = RustProject {
+            root: PathBuf::from("/tmp/doesnt-matter"),
+            name: "my-project".to_string(),
+            deps: vec![Dependency {
+                license: Some(parse_spdx_expr("GPL-3.0")),
+                ..dep("copyleft-thing", "0.1.0")
+            }],
+        };
+
+        let root_license = parse_spdx_expr("GPL-3.0");
+        assert!(project.license_conflicts(&root_license).is_empty());
+    }
+}
@@ -0,0 +1,121 @@
+//! Reads cargo's on-disk registry index directly, independent of whatever
+//! crate source has actually been extracted into `~/.cargo/registry/src`.
+//!
+//! `list_registry_crates`/`find_crate` (in `cargo`) only see versions that
+//! have already been downloaded and unpacked, so they're blind to versions
+//! that exist in the index but were never fetched. This module parses the
+//! index itself - sharded by crate-name length (`1/`, `2/`, `3/<first>/`,
+//! `<aa>/<bb>/<name>`, see `cargo::index_rel_path`) with each file holding
+//! one newline-delimited JSON record per published version - to answer
+//! "what versions exist" and "what did they depend on" without requiring
+//! any of them to be on disk.
+
+use crate::cargo::{cargo_home_dir, index_rel_path};
+use camino::{Utf8Path, Utf8PathBuf};
+use serde::Deserialize;
+
+/// One dependency entry from an index version record.
+#[derive(Debug, Clone, Deserialize)]
+pub struct IndexDep {
+    pub name: String,
+    pub req: String,
+    #[serde(default)]
+    pub features: Vec<String>,
+    #[serde(default)]
+    pub optional: bool,
+    #[serde(default)]
+    pub kind: Option<String>,
+}
+
+/// A single published version of a crate, as recorded in the registry
+/// index.
+#[derive(Debug, Clone, Deserialize)]
+pub struct IndexVersion {
+    pub name: String,
+    #[serde(rename = "vers")]
+    pub version: String,
+    #[serde(default)]
+    pub deps: Vec<IndexDep>,
+    #[serde(default)]
+    pub features: std::collections::BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    pub yanked: bool,
+    pub cksum: Option<String>,
+}
+
+/// Read every version record for `name` out of one index snapshot root
+/// (e.g. `$CARGO_HOME/registry/index/<dir>`), checking both the
+/// sparse-protocol `.cache/` layout and a plain git-checked-out index.
+/// Returns an empty list, not an error, if the index has no entry for
+/// `name` or isn't readable - the caller decides whether that's worth
+/// reporting.
+pub fn read_index_versions(index_root: &Utf8Path, name: &str) -> Vec<IndexVersion> {
+    let rel = index_rel_path(name);
+
+    for candidate in [index_root.join(".cache").join(&rel), index_root.join(&rel)] {
+        let Ok(contents) = std::fs::read_to_string(&candidate) else {
+            continue;
+        };
+        let body_start = contents.find('{').unwrap_or(contents.len());
+        let versions: Vec<IndexVersion> = contents[body_start..]
+            .lines()
+            .filter_map(|line| {
+                let line = line.trim_matches('\0').trim();
+                if line.is_empty() {
+                    return None;
+                }
+                serde_json::from_str(line).ok()
+            })
+            .collect();
+        if !versions.is_empty() {
+            return versions;
+        }
+    }
+    Vec::new()
+}
+
+/// Every locally cached registry index root under
+/// `$CARGO_HOME/registry/index/<dir>` - one per registry cargo has ever
+/// fetched from (usually just `index.crates.io-<hash>`).
+fn local_index_roots() -> Vec<Utf8PathBuf> {
+    let Some(cargo_home) = cargo_home_dir() else {
+        return Vec::new();
+    };
+    let index_dir = cargo_home.join("registry/index");
+    std::fs::read_dir(&index_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .filter_map(|path| Utf8PathBuf::try_from(path).ok())
+        .collect()
+}
+
+/// All versions of `name` known to any locally cached registry index,
+/// newest-first, with yanked versions dropped unless `include_yanked` is
+/// set. This is the only way to see a version that was never extracted
+/// into `~/.cargo/registry/src`.
+pub fn list_all_versions(name: &str, include_yanked: bool) -> Vec<IndexVersion> {
+    let mut versions: Vec<IndexVersion> = local_index_roots()
+        .iter()
+        .flat_map(|root| read_index_versions(root, name))
+        .filter(|v| include_yanked || !v.yanked)
+        .collect();
+
+    versions.sort_by(|a, b| compare_versions(&b.version, &a.version));
+    versions.dedup_by(|a, b| a.version == b.version);
+    versions
+}
+
+/// Compares two version strings in ascending semver order when both parse,
+/// falling back to a plain string compare otherwise (callers swap the
+/// arguments to sort newest-first) - good enough for
+/// sorting/deduplicating a single crate's own version list, which is all
+/// this module needs.
+fn compare_versions(a: &str, b: &str) -> std::cmp::Ordering {
+    match (semver::Version::parse(a), semver::Version::parse(b)) {
+        (Ok(a), Ok(b)) => a.cmp(&b),
+        _ => a.cmp(b),
+    }
+}
@@ -0,0 +1,219 @@
+//! Blocking and async clients for fetching a published Octo-Index over HTTP.
+//!
+//! Both clients validate the fetched bytes the same way `OctoIndex::from_bytes`
+//! does (magic number, then format version) before handing back a usable
+//! index, retry transient failures with exponential backoff, and cache the
+//! last-fetched index on disk so a restart doesn't always re-download.
+
+use crate::octo_index::OctoIndex;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+const USER_AGENT: &str = concat!(
+    "fastdeps-remote-index/",
+    env!("CARGO_PKG_VERSION"),
+    " (https://github.com/xandwr/fastdeps)"
+);
+
+/// Sent with the request so the server can skip the body if we already have
+/// the latest index; an `If-Modified-Since`-like header, but keyed on the
+/// index's own `generated_at` timestamp rather than HTTP's date format.
+const GENERATED_AT_HEADER: &str = "X-Octo-Generated-At";
+
+const DEFAULT_MAX_RETRIES: u32 = 3;
+const INITIAL_BACKOFF: Duration = Duration::from_millis(250);
+
+#[derive(Debug, Error)]
+pub enum RemoteIndexError {
+    #[error("HTTP error: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("server returned {0}: {1}")]
+    BadStatus(reqwest::StatusCode, String),
+    #[error("fetched index failed validation: {0}")]
+    InvalidIndex(#[from] anyhow::Error),
+    #[error("index format version {found} is not supported (expected {expected})")]
+    UnsupportedVersion { found: u32, expected: u32 },
+}
+
+/// Outcome of one fetch attempt: either a freshly downloaded index, or a
+/// confirmation that the cached copy is already current.
+enum FetchOutcome {
+    Fresh(OctoIndex),
+    NotModified,
+}
+
+/// The `generated_at` of the index already cached on disk, if one loads;
+/// used to decide whether a conditional `generated_at` header is attached.
+fn cached_generated_at(cache_path: &Path) -> Option<u64> {
+    OctoIndex::load(cache_path).ok().map(|idx| idx.generated_at)
+}
+
+fn validate(index: OctoIndex) -> Result<OctoIndex, RemoteIndexError> {
+    if !index.version_supported() {
+        return Err(RemoteIndexError::UnsupportedVersion {
+            found: index.version,
+            expected: OctoIndex::current_format_version(),
+        });
+    }
+    Ok(index)
+}
+
+fn backoff_delay(attempt: u32) -> Duration {
+    INITIAL_BACKOFF * 2u32.pow(attempt)
+}
+
+/// Whether a failure is worth retrying: network errors and server (5xx)
+/// errors are transient; client (4xx) errors mean the request itself is
+/// wrong and retrying won't help.
+fn is_retryable(status: Option<reqwest::StatusCode>) -> bool {
+    match status {
+        Some(status) => status.is_server_error(),
+        None => true,
+    }
+}
+
+/// Blocking client for fetching an Octo-Index, suitable for CLI commands
+/// that don't otherwise need a Tokio runtime.
+pub struct SyncIndexClient {
+    client: reqwest::blocking::Client,
+    cache_path: PathBuf,
+    max_retries: u32,
+}
+
+impl SyncIndexClient {
+    pub fn new(cache_path: impl Into<PathBuf>) -> Result<Self, RemoteIndexError> {
+        let client = reqwest::blocking::Client::builder()
+            .user_agent(USER_AGENT)
+            .build()?;
+        Ok(Self {
+            client,
+            cache_path: cache_path.into(),
+            max_retries: DEFAULT_MAX_RETRIES,
+        })
+    }
+
+    /// Fetch the index at `url`, retrying transient failures with
+    /// exponential backoff. Returns the cached copy unchanged if the server
+    /// reports nothing newer than what's already on disk, and otherwise
+    /// saves the freshly fetched index to the cache path before returning it.
+    pub fn fetch_blocking(&self, url: &str) -> Result<OctoIndex, RemoteIndexError> {
+        let known_generated_at = cached_generated_at(&self.cache_path);
+
+        let mut attempt = 0;
+        loop {
+            let mut request = self.client.get(url);
+            if let Some(generated_at) = known_generated_at {
+                request = request.header(GENERATED_AT_HEADER, generated_at.to_string());
+            }
+
+            match request.send().and_then(|r| r.error_for_status()) {
+                Ok(response) => {
+                    if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+                        break self.load_cached_or_err();
+                    }
+                    let bytes = response.bytes()?;
+                    let index = validate(OctoIndex::from_bytes(&bytes)?)?;
+                    index.save(&self.cache_path)?;
+                    break Ok(index);
+                }
+                Err(err) => {
+                    let status = err.status();
+                    if attempt >= self.max_retries || !is_retryable(status) {
+                        break Err(err.into());
+                    }
+                    std::thread::sleep(backoff_delay(attempt));
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
+    fn load_cached_or_err(&self) -> Result<OctoIndex, RemoteIndexError> {
+        OctoIndex::load(&self.cache_path)
+            .map_err(RemoteIndexError::InvalidIndex)
+            .and_then(validate)
+    }
+}
+
+/// Async (Tokio) client for fetching an Octo-Index, for callers already
+/// running inside a Tokio runtime (e.g. the MCP server).
+pub struct AsyncIndexClient {
+    client: reqwest::Client,
+    cache_path: PathBuf,
+    max_retries: u32,
+}
+
+impl AsyncIndexClient {
+    pub fn new(cache_path: impl Into<PathBuf>) -> Result<Self, RemoteIndexError> {
+        let client = reqwest::Client::builder().user_agent(USER_AGENT).build()?;
+        Ok(Self {
+            client,
+            cache_path: cache_path.into(),
+            max_retries: DEFAULT_MAX_RETRIES,
+        })
+    }
+
+    pub async fn fetch(&self, url: &str) -> Result<OctoIndex, RemoteIndexError> {
+        let known_generated_at = cached_generated_at(&self.cache_path);
+
+        let mut attempt = 0;
+        loop {
+            let mut request = self.client.get(url);
+            if let Some(generated_at) = known_generated_at {
+                request = request.header(GENERATED_AT_HEADER, generated_at.to_string());
+            }
+
+            match request.send().await.and_then(|r| r.error_for_status()) {
+                Ok(response) => {
+                    let outcome = if response.status() == reqwest::StatusCode::NOT_MODIFIED {
+                        FetchOutcome::NotModified
+                    } else {
+                        let bytes = response.bytes().await?;
+                        FetchOutcome::Fresh(validate(OctoIndex::from_bytes(&bytes)?)?)
+                    };
+
+                    match outcome {
+                        FetchOutcome::NotModified => {
+                            break OctoIndex::load(&self.cache_path)
+                                .map_err(RemoteIndexError::InvalidIndex)
+                                .and_then(validate);
+                        }
+                        FetchOutcome::Fresh(index) => {
+                            index.save(&self.cache_path)?;
+                            break Ok(index);
+                        }
+                    }
+                }
+                Err(err) => {
+                    let status = err.status();
+                    if attempt >= self.max_retries || !is_retryable(status) {
+                        break Err(err.into());
+                    }
+                    tokio::time::sleep(backoff_delay(attempt)).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_delay_grows_exponentially() {
+        assert_eq!(backoff_delay(0), Duration::from_millis(250));
+        assert_eq!(backoff_delay(1), Duration::from_millis(500));
+        assert_eq!(backoff_delay(2), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn test_is_retryable() {
+        assert!(is_retryable(None));
+        assert!(is_retryable(Some(reqwest::StatusCode::BAD_GATEWAY)));
+        assert!(!is_retryable(Some(reqwest::StatusCode::NOT_FOUND)));
+        assert!(!is_retryable(Some(reqwest::StatusCode::UNAUTHORIZED)));
+    }
+}
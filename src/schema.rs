@@ -4,11 +4,13 @@
 //! information needed for documentation, migration tracking, and code intelligence.
 
 use camino::Utf8PathBuf;
+use rmcp::schemars;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 
 /// Top-level index mapping packages to their data locations.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Index {
     /// Schema version for forward compatibility.
     pub format_version: u32,
@@ -38,7 +40,7 @@ impl Default for Index {
 }
 
 /// Package-level metadata.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PackageMeta {
     pub name: String,
     pub version: String,
@@ -56,7 +58,7 @@ pub struct PackageMeta {
 }
 
 /// Supported language ecosystems.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum Ecosystem {
     Rust,
@@ -66,13 +68,13 @@ pub enum Ecosystem {
 }
 
 /// The API surface of a package - a flat list of items.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PackageItems {
     pub items: Vec<Item>,
 }
 
 /// A single API item (struct, function, trait, etc.).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Item {
     /// Fully qualified path: `crate::module::Item` for Rust.
     pub path: String,
@@ -81,12 +83,34 @@ pub struct Item {
     /// The signature in native syntax.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signature: Option<String>,
+    /// Structured signature detail (ordered params, return type, own
+    /// generics), populated for functions; `None` for item kinds that have
+    /// no parameter list.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_detail: Option<Signature>,
+    /// All overload forms, for a function with multiple declared
+    /// signatures (TypeScript overloads). Empty unless this item merges
+    /// more than one declaration; the implementation signature, if one was
+    /// present, is not included here.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub signatures: Vec<String>,
     /// Documentation string.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub doc: Option<String>,
     /// Visibility level.
     #[serde(default, skip_serializing_if = "Visibility::is_public")]
     pub visibility: Visibility,
+    /// Generic parameters, lifetimes, and where-clause bounds.
+    #[serde(default, skip_serializing_if = "Generics::is_empty")]
+    pub generics: Generics,
+    /// Raw outer attributes attached to this item (derives, cfg gates,
+    /// deprecated, ...), in source order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attrs: Vec<Attribute>,
+    /// Decorators attached to this item (e.g. TypeScript's `@Component({...})`),
+    /// verbatim including arguments, in source order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<String>,
 
     // === Struct/Enum specific ===
     /// Fields for structs/variants.
@@ -106,6 +130,10 @@ pub struct Item {
     /// Related items with their relationship type.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub related: Vec<Relation>,
+    /// Intra-doc link targets mentioned in `doc` that could not be resolved
+    /// to a known item path in this run.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unresolved_doc_links: Vec<String>,
 
     // === Lifecycle ===
     /// Version when this item was introduced.
@@ -117,13 +145,21 @@ pub struct Item {
     /// Previous path if this item was moved/renamed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub moved_from: Option<String>,
+    /// Source module specifier for an unresolved re-export (e.g. `./foo` in
+    /// `export { Foo } from './foo'`). Such an item has no fields/methods of
+    /// its own; no pass resolves it against the referenced module.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reexport_from: Option<String>,
     /// Deprecation message if deprecated.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub deprecated: Option<String>,
+    /// `#[cfg(...)]` predicate gating this item, if feature-gated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cfg: Option<String>,
 }
 
 /// Universal item kinds across languages.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ItemKind {
     /// Rust struct, Go struct, Python class, TS class.
@@ -145,7 +181,7 @@ pub enum ItemKind {
 }
 
 /// Visibility levels.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum Visibility {
     #[default]
@@ -162,8 +198,84 @@ impl Visibility {
     }
 }
 
+impl Item {
+    /// Feature names gating this item, pulled out of its raw `cfg`
+    /// predicate rather than tracked separately during parsing, so
+    /// `all(feature = "a", feature = "b")` or `any(feature = "a", not(feature
+    /// = "b"))` all surface every feature name mentioned regardless of how
+    /// they're combined. Empty if the item isn't feature-gated.
+    pub fn feature_gates(&self) -> Vec<String> {
+        let Some(cfg) = &self.cfg else {
+            return Vec::new();
+        };
+
+        let mut gates = Vec::new();
+        let mut rest = cfg.as_str();
+        while let Some(idx) = rest.find("feature") {
+            rest = &rest[idx + "feature".len()..];
+            let Some(start) = rest.find('"') else {
+                break;
+            };
+            let Some(len) = rest[start + 1..].find('"') else {
+                break;
+            };
+            gates.push(rest[start + 1..start + 1 + len].to_string());
+            rest = &rest[start + 1 + len + 1..];
+        }
+        gates
+    }
+}
+
+/// Generic parameters, lifetimes, and where-clause bounds for an item.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Generics {
+    /// Type/const parameters with their trait bounds.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<GenericParam>,
+    /// Lifetime parameters, e.g. `'a`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lifetimes: Vec<String>,
+    /// Additional bounds from a `where` clause, normalized as predicate strings.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub where_clauses: Vec<String>,
+}
+
+impl Generics {
+    pub fn is_empty(&self) -> bool {
+        self.params.is_empty() && self.lifetimes.is_empty() && self.where_clauses.is_empty()
+    }
+}
+
+/// A single type parameter and its trait bounds, e.g. `T: Clone + Send`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct GenericParam {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bounds: Vec<String>,
+    /// The parameter's default type, e.g. `unknown` in `V = unknown`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+}
+
+/// A single outer attribute, e.g. `#[derive(Clone, Debug)]` or `#[cfg(test)]`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Attribute {
+    /// The attribute's path, e.g. `derive`, `cfg`, `deprecated`.
+    pub path: String,
+    /// Argument tokens, e.g. `derive(Clone, Debug)` yields `["Clone", "Debug"]`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub args: Vec<String>,
+    /// Whether this used the newer `#[unsafe(...)]` wrapper form.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub is_unsafe: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
 /// A struct field or similar.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Field {
     pub name: String,
     #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
@@ -172,22 +284,84 @@ pub struct Field {
     pub doc: Option<String>,
     #[serde(default, skip_serializing_if = "Visibility::is_public")]
     pub visibility: Visibility,
+    /// Decorators attached to this field (e.g. TypeScript's `@Input()`),
+    /// verbatim including arguments, in source order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<String>,
 }
 
 /// A method on a type.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Method {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub signature: Option<String>,
+    /// Structured signature detail; see `Item::signature_detail`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_detail: Option<Signature>,
+    /// All overload forms; see `Item::signatures`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub signatures: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub doc: Option<String>,
     #[serde(default, skip_serializing_if = "Visibility::is_public")]
     pub visibility: Visibility,
+    /// This method's own generic parameters, lifetimes, and where-clause
+    /// bounds (as opposed to the enclosing type's).
+    #[serde(default, skip_serializing_if = "Generics::is_empty")]
+    pub generics: Generics,
+    /// Decorators attached to this method (e.g. TypeScript's `@HostListener(...)`),
+    /// verbatim including arguments, in source order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<String>,
+}
+
+/// A single function parameter, e.g. `value: &'a str`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Param {
+    pub name: String,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub ty: Option<String>,
+    /// Whether this parameter is optional, e.g. TypeScript's trailing `?`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub optional: bool,
+    /// The parameter's default value expression, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub default: Option<String>,
+    /// Whether this is a rest parameter, e.g. `...args`.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub rest: bool,
+    /// Decorators on this parameter (e.g. a TypeScript parameter-property's
+    /// `@Inject(...)`), verbatim including arguments, in source order.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub decorators: Vec<String>,
+}
+
+/// A function/method's structured signature, complementing the flat
+/// `signature` string with data a renderer can use directly instead of
+/// re-parsing it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Signature {
+    /// Ordered parameters, excluding `self`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub params: Vec<Param>,
+    /// The written return type, e.g. `&str` or `Option<&'a Foo>`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_type: Option<String>,
+    /// This function's own generic parameters, lifetimes, and where-clause
+    /// bounds (as opposed to the enclosing type's).
+    #[serde(default, skip_serializing_if = "Generics::is_empty")]
+    pub generics: Generics,
+    /// `return_type` with an elided reference lifetime rewritten to its
+    /// explicit form per the standard elision rules (a single input
+    /// lifetime, or `&self`'s lifetime when present, propagates to elided
+    /// outputs). `None` if the return type had nothing elided to expand.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expanded_return_type: Option<String>,
 }
 
 /// An enum variant.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct Variant {
     pub name: String,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -195,10 +369,14 @@ pub struct Variant {
     /// Fields if this is a struct variant.
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub fields: Vec<Field>,
+    /// The variant's explicit discriminant expression, e.g. `2` in
+    /// `Foo = 2` or `"foo"` in a TypeScript string enum member.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
 }
 
 /// A relationship to another item.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct Relation {
     /// Path to the related item.
     pub path: String,
@@ -207,7 +385,7 @@ pub struct Relation {
 }
 
 /// Types of relationships between items.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum RelationKind {
     /// Bevy: required component.
@@ -220,6 +398,32 @@ pub enum RelationKind {
     Implements,
     /// This item extends/inherits from the target.
     Extends,
+    /// This item's doc comment contains an intra-doc link to the target.
+    DocLink,
+    /// This item is a `pub use` re-export of the target.
+    ReExportOf,
+}
+
+/// Schema-only wrapper bundling every independently-serialized file shape
+/// in this format (the top-level index, a package's metadata, and a
+/// package's item list) so `write_schema` can validate all of them from a
+/// single generated document instead of picking just one root type.
+#[derive(Debug, JsonSchema)]
+struct InterchangeFormat {
+    index: Index,
+    package_meta: PackageMeta,
+    package_items: PackageItems,
+}
+
+/// Write the JSON Schema for the current `format_version` of this
+/// interchange format to `path`, so non-Rust tooling (the `Ecosystem` list
+/// already spans TypeScript, Python, and Go) can generate bindings and
+/// validate files before ingest.
+pub fn write_schema(path: &std::path::Path) -> Result<(), Box<dyn std::error::Error>> {
+    let root = schemars::schema_for!(InterchangeFormat);
+    let json = serde_json::to_string_pretty(&root)?;
+    std::fs::write(path, json)?;
+    Ok(())
 }
 
 #[cfg(test)]
@@ -232,13 +436,19 @@ mod tests {
             path: "bevy::light::DirectionalLight".into(),
             kind: ItemKind::Struct,
             signature: Some("pub struct DirectionalLight { ... }".into()),
+            signature_detail: None,
+            signatures: vec![],
             doc: Some("A directional light source.".into()),
             visibility: Visibility::Public,
+            generics: Generics::default(),
+            attrs: vec![],
+            decorators: vec![],
             fields: vec![Field {
                 name: "intensity".into(),
                 ty: Some("f32".into()),
                 doc: Some("Light intensity.".into()),
                 visibility: Visibility::Public,
+                decorators: vec![],
             }],
             methods: vec![],
             traits: vec!["Component".into(), "Default".into()],
@@ -247,10 +457,13 @@ mod tests {
                 path: "Transform".into(),
                 kind: RelationKind::RequiredComponent,
             }],
+            unresolved_doc_links: vec![],
             since: Some("0.10.0".into()),
             until: None,
             moved_from: None,
+            reexport_from: None,
             deprecated: None,
+            cfg: None,
         };
 
         let json = serde_json::to_string_pretty(&item).unwrap();
@@ -260,4 +473,19 @@ mod tests {
         let parsed: Item = serde_json::from_str(&json).unwrap();
         assert_eq!(parsed.path, item.path);
     }
+
+    #[test]
+    fn test_write_schema_produces_valid_json() {
+        let path = std::env::temp_dir().join(format!(
+            "fastdeps_write_schema_test_{}.json",
+            std::process::id()
+        ));
+
+        write_schema(&path).unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        let schema: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        assert!(schema.get("definitions").is_some());
+
+        std::fs::remove_file(&path).unwrap();
+    }
 }
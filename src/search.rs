@@ -1,9 +1,16 @@
 //! Smart search with fuzzy matching, scoring, and pagination.
 
 use crate::cache::Cache;
-use crate::cargo::{RegistryCrate, get_direct_dep_names, resolve_project_deps};
+use crate::cargo::{get_direct_dep_names, resolve_project_deps, RegistryCrate};
+use crate::fst_index::SymbolIndex;
+use crate::import_map;
 use camino::{Utf8Path, Utf8PathBuf};
-use std::collections::HashSet;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+
+/// Max edit distance `fuzzy_search` tolerates when streaming the symbol
+/// index's Levenshtein automaton.
+const FUZZY_MAX_EDIT_DISTANCE: u32 = 2;
 
 /// A scored search result with metadata.
 #[derive(Debug, Clone)]
@@ -16,6 +23,16 @@ pub struct ScoredResult {
     pub score: u32,
     pub is_direct_dep: bool,
     pub match_type: MatchType,
+    /// The shortest path a user would actually type to import this item
+    /// (e.g. `bevy::prelude::Component` for a definition at
+    /// `bevy_ecs::component::Component`), if it differs from `path` and a
+    /// cache is available to resolve it through. See
+    /// `SearchEngine::resolve_import_path`.
+    pub canonical_import: Option<String>,
+    /// Byte offset of each query term's first match in `path`, in query
+    /// order, for callers that want to highlight them. Empty unless
+    /// `match_type` is `MatchType::MultiTerm`.
+    pub term_offsets: Vec<usize>,
 }
 
 /// How the result matched the query.
@@ -27,12 +44,17 @@ pub enum MatchType {
     Prefix,
     /// Item name contains query
     Contains,
-    /// Fuzzy match with edit distance
-    Fuzzy { distance: usize },
+    /// Ordered-subsequence match (e.g. `dst_fog` -> `DistanceFog`), scored by
+    /// `score_subsequence`. See that function's doc comment.
+    Subsequence { score: u32 },
     /// Crate name match (for crate queries like "bevy")
     CrateName,
     /// Crate prefix match (bevy -> bevy_ecs)
     CratePrefix,
+    /// Every term of a multi-term query (e.g. `async read`) found somewhere
+    /// in the path, scored by how close together and in-order they appear.
+    /// See `SearchEngine::score_multi_term`.
+    MultiTerm { matched: usize, proximity: u32 },
 }
 
 impl std::fmt::Display for MatchType {
@@ -41,9 +63,12 @@ impl std::fmt::Display for MatchType {
             MatchType::Exact => write!(f, "exact"),
             MatchType::Prefix => write!(f, "prefix"),
             MatchType::Contains => write!(f, "contains"),
-            MatchType::Fuzzy { distance } => write!(f, "fuzzy~{}", distance),
+            MatchType::Subsequence { score } => write!(f, "subsequence~{}", score),
             MatchType::CrateName => write!(f, "crate"),
             MatchType::CratePrefix => write!(f, "crate_prefix"),
+            MatchType::MultiTerm { matched, proximity } => {
+                write!(f, "multi_term~{}/{}", matched, proximity)
+            }
         }
     }
 }
@@ -77,12 +102,14 @@ pub struct SearchOptions {
     pub offset: usize,
     /// Enable fuzzy matching
     pub fuzzy: bool,
-    /// Maximum edit distance for fuzzy matching
-    pub max_edit_distance: usize,
     /// Only show direct dependencies
     pub direct_only: bool,
     /// Filter by item kind (struct, trait, function, etc.)
     pub kind_filter: Option<String>,
+    /// Ranking rules applied in priority order: the first rule that doesn't
+    /// return `Equal` for a pair of results decides their relative order,
+    /// later rules only break ties left by earlier ones. See `RankingRule`.
+    pub ranking_rules: Vec<RankingRuleKind>,
 }
 
 impl SearchOptions {
@@ -91,11 +118,16 @@ impl SearchOptions {
             limit: 25,
             offset: 0,
             fuzzy: true,
-            max_edit_distance: 2,
+            ranking_rules: RankingRuleKind::default_pipeline(),
             ..Default::default()
         }
     }
 
+    pub fn with_ranking_rules(mut self, rules: Vec<RankingRuleKind>) -> Self {
+        self.ranking_rules = rules;
+        self
+    }
+
     pub fn with_limit(mut self, limit: usize) -> Self {
         self.limit = limit;
         self
@@ -157,25 +189,198 @@ impl std::fmt::Display for CrateRelationship {
     }
 }
 
+/// One stage in the ranking pipeline, selectable via
+/// `SearchOptions::ranking_rules`. Modeled on MeiliSearch's ranking-rule
+/// stack: each rule compares a single axis and returns `Equal` to defer to
+/// the next rule, rather than folding everything into one opaque score.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RankingRuleKind {
+    /// Exact > prefix > contains > fuzzy/subsequence match on the item name.
+    Exactness,
+    /// Among subsequence/fuzzy matches, the closer match (higher subsequence
+    /// score) first.
+    Typos,
+    /// Distance between matched query terms for multi-term queries.
+    Proximity,
+    /// Direct dependencies before transitive ones.
+    DirectDep,
+    /// User-preferred item kinds (struct/trait/fn/...) first.
+    ItemKind,
+    /// Larger (more indexed items) crates first, as a popularity proxy.
+    CrateItemCount,
+}
+
+impl RankingRuleKind {
+    /// The order `SearchOptions::new()` applies rules in: roughly the
+    /// priority the old single `score` field encoded (match quality and
+    /// direct-dep status dominated), with the newer axes added as
+    /// lower-priority tie-breakers.
+    fn default_pipeline() -> Vec<RankingRuleKind> {
+        vec![
+            RankingRuleKind::Exactness,
+            RankingRuleKind::Typos,
+            RankingRuleKind::DirectDep,
+            RankingRuleKind::Proximity,
+            RankingRuleKind::ItemKind,
+            RankingRuleKind::CrateItemCount,
+        ]
+    }
+
+    fn as_rule(self) -> &'static dyn RankingRule {
+        match self {
+            RankingRuleKind::Exactness => &Exactness,
+            RankingRuleKind::Typos => &Typos,
+            RankingRuleKind::Proximity => &Proximity,
+            RankingRuleKind::DirectDep => &DirectDep,
+            RankingRuleKind::ItemKind => &ItemKind,
+            RankingRuleKind::CrateItemCount => &CrateItemCount,
+        }
+    }
+}
+
+/// Per-search data a `RankingRule` needs but shouldn't recompute on every
+/// comparison, built once by `SearchEngine::build_ranking_context` before
+/// sorting.
+#[derive(Debug, Default)]
+struct RankingContext {
+    /// Total indexed item count per crate name, used by `CrateItemCount`.
+    crate_item_counts: HashMap<String, usize>,
+}
+
+/// A single comparison axis in the ranking pipeline. See `RankingRuleKind`.
+trait RankingRule {
+    fn compare(&self, ctx: &RankingContext, a: &ScoredResult, b: &ScoredResult) -> Ordering;
+}
+
+/// Tier for `MatchType`, lower is better; `Exactness` orders by this alone.
+fn match_type_tier(match_type: &MatchType) -> u8 {
+    match match_type {
+        MatchType::Exact | MatchType::CrateName => 0,
+        MatchType::Prefix | MatchType::CratePrefix => 1,
+        MatchType::Contains => 2,
+        MatchType::MultiTerm { .. } => 3,
+        MatchType::Subsequence { .. } => 4,
+    }
+}
+
+struct Exactness;
+impl RankingRule for Exactness {
+    fn compare(&self, _ctx: &RankingContext, a: &ScoredResult, b: &ScoredResult) -> Ordering {
+        match_type_tier(&a.match_type).cmp(&match_type_tier(&b.match_type))
+    }
+}
+
+struct Typos;
+impl RankingRule for Typos {
+    fn compare(&self, _ctx: &RankingContext, a: &ScoredResult, b: &ScoredResult) -> Ordering {
+        match (&a.match_type, &b.match_type) {
+            (
+                MatchType::Subsequence { score: a_score },
+                MatchType::Subsequence { score: b_score },
+            ) => b_score.cmp(a_score),
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+struct Proximity;
+impl RankingRule for Proximity {
+    fn compare(&self, _ctx: &RankingContext, a: &ScoredResult, b: &ScoredResult) -> Ordering {
+        match (&a.match_type, &b.match_type) {
+            (
+                MatchType::MultiTerm {
+                    proximity: a_proximity,
+                    ..
+                },
+                MatchType::MultiTerm {
+                    proximity: b_proximity,
+                    ..
+                },
+            ) => b_proximity.cmp(a_proximity),
+            // Single-term match types carry no term-distance data to compare.
+            _ => Ordering::Equal,
+        }
+    }
+}
+
+struct DirectDep;
+impl RankingRule for DirectDep {
+    fn compare(&self, _ctx: &RankingContext, a: &ScoredResult, b: &ScoredResult) -> Ordering {
+        b.is_direct_dep.cmp(&a.is_direct_dep)
+    }
+}
+
+/// Item kinds in the order this rule prefers them; anything else sorts last.
+const PREFERRED_KIND_ORDER: &[&str] = &[
+    "struct", "trait", "enum", "fn", "type", "const", "static", "macro", "mod",
+];
+
+fn kind_rank(kind: &str) -> usize {
+    PREFERRED_KIND_ORDER
+        .iter()
+        .position(|k| k.eq_ignore_ascii_case(kind))
+        .unwrap_or(PREFERRED_KIND_ORDER.len())
+}
+
+struct ItemKind;
+impl RankingRule for ItemKind {
+    fn compare(&self, _ctx: &RankingContext, a: &ScoredResult, b: &ScoredResult) -> Ordering {
+        kind_rank(&a.kind).cmp(&kind_rank(&b.kind))
+    }
+}
+
+struct CrateItemCount;
+impl RankingRule for CrateItemCount {
+    fn compare(&self, ctx: &RankingContext, a: &ScoredResult, b: &ScoredResult) -> Ordering {
+        let a_count = ctx
+            .crate_item_counts
+            .get(&a.crate_name)
+            .copied()
+            .unwrap_or(0);
+        let b_count = ctx
+            .crate_item_counts
+            .get(&b.crate_name)
+            .copied()
+            .unwrap_or(0);
+        b_count.cmp(&a_count)
+    }
+}
+
 /// Smart search engine.
 pub struct SearchEngine {
     project_dir: Utf8PathBuf,
     direct_deps: HashSet<String>,
     all_deps: Vec<RegistryCrate>,
+    /// FST-backed symbol index spanning every indexed crate, used by
+    /// `fuzzy_search` to avoid a per-dep linear scan. `None` when there's
+    /// no cache to build it from yet (falls back to the old scan).
+    symbol_index: Option<SymbolIndex>,
 }
 
 impl SearchEngine {
     pub fn new(project_dir: &Utf8Path) -> Result<Self, String> {
         let all_deps = resolve_project_deps(project_dir, false).map_err(|e| e.to_string())?;
         let direct_deps = get_direct_dep_names(project_dir).map_err(|e| e.to_string())?;
+        let symbol_index = Cache::open_existing()
+            .ok()
+            .and_then(|cache| SymbolIndex::open_or_build(&cache).ok());
 
         Ok(Self {
             project_dir: project_dir.to_owned(),
             direct_deps,
             all_deps,
+            symbol_index,
         })
     }
 
+    /// Whether `crate_name` is a direct (not merely transitive) dependency
+    /// of the project, for callers building their own ●/○ markers outside
+    /// `search`'s own result set (e.g. the `impls` tool's cross-reference
+    /// lookups).
+    pub fn is_direct_dep(&self, crate_name: &str) -> bool {
+        self.direct_deps.contains(crate_name)
+    }
+
     /// Search for symbols with smart matching and scoring.
     pub fn search(&self, query: &str, options: &SearchOptions) -> Result<SearchResponse, String> {
         let query_lower = query.to_lowercase();
@@ -191,25 +396,43 @@ impl SearchEngine {
             related_crates = self.find_related_crates(&query_lower);
         }
 
+        // Fast path for a qualified ("::") query: binary-search straight to
+        // its prefix range in the FQN-keyed symbol index instead of
+        // scanning every cached item via FTS - the common "type the first
+        // few letters of a path" case never touches unrelated crates. Only
+        // runs the scorer over that bounded window; falls back to the
+        // normal cache scan below when the index is missing or the prefix
+        // range comes up empty.
+        if query.contains("::") {
+            if let Some(index) = &self.symbol_index {
+                all_results = self.fqn_prefix_search(index, query, options);
+            }
+        }
+
         // Get results from cache if available
-        if Cache::exists() {
+        if all_results.is_empty() && Cache::exists() {
             if let Ok(cache) = Cache::open_existing() {
-                // Get raw results
+                // Get raw results. Pass the original-case `query` (not
+                // `query_lower`) through to scoring so `score_item`'s
+                // subsequence tier can apply smart-case matching.
                 let raw_results = if let Some(ref crate_filter) = options.crate_filter {
                     // Search within specific crate
-                    self.search_in_crate(&cache, crate_filter, &query_lower, options)?
+                    self.search_in_crate(&cache, crate_filter, query, options)?
                 } else {
                     // Search across all deps
-                    self.search_all(&cache, &query_lower, options)?
+                    self.search_all(&cache, query, options)?
                 };
 
                 all_results = raw_results;
             }
         }
 
-        // If we have few results and fuzzy is enabled, try fuzzy matching
-        if all_results.len() < 5 && options.fuzzy {
-            let fuzzy_results = self.fuzzy_search(&query_lower, options)?;
+        // Always try fuzzy matching when enabled - now that fuzzy streams a
+        // Levenshtein automaton over the symbol index in one pass instead
+        // of scanning every dep, it no longer needs gating on a low result
+        // count to stay cheap.
+        if options.fuzzy {
+            let fuzzy_results = self.fuzzy_search(query, options)?;
 
             // Add fuzzy results that aren't already in all_results
             let existing: HashSet<(String, String)> = all_results
@@ -224,8 +447,18 @@ impl SearchEngine {
             }
         }
 
-        // Sort by score (descending), then by path
-        all_results.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.path.cmp(&b.path)));
+        // Apply the ranking-rule pipeline in priority order, falling back to
+        // path for a deterministic order once every rule ties.
+        let ranking_ctx = self.build_ranking_context(&all_results);
+        all_results.sort_by(|a, b| {
+            for rule in &options.ranking_rules {
+                let ordering = rule.as_rule().compare(&ranking_ctx, a, b);
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            a.path.cmp(&b.path)
+        });
 
         // Build suggestions if few results
         let suggestions = if all_results.len() < 3 {
@@ -271,12 +504,15 @@ impl SearchEngine {
     fn find_related_crates(&self, query: &str) -> Vec<RelatedCrate> {
         let mut related = Vec::new();
         let query_prefix = format!("{}_", query);
+        let query_dep = self.all_deps.iter().find(|d| d.name == query);
 
         for dep in &self.all_deps {
             let relationship = if dep.name == query {
                 Some(CrateRelationship::Direct)
             } else if dep.name.starts_with(&query_prefix) {
                 Some(CrateRelationship::Prefix)
+            } else if query_dep.is_some_and(|q| self.reexports_from(q, &dep.name)) {
+                Some(CrateRelationship::ReExport)
             } else {
                 None
             };
@@ -286,7 +522,9 @@ impl SearchEngine {
                 let item_count = if Cache::exists() {
                     Cache::open_existing()
                         .ok()
-                        .and_then(|cache| cache.search_crate(&dep.name, Some(&dep.version)).ok())
+                        .and_then(|cache| {
+                            cache.search_crate(&dep.name, Some(&dep.version), None).ok()
+                        })
                         .map(|items| items.len())
                         .unwrap_or(0)
                 } else {
@@ -313,6 +551,46 @@ impl SearchEngine {
         related
     }
 
+    /// Whether `dep`'s own `src/lib.rs` re-exports `other_crate` wholesale
+    /// (`pub use other_crate::...`). Unlike `is_reexport_crate`, which asks
+    /// "is this crate *mostly* re-exports", this asks a narrower question -
+    /// "does this specific crate re-export that specific other crate" - so
+    /// it's a plain textual scan rather than an indexed-item check.
+    fn reexports_from(&self, dep: &RegistryCrate, other_crate: &str) -> bool {
+        let lib_path = dep.path.join("src/lib.rs");
+        let Ok(content) = std::fs::read_to_string(&lib_path) else {
+            return false;
+        };
+        let needle = format!("pub use {}::", other_crate);
+        let extern_needle = format!("pub extern crate {}", other_crate);
+        content.lines().any(|l| {
+            l.trim_start().starts_with(&needle) || l.trim_start().starts_with(&extern_needle)
+        })
+    }
+
+    /// The shortest path a caller would actually type to import `item_path`,
+    /// following `pub use` re-export edges recorded in `cache`. `None` if no
+    /// shorter alias exists, or the canonical path is `item_path` itself.
+    fn canonical_import(&self, cache: &Cache, item_path: &str) -> Option<String> {
+        let canonical = import_map::canonical_path(cache, item_path)?;
+        if canonical == item_path {
+            None
+        } else {
+            Some(canonical)
+        }
+    }
+
+    /// Public entry point for resolving the shortest import path of a known
+    /// item, for callers (e.g. the CLI `peek` command) that have a crate and
+    /// item path but no `SearchEngine` search result in hand.
+    pub fn resolve_import_path(&self, crate_name: &str, item_path: &str) -> Option<String> {
+        if !item_path.starts_with(crate_name) {
+            return None;
+        }
+        let cache = Cache::open_existing().ok()?;
+        self.canonical_import(&cache, item_path)
+    }
+
     /// Search within a specific crate.
     fn search_in_crate(
         &self,
@@ -322,7 +600,7 @@ impl SearchEngine {
         options: &SearchOptions,
     ) -> Result<Vec<ScoredResult>, String> {
         let items = cache
-            .search_crate(crate_name, None)
+            .search_crate(crate_name, None, None)
             .map_err(|e| e.to_string())?;
 
         let is_direct = self.direct_deps.contains(crate_name);
@@ -337,8 +615,9 @@ impl SearchEngine {
                     }
                 }
 
-                let (score, match_type) = self.score_item(&item.path, query);
+                let (score, match_type, term_offsets) = self.score_item(&item.path, query);
                 if score > 0 {
+                    let canonical_import = self.canonical_import(cache, &item.path);
                     Some(ScoredResult {
                         crate_name: crate_name.to_string(),
                         crate_version: String::new(), // TODO: get from cache
@@ -348,6 +627,8 @@ impl SearchEngine {
                         score: if is_direct { score + 10 } else { score },
                         is_direct_dep: is_direct,
                         match_type,
+                        canonical_import,
+                        term_offsets,
                     })
                 } else {
                     None
@@ -366,7 +647,7 @@ impl SearchEngine {
         options: &SearchOptions,
     ) -> Result<Vec<ScoredResult>, String> {
         // Use FTS search for efficiency
-        let raw_results = cache.search(query).map_err(|e| e.to_string())?;
+        let raw_results = cache.search(query, None).map_err(|e| e.to_string())?;
 
         // Build dep set for filtering
         let dep_set: HashSet<_> = self
@@ -393,7 +674,8 @@ impl SearchEngine {
             })
             .map(|r| {
                 let is_direct = self.direct_deps.contains(&r.crate_name);
-                let (score, match_type) = self.score_item(&r.path, query);
+                let (score, match_type, term_offsets) = self.score_item(&r.path, query);
+                let canonical_import = self.canonical_import(cache, &r.path);
                 ScoredResult {
                     crate_name: r.crate_name,
                     crate_version: r.crate_version,
@@ -403,6 +685,8 @@ impl SearchEngine {
                     score: if is_direct { score + 10 } else { score },
                     is_direct_dep: is_direct,
                     match_type,
+                    canonical_import,
+                    term_offsets,
                 }
             })
             .collect();
@@ -410,54 +694,244 @@ impl SearchEngine {
         Ok(results)
     }
 
-    /// Score an item path against a query.
-    fn score_item(&self, path: &str, query: &str) -> (u32, MatchType) {
+    /// Score an item path against a query. A query with more than one
+    /// whitespace-separated term (e.g. `async read`) is handled by
+    /// `score_multi_term` instead of the single-token tiers below, so a
+    /// descriptive phrase can match terms scattered across the path (a
+    /// module name and an item name) rather than needing one contiguous
+    /// fragment.
+    fn score_item(&self, path: &str, query: &str) -> (u32, MatchType, Vec<usize>) {
+        let terms: Vec<&str> = query.split_whitespace().collect();
+        if terms.len() > 1 {
+            return self.score_multi_term(path, &terms);
+        }
+
         let path_lower = path.to_lowercase();
         let query_lower = query.to_lowercase();
 
         // Extract the item name (last component)
         let item_name = path_lower.split("::").last().unwrap_or(&path_lower);
+        // Original-case item name, kept separately so the subsequence tier
+        // below can see camelCase word boundaries and apply smart-case.
+        let item_name_original = path.split("::").last().unwrap_or(path);
 
         // Exact match on item name
         if item_name == query_lower {
-            return (100, MatchType::Exact);
+            return (100, MatchType::Exact, Vec::new());
         }
 
         // Prefix match
         if item_name.starts_with(&query_lower) {
             let score = 80 - (item_name.len() - query_lower.len()).min(20) as u32;
-            return (score, MatchType::Prefix);
+            return (score, MatchType::Prefix, Vec::new());
         }
 
         // Contains match
         if item_name.contains(&query_lower) {
-            return (50, MatchType::Contains);
+            return (50, MatchType::Contains, Vec::new());
         }
 
         // Full path contains
         if path_lower.contains(&query_lower) {
-            return (30, MatchType::Contains);
+            return (30, MatchType::Contains, Vec::new());
         }
 
-        (0, MatchType::Contains)
+        // Ordered-subsequence match (not contiguous, e.g. `dst_fog` against
+        // `DistanceFog`). CharBag-reject first since most candidates share no
+        // characters with the query at all.
+        if CharBag::from_str(&query_lower).is_subset_of(CharBag::from_str(item_name)) {
+            if let Some(raw) = score_subsequence(query, item_name_original) {
+                let score = subsequence_score_to_u32(raw);
+                return (score, MatchType::Subsequence { score }, Vec::new());
+            }
+        }
+
+        (0, MatchType::Contains, Vec::new())
     }
 
-    /// Perform fuzzy search using edit distance.
+    /// Score a multi-term query: every term must appear somewhere in `path`
+    /// (case-insensitively), or the item doesn't match at all. Terms that do
+    /// all appear are scored by `proximity_score` on how close together and
+    /// in query order they land.
+    fn score_multi_term(&self, path: &str, terms: &[&str]) -> (u32, MatchType, Vec<usize>) {
+        let path_lower = path.to_lowercase();
+        let mut offsets = Vec::with_capacity(terms.len());
+
+        for term in terms {
+            match path_lower.find(&term.to_lowercase()) {
+                Some(offset) => offsets.push(offset),
+                None => return (0, MatchType::Contains, Vec::new()),
+            }
+        }
+
+        let proximity = Self::proximity_score(terms, &offsets);
+        let match_type = MatchType::MultiTerm {
+            matched: terms.len(),
+            proximity,
+        };
+        (proximity, match_type, offsets)
+    }
+
+    /// How close together and in-order `terms` land at `offsets` within a
+    /// path: 100 if every term appears back-to-back in query order, less for
+    /// larger gaps between consecutive terms, and capped at 50 if any pair
+    /// is out of order (e.g. the query's second term appears before its
+    /// first in the path).
+    fn proximity_score(terms: &[&str], offsets: &[usize]) -> u32 {
+        let in_order = offsets.windows(2).all(|pair| pair[0] <= pair[1]);
+        let gap_sum: usize = (0..offsets.len().saturating_sub(1))
+            .map(|i| {
+                let end_of_term = offsets[i] + terms[i].len();
+                offsets[i + 1].saturating_sub(end_of_term)
+            })
+            .sum();
+
+        let base: u32 = if in_order { 100 } else { 50 };
+        base.saturating_sub((gap_sum as u32).min(base))
+    }
+
+    /// Bounded-prefix fast path for a qualified query: looks up `query`'s
+    /// range in the symbol index's FQN-keyed map instead of scanning every
+    /// cached item, then runs the normal scorer only over that window.
+    fn fqn_prefix_search(
+        &self,
+        index: &SymbolIndex,
+        query: &str,
+        options: &SearchOptions,
+    ) -> Vec<ScoredResult> {
+        let postings = index.lookup_fqn_prefix(query);
+        let cache = Cache::open_existing().ok();
+
+        postings
+            .into_iter()
+            .filter(|p| {
+                if let Some(ref filter) = options.crate_filter {
+                    if &p.crate_name != filter {
+                        return false;
+                    }
+                }
+                if options.direct_only && !self.direct_deps.contains(&p.crate_name) {
+                    return false;
+                }
+                if let Some(ref kind) = options.kind_filter {
+                    if !p.kind.eq_ignore_ascii_case(kind) {
+                        return false;
+                    }
+                }
+                true
+            })
+            .map(|p| {
+                let is_direct = self.direct_deps.contains(&p.crate_name);
+                let (score, match_type, term_offsets) = self.score_item(&p.path, query);
+                let canonical_import = cache
+                    .as_ref()
+                    .and_then(|cache| self.canonical_import(cache, &p.path));
+                ScoredResult {
+                    crate_name: p.crate_name.clone(),
+                    crate_version: p.crate_version.clone(),
+                    path: p.path.clone(),
+                    kind: p.kind.clone(),
+                    signature: p.signature.clone(),
+                    score: if is_direct { score + 10 } else { score },
+                    is_direct_dep: is_direct,
+                    match_type,
+                    canonical_import,
+                    term_offsets,
+                }
+            })
+            .collect()
+    }
+
+    /// Perform fuzzy search: stream the symbol index's Levenshtein
+    /// automaton when an index is available, otherwise fall back to a
+    /// per-dep scan that CharBag-prefilters candidates (Zed's fuzzy-finder
+    /// approach). Either way a candidate is kept only if `score_subsequence`
+    /// finds the query as an ordered subsequence of its item name.
     fn fuzzy_search(
         &self,
         query: &str,
         options: &SearchOptions,
     ) -> Result<Vec<ScoredResult>, String> {
-        let mut results = Vec::new();
-
         if !Cache::exists() {
-            return Ok(results);
+            return Ok(Vec::new());
         }
 
+        match &self.symbol_index {
+            Some(index) => self.fuzzy_search_indexed(index, query, options),
+            // No persisted index yet (e.g. first run before a cache
+            // build has completed) - fall back to the per-dep scan.
+            None => self.fuzzy_search_scan(query, options),
+        }
+    }
+
+    /// Fuzzy search via one streamed pass over the symbol index's
+    /// Levenshtein automaton, instead of looping over every dep.
+    fn fuzzy_search_indexed(
+        &self,
+        index: &SymbolIndex,
+        query: &str,
+        options: &SearchOptions,
+    ) -> Result<Vec<ScoredResult>, String> {
+        let postings = index
+            .lookup_fuzzy(query, FUZZY_MAX_EDIT_DISTANCE)
+            .map_err(|e| e.to_string())?;
+        let cache = Cache::open_existing().ok();
+
+        let results = postings
+            .into_iter()
+            .filter(|p| {
+                if let Some(ref filter) = options.crate_filter {
+                    if &p.crate_name != filter {
+                        return false;
+                    }
+                }
+                if options.direct_only && !self.direct_deps.contains(&p.crate_name) {
+                    return false;
+                }
+                if let Some(ref kind) = options.kind_filter {
+                    if !p.kind.eq_ignore_ascii_case(kind) {
+                        return false;
+                    }
+                }
+                true
+            })
+            .filter_map(|p| {
+                let item_name = p.path.split("::").last().unwrap_or(&p.path);
+                let raw = score_subsequence(query, item_name)?;
+                let score = subsequence_score_to_u32(raw);
+                let canonical_import = cache
+                    .as_ref()
+                    .and_then(|cache| self.canonical_import(cache, &p.path));
+                Some(ScoredResult {
+                    crate_name: p.crate_name.clone(),
+                    crate_version: p.crate_version.clone(),
+                    path: p.path.clone(),
+                    kind: p.kind.clone(),
+                    signature: p.signature.clone(),
+                    score,
+                    is_direct_dep: self.direct_deps.contains(&p.crate_name),
+                    match_type: MatchType::Subsequence { score },
+                    canonical_import,
+                    term_offsets: Vec::new(),
+                })
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    /// Per-dep CharBag-prefiltered subsequence scan, used only when no
+    /// symbol index is available yet.
+    fn fuzzy_search_scan(
+        &self,
+        query: &str,
+        options: &SearchOptions,
+    ) -> Result<Vec<ScoredResult>, String> {
+        let mut results = Vec::new();
+
         let cache = Cache::open_existing().map_err(|e| e.to_string())?;
+        let query_bag = CharBag::from_str(query);
 
-        // Get all indexed items and check edit distance
-        // This is expensive, so we limit the scope
         for dep in &self.all_deps {
             if options.direct_only && !self.direct_deps.contains(&dep.name) {
                 continue;
@@ -469,13 +943,17 @@ impl SearchEngine {
                 }
             }
 
-            if let Ok(items) = cache.search_crate(&dep.name, Some(&dep.version)) {
+            if let Ok(items) = cache.search_crate(&dep.name, Some(&dep.version), None) {
                 for item in items {
                     let item_name = item.path.split("::").last().unwrap_or(&item.path);
-                    let distance = levenshtein(query, &item_name.to_lowercase());
 
-                    if distance <= options.max_edit_distance {
-                        let score = (100 - distance * 25).max(10) as u32;
+                    if !query_bag.is_subset_of(CharBag::from_str(item_name)) {
+                        continue;
+                    }
+
+                    if let Some(raw) = score_subsequence(query, item_name) {
+                        let score = subsequence_score_to_u32(raw);
+                        let canonical_import = self.canonical_import(&cache, &item.path);
                         results.push(ScoredResult {
                             crate_name: dep.name.clone(),
                             crate_version: dep.version.clone(),
@@ -484,7 +962,9 @@ impl SearchEngine {
                             signature: item.signature,
                             score,
                             is_direct_dep: self.direct_deps.contains(&dep.name),
-                            match_type: MatchType::Fuzzy { distance },
+                            match_type: MatchType::Subsequence { score },
+                            canonical_import,
+                            term_offsets: Vec::new(),
                         });
                     }
                 }
@@ -505,12 +985,70 @@ impl SearchEngine {
             }
         }
 
-        // Common typo corrections could go here
+        // Common typo corrections, via Levenshtein distance against the
+        // real dependency list.
+        for name in self.suggest_crate_names(query) {
+            let suggestion = format!("crate:{}", name);
+            if !suggestions.contains(&suggestion) {
+                suggestions.push(suggestion);
+            }
+        }
 
         suggestions.truncate(5);
         suggestions
     }
 
+    /// Dependency names within edit distance `max(1, query.len()/3)` of
+    /// `query` - closest first, alphabetical on ties - for "Did you mean?"
+    /// recovery when a crate-name lookup misses a typo. Case-insensitive.
+    pub fn suggest_crate_names(&self, query: &str) -> Vec<String> {
+        let query_lower = query.to_lowercase();
+        let threshold = (query_lower.len() / 3).max(1);
+
+        let mut candidates: Vec<(usize, &str)> = self
+            .all_deps
+            .iter()
+            .map(|dep| {
+                (
+                    levenshtein_distance(&query_lower, &dep.name.to_lowercase()),
+                    dep.name.as_str(),
+                )
+            })
+            .filter(|(distance, name)| *distance <= threshold && *name != query_lower)
+            .collect();
+
+        candidates.sort_by(|a, b| a.0.cmp(&b.0).then_with(|| a.1.cmp(b.1)));
+        candidates.truncate(5);
+        candidates
+            .into_iter()
+            .map(|(_, name)| name.to_string())
+            .collect()
+    }
+
+    /// Precompute the per-crate item counts `CrateItemCount` needs, once per
+    /// search rather than once per comparison, by looking up every distinct
+    /// crate name appearing in `results`.
+    fn build_ranking_context(&self, results: &[ScoredResult]) -> RankingContext {
+        let mut crate_item_counts = HashMap::new();
+
+        if let Ok(cache) = Cache::open_existing() {
+            let crate_names: HashSet<&str> =
+                results.iter().map(|r| r.crate_name.as_str()).collect();
+
+            for crate_name in crate_names {
+                if let Some(dep) = self.all_deps.iter().find(|d| d.name == crate_name) {
+                    let count = cache
+                        .search_crate(&dep.name, Some(&dep.version), None)
+                        .map(|items| items.len())
+                        .unwrap_or(0);
+                    crate_item_counts.insert(crate_name.to_string(), count);
+                }
+            }
+        }
+
+        RankingContext { crate_item_counts }
+    }
+
     /// Get crate info for peek command (detects re-export crates).
     pub fn get_crate_info(&self, crate_name: &str) -> Result<CrateInfo, String> {
         let dep = self
@@ -520,13 +1058,17 @@ impl SearchEngine {
             .ok_or_else(|| format!("Crate '{}' not found", crate_name))?;
 
         // Check if this is a re-export crate by looking at lib.rs
-        let is_reexport = self.detect_reexport_crate(dep);
+        let is_reexport = self.is_reexport_crate(dep);
 
         // Get item count
         let item_count = if Cache::exists() {
             Cache::open_existing()
                 .ok()
-                .and_then(|cache| cache.search_crate(crate_name, Some(&dep.version)).ok())
+                .and_then(|cache| {
+                    cache
+                        .search_crate(crate_name, Some(&dep.version), None)
+                        .ok()
+                })
                 .map(|items| items.len())
                 .unwrap_or(0)
         } else {
@@ -547,34 +1089,27 @@ impl SearchEngine {
         })
     }
 
-    /// Detect if a crate is a thin re-export wrapper.
-    fn detect_reexport_crate(&self, dep: &RegistryCrate) -> bool {
-        let lib_path = dep.path.join("src/lib.rs");
-        if let Ok(content) = std::fs::read_to_string(&lib_path) {
-            // Check for patterns like `pub use other_crate::*;` or re-export patterns
-            let lines: Vec<_> = content
-                .lines()
-                .filter(|l| {
-                    !l.trim().starts_with("//")
-                        && !l.trim().starts_with("#")
-                        && !l.trim().is_empty()
-                })
-                .collect();
+    /// Detect if a crate is a thin re-export wrapper: most of its indexed
+    /// items are themselves `pub use` re-exports (real `RelationKind::
+    /// ReExportOf` edges from the cache), rather than guessing from a raw
+    /// line-count ratio over `lib.rs` the way this used to work.
+    fn is_reexport_crate(&self, dep: &RegistryCrate) -> bool {
+        let Ok(cache) = Cache::open_existing() else {
+            return false;
+        };
+        let Ok(items) = cache.search_crate(&dep.name, Some(&dep.version), None) else {
+            return false;
+        };
+        if items.is_empty() {
+            return false;
+        }
 
-            // If the file is very short and mostly re-exports, it's a wrapper
-            if lines.len() < 20 {
-                let reexport_count = lines
-                    .iter()
-                    .filter(|l| l.contains("pub use") || l.contains("pub extern crate"))
-                    .count();
+        let reexport_count = items
+            .iter()
+            .filter(|item| cache.is_reexport(&item.path).unwrap_or(false))
+            .count();
 
-                // If more than half the code lines are re-exports
-                if reexport_count > 0 && reexport_count >= lines.len() / 2 {
-                    return true;
-                }
-            }
-        }
-        false
+        reexport_count * 2 >= items.len()
     }
 }
 
@@ -590,44 +1125,173 @@ pub struct CrateInfo {
     pub related_crates: Vec<RelatedCrate>,
 }
 
-/// Levenshtein edit distance for fuzzy matching.
-fn levenshtein(a: &str, b: &str) -> usize {
-    let a_chars: Vec<_> = a.chars().collect();
-    let b_chars: Vec<_> = b.chars().collect();
-
-    let m = a_chars.len();
-    let n = b_chars.len();
-
-    if m == 0 {
-        return n;
+/// A cheap bitmask of which characters appear in a string (lowercased,
+/// folding digits and `_` into their own bits), used to reject most fuzzy
+/// candidates before the more expensive subsequence DP runs. Modeled on
+/// Zed's `CharBag` fuzzy-finder prefilter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+struct CharBag(u64);
+
+impl CharBag {
+    fn from_str(s: &str) -> Self {
+        let mut bits = 0u64;
+        for c in s.chars() {
+            if let Some(bit) = Self::bit_for(c) {
+                bits |= 1 << bit;
+            }
+        }
+        CharBag(bits)
     }
-    if n == 0 {
-        return m;
+
+    fn bit_for(c: char) -> Option<u32> {
+        match c.to_ascii_lowercase() {
+            c @ 'a'..='z' => Some(c as u32 - 'a' as u32),
+            c @ '0'..='9' => Some(26 + (c as u32 - '0' as u32)),
+            '_' => Some(36),
+            _ => None,
+        }
     }
 
-    let mut dp = vec![vec![0; n + 1]; m + 1];
+    /// True if every character in `self` is also present in `other` - a
+    /// necessary (not sufficient) condition for `self`'s string to be an
+    /// ordered subsequence of `other`'s.
+    fn is_subset_of(self, other: CharBag) -> bool {
+        self.0 & other.0 == self.0
+    }
+}
 
-    for i in 0..=m {
-        dp[i][0] = i;
+/// Tuning for `score_subsequence`'s gap penalty: consecutive matched
+/// characters are penalized by `max(MIN_DISTANCE_PENALTY, BASE_DISTANCE_PENALTY
+/// - gap * ADDITIONAL_DISTANCE_PENALTY)`, where `gap` is the number of
+/// unmatched candidate characters between them.
+const MIN_DISTANCE_PENALTY: f64 = 0.2;
+const BASE_DISTANCE_PENALTY: f64 = 0.6;
+const ADDITIONAL_DISTANCE_PENALTY: f64 = 0.05;
+
+/// Classic Levenshtein edit distance via the `(m+1)x(n+1)` DP table: the
+/// fewest single-character insertions, deletions, or substitutions to turn
+/// `a` into `b`.
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    let (m, n) = (a.len(), b.len());
+
+    let mut d = vec![vec![0usize; n + 1]; m + 1];
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
     }
     for j in 0..=n {
-        dp[0][j] = j;
+        d[0][j] = j;
     }
 
     for i in 1..=m {
         for j in 1..=n {
-            let cost = if a_chars[i - 1] == b_chars[j - 1] {
-                0
-            } else {
-                1
-            };
-            dp[i][j] = (dp[i - 1][j] + 1)
-                .min(dp[i][j - 1] + 1)
-                .min(dp[i - 1][j - 1] + cost);
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+            d[i][j] = (d[i - 1][j] + 1)
+                .min(d[i][j - 1] + 1)
+                .min(d[i - 1][j - 1] + cost);
         }
     }
 
-    dp[m][n]
+    d[m][n]
+}
+
+/// True if `candidate[idx]` starts a "word" - the start of the string, right
+/// after a `_`/`-` separator, or a lowercase-to-uppercase camelCase
+/// transition. Matches landing on a word boundary score higher, since they
+/// read as an intentional abbreviation (e.g. the `D` and `F` in `DistanceFog`).
+fn is_word_boundary(chars: &[char], idx: usize) -> bool {
+    idx == 0
+        || matches!(chars[idx - 1], '_' | '-')
+        || (chars[idx - 1].is_lowercase() && chars[idx].is_uppercase())
+}
+
+/// Zed-style ordered-subsequence matcher: every character of `query` must
+/// appear in `candidate`, in order but not necessarily contiguously. Returns
+/// `None` if `query` isn't a subsequence of `candidate`, otherwise the best
+/// score found by a DP over (query position, candidate position) that
+/// starts from a base of 1.0 per matched character and rewards contiguous
+/// and word-boundary matches while penalizing gaps between matches.
+///
+/// Case sensitivity is "smart case": matching is case-sensitive if `query`
+/// contains any uppercase letter, case-insensitive otherwise.
+fn score_subsequence(query: &str, candidate: &str) -> Option<f64> {
+    if query.is_empty() {
+        return Some(1.0);
+    }
+
+    let case_sensitive = query.chars().any(|c| c.is_uppercase());
+    let query_chars: Vec<char> = query.chars().collect();
+    let candidate_chars: Vec<char> = candidate.chars().collect();
+    let n = query_chars.len();
+    let m = candidate_chars.len();
+    if m < n {
+        return None;
+    }
+
+    let chars_match = |q: char, c: char| -> bool {
+        if case_sensitive {
+            q == c
+        } else {
+            q.eq_ignore_ascii_case(&c)
+        }
+    };
+
+    // dp[j] = best score matching the first `i` query characters with the
+    // i-th one landing at candidate index `j` (None if unreachable).
+    let mut dp: Vec<Option<f64>> = (0..m)
+        .map(|j| {
+            chars_match(query_chars[0], candidate_chars[j])
+                .then(|| boundary_multiplier(&candidate_chars, j))
+        })
+        .collect();
+
+    for query_char in &query_chars[1..] {
+        let mut next: Vec<Option<f64>> = vec![None; m];
+        for j in 0..m {
+            if !chars_match(*query_char, candidate_chars[j]) {
+                continue;
+            }
+            let mut best: Option<f64> = None;
+            for (k, prev) in dp.iter().enumerate().take(j) {
+                let Some(prev_score) = prev else { continue };
+                let gap = (j - k - 1) as f64;
+                let gap_penalty = (BASE_DISTANCE_PENALTY - gap * ADDITIONAL_DISTANCE_PENALTY)
+                    .max(MIN_DISTANCE_PENALTY);
+                let contiguous_bonus = if j == k + 1 { 1.5 } else { 1.0 };
+                let candidate_score = prev_score
+                    * gap_penalty
+                    * contiguous_bonus
+                    * boundary_multiplier(&candidate_chars, j);
+                best = Some(best.map_or(candidate_score, |b: f64| b.max(candidate_score)));
+            }
+            next[j] = best;
+        }
+        dp = next;
+    }
+
+    dp.into_iter()
+        .flatten()
+        .fold(None, |acc, s| Some(acc.map_or(s, |a: f64| a.max(s))))
+}
+
+/// Per-match bonus multiplier for landing on a word boundary; see
+/// `is_word_boundary`.
+fn boundary_multiplier(chars: &[char], idx: usize) -> f64 {
+    if is_word_boundary(chars, idx) {
+        2.0
+    } else {
+        1.0
+    }
+}
+
+/// Map a `score_subsequence` result into the `u32` range the rest of the
+/// scoring pipeline uses. Per-character bonuses compound multiplicatively,
+/// so a `sqrt` compresses long contiguous/boundary-aligned matches back
+/// down instead of letting them grow unbounded; the result is clamped below
+/// `Exact`/`Prefix` matches' scores so plain substring hits still win ties.
+fn subsequence_score_to_u32(raw: f64) -> u32 {
+    (raw.sqrt() * 20.0).clamp(1.0, 90.0).round() as u32
 }
 
 #[cfg(test)]
@@ -635,11 +1299,50 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_levenshtein() {
-        assert_eq!(levenshtein("kitten", "sitting"), 3);
-        assert_eq!(levenshtein("", "abc"), 3);
-        assert_eq!(levenshtein("abc", "abc"), 0);
-        assert_eq!(levenshtein("DistnceFog", "DistanceFog"), 1);
+    fn test_char_bag_subset() {
+        let query = CharBag::from_str("dstfog");
+        assert!(query.is_subset_of(CharBag::from_str("distancefog")));
+        assert!(!query.is_subset_of(CharBag::from_str("distance")));
+    }
+
+    #[test]
+    fn test_score_subsequence_rejects_out_of_order() {
+        assert_eq!(score_subsequence("fog", "goof"), None);
+    }
+
+    #[test]
+    fn test_score_subsequence_matches_camel_case_abbreviation() {
+        // dst_fog -> DistanceFog: non-contiguous, but every query char
+        // appears in the candidate in order.
+        assert!(score_subsequence("dstfog", "distancefog").is_some());
+    }
+
+    #[test]
+    fn test_score_subsequence_contiguous_scores_higher_than_scattered() {
+        let contiguous = score_subsequence("dist", "distancefog").unwrap();
+        let scattered = score_subsequence("dsfg", "distancefog").unwrap();
+        assert!(contiguous > scattered);
+    }
+
+    #[test]
+    fn test_score_subsequence_smart_case() {
+        // Uppercase in the query makes matching case-sensitive.
+        assert_eq!(score_subsequence("Fog", "fog"), None);
+        assert!(score_subsequence("fog", "Fog").is_some());
+    }
+
+    #[test]
+    fn test_score_item_falls_back_to_subsequence_match() {
+        let engine = SearchEngine {
+            project_dir: Utf8PathBuf::from("."),
+            direct_deps: HashSet::new(),
+            all_deps: vec![],
+            symbol_index: None,
+        };
+
+        let (score, match_type, _offsets) = engine.score_item("fog::DistanceFog", "dstfog");
+        assert!(matches!(match_type, MatchType::Subsequence { .. }));
+        assert!(score > 0);
     }
 
     #[test]
@@ -648,17 +1351,115 @@ mod tests {
             project_dir: Utf8PathBuf::from("."),
             direct_deps: HashSet::new(),
             all_deps: vec![],
+            symbol_index: None,
         };
 
-        let (score, match_type) = engine.score_item("serde::Serialize", "serialize");
+        let (score, match_type, _offsets) = engine.score_item("serde::Serialize", "serialize");
         assert_eq!(match_type, MatchType::Exact);
         assert_eq!(score, 100);
 
-        let (score, match_type) = engine.score_item("serde::Serializer", "serial");
+        let (score, match_type, _offsets) = engine.score_item("serde::Serializer", "serial");
         assert_eq!(match_type, MatchType::Prefix);
         assert!(score > 50);
 
-        let (score, match_type) = engine.score_item("serde::de::Deserialize", "serial");
+        let (score, match_type, _offsets) = engine.score_item("serde::de::Deserialize", "serial");
+        assert_eq!(match_type, MatchType::Contains);
+    }
+
+    #[test]
+    fn test_score_item_multi_term_requires_every_term() {
+        let engine = SearchEngine {
+            project_dir: Utf8PathBuf::from("."),
+            direct_deps: HashSet::new(),
+            all_deps: vec![],
+            symbol_index: None,
+        };
+
+        let (score, match_type, offsets) = engine.score_item("tokio::io::async_read", "async read");
+        assert!(matches!(
+            match_type,
+            MatchType::MultiTerm { matched: 2, .. }
+        ));
+        assert!(score > 0);
+        assert_eq!(offsets.len(), 2);
+
+        let (score, match_type, _offsets) =
+            engine.score_item("tokio::io::async_read", "async missing");
+        assert_eq!(score, 0);
         assert_eq!(match_type, MatchType::Contains);
     }
+
+    #[test]
+    fn test_proximity_score_rewards_adjacent_in_order_terms() {
+        let close = SearchEngine::proximity_score(&["async", "read"], &[0, 6]);
+        let far = SearchEngine::proximity_score(&["async", "read"], &[0, 40]);
+        let out_of_order = SearchEngine::proximity_score(&["async", "read"], &[6, 0]);
+        assert!(close > far);
+        assert!(close > out_of_order);
+    }
+
+    fn dummy_result(match_type: MatchType, is_direct_dep: bool) -> ScoredResult {
+        ScoredResult {
+            crate_name: "serde".to_string(),
+            crate_version: "1.0.0".to_string(),
+            path: "serde::Serialize".to_string(),
+            kind: "trait".to_string(),
+            signature: None,
+            score: 0,
+            is_direct_dep,
+            match_type,
+            canonical_import: None,
+            term_offsets: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_exactness_rule_prefers_exact_over_prefix() {
+        let ctx = RankingContext::default();
+        let exact = dummy_result(MatchType::Exact, false);
+        let prefix = dummy_result(MatchType::Prefix, false);
+        assert_eq!(Exactness.compare(&ctx, &exact, &prefix), Ordering::Less);
+        assert_eq!(Exactness.compare(&ctx, &prefix, &exact), Ordering::Greater);
+    }
+
+    #[test]
+    fn test_direct_dep_rule_prefers_direct() {
+        let ctx = RankingContext::default();
+        let direct = dummy_result(MatchType::Contains, true);
+        let transitive = dummy_result(MatchType::Contains, false);
+        assert_eq!(
+            DirectDep.compare(&ctx, &direct, &transitive),
+            Ordering::Less
+        );
+    }
+
+    #[test]
+    fn test_levenshtein_distance() {
+        assert_eq!(levenshtein_distance("serde", "serde"), 0);
+        assert_eq!(levenshtein_distance("serde", "serd"), 1);
+        assert_eq!(levenshtein_distance("tokio", "tokino"), 1);
+        assert_eq!(levenshtein_distance("kitten", "sitting"), 3);
+    }
+
+    fn dummy_dep(name: &str) -> RegistryCrate {
+        RegistryCrate {
+            name: name.to_string(),
+            version: "1.0.0".to_string(),
+            path: Utf8PathBuf::from("."),
+            registry: None,
+        }
+    }
+
+    #[test]
+    fn test_suggest_crate_names_within_threshold() {
+        let engine = SearchEngine {
+            project_dir: Utf8PathBuf::from("."),
+            direct_deps: HashSet::new(),
+            all_deps: vec![dummy_dep("serde"), dummy_dep("tokio"), dummy_dep("rand")],
+            symbol_index: None,
+        };
+
+        assert_eq!(engine.suggest_crate_names("serd"), vec!["serde"]);
+        assert!(engine.suggest_crate_names("xyzxyz").is_empty());
+    }
 }
@@ -0,0 +1,148 @@
+//! Pluggable storage backends for the crate-symbol cache.
+//!
+//! `Cache` (in `cache.rs`) delegates every read and write to whichever
+//! `CacheBackend` it was opened with. The default, and the only one with
+//! real relevance ranking, is SQLite + FTS5 (`sqlite::SqliteBackend`).
+//! Setting `FASTDEPS_BACKEND=redb` switches to an embedded `redb`
+//! key-value store (`redb::RedbBackend`) instead, trading that BM25
+//! ranking and arbitrary SQL for the better concurrent-read behavior
+//! redb's MVCC model gives read-heavy CI.
+
+pub mod redb;
+pub mod sqlite;
+
+use crate::cache::{CacheError, CacheStats, CachedItem, ExportedCrate, SearchResult};
+use crate::cargo::RegistryCrate;
+use crate::schema::Item;
+use camino::Utf8PathBuf;
+use std::collections::{HashMap, HashSet};
+
+const CACHE_DIR: &str = ".fastdeps";
+
+/// Operations `Cache` needs from a storage engine. Implement this once per
+/// backend and `Cache` itself stays storage-agnostic.
+pub trait CacheBackend {
+    fn is_indexed(&self, name: &str, version: &str) -> Result<bool, CacheError>;
+    fn get_indexed_set(&self) -> Result<HashSet<String>, CacheError>;
+    /// Stored `source_fingerprint` for every indexed crate, keyed by
+    /// `"name@version"`, used to detect on-disk changes to path/patch
+    /// dependencies between indexing runs.
+    fn get_fingerprints(&self) -> Result<HashMap<String, String>, CacheError>;
+    fn batch_index(&self, batch: &[(RegistryCrate, Vec<Item>, String)]) -> Result<(), CacheError>;
+    /// `feature` restricts results to items gated behind that feature name.
+    fn search(&self, query: &str, feature: Option<&str>) -> Result<Vec<SearchResult>, CacheError>;
+    fn search_crate(
+        &self,
+        crate_name: &str,
+        crate_version: Option<&str>,
+        feature: Option<&str>,
+    ) -> Result<Vec<CachedItem>, CacheError>;
+    fn list_indexed(&self) -> Result<Vec<(String, String)>, CacheError>;
+    /// Feature flags declared by `crate_name`'s manifest, as `(name,
+    /// subfeatures)` pairs.
+    fn list_features(&self, crate_name: &str) -> Result<Vec<(String, Vec<String>)>, CacheError>;
+    fn stats(&self) -> Result<CacheStats, CacheError>;
+    fn clear(&self) -> Result<(), CacheError>;
+
+    /// Every indexed crate's full stored data (fingerprint, declared
+    /// features, and items), for `Cache::export`'s portable dump.
+    fn export_all(&self) -> Result<Vec<ExportedCrate>, CacheError>;
+
+    /// Items that reference `path` (e.g. implement it as a trait, or
+    /// re-export it) - the "who uses this" side of the cross-reference
+    /// graph.
+    fn find_referrers(&self, path: &str) -> Result<Vec<SearchResult>, CacheError>;
+    /// Items `path` itself references, resolved back to their own
+    /// indexed declarations.
+    fn find_references_from(&self, path: &str) -> Result<Vec<SearchResult>, CacheError>;
+
+    /// Items that are a `pub use` re-export of `path` - the
+    /// `RelationKind::ReExportOf` subset of `find_referrers`, used by
+    /// `import_map::ImportMap` to find every alias an item is reachable
+    /// through.
+    fn find_reexports_of(&self, path: &str) -> Result<Vec<SearchResult>, CacheError>;
+    /// Whether `path` itself is a `pub use` re-export of something else
+    /// (has an outgoing `RelationKind::ReExportOf` edge).
+    fn is_reexport(&self, path: &str) -> Result<bool, CacheError>;
+
+    /// Items that `impl trait_path for ...` - the `RelationKind::Implements`
+    /// subset of `find_referrers`, used by the `impls` MCP tool's
+    /// trait-to-types direction. `trait_path` is matched as recorded by the
+    /// parser, i.e. already stripped of generic parameters.
+    fn find_implementors(&self, trait_path: &str) -> Result<Vec<SearchResult>, CacheError>;
+    /// Traits `type_path` implements, as raw trait-name strings rather than
+    /// `SearchResult`s - the implemented trait may not itself be an indexed
+    /// item (a foreign or std trait), so a join against `items` would
+    /// silently drop it.
+    fn find_implemented_traits(&self, type_path: &str) -> Result<Vec<String>, CacheError>;
+}
+
+/// Which backend to use, read from `FASTDEPS_BACKEND`. Unset or
+/// unrecognized values fall back to SQLite.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum BackendKind {
+    Sqlite,
+    Redb,
+}
+
+impl BackendKind {
+    fn from_env() -> Self {
+        match std::env::var("FASTDEPS_BACKEND") {
+            Ok(v) if v.eq_ignore_ascii_case("redb") => BackendKind::Redb,
+            _ => BackendKind::Sqlite,
+        }
+    }
+
+    fn file_name(self) -> &'static str {
+        match self {
+            BackendKind::Sqlite => "cache.sqlite",
+            BackendKind::Redb => "cache.redb",
+        }
+    }
+}
+
+/// Path to the file backing whichever backend `FASTDEPS_BACKEND` selects.
+fn backend_path() -> Utf8PathBuf {
+    Utf8PathBuf::from(CACHE_DIR).join(BackendKind::from_env().file_name())
+}
+
+/// Whether the currently configured backend's file already exists.
+pub fn backend_exists() -> bool {
+    backend_path().exists()
+}
+
+/// Last-modified time of the backend file, used by `fst_index` to decide
+/// whether a persisted symbol index is stale relative to the cache it was
+/// built from. `None` if the backend file doesn't exist or its mtime can't
+/// be read.
+pub fn backend_mtime() -> Option<std::time::SystemTime> {
+    std::fs::metadata(backend_path()).ok()?.modified().ok()
+}
+
+/// Open (creating the cache directory and schema if necessary) whichever
+/// backend `FASTDEPS_BACKEND` currently selects.
+pub fn open_backend() -> Result<Box<dyn CacheBackend>, CacheError> {
+    let cache_dir = Utf8PathBuf::from(CACHE_DIR);
+    if !cache_dir.exists() {
+        std::fs::create_dir_all(&cache_dir)?;
+    }
+
+    match BackendKind::from_env() {
+        BackendKind::Sqlite => Ok(Box::new(sqlite::SqliteBackend::open(&backend_path())?)),
+        BackendKind::Redb => Ok(Box::new(redb::RedbBackend::open(&backend_path())?)),
+    }
+}
+
+/// Open an already-existing backend, erroring rather than creating one.
+pub fn open_existing_backend() -> Result<Box<dyn CacheBackend>, CacheError> {
+    if !backend_exists() {
+        return Err(CacheError::NotInitialized);
+    }
+
+    match BackendKind::from_env() {
+        BackendKind::Sqlite => Ok(Box::new(sqlite::SqliteBackend::open_existing(
+            &backend_path(),
+        )?)),
+        BackendKind::Redb => Ok(Box::new(redb::RedbBackend::open_existing(&backend_path())?)),
+    }
+}
@@ -0,0 +1,795 @@
+//! Embedded `redb` key-value backend. Trades the SQLite backend's BM25
+//! relevance ranking and arbitrary SQL for redb's MVCC concurrent-read
+//! model, which suits read-heavy CI better than a single-writer WAL file.
+//!
+//! Crates are keyed by `"name@version"`. Items are keyed by
+//! `"name@version\0item_path"` so a crate's items sort together and can
+//! be range-scanned with a `start..end` prefix bound - redb orders `&str`
+//! keys lexicographically, and `\0` sorts below every printable path
+//! character, so the bound never spills into the next crate's items.
+
+use super::CacheBackend;
+use crate::cache::{CacheError, CacheStats, CachedItem, ExportedCrate, ExportedItem, SearchResult};
+use crate::cargo::RegistryCrate;
+use crate::schema::Item;
+use camino::Utf8Path;
+use redb::{Database, ReadableTable, TableDefinition};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+const CRATES_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("crates");
+const ITEMS_TABLE: TableDefinition<&str, &[u8]> = TableDefinition::new("items");
+
+#[derive(Serialize, Deserialize)]
+struct CrateRecord {
+    path: String,
+    indexed_at: i64,
+    /// Stat-based content-freshness signal, mirroring the SQLite backend's
+    /// `crates.source_fingerprint` column.
+    #[serde(default)]
+    source_fingerprint: String,
+    /// Feature flags from the crate's own manifest, as `(name,
+    /// subfeatures)` pairs - the `redb` equivalent of the SQLite backend's
+    /// `features` table.
+    #[serde(default)]
+    features: Vec<(String, Vec<String>)>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct ItemRecord {
+    kind: String,
+    signature: Option<String>,
+    doc: Option<String>,
+    visibility: String,
+    /// Cross-reference edges from this item, as `(to_path, kind)` pairs -
+    /// the `redb` equivalent of the SQLite backend's `refs` table.
+    #[serde(default)]
+    related: Vec<(String, String)>,
+    /// Feature names gating this item, mirroring the SQLite backend's
+    /// `items.feature_gates` column.
+    #[serde(default)]
+    feature_gates: Vec<String>,
+}
+
+pub struct RedbBackend {
+    db: Database,
+    path: camino::Utf8PathBuf,
+}
+
+fn crate_key(name: &str, version: &str) -> String {
+    format!("{}@{}", name, version)
+}
+
+fn item_key(crate_key: &str, item_path: &str) -> String {
+    format!("{}\0{}", crate_key, item_path)
+}
+
+/// Exclusive `start..end` bound over every item key belonging to `crate_key`.
+fn item_range(crate_key: &str) -> (String, String) {
+    (format!("{}\0", crate_key), format!("{}\u{1}", crate_key))
+}
+
+impl RedbBackend {
+    /// Open or create the database at `path`, creating the tables if needed.
+    pub fn open(path: &Utf8Path) -> Result<Self, CacheError> {
+        let db = Database::create(path).map_err(|e| CacheError::Backend(e.into()))?;
+
+        let txn = db
+            .begin_write()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        {
+            txn.open_table(CRATES_TABLE)
+                .map_err(|e| CacheError::Backend(e.into()))?;
+            txn.open_table(ITEMS_TABLE)
+                .map_err(|e| CacheError::Backend(e.into()))?;
+        }
+        txn.commit().map_err(|e| CacheError::Backend(e.into()))?;
+
+        Ok(Self {
+            db,
+            path: path.to_owned(),
+        })
+    }
+
+    /// Open an existing database without creating anything.
+    pub fn open_existing(path: &Utf8Path) -> Result<Self, CacheError> {
+        let db = Database::open(path).map_err(|e| CacheError::Backend(e.into()))?;
+        Ok(Self {
+            db,
+            path: path.to_owned(),
+        })
+    }
+}
+
+impl CacheBackend for RedbBackend {
+    fn is_indexed(&self, name: &str, version: &str) -> Result<bool, CacheError> {
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let table = txn
+            .open_table(CRATES_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        Ok(table
+            .get(crate_key(name, version).as_str())
+            .map_err(|e| CacheError::Backend(e.into()))?
+            .is_some())
+    }
+
+    fn get_indexed_set(&self) -> Result<HashSet<String>, CacheError> {
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let table = txn
+            .open_table(CRATES_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+
+        let mut keys = HashSet::new();
+        for entry in table.iter().map_err(|e| CacheError::Backend(e.into()))? {
+            let (key, _) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+            keys.insert(key.value().to_string());
+        }
+        Ok(keys)
+    }
+
+    fn get_fingerprints(&self) -> Result<HashMap<String, String>, CacheError> {
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let table = txn
+            .open_table(CRATES_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+
+        let mut fingerprints = HashMap::new();
+        for entry in table.iter().map_err(|e| CacheError::Backend(e.into()))? {
+            let (key, value) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+            let record: CrateRecord =
+                serde_json::from_slice(value.value()).map_err(|e| CacheError::Backend(e.into()))?;
+            if !record.source_fingerprint.is_empty() {
+                fingerprints.insert(key.value().to_string(), record.source_fingerprint);
+            }
+        }
+        Ok(fingerprints)
+    }
+
+    fn batch_index(&self, batch: &[(RegistryCrate, Vec<Item>, String)]) -> Result<(), CacheError> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        let txn = self
+            .db
+            .begin_write()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        {
+            let mut crates_table = txn
+                .open_table(CRATES_TABLE)
+                .map_err(|e| CacheError::Backend(e.into()))?;
+            let mut items_table = txn
+                .open_table(ITEMS_TABLE)
+                .map_err(|e| CacheError::Backend(e.into()))?;
+
+            for (krate, items, fingerprint) in batch {
+                let ckey = crate_key(&krate.name, &krate.version);
+
+                // Drop any previously indexed items for this crate version
+                // before writing the fresh set.
+                let (start, end) = item_range(&ckey);
+                let stale: Vec<String> = items_table
+                    .range::<&str>(start.as_str()..end.as_str())
+                    .map_err(|e| CacheError::Backend(e.into()))?
+                    .map(|entry| entry.map(|(k, _)| k.value().to_string()))
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| CacheError::Backend(e.into()))?;
+                for key in stale {
+                    items_table
+                        .remove(key.as_str())
+                        .map_err(|e| CacheError::Backend(e.into()))?;
+                }
+
+                let record = CrateRecord {
+                    path: krate.path.as_str().to_string(),
+                    indexed_at: now,
+                    source_fingerprint: fingerprint.clone(),
+                    features: krate.features(),
+                };
+                let bytes =
+                    serde_json::to_vec(&record).map_err(|e| CacheError::Backend(e.into()))?;
+                crates_table
+                    .insert(ckey.as_str(), bytes.as_slice())
+                    .map_err(|e| CacheError::Backend(e.into()))?;
+
+                for item in items {
+                    let record = ItemRecord {
+                        kind: format!("{:?}", item.kind).to_lowercase(),
+                        signature: item.signature.clone(),
+                        doc: item.doc.clone(),
+                        visibility: format!("{:?}", item.visibility).to_lowercase(),
+                        related: item
+                            .related
+                            .iter()
+                            .map(|r| (r.path.clone(), format!("{:?}", r.kind).to_lowercase()))
+                            .collect(),
+                        feature_gates: item.feature_gates(),
+                    };
+                    let bytes =
+                        serde_json::to_vec(&record).map_err(|e| CacheError::Backend(e.into()))?;
+                    let ikey = item_key(&ckey, &item.path);
+                    items_table
+                        .insert(ikey.as_str(), bytes.as_slice())
+                        .map_err(|e| CacheError::Backend(e.into()))?;
+                }
+            }
+        }
+        txn.commit().map_err(|e| CacheError::Backend(e.into()))?;
+        Ok(())
+    }
+
+    /// Linear substring scan over every item. `redb` has no FTS5
+    /// equivalent, so this can't produce a real relevance score the way
+    /// the SQLite backend's BM25 ranking does - every match gets `score:
+    /// 0.0` and results keep whatever order the table iterates in.
+    fn search(&self, query: &str, feature: Option<&str>) -> Result<Vec<SearchResult>, CacheError> {
+        let needle = query.to_lowercase();
+
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let items_table = txn
+            .open_table(ITEMS_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let crates_table = txn
+            .open_table(CRATES_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+
+        let mut results = Vec::new();
+        for entry in items_table
+            .iter()
+            .map_err(|e| CacheError::Backend(e.into()))?
+        {
+            let (key, value) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+            let Some((ckey, item_path)) = key.value().split_once('\0') else {
+                continue;
+            };
+            if !item_path.to_lowercase().contains(&needle) {
+                continue;
+            }
+
+            let record: ItemRecord =
+                serde_json::from_slice(value.value()).map_err(|e| CacheError::Backend(e.into()))?;
+            if let Some(f) = feature {
+                if !record.feature_gates.iter().any(|g| g == f) {
+                    continue;
+                }
+            }
+            let Some((name, version)) = ckey.rsplit_once('@') else {
+                continue;
+            };
+            let _ = crates_table; // crate metadata isn't needed beyond the key split above
+
+            results.push(SearchResult {
+                crate_name: name.to_string(),
+                crate_version: version.to_string(),
+                path: item_path.to_string(),
+                kind: record.kind,
+                signature: record.signature,
+                score: 0.0,
+            });
+        }
+
+        Ok(results)
+    }
+
+    fn search_crate(
+        &self,
+        crate_name: &str,
+        crate_version: Option<&str>,
+        feature: Option<&str>,
+    ) -> Result<Vec<CachedItem>, CacheError> {
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let crates_table = txn
+            .open_table(CRATES_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let items_table = txn
+            .open_table(ITEMS_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+
+        let version = match crate_version {
+            Some(v) => v.to_string(),
+            None => {
+                // No native MAX(version) here; fall back to the
+                // lexicographically greatest version we have on hand.
+                let prefix = format!("{}@", crate_name);
+                let mut latest: Option<String> = None;
+                for entry in crates_table
+                    .iter()
+                    .map_err(|e| CacheError::Backend(e.into()))?
+                {
+                    let (key, _) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+                    if let Some(v) = key.value().strip_prefix(&prefix) {
+                        let is_newer = match &latest {
+                            Some(cur) => v > cur.as_str(),
+                            None => true,
+                        };
+                        if is_newer {
+                            latest = Some(v.to_string());
+                        }
+                    }
+                }
+                match latest {
+                    Some(v) => v,
+                    None => return Ok(Vec::new()),
+                }
+            }
+        };
+
+        let ckey = crate_key(crate_name, &version);
+        let (start, end) = item_range(&ckey);
+
+        let mut results = Vec::new();
+        for entry in items_table
+            .range::<&str>(start.as_str()..end.as_str())
+            .map_err(|e| CacheError::Backend(e.into()))?
+        {
+            let (key, value) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+            let item_path = key
+                .value()
+                .split_once('\0')
+                .map(|(_, path)| path)
+                .unwrap_or(key.value());
+            let record: ItemRecord =
+                serde_json::from_slice(value.value()).map_err(|e| CacheError::Backend(e.into()))?;
+            if let Some(f) = feature {
+                if !record.feature_gates.iter().any(|g| g == f) {
+                    continue;
+                }
+            }
+            results.push(CachedItem {
+                path: item_path.to_string(),
+                kind: record.kind,
+                signature: record.signature,
+                doc: record.doc,
+                visibility: record.visibility,
+            });
+        }
+        results.sort_by(|a, b| a.path.cmp(&b.path));
+
+        Ok(results)
+    }
+
+    fn list_indexed(&self) -> Result<Vec<(String, String)>, CacheError> {
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let table = txn
+            .open_table(CRATES_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+
+        let mut results = Vec::new();
+        for entry in table.iter().map_err(|e| CacheError::Backend(e.into()))? {
+            let (key, _) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+            if let Some((name, version)) = key.value().rsplit_once('@') {
+                results.push((name.to_string(), version.to_string()));
+            }
+        }
+        results.sort();
+        Ok(results)
+    }
+
+    /// Feature flags declared by `crate_name`'s manifest, as `(name,
+    /// subfeatures)` pairs, for the lexicographically greatest indexed
+    /// version if several are present.
+    fn list_features(&self, crate_name: &str) -> Result<Vec<(String, Vec<String>)>, CacheError> {
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let table = txn
+            .open_table(CRATES_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+
+        let prefix = format!("{}@", crate_name);
+        let mut latest: Option<(String, CrateRecord)> = None;
+        for entry in table.iter().map_err(|e| CacheError::Backend(e.into()))? {
+            let (key, value) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+            let Some(version) = key.value().strip_prefix(&prefix) else {
+                continue;
+            };
+            let is_newer = match &latest {
+                Some((cur, _)) => version > cur.as_str(),
+                None => true,
+            };
+            if is_newer {
+                let record: CrateRecord = serde_json::from_slice(value.value())
+                    .map_err(|e| CacheError::Backend(e.into()))?;
+                latest = Some((version.to_string(), record));
+            }
+        }
+
+        Ok(latest
+            .map(|(_, record)| record.features)
+            .unwrap_or_default())
+    }
+
+    fn stats(&self) -> Result<CacheStats, CacheError> {
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let crates_table = txn
+            .open_table(CRATES_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let items_table = txn
+            .open_table(ITEMS_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+
+        let db_size = std::fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
+
+        Ok(CacheStats {
+            crate_count: crates_table
+                .len()
+                .map_err(|e| CacheError::Backend(e.into()))? as usize,
+            item_count: items_table
+                .len()
+                .map_err(|e| CacheError::Backend(e.into()))? as usize,
+            db_size_bytes: db_size,
+        })
+    }
+
+    fn clear(&self) -> Result<(), CacheError> {
+        let txn = self
+            .db
+            .begin_write()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        {
+            let mut crates_table = txn
+                .open_table(CRATES_TABLE)
+                .map_err(|e| CacheError::Backend(e.into()))?;
+            crates_table
+                .retain(|_, _| false)
+                .map_err(|e| CacheError::Backend(e.into()))?;
+
+            let mut items_table = txn
+                .open_table(ITEMS_TABLE)
+                .map_err(|e| CacheError::Backend(e.into()))?;
+            items_table
+                .retain(|_, _| false)
+                .map_err(|e| CacheError::Backend(e.into()))?;
+        }
+        txn.commit().map_err(|e| CacheError::Backend(e.into()))?;
+        Ok(())
+    }
+
+    fn export_all(&self) -> Result<Vec<ExportedCrate>, CacheError> {
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let crates_table = txn
+            .open_table(CRATES_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let items_table = txn
+            .open_table(ITEMS_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+
+        let mut result = Vec::new();
+        for entry in crates_table
+            .iter()
+            .map_err(|e| CacheError::Backend(e.into()))?
+        {
+            let (key, value) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+            let ckey = key.value().to_string();
+            let Some((name, version)) = ckey.rsplit_once('@') else {
+                continue;
+            };
+            let record: CrateRecord =
+                serde_json::from_slice(value.value()).map_err(|e| CacheError::Backend(e.into()))?;
+
+            let (start, end) = item_range(&ckey);
+            let mut items = Vec::new();
+            for item_entry in items_table
+                .range::<&str>(start.as_str()..end.as_str())
+                .map_err(|e| CacheError::Backend(e.into()))?
+            {
+                let (item_key, item_value) =
+                    item_entry.map_err(|e| CacheError::Backend(e.into()))?;
+                let item_path = item_key
+                    .value()
+                    .split_once('\0')
+                    .map(|(_, path)| path)
+                    .unwrap_or(item_key.value());
+                let item_record: ItemRecord = serde_json::from_slice(item_value.value())
+                    .map_err(|e| CacheError::Backend(e.into()))?;
+                items.push(ExportedItem {
+                    path: item_path.to_string(),
+                    kind: item_record.kind,
+                    signature: item_record.signature,
+                    doc: item_record.doc,
+                    visibility: item_record.visibility,
+                    feature_gates: item_record.feature_gates,
+                });
+            }
+
+            result.push(ExportedCrate {
+                name: name.to_string(),
+                version: version.to_string(),
+                path: record.path,
+                fingerprint: record.source_fingerprint,
+                features: record.features,
+                items,
+            });
+        }
+
+        Ok(result)
+    }
+
+    /// Linear scan for items whose `related` edges point at `path`.
+    fn find_referrers(&self, path: &str) -> Result<Vec<SearchResult>, CacheError> {
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let items_table = txn
+            .open_table(ITEMS_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+
+        let mut results = Vec::new();
+        for entry in items_table
+            .iter()
+            .map_err(|e| CacheError::Backend(e.into()))?
+        {
+            let (key, value) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+            let Some((ckey, item_path)) = key.value().split_once('\0') else {
+                continue;
+            };
+            let record: ItemRecord =
+                serde_json::from_slice(value.value()).map_err(|e| CacheError::Backend(e.into()))?;
+            if !record.related.iter().any(|(to_path, _)| to_path == path) {
+                continue;
+            }
+            let Some((name, version)) = ckey.rsplit_once('@') else {
+                continue;
+            };
+
+            results.push(SearchResult {
+                crate_name: name.to_string(),
+                crate_version: version.to_string(),
+                path: item_path.to_string(),
+                kind: record.kind,
+                signature: record.signature,
+                score: 0.0,
+            });
+        }
+
+        Ok(results)
+    }
+
+    /// Linear scan for `path`'s own `related` edges, resolved back to
+    /// whichever of their targets are themselves indexed items.
+    fn find_references_from(&self, path: &str) -> Result<Vec<SearchResult>, CacheError> {
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let items_table = txn
+            .open_table(ITEMS_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+
+        let mut targets: Vec<String> = Vec::new();
+        for entry in items_table
+            .iter()
+            .map_err(|e| CacheError::Backend(e.into()))?
+        {
+            let (key, value) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+            let Some((_, item_path)) = key.value().split_once('\0') else {
+                continue;
+            };
+            if item_path != path {
+                continue;
+            }
+            let record: ItemRecord =
+                serde_json::from_slice(value.value()).map_err(|e| CacheError::Backend(e.into()))?;
+            targets.extend(record.related.into_iter().map(|(to_path, _)| to_path));
+        }
+
+        if targets.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut results = Vec::new();
+        for entry in items_table
+            .iter()
+            .map_err(|e| CacheError::Backend(e.into()))?
+        {
+            let (key, value) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+            let Some((ckey, item_path)) = key.value().split_once('\0') else {
+                continue;
+            };
+            if !targets.iter().any(|t| t == item_path) {
+                continue;
+            }
+            let record: ItemRecord =
+                serde_json::from_slice(value.value()).map_err(|e| CacheError::Backend(e.into()))?;
+            let Some((name, version)) = ckey.rsplit_once('@') else {
+                continue;
+            };
+
+            results.push(SearchResult {
+                crate_name: name.to_string(),
+                crate_version: version.to_string(),
+                path: item_path.to_string(),
+                kind: record.kind,
+                signature: record.signature,
+                score: 0.0,
+            });
+        }
+
+        Ok(results)
+    }
+
+    /// Same linear scan as `find_referrers`, restricted to `reexportof`
+    /// edges.
+    fn find_reexports_of(&self, path: &str) -> Result<Vec<SearchResult>, CacheError> {
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let items_table = txn
+            .open_table(ITEMS_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+
+        let mut results = Vec::new();
+        for entry in items_table
+            .iter()
+            .map_err(|e| CacheError::Backend(e.into()))?
+        {
+            let (key, value) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+            let Some((ckey, item_path)) = key.value().split_once('\0') else {
+                continue;
+            };
+            let record: ItemRecord =
+                serde_json::from_slice(value.value()).map_err(|e| CacheError::Backend(e.into()))?;
+            if !record
+                .related
+                .iter()
+                .any(|(to_path, kind)| to_path == path && kind == "reexportof")
+            {
+                continue;
+            }
+            let Some((name, version)) = ckey.rsplit_once('@') else {
+                continue;
+            };
+
+            results.push(SearchResult {
+                crate_name: name.to_string(),
+                crate_version: version.to_string(),
+                path: item_path.to_string(),
+                kind: record.kind,
+                signature: record.signature,
+                score: 0.0,
+            });
+        }
+
+        Ok(results)
+    }
+
+    fn is_reexport(&self, path: &str) -> Result<bool, CacheError> {
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let items_table = txn
+            .open_table(ITEMS_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+
+        for entry in items_table
+            .iter()
+            .map_err(|e| CacheError::Backend(e.into()))?
+        {
+            let (key, value) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+            let Some((_, item_path)) = key.value().split_once('\0') else {
+                continue;
+            };
+            if item_path != path {
+                continue;
+            }
+            let record: ItemRecord =
+                serde_json::from_slice(value.value()).map_err(|e| CacheError::Backend(e.into()))?;
+            return Ok(record.related.iter().any(|(_, kind)| kind == "reexportof"));
+        }
+
+        Ok(false)
+    }
+
+    /// Same linear scan as `find_reexports_of`, restricted to `implements`
+    /// edges.
+    fn find_implementors(&self, trait_path: &str) -> Result<Vec<SearchResult>, CacheError> {
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let items_table = txn
+            .open_table(ITEMS_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+
+        let mut results = Vec::new();
+        for entry in items_table
+            .iter()
+            .map_err(|e| CacheError::Backend(e.into()))?
+        {
+            let (key, value) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+            let Some((ckey, item_path)) = key.value().split_once('\0') else {
+                continue;
+            };
+            let record: ItemRecord =
+                serde_json::from_slice(value.value()).map_err(|e| CacheError::Backend(e.into()))?;
+            if !record
+                .related
+                .iter()
+                .any(|(to_path, kind)| to_path == trait_path && kind == "implements")
+            {
+                continue;
+            }
+            let Some((name, version)) = ckey.rsplit_once('@') else {
+                continue;
+            };
+
+            results.push(SearchResult {
+                crate_name: name.to_string(),
+                crate_version: version.to_string(),
+                path: item_path.to_string(),
+                kind: record.kind,
+                signature: record.signature,
+                score: 0.0,
+            });
+        }
+
+        Ok(results)
+    }
+
+    /// Linear scan for `type_path`'s own `implements` edges, returned as raw
+    /// trait-name strings since the trait itself may not be an indexed item.
+    fn find_implemented_traits(&self, type_path: &str) -> Result<Vec<String>, CacheError> {
+        let txn = self
+            .db
+            .begin_read()
+            .map_err(|e| CacheError::Backend(e.into()))?;
+        let items_table = txn
+            .open_table(ITEMS_TABLE)
+            .map_err(|e| CacheError::Backend(e.into()))?;
+
+        let mut traits = Vec::new();
+        for entry in items_table
+            .iter()
+            .map_err(|e| CacheError::Backend(e.into()))?
+        {
+            let (key, value) = entry.map_err(|e| CacheError::Backend(e.into()))?;
+            let Some((_, item_path)) = key.value().split_once('\0') else {
+                continue;
+            };
+            if item_path != type_path {
+                continue;
+            }
+            let record: ItemRecord =
+                serde_json::from_slice(value.value()).map_err(|e| CacheError::Backend(e.into()))?;
+            traits.extend(
+                record
+                    .related
+                    .into_iter()
+                    .filter(|(_, kind)| kind == "implements")
+                    .map(|(to_path, _)| to_path),
+            );
+        }
+
+        traits.sort();
+        Ok(traits)
+    }
+}
@@ -0,0 +1,870 @@
+//! SQLite + FTS5 backend. The default `CacheBackend` and the only one
+//! that can compute a real relevance score for `search`.
+
+use super::CacheBackend;
+use crate::cache::{CacheError, CacheStats, CachedItem, ExportedCrate, ExportedItem, SearchResult};
+use crate::cargo::RegistryCrate;
+use crate::schema::Item;
+use camino::Utf8Path;
+use rusqlite::{params, Connection};
+use std::collections::{HashMap, HashSet};
+const SCHEMA_VERSION: i32 = 5;
+
+pub struct SqliteBackend {
+    conn: Connection,
+    path: camino::Utf8PathBuf,
+}
+
+impl SqliteBackend {
+    /// Open or create the database at `path`, running schema migrations.
+    pub fn open(path: &Utf8Path) -> Result<Self, CacheError> {
+        let conn = Connection::open(path)?;
+
+        // Enable WAL mode for better concurrent access
+        conn.execute_batch(
+            r#"
+            PRAGMA journal_mode = WAL;
+            PRAGMA synchronous = NORMAL;
+            PRAGMA cache_size = -64000;
+            PRAGMA busy_timeout = 5000;
+            "#,
+        )?;
+
+        let backend = Self {
+            conn,
+            path: path.to_owned(),
+        };
+        backend.init_schema()?;
+        Ok(backend)
+    }
+
+    /// Open an existing database without running migrations.
+    pub fn open_existing(path: &Utf8Path) -> Result<Self, CacheError> {
+        let conn = Connection::open(path)?;
+
+        // Enable WAL mode for reads too
+        conn.execute_batch(
+            r#"
+            PRAGMA journal_mode = WAL;
+            PRAGMA synchronous = NORMAL;
+            PRAGMA busy_timeout = 5000;
+            "#,
+        )?;
+
+        Ok(Self {
+            conn,
+            path: path.to_owned(),
+        })
+    }
+
+    fn init_schema(&self) -> Result<(), CacheError> {
+        let conn = &self.conn;
+
+        // Create base tables
+        conn.execute_batch(
+            r#"
+            CREATE TABLE IF NOT EXISTS meta (
+                key TEXT PRIMARY KEY,
+                value TEXT
+            );
+
+            CREATE TABLE IF NOT EXISTS crates (
+                id INTEGER PRIMARY KEY,
+                name TEXT NOT NULL,
+                version TEXT NOT NULL,
+                path TEXT NOT NULL,
+                indexed_at INTEGER NOT NULL,
+                source_fingerprint TEXT,
+                UNIQUE(name, version)
+            );
+
+            CREATE TABLE IF NOT EXISTS items (
+                id INTEGER PRIMARY KEY,
+                crate_id INTEGER NOT NULL REFERENCES crates(id) ON DELETE CASCADE,
+                path TEXT NOT NULL,
+                kind TEXT NOT NULL,
+                signature TEXT,
+                doc TEXT,
+                visibility TEXT NOT NULL,
+                feature_gates TEXT,
+                UNIQUE(crate_id, path)
+            );
+
+            CREATE TABLE IF NOT EXISTS refs (
+                id INTEGER PRIMARY KEY,
+                from_item_id INTEGER NOT NULL REFERENCES items(id) ON DELETE CASCADE,
+                to_path TEXT NOT NULL,
+                kind TEXT
+            );
+
+            CREATE TABLE IF NOT EXISTS features (
+                id INTEGER PRIMARY KEY,
+                crate_id INTEGER NOT NULL REFERENCES crates(id) ON DELETE CASCADE,
+                name TEXT NOT NULL,
+                subfeatures TEXT,
+                UNIQUE(crate_id, name)
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_items_path ON items(path);
+            CREATE INDEX IF NOT EXISTS idx_items_kind ON items(kind);
+            CREATE INDEX IF NOT EXISTS idx_crates_name ON crates(name);
+            CREATE INDEX IF NOT EXISTS idx_refs_to_path ON refs(to_path);
+            CREATE INDEX IF NOT EXISTS idx_features_crate_id ON features(crate_id);
+            "#,
+        )?;
+
+        // Check current schema version and migrate if needed
+        let current_version: i32 = conn
+            .query_row(
+                "SELECT COALESCE((SELECT value FROM meta WHERE key = 'schema_version'), '0')",
+                [],
+                |row| {
+                    let v: String = row.get(0)?;
+                    Ok(v.parse().unwrap_or(0))
+                },
+            )
+            .unwrap_or(0);
+
+        if current_version < 2 {
+            Self::migrate_to_v2(conn)?;
+        }
+
+        if current_version < 3 {
+            Self::migrate_to_v3(conn)?;
+        }
+
+        if current_version < 4 {
+            Self::migrate_to_v4(conn)?;
+        }
+
+        if current_version < 5 {
+            Self::migrate_to_v5(conn)?;
+        }
+
+        // Update schema version
+        conn.execute(
+            "INSERT OR REPLACE INTO meta (key, value) VALUES ('schema_version', ?)",
+            params![SCHEMA_VERSION.to_string()],
+        )?;
+
+        Ok(())
+    }
+
+    /// Migrate schema from v1 to v2: Add FTS5 full-text search
+    fn migrate_to_v2(conn: &Connection) -> Result<(), CacheError> {
+        eprintln!("Migrating cache to v2 (adding FTS5 search)...");
+
+        // Create FTS5 virtual table for fast text search
+        // Using trigram tokenizer for substring matching
+        conn.execute_batch(
+            r#"
+            CREATE VIRTUAL TABLE IF NOT EXISTS items_fts USING fts5(
+                path,
+                content='items',
+                content_rowid='id',
+                tokenize='trigram'
+            );
+
+            -- Triggers to keep FTS index in sync with items table
+            CREATE TRIGGER IF NOT EXISTS items_fts_insert AFTER INSERT ON items BEGIN
+                INSERT INTO items_fts(rowid, path) VALUES (new.id, new.path);
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS items_fts_delete AFTER DELETE ON items BEGIN
+                INSERT INTO items_fts(items_fts, rowid, path) VALUES('delete', old.id, old.path);
+            END;
+
+            CREATE TRIGGER IF NOT EXISTS items_fts_update AFTER UPDATE ON items BEGIN
+                INSERT INTO items_fts(items_fts, rowid, path) VALUES('delete', old.id, old.path);
+                INSERT INTO items_fts(rowid, path) VALUES (new.id, new.path);
+            END;
+            "#,
+        )?;
+
+        // Rebuild FTS index from existing data
+        let item_count: i64 = conn.query_row("SELECT COUNT(*) FROM items", [], |row| row.get(0))?;
+
+        if item_count > 0 {
+            eprintln!("Rebuilding FTS index for {} items...", item_count);
+            conn.execute("INSERT INTO items_fts(items_fts) VALUES('rebuild')", [])?;
+        }
+
+        eprintln!("Migration to v2 complete.");
+        Ok(())
+    }
+
+    /// Migrate schema from v2 to v3: widen `items_fts` to also index
+    /// `signature` and `doc`, so BM25 ranking in `search` can tell a
+    /// symbol-name match from a weaker hit buried in its doc comment
+    /// instead of scoring on `path` alone.
+    fn migrate_to_v3(conn: &Connection) -> Result<(), CacheError> {
+        eprintln!("Migrating cache to v3 (widening FTS index for relevance ranking)...");
+
+        conn.execute_batch(
+            r#"
+            DROP TRIGGER IF EXISTS items_fts_insert;
+            DROP TRIGGER IF EXISTS items_fts_delete;
+            DROP TRIGGER IF EXISTS items_fts_update;
+            DROP TABLE IF EXISTS items_fts;
+
+            CREATE VIRTUAL TABLE items_fts USING fts5(
+                path,
+                signature,
+                doc,
+                content='items',
+                content_rowid='id',
+                tokenize='trigram'
+            );
+
+            CREATE TRIGGER items_fts_insert AFTER INSERT ON items BEGIN
+                INSERT INTO items_fts(rowid, path, signature, doc)
+                VALUES (new.id, new.path, new.signature, new.doc);
+            END;
+
+            CREATE TRIGGER items_fts_delete AFTER DELETE ON items BEGIN
+                INSERT INTO items_fts(items_fts, rowid, path, signature, doc)
+                VALUES('delete', old.id, old.path, old.signature, old.doc);
+            END;
+
+            CREATE TRIGGER items_fts_update AFTER UPDATE ON items BEGIN
+                INSERT INTO items_fts(items_fts, rowid, path, signature, doc)
+                VALUES('delete', old.id, old.path, old.signature, old.doc);
+                INSERT INTO items_fts(rowid, path, signature, doc)
+                VALUES (new.id, new.path, new.signature, new.doc);
+            END;
+            "#,
+        )?;
+
+        let item_count: i64 = conn.query_row("SELECT COUNT(*) FROM items", [], |row| row.get(0))?;
+
+        if item_count > 0 {
+            eprintln!("Rebuilding FTS index for {} items...", item_count);
+            conn.execute("INSERT INTO items_fts(items_fts) VALUES('rebuild')", [])?;
+        }
+
+        eprintln!("Migration to v3 complete.");
+        Ok(())
+    }
+
+    /// Migrate schema from v3 to v4: add `crates.source_fingerprint`, a
+    /// stat-based content-freshness signal `parallel_index` compares
+    /// against the current on-disk state to decide whether a path/patch
+    /// dependency needs reindexing even when its `name@version` hasn't
+    /// changed. `CREATE TABLE IF NOT EXISTS` above already adds the column
+    /// for brand new databases, so this only needs to run for ones that
+    /// predate it.
+    fn migrate_to_v4(conn: &Connection) -> Result<(), CacheError> {
+        let has_column = conn
+            .prepare("SELECT source_fingerprint FROM crates LIMIT 1")
+            .is_ok();
+
+        if !has_column {
+            eprintln!("Migrating cache to v4 (adding source_fingerprint)...");
+            conn.execute("ALTER TABLE crates ADD COLUMN source_fingerprint TEXT", [])?;
+            eprintln!("Migration to v4 complete.");
+        }
+
+        Ok(())
+    }
+
+    /// Migrate schema from v4 to v5: add the `features` table and
+    /// `items.feature_gates`, so indexed data can answer "what does
+    /// enabling feature X add to this crate's API?". `CREATE TABLE IF NOT
+    /// EXISTS`/the base `items` definition above already cover brand new
+    /// databases, so this only needs to run for ones that predate it.
+    fn migrate_to_v5(conn: &Connection) -> Result<(), CacheError> {
+        let has_column = conn
+            .prepare("SELECT feature_gates FROM items LIMIT 1")
+            .is_ok();
+
+        if !has_column {
+            eprintln!("Migrating cache to v5 (adding feature tracking)...");
+            conn.execute("ALTER TABLE items ADD COLUMN feature_gates TEXT", [])?;
+            conn.execute_batch(
+                r#"
+                CREATE TABLE IF NOT EXISTS features (
+                    id INTEGER PRIMARY KEY,
+                    crate_id INTEGER NOT NULL REFERENCES crates(id) ON DELETE CASCADE,
+                    name TEXT NOT NULL,
+                    subfeatures TEXT,
+                    UNIQUE(crate_id, name)
+                );
+                CREATE INDEX IF NOT EXISTS idx_features_crate_id ON features(crate_id);
+                "#,
+            )?;
+            eprintln!("Migration to v5 complete.");
+        }
+
+        Ok(())
+    }
+}
+
+impl CacheBackend for SqliteBackend {
+    fn is_indexed(&self, name: &str, version: &str) -> Result<bool, CacheError> {
+        let conn = &self.conn;
+        let count: i32 = conn.query_row(
+            "SELECT COUNT(*) FROM crates WHERE name = ? AND version = ?",
+            params![name, version],
+            |row| row.get(0),
+        )?;
+        Ok(count > 0)
+    }
+
+    fn get_indexed_set(&self) -> Result<HashSet<String>, CacheError> {
+        let conn = &self.conn;
+        let mut stmt = conn.prepare("SELECT name, version FROM crates")?;
+        let results = stmt
+            .query_map([], |row| {
+                let name: String = row.get(0)?;
+                let version: String = row.get(1)?;
+                Ok(format!("{}@{}", name, version))
+            })?
+            .collect::<Result<HashSet<_>, _>>()?;
+        Ok(results)
+    }
+
+    fn get_fingerprints(&self) -> Result<HashMap<String, String>, CacheError> {
+        let conn = &self.conn;
+        let mut stmt = conn
+            .prepare("SELECT name, version, source_fingerprint FROM crates WHERE source_fingerprint IS NOT NULL")?;
+        let results = stmt
+            .query_map([], |row| {
+                let name: String = row.get(0)?;
+                let version: String = row.get(1)?;
+                let fingerprint: String = row.get(2)?;
+                Ok((format!("{}@{}", name, version), fingerprint))
+            })?
+            .collect::<Result<HashMap<_, _>, _>>()?;
+        Ok(results)
+    }
+
+    fn batch_index(&self, batch: &[(RegistryCrate, Vec<Item>, String)]) -> Result<(), CacheError> {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64;
+
+        let conn = &self.conn;
+        conn.execute("BEGIN IMMEDIATE", [])?;
+
+        // Pre-prepare statements for better performance
+        let mut crate_stmt = conn.prepare_cached(
+            "INSERT OR REPLACE INTO crates (name, version, path, indexed_at, source_fingerprint) VALUES (?, ?, ?, ?, ?)",
+        )?;
+        // Refs must go before items: it looks items up by crate_id, which is
+        // no longer resolvable once delete_stmt removes them. All three key
+        // on (name, version) because the REPLACE below gives the crate a
+        // new id.
+        let mut refs_delete_stmt = conn.prepare_cached(
+            "DELETE FROM refs WHERE from_item_id IN (SELECT id FROM items WHERE crate_id IN (SELECT id FROM crates WHERE name = ? AND version = ?))",
+        )?;
+        let mut delete_stmt = conn.prepare_cached(
+            "DELETE FROM items WHERE crate_id IN (SELECT id FROM crates WHERE name = ? AND version = ?)",
+        )?;
+        let mut features_delete_stmt = conn.prepare_cached(
+            "DELETE FROM features WHERE crate_id IN (SELECT id FROM crates WHERE name = ? AND version = ?)",
+        )?;
+        let mut item_stmt = conn.prepare_cached(
+            "INSERT OR REPLACE INTO items (crate_id, path, kind, signature, doc, visibility, feature_gates) VALUES (?, ?, ?, ?, ?, ?, ?)",
+        )?;
+        let mut ref_stmt =
+            conn.prepare_cached("INSERT INTO refs (from_item_id, to_path, kind) VALUES (?, ?, ?)")?;
+        let mut feature_stmt = conn.prepare_cached(
+            "INSERT OR REPLACE INTO features (crate_id, name, subfeatures) VALUES (?, ?, ?)",
+        )?;
+
+        for (krate, items, fingerprint) in batch {
+            // Delete old refs/items/features for this crate
+            refs_delete_stmt.execute(params![krate.name, krate.version])?;
+            delete_stmt.execute(params![krate.name, krate.version])?;
+            features_delete_stmt.execute(params![krate.name, krate.version])?;
+
+            // Insert or replace crate and get ID via last_insert_rowid
+            crate_stmt.execute(params![
+                krate.name,
+                krate.version,
+                krate.path.as_str(),
+                now,
+                fingerprint
+            ])?;
+            let crate_id = conn.last_insert_rowid();
+
+            // Insert items
+            for item in items {
+                let kind = format!("{:?}", item.kind).to_lowercase();
+                let vis = format!("{:?}", item.visibility).to_lowercase();
+                let feature_gates = item.feature_gates();
+                let feature_gates = if feature_gates.is_empty() {
+                    None
+                } else {
+                    Some(
+                        serde_json::to_string(&feature_gates)
+                            .map_err(|e| CacheError::Backend(e.into()))?,
+                    )
+                };
+                item_stmt.execute(params![
+                    crate_id,
+                    item.path,
+                    kind,
+                    item.signature,
+                    item.doc,
+                    vis,
+                    feature_gates
+                ])?;
+                let item_id = conn.last_insert_rowid();
+
+                for relation in &item.related {
+                    let relation_kind = format!("{:?}", relation.kind).to_lowercase();
+                    ref_stmt.execute(params![item_id, relation.path, relation_kind])?;
+                }
+            }
+
+            // Insert features declared in the crate's own manifest
+            for (name, subfeatures) in krate.features() {
+                let subfeatures = serde_json::to_string(&subfeatures)
+                    .map_err(|e| CacheError::Backend(e.into()))?;
+                feature_stmt.execute(params![crate_id, name, subfeatures])?;
+            }
+        }
+
+        // Drop statements before commit to release borrows
+        drop(crate_stmt);
+        drop(refs_delete_stmt);
+        drop(delete_stmt);
+        drop(features_delete_stmt);
+        drop(item_stmt);
+        drop(ref_stmt);
+        drop(feature_stmt);
+
+        conn.execute("COMMIT", [])?;
+        Ok(())
+    }
+
+    /// Search for items matching a query using FTS5 full-text search,
+    /// ranked by BM25 relevance rather than alphabetically. `path` is
+    /// weighted well above `signature` and `doc` so a symbol name match
+    /// outranks one that only shows up in its doc comment. An optional
+    /// `feature` restricts results to items gated behind that feature name.
+    fn search(&self, query: &str, feature: Option<&str>) -> Result<Vec<SearchResult>, CacheError> {
+        // Escape special FTS5 characters and prepare for trigram search
+        let escaped_query = query.replace('"', "\"\"").to_lowercase();
+
+        let conn = &self.conn;
+
+        // Use FTS5 with trigram tokenizer for fast substring matching.
+        // bm25() needs the FTS table itself in the FROM/JOIN chain, not
+        // just a `rowid IN (...)` subquery, to compute a per-row score.
+        let mut sql = String::from(
+            r#"
+            SELECT c.name, c.version, i.path, i.kind, i.signature,
+                   bm25(items_fts, 10.0, 3.0, 1.0) AS score
+            FROM items_fts
+            JOIN items i ON i.id = items_fts.rowid
+            JOIN crates c ON i.crate_id = c.id
+            WHERE items_fts MATCH ?
+            "#,
+        );
+        if feature.is_some() {
+            // feature_gates is a JSON array of feature-name strings;
+            // there's no JSON1 dependency elsewhere in this schema, so
+            // match the quoted name as a substring rather than pull in
+            // json_each for one query.
+            sql.push_str(" AND i.feature_gates LIKE ?");
+        }
+        sql.push_str(" ORDER BY score");
+
+        let mut stmt = conn.prepare(&sql)?;
+        let fts_query = format!("\"{}\"", escaped_query);
+
+        let results = if let Some(f) = feature {
+            let pattern = format!("%\"{}\"%", f);
+            stmt.query_map(params![fts_query, pattern], Self::row_to_search_result)?
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            stmt.query_map(params![fts_query], Self::row_to_search_result)?
+                .collect::<Result<Vec<_>, _>>()?
+        };
+
+        Ok(results)
+    }
+
+    fn row_to_search_result(row: &rusqlite::Row) -> rusqlite::Result<SearchResult> {
+        Ok(SearchResult {
+            crate_name: row.get(0)?,
+            crate_version: row.get(1)?,
+            path: row.get(2)?,
+            kind: row.get(3)?,
+            signature: row.get(4)?,
+            score: row.get(5)?,
+        })
+    }
+
+    fn search_crate(
+        &self,
+        crate_name: &str,
+        crate_version: Option<&str>,
+        feature: Option<&str>,
+    ) -> Result<Vec<CachedItem>, CacheError> {
+        let mut query = String::from(
+            r#"
+            SELECT i.path, i.kind, i.signature, i.doc, i.visibility
+            FROM items i
+            JOIN crates c ON i.crate_id = c.id
+            WHERE c.name = ?
+            "#,
+        );
+
+        if crate_version.is_some() {
+            query.push_str(" AND c.version = ?");
+        } else {
+            // Get latest version
+            query.push_str(" AND c.version = (SELECT MAX(version) FROM crates WHERE name = ?)");
+        }
+        if feature.is_some() {
+            query.push_str(" AND i.feature_gates LIKE ?");
+        }
+        query.push_str(" ORDER BY i.path");
+
+        let conn = &self.conn;
+        let mut stmt = conn.prepare(&query)?;
+
+        let version_param = crate_version.unwrap_or(crate_name);
+        let map_row = |row: &rusqlite::Row| {
+            Ok(CachedItem {
+                path: row.get(0)?,
+                kind: row.get(1)?,
+                signature: row.get(2)?,
+                doc: row.get(3)?,
+                visibility: row.get(4)?,
+            })
+        };
+
+        let results = if let Some(f) = feature {
+            let pattern = format!("%\"{}\"%", f);
+            stmt.query_map(params![crate_name, version_param, pattern], map_row)?
+                .collect::<Result<Vec<_>, _>>()?
+        } else {
+            stmt.query_map(params![crate_name, version_param], map_row)?
+                .collect::<Result<Vec<_>, _>>()?
+        };
+
+        Ok(results)
+    }
+
+    fn list_indexed(&self) -> Result<Vec<(String, String)>, CacheError> {
+        let conn = &self.conn;
+        let mut stmt = conn.prepare("SELECT name, version FROM crates ORDER BY name, version")?;
+
+        let results = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(results)
+    }
+
+    /// Feature flags declared by a crate's manifest, as `(name,
+    /// subfeatures)` pairs, for the latest indexed version if several are
+    /// present.
+    fn list_features(&self, crate_name: &str) -> Result<Vec<(String, Vec<String>)>, CacheError> {
+        let conn = &self.conn;
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT f.name, f.subfeatures
+            FROM features f
+            JOIN crates c ON f.crate_id = c.id
+            WHERE c.name = ?
+              AND c.version = (SELECT MAX(version) FROM crates WHERE name = ?)
+            ORDER BY f.name
+            "#,
+        )?;
+
+        let results = stmt
+            .query_map(params![crate_name, crate_name], |row| {
+                let name: String = row.get(0)?;
+                let subfeatures: Option<String> = row.get(1)?;
+                Ok((name, subfeatures))
+            })?
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .map(|(name, subfeatures)| {
+                let subfeatures = subfeatures
+                    .and_then(|s| serde_json::from_str::<Vec<String>>(&s).ok())
+                    .unwrap_or_default();
+                (name, subfeatures)
+            })
+            .collect();
+
+        Ok(results)
+    }
+
+    fn stats(&self) -> Result<CacheStats, CacheError> {
+        let conn = &self.conn;
+        let crate_count: i64 =
+            conn.query_row("SELECT COUNT(*) FROM crates", [], |row| row.get(0))?;
+
+        let item_count: i64 = conn.query_row("SELECT COUNT(*) FROM items", [], |row| row.get(0))?;
+
+        let db_size = std::fs::metadata(&self.path).map(|m| m.len()).unwrap_or(0);
+
+        Ok(CacheStats {
+            crate_count: crate_count as usize,
+            item_count: item_count as usize,
+            db_size_bytes: db_size,
+        })
+    }
+
+    fn clear(&self) -> Result<(), CacheError> {
+        let conn = &self.conn;
+        conn.execute_batch(
+            r#"
+            DELETE FROM refs;
+            DELETE FROM features;
+            DELETE FROM items;
+            DELETE FROM crates;
+            "#,
+        )?;
+        Ok(())
+    }
+
+    fn export_all(&self) -> Result<Vec<ExportedCrate>, CacheError> {
+        let conn = &self.conn;
+
+        let mut crate_stmt = conn.prepare(
+            "SELECT id, name, version, path, COALESCE(source_fingerprint, '') FROM crates ORDER BY name, version",
+        )?;
+        let crates: Vec<(i64, String, String, String, String)> = crate_stmt
+            .query_map([], |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        drop(crate_stmt);
+
+        let mut item_stmt = conn.prepare(
+            "SELECT path, kind, signature, doc, visibility, feature_gates FROM items WHERE crate_id = ? ORDER BY path",
+        )?;
+        let mut feature_stmt = conn
+            .prepare("SELECT name, subfeatures FROM features WHERE crate_id = ? ORDER BY name")?;
+
+        let mut result = Vec::with_capacity(crates.len());
+        for (id, name, version, path, fingerprint) in crates {
+            let raw_items: Vec<(
+                String,
+                String,
+                Option<String>,
+                Option<String>,
+                String,
+                Option<String>,
+            )> = item_stmt
+                .query_map(params![id], |row| {
+                    Ok((
+                        row.get(0)?,
+                        row.get(1)?,
+                        row.get(2)?,
+                        row.get(3)?,
+                        row.get(4)?,
+                        row.get(5)?,
+                    ))
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+            let items = raw_items
+                .into_iter()
+                .map(|(path, kind, signature, doc, visibility, feature_gates)| {
+                    let feature_gates = feature_gates
+                        .and_then(|s| serde_json::from_str::<Vec<String>>(&s).ok())
+                        .unwrap_or_default();
+                    ExportedItem {
+                        path,
+                        kind,
+                        signature,
+                        doc,
+                        visibility,
+                        feature_gates,
+                    }
+                })
+                .collect();
+
+            let raw_features: Vec<(String, Option<String>)> = feature_stmt
+                .query_map(params![id], |row| Ok((row.get(0)?, row.get(1)?)))?
+                .collect::<Result<Vec<_>, _>>()?;
+            let features = raw_features
+                .into_iter()
+                .map(|(name, subfeatures)| {
+                    let subfeatures = subfeatures
+                        .and_then(|s| serde_json::from_str::<Vec<String>>(&s).ok())
+                        .unwrap_or_default();
+                    (name, subfeatures)
+                })
+                .collect();
+
+            result.push(ExportedCrate {
+                name,
+                version,
+                path,
+                fingerprint,
+                features,
+                items,
+            });
+        }
+
+        Ok(result)
+    }
+
+    fn find_referrers(&self, path: &str) -> Result<Vec<SearchResult>, CacheError> {
+        let conn = &self.conn;
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT c.name, c.version, i.path, i.kind, i.signature
+            FROM refs r
+            JOIN items i ON i.id = r.from_item_id
+            JOIN crates c ON c.id = i.crate_id
+            WHERE r.to_path = ?
+            ORDER BY c.name, c.version, i.path
+            "#,
+        )?;
+
+        let results = stmt
+            .query_map(params![path], |row| {
+                Ok(SearchResult {
+                    crate_name: row.get(0)?,
+                    crate_version: row.get(1)?,
+                    path: row.get(2)?,
+                    kind: row.get(3)?,
+                    signature: row.get(4)?,
+                    score: 0.0,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(results)
+    }
+
+    fn find_references_from(&self, path: &str) -> Result<Vec<SearchResult>, CacheError> {
+        let conn = &self.conn;
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT c.name, c.version, ti.path, ti.kind, ti.signature
+            FROM items si
+            JOIN refs r ON r.from_item_id = si.id
+            JOIN items ti ON ti.path = r.to_path
+            JOIN crates c ON c.id = ti.crate_id
+            WHERE si.path = ?
+            ORDER BY c.name, c.version, ti.path
+            "#,
+        )?;
+
+        let results = stmt
+            .query_map(params![path], |row| {
+                Ok(SearchResult {
+                    crate_name: row.get(0)?,
+                    crate_version: row.get(1)?,
+                    path: row.get(2)?,
+                    kind: row.get(3)?,
+                    signature: row.get(4)?,
+                    score: 0.0,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(results)
+    }
+
+    fn find_reexports_of(&self, path: &str) -> Result<Vec<SearchResult>, CacheError> {
+        let conn = &self.conn;
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT c.name, c.version, i.path, i.kind, i.signature
+            FROM refs r
+            JOIN items i ON i.id = r.from_item_id
+            JOIN crates c ON c.id = i.crate_id
+            WHERE r.to_path = ? AND r.kind = 'reexportof'
+            ORDER BY c.name, c.version, i.path
+            "#,
+        )?;
+
+        let results = stmt
+            .query_map(params![path], |row| {
+                Ok(SearchResult {
+                    crate_name: row.get(0)?,
+                    crate_version: row.get(1)?,
+                    path: row.get(2)?,
+                    kind: row.get(3)?,
+                    signature: row.get(4)?,
+                    score: 0.0,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(results)
+    }
+
+    fn is_reexport(&self, path: &str) -> Result<bool, CacheError> {
+        let conn = &self.conn;
+        let exists: bool = conn.query_row(
+            r#"
+            SELECT EXISTS(
+                SELECT 1 FROM refs r
+                JOIN items i ON i.id = r.from_item_id
+                WHERE i.path = ?1 AND r.kind = 'reexportof'
+            )
+            "#,
+            params![path],
+            |row| row.get(0),
+        )?;
+        Ok(exists)
+    }
+
+    /// Same query as `find_reexports_of`, restricted to `implements` edges.
+    fn find_implementors(&self, trait_path: &str) -> Result<Vec<SearchResult>, CacheError> {
+        let conn = &self.conn;
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT c.name, c.version, i.path, i.kind, i.signature
+            FROM refs r
+            JOIN items i ON i.id = r.from_item_id
+            JOIN crates c ON c.id = i.crate_id
+            WHERE r.to_path = ? AND r.kind = 'implements'
+            ORDER BY c.name, c.version, i.path
+            "#,
+        )?;
+
+        let results = stmt
+            .query_map(params![trait_path], |row| {
+                Ok(SearchResult {
+                    crate_name: row.get(0)?,
+                    crate_version: row.get(1)?,
+                    path: row.get(2)?,
+                    kind: row.get(3)?,
+                    signature: row.get(4)?,
+                    score: 0.0,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(results)
+    }
+
+    fn find_implemented_traits(&self, type_path: &str) -> Result<Vec<String>, CacheError> {
+        let conn = &self.conn;
+        let mut stmt = conn.prepare(
+            r#"
+            SELECT r.to_path
+            FROM refs r
+            JOIN items i ON i.id = r.from_item_id
+            WHERE i.path = ?1 AND r.kind = 'implements'
+            ORDER BY r.to_path
+            "#,
+        )?;
+
+        let traits = stmt
+            .query_map(params![type_path], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+
+        Ok(traits)
+    }
+}
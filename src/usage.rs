@@ -7,7 +7,10 @@
 //! - Derives using crate macros
 
 use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
+use syn::visit::Visit;
 use syn::{Attribute, Item, UseTree};
 
 /// A usage site in the project's code
@@ -103,8 +106,10 @@ pub fn analyze_usage(project_root: &Path, crate_name: &str) -> Result<UsageSumma
         anyhow::bail!("No src directory found in project");
     }
 
+    let manifest_renames = read_dependency_renames(project_root);
+
     let mut sites = Vec::new();
-    scan_directory(&src_dir, crate_name, &mut sites)?;
+    scan_directory(&src_dir, crate_name, &manifest_renames, &mut sites)?;
 
     // Count imports
     let mut import_counts: std::collections::HashMap<String, usize> =
@@ -135,70 +140,203 @@ pub fn analyze_usage(project_root: &Path, crate_name: &str) -> Result<UsageSumma
     })
 }
 
-fn scan_directory(dir: &Path, crate_name: &str, sites: &mut Vec<UsageSite>) -> Result<()> {
+/// Extensions of non-Rust source files scanned for cross-language imports of
+/// `crate_name`, so a monorepo that wraps a Rust crate with a TS/JS or Python
+/// package still shows up in `analyze_usage`'s report.
+const FOREIGN_IMPORT_EXTENSIONS: &[&str] = &["ts", "tsx", "js", "jsx", "mjs", "cjs", "py", "pyi"];
+
+fn scan_directory(
+    dir: &Path,
+    crate_name: &str,
+    manifest_renames: &HashMap<String, String>,
+    sites: &mut Vec<UsageSite>,
+) -> Result<()> {
     for entry in std::fs::read_dir(dir)? {
         let entry = entry?;
         let path = entry.path();
+        let ext = path.extension().and_then(|e| e.to_str());
 
-        if path.is_file() && path.extension().map(|e| e == "rs").unwrap_or(false) {
-            let _ = scan_file(&path, crate_name, sites);
+        if ext == Some("rs") {
+            let _ = scan_file(&path, crate_name, manifest_renames, sites);
+        } else if let Some(ext) = ext.filter(|e| FOREIGN_IMPORT_EXTENSIONS.contains(e)) {
+            let _ = scan_foreign_file(&path, ext, crate_name, sites);
         } else if path.is_dir() {
-            let _ = scan_directory(&path, crate_name, sites);
+            let _ = scan_directory(&path, crate_name, manifest_renames, sites);
         }
     }
     Ok(())
 }
 
-fn scan_file(path: &Path, crate_name: &str, sites: &mut Vec<UsageSite>) -> Result<()> {
+/// Scans a non-Rust source file for import statements naming `crate_name`,
+/// via a lightweight per-line scan rather than a real parse: the
+/// `languages::LanguageParser` backends that already parse TS/JS and Python
+/// live in the `fastdeps` binary crate's module tree, which this `cratefind`
+/// library crate - where the usage analyzer lives - can't reach. This covers
+/// the common import forms without pulling in a second parser stack.
+fn scan_foreign_file(
+    path: &Path,
+    ext: &str,
+    crate_name: &str,
+    sites: &mut Vec<UsageSite>,
+) -> Result<()> {
+    let content = std::fs::read_to_string(path)?;
+
+    for (idx, line) in content.lines().enumerate() {
+        let module = match ext {
+            "py" | "pyi" => extract_python_import(line),
+            _ => extract_js_import(line),
+        };
+        let Some(module) = module else { continue };
+
+        let matches_target = module == crate_name
+            || module.starts_with(&format!("{crate_name}/"))
+            || module.starts_with(&format!("{crate_name}."));
+        if !matches_target {
+            continue;
+        }
+
+        sites.push(UsageSite {
+            file: path.to_path_buf(),
+            line: idx + 1,
+            path: module.to_string(),
+            kind: UsageKind::Import,
+            context: None,
+        });
+    }
+
+    Ok(())
+}
+
+/// Extracts the quoted module specifier from a `import ... from '...'`,
+/// bare `import '...'`, or `require('...')` line.
+fn extract_js_import(line: &str) -> Option<&str> {
+    let line = line.trim();
+    if !(line.starts_with("import") || line.starts_with("export") || line.contains("require(")) {
+        return None;
+    }
+    let start = line.find(['\'', '"'])?;
+    let quote = line.as_bytes()[start] as char;
+    let rest = &line[start + 1..];
+    let end = rest.find(quote)?;
+    Some(&rest[..end])
+}
+
+/// Extracts the root module name from an `import NAME[.sub][, ...]` or
+/// `from NAME[.sub] import ...` line.
+fn extract_python_import(line: &str) -> Option<&str> {
+    let line = line.trim();
+    let rest = line
+        .strip_prefix("from ")
+        .or_else(|| line.strip_prefix("import "))?;
+    let module = rest
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .find(|s| !s.is_empty())?;
+    Some(module.split('.').next().unwrap_or(module))
+}
+
+fn scan_file(
+    path: &Path,
+    crate_name: &str,
+    manifest_renames: &HashMap<String, String>,
+    sites: &mut Vec<UsageSite>,
+) -> Result<()> {
     let content = std::fs::read_to_string(path)?;
     let syntax = syn::parse_file(&content).context("Failed to parse file")?;
 
     // Also do a simple line-by-line scan for line numbers
     let lines: Vec<&str> = content.lines().collect();
 
+    let crate_aliases = collect_crate_aliases(&syntax.items, manifest_renames);
+    let item_aliases = collect_item_aliases(&syntax.items, &crate_aliases);
+
     for item in &syntax.items {
-        scan_item(item, path, crate_name, &lines, sites);
+        scan_item(
+            item,
+            FileCtx::new(path, crate_name, &crate_aliases, &item_aliases),
+            &lines,
+            sites,
+        );
     }
 
+    let mut macro_visitor = MacroCallVisitor {
+        ctx: FileCtx::new(path, crate_name, &crate_aliases, &item_aliases),
+        lines: &lines,
+        sites,
+    };
+    macro_visitor.visit_file(&syntax);
+
     Ok(())
 }
 
-fn scan_item(
-    item: &Item,
-    file: &Path,
-    crate_name: &str,
-    lines: &[&str],
-    sites: &mut Vec<UsageSite>,
-) {
+/// Bundles everything `scan_item` needs about the file being scanned and
+/// the crate being queried, so adding a new resolution source doesn't mean
+/// widening every recursive call's argument list again.
+#[derive(Clone, Copy)]
+struct FileCtx<'a> {
+    file: &'a Path,
+    crate_name: &'a str,
+    crate_aliases: &'a HashMap<String, String>,
+    item_aliases: &'a HashMap<String, (String, String)>,
+}
+
+impl<'a> FileCtx<'a> {
+    fn new(
+        file: &'a Path,
+        crate_name: &'a str,
+        crate_aliases: &'a HashMap<String, String>,
+        item_aliases: &'a HashMap<String, (String, String)>,
+    ) -> Self {
+        Self {
+            file,
+            crate_name,
+            crate_aliases,
+            item_aliases,
+        }
+    }
+}
+
+fn scan_item(item: &Item, ctx: FileCtx<'_>, lines: &[&str], sites: &mut Vec<UsageSite>) {
+    let file = ctx.file;
     match item {
         Item::Use(u) => {
             // Check if this use imports from the target crate
-            extract_use_paths(&u.tree, crate_name, file, lines, sites);
+            extract_use_paths(
+                &u.tree,
+                ctx.crate_name,
+                ctx.crate_aliases,
+                file,
+                lines,
+                sites,
+            );
         }
 
         Item::Struct(s) => {
-            check_derives(&s.attrs, crate_name, file, lines, sites);
-            // Recurse into any items this might contain
+            check_derives(&s.attrs, &ctx, file, lines, sites);
         }
 
         Item::Enum(e) => {
-            check_derives(&e.attrs, crate_name, file, lines, sites);
+            check_derives(&e.attrs, &ctx, file, lines, sites);
         }
 
         Item::Impl(i) => {
             // Check if implementing a trait from the target crate
             if let Some((_, trait_path, _)) = &i.trait_ {
-                if let Some(first) = trait_path.segments.first() {
-                    if first.ident == crate_name {
-                        let full_path = trait_path
+                let segments: Vec<String> = trait_path
+                    .segments
+                    .iter()
+                    .map(|s| s.ident.to_string())
+                    .collect();
+                if let Some((origin_crate, _)) =
+                    resolve_path(&segments, ctx.crate_aliases, ctx.item_aliases)
+                {
+                    if origin_crate == ctx.crate_name {
+                        let full_path = segments.join("::");
+                        let line = trait_path
                             .segments
-                            .iter()
-                            .map(|s| s.ident.to_string())
-                            .collect::<Vec<_>>()
-                            .join("::");
-
-                        // Find line number
-                        let line = find_line_containing(lines, &format!("impl {}", first.ident))
+                            .first()
+                            .and_then(|first| {
+                                find_line_containing(lines, &format!("impl {}", first.ident))
+                            })
                             .unwrap_or(1);
 
                         sites.push(UsageSite {
@@ -215,20 +353,20 @@ fn scan_item(
             // Scan items in impl block
             for impl_item in &i.items {
                 if let syn::ImplItem::Fn(method) = impl_item {
-                    check_attrs(&method.attrs, crate_name, file, lines, sites);
+                    check_attrs(&method.attrs, &ctx, file, lines, sites);
                 }
             }
         }
 
         Item::Fn(f) => {
-            check_attrs(&f.attrs, crate_name, file, lines, sites);
+            check_attrs(&f.attrs, &ctx, file, lines, sites);
         }
 
         Item::Mod(m) => {
-            check_attrs(&m.attrs, crate_name, file, lines, sites);
+            check_attrs(&m.attrs, &ctx, file, lines, sites);
             if let Some((_, items)) = &m.content {
                 for item in items {
-                    scan_item(item, file, crate_name, lines, sites);
+                    scan_item(item, ctx, lines, sites);
                 }
             }
         }
@@ -237,22 +375,28 @@ fn scan_item(
     }
 }
 
-/// Extract paths from a use tree that reference the target crate
+/// Extract paths from a use tree that reference the target crate (directly,
+/// or via a Cargo.toml `package = "..."`/`extern crate ... as` rename).
 fn extract_use_paths(
     tree: &UseTree,
     crate_name: &str,
+    crate_aliases: &HashMap<String, String>,
     file: &Path,
     lines: &[&str],
     sites: &mut Vec<UsageSite>,
 ) {
     match tree {
         UseTree::Path(p) => {
-            if p.ident == crate_name {
+            let ident = p.ident.to_string();
+            let resolved = crate_aliases
+                .get(&ident)
+                .map(String::as_str)
+                .unwrap_or(&ident);
+            if resolved == crate_name {
                 // This use statement imports from our target crate
                 let paths = flatten_use_tree(&p.tree, crate_name);
                 for path in paths {
-                    let line =
-                        find_line_containing(lines, &format!("use {}", crate_name)).unwrap_or(1);
+                    let line = find_line_containing(lines, &format!("use {}", ident)).unwrap_or(1);
                     sites.push(UsageSite {
                         file: file.to_path_buf(),
                         line,
@@ -263,12 +407,12 @@ fn extract_use_paths(
                 }
             } else {
                 // Recurse in case of nested paths
-                extract_use_paths(&p.tree, crate_name, file, lines, sites);
+                extract_use_paths(&p.tree, crate_name, crate_aliases, file, lines, sites);
             }
         }
         UseTree::Group(g) => {
             for tree in &g.items {
-                extract_use_paths(tree, crate_name, file, lines, sites);
+                extract_use_paths(tree, crate_name, crate_aliases, file, lines, sites);
             }
         }
         _ => {}
@@ -299,68 +443,84 @@ fn flatten_use_tree(tree: &UseTree, prefix: &str) -> Vec<String> {
     }
 }
 
-/// Check derive macros for references to target crate
+/// Check derive macros for references to the target crate. Each derive
+/// entry is parsed as a real `syn::Path` (not string-matched) and resolved
+/// through the file's import aliases, so a renamed import like `use
+/// serde::Serialize as Ser;` still reports `#[derive(Ser)]` as a `serde`
+/// usage instead of requiring a hardcoded per-crate derive whitelist.
 fn check_derives(
     attrs: &[Attribute],
-    crate_name: &str,
+    ctx: &FileCtx<'_>,
     file: &Path,
     lines: &[&str],
     sites: &mut Vec<UsageSite>,
 ) {
     for attr in attrs {
-        if attr.path().is_ident("derive") {
-            // Parse the derive contents
-            if let Ok(meta) = attr.meta.require_list() {
-                let tokens = meta.tokens.to_string();
-                // Common derives from popular crates
-                let known_derives: Vec<(&str, &str)> = vec![
-                    ("serde", "Serialize"),
-                    ("serde", "Deserialize"),
-                    ("thiserror", "Error"),
-                    ("clap", "Parser"),
-                    ("clap", "Args"),
-                    ("clap", "Subcommand"),
-                    ("clap", "ValueEnum"),
-                ];
-
-                for (crate_match, derive_name) in known_derives {
-                    if crate_match == crate_name && tokens.contains(derive_name) {
-                        let line = find_line_containing(lines, &format!("derive("))
-                            .or_else(|| find_line_containing(lines, derive_name))
-                            .unwrap_or(1);
-                        sites.push(UsageSite {
-                            file: file.to_path_buf(),
-                            line,
-                            path: derive_name.to_string(),
-                            kind: UsageKind::Derive,
-                            context: None,
-                        });
-                    }
-                }
+        if !attr.path().is_ident("derive") {
+            continue;
+        }
+        let Ok(paths) = attr.parse_args_with(
+            syn::punctuated::Punctuated::<syn::Path, syn::Token![,]>::parse_terminated,
+        ) else {
+            continue;
+        };
+
+        for path in paths {
+            let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+            let Some((origin_crate, item_name)) =
+                resolve_path(&segments, ctx.crate_aliases, ctx.item_aliases)
+            else {
+                continue;
+            };
+            if origin_crate != ctx.crate_name {
+                continue;
             }
+
+            let line = find_line_containing(lines, "derive(")
+                .or_else(|| find_line_containing(lines, &item_name))
+                .unwrap_or(1);
+            sites.push(UsageSite {
+                file: file.to_path_buf(),
+                line,
+                path: item_name,
+                kind: UsageKind::Derive,
+                context: None,
+            });
         }
     }
 }
 
-/// Check attributes for references to target crate
+/// Check attributes for references to the target crate, resolving through
+/// crate-level renames the same way `check_derives` does (e.g. a `#[attr]`
+/// macro imported under an alias still resolves to its real crate).
 fn check_attrs(
     attrs: &[Attribute],
-    crate_name: &str,
+    ctx: &FileCtx<'_>,
     file: &Path,
     lines: &[&str],
     sites: &mut Vec<UsageSite>,
 ) {
     for attr in attrs {
-        let path_str = attr
+        let segments: Vec<String> = attr
             .path()
             .segments
             .iter()
             .map(|s| s.ident.to_string())
-            .collect::<Vec<_>>()
-            .join("::");
-
-        if path_str.starts_with(crate_name) || path_str == crate_name {
-            let line = find_line_containing(lines, &format!("#[{}", crate_name)).unwrap_or(1);
+            .collect();
+        let path_str = segments.join("::");
+
+        let resolves_to_target = segments.first().is_some_and(|first| {
+            let resolved = ctx
+                .crate_aliases
+                .get(first)
+                .map(String::as_str)
+                .unwrap_or(first);
+            resolved == ctx.crate_name
+        }) || resolve_path(&segments, ctx.crate_aliases, ctx.item_aliases)
+            .is_some_and(|(origin_crate, _)| origin_crate == ctx.crate_name);
+
+        if resolves_to_target {
+            let line = find_line_containing(lines, &format!("#[{}", ctx.crate_name)).unwrap_or(1);
             sites.push(UsageSite {
                 file: file.to_path_buf(),
                 line,
@@ -372,6 +532,223 @@ fn check_attrs(
     }
 }
 
+/// Resolves a (possibly single-segment, possibly alias-renamed) path used
+/// in a derive/macro-call/attribute position back to the crate it really
+/// came from, following both the file's own `use` imports and Cargo.toml's
+/// `package = "..."` dependency renames - so e.g. `use serde::Serialize as
+/// Ser;` and `#[derive(Ser)]` still resolve to `("serde", "Serialize")`.
+fn resolve_path(
+    segments: &[String],
+    crate_aliases: &HashMap<String, String>,
+    item_aliases: &HashMap<String, (String, String)>,
+) -> Option<(String, String)> {
+    match segments {
+        [] => None,
+        [single] => item_aliases.get(single).cloned(),
+        [head, .., tail] => {
+            let origin_crate = crate_aliases
+                .get(head)
+                .cloned()
+                .unwrap_or_else(|| head.clone());
+            Some((origin_crate, tail.clone()))
+        }
+    }
+}
+
+/// Crate-level aliases visible in a file: Cargo.toml's `package = "..."`
+/// renames, plus any `extern crate real as alias;` declared in this file,
+/// which shadows/extends the manifest-level renames for code still using
+/// the 2018-style `extern crate` import.
+fn collect_crate_aliases(
+    items: &[Item],
+    manifest_renames: &HashMap<String, String>,
+) -> HashMap<String, String> {
+    let mut aliases = manifest_renames.clone();
+    collect_extern_crate_aliases(items, &mut aliases);
+    aliases
+}
+
+fn collect_extern_crate_aliases(items: &[Item], aliases: &mut HashMap<String, String>) {
+    for item in items {
+        match item {
+            Item::ExternCrate(ec) => {
+                if let Some((_, rename)) = &ec.rename {
+                    aliases.insert(rename.to_string(), ec.ident.to_string());
+                }
+            }
+            Item::Mod(m) => {
+                if let Some((_, inner)) = &m.content {
+                    collect_extern_crate_aliases(inner, aliases);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Locally-bound item names visible in a file (from `use ...;` imports,
+/// with `as` renames applied) mapped to the crate and item they really
+/// name - e.g. `use serde::Serialize as Ser;` records `"Ser" -> ("serde",
+/// "Serialize")`. Crate-level aliases are resolved first so a renamed
+/// dependency's imports still point at its real name.
+fn collect_item_aliases(
+    items: &[Item],
+    crate_aliases: &HashMap<String, String>,
+) -> HashMap<String, (String, String)> {
+    let mut aliases = HashMap::new();
+    collect_item_aliases_inner(items, crate_aliases, &mut aliases);
+    aliases
+}
+
+fn collect_item_aliases_inner(
+    items: &[Item],
+    crate_aliases: &HashMap<String, String>,
+    aliases: &mut HashMap<String, (String, String)>,
+) {
+    for item in items {
+        match item {
+            Item::Use(u) => {
+                for (segments, local_name) in flatten_use_tree_full(&u.tree, Vec::new()) {
+                    let Some(root) = segments.first() else {
+                        continue;
+                    };
+                    let origin_crate = crate_aliases
+                        .get(root)
+                        .cloned()
+                        .unwrap_or_else(|| root.clone());
+                    let item_name = segments.last().cloned().unwrap_or_else(|| root.clone());
+                    aliases.insert(local_name, (origin_crate, item_name));
+                }
+            }
+            Item::Mod(m) => {
+                if let Some((_, inner)) = &m.content {
+                    collect_item_aliases_inner(inner, crate_aliases, aliases);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Flattens a use tree into `(full_path_segments, locally_bound_name)`
+/// pairs - unlike `flatten_use_tree`, this keeps the crate-root segment
+/// and follows `as` renames, which is what alias resolution needs. Glob
+/// imports bind no nameable local identifier, so they're dropped.
+fn flatten_use_tree_full(tree: &UseTree, prefix: Vec<String>) -> Vec<(Vec<String>, String)> {
+    match tree {
+        UseTree::Path(p) => {
+            let mut next = prefix;
+            next.push(p.ident.to_string());
+            flatten_use_tree_full(&p.tree, next)
+        }
+        UseTree::Name(n) => {
+            let mut full = prefix;
+            full.push(n.ident.to_string());
+            vec![(full, n.ident.to_string())]
+        }
+        UseTree::Rename(r) => {
+            let mut full = prefix;
+            full.push(r.ident.to_string());
+            vec![(full, r.rename.to_string())]
+        }
+        UseTree::Glob(_) => Vec::new(),
+        UseTree::Group(g) => g
+            .items
+            .iter()
+            .flat_map(|t| flatten_use_tree_full(t, prefix.clone()))
+            .collect(),
+    }
+}
+
+/// Dependency renames declared in `project_root`'s `Cargo.toml` via
+/// `package = "real_name"` - the key actually written in source (`use
+/// alias::...`) mapped to the crate it really resolves to.
+fn read_dependency_renames(project_root: &Path) -> HashMap<String, String> {
+    #[derive(Debug, Deserialize)]
+    struct DepEntry {
+        package: Option<String>,
+    }
+
+    #[derive(Debug, Deserialize)]
+    #[serde(untagged)]
+    enum DepValue {
+        Detailed(DepEntry),
+        Other(toml::Value),
+    }
+
+    #[derive(Debug, Deserialize)]
+    struct CargoToml {
+        dependencies: Option<HashMap<String, DepValue>>,
+        #[serde(rename = "dev-dependencies")]
+        dev_dependencies: Option<HashMap<String, DepValue>>,
+        #[serde(rename = "build-dependencies")]
+        build_dependencies: Option<HashMap<String, DepValue>>,
+    }
+
+    let toml_path = project_root.join("Cargo.toml");
+    let Ok(contents) = std::fs::read_to_string(&toml_path) else {
+        return HashMap::new();
+    };
+    let Ok(manifest) = toml::from_str::<CargoToml>(&contents) else {
+        return HashMap::new();
+    };
+
+    let mut renames = HashMap::new();
+    for table in [
+        manifest.dependencies,
+        manifest.dev_dependencies,
+        manifest.build_dependencies,
+    ]
+    .into_iter()
+    .flatten()
+    {
+        for (alias, value) in table {
+            if let DepValue::Detailed(DepEntry {
+                package: Some(real_name),
+            }) = value
+            {
+                renames.insert(alias, real_name);
+            }
+        }
+    }
+    renames
+}
+
+/// Walks a file's expression bodies for macro invocations (`foo::bar!()`)
+/// resolving to the target crate, emitting `UsageKind::MacroCall` sites.
+struct MacroCallVisitor<'a> {
+    ctx: FileCtx<'a>,
+    lines: &'a [&'a str],
+    sites: &'a mut Vec<UsageSite>,
+}
+
+impl<'ast> Visit<'ast> for MacroCallVisitor<'_> {
+    fn visit_macro(&mut self, node: &'ast syn::Macro) {
+        let segments: Vec<String> = node
+            .path
+            .segments
+            .iter()
+            .map(|s| s.ident.to_string())
+            .collect();
+        if let Some((origin_crate, item_name)) =
+            resolve_path(&segments, self.ctx.crate_aliases, self.ctx.item_aliases)
+        {
+            if origin_crate == self.ctx.crate_name {
+                let line =
+                    find_line_containing(self.lines, &format!("{}!", item_name)).unwrap_or(1);
+                self.sites.push(UsageSite {
+                    file: self.ctx.file.to_path_buf(),
+                    line,
+                    path: format!("{}!", item_name),
+                    kind: UsageKind::MacroCall,
+                    context: None,
+                });
+            }
+        }
+        syn::visit::visit_macro(self, node);
+    }
+}
+
 /// Find the line number (1-indexed) containing a substring
 fn find_line_containing(lines: &[&str], needle: &str) -> Option<usize> {
     lines